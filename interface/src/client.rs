@@ -0,0 +1,40 @@
+//! Cross-contract client for the read and relier entry points of AnchorKit.
+
+use soroban_sdk::{contractclient, Address, BytesN, Env, Symbol, Vec};
+
+use crate::types::{Attestation, AttestationView};
+
+/// Subset of the AnchorKit contract interface that consumer contracts call.
+///
+/// `#[contractclient]` generates [`AnchorKitClient`]; construct it with the
+/// deployed AnchorKit contract address. Use `supports` to probe optional
+/// interface groups before calling into them.
+#[contractclient(name = "AnchorKitClient")]
+pub trait AnchorKitInterface {
+    fn supports(env: Env, interface: Symbol) -> bool;
+    fn get_interfaces(env: Env) -> Vec<Symbol>;
+    fn is_attestor(env: Env, attestor: Address) -> bool;
+    fn get_attestation(env: Env, id: u64) -> Option<Attestation>;
+    fn get_attestation_v2(env: Env, id: u64) -> Option<AttestationView>;
+    fn is_valid(env: Env, id: u64) -> bool;
+    fn verify_for_holder(env: Env, id: u64, holder: Address) -> bool;
+    fn verify(env: Env, id: u64, expected_hash: BytesN<32>) -> bool;
+    fn verify_for_subject(env: Env, subject: Address, schema_id: u64) -> bool;
+    fn check_policy(env: Env, policy_id: u64, subject: Address) -> bool;
+    fn get_primary(env: Env, subject: Address, schema_id: u64) -> Option<u64>;
+    fn pin_attestation(env: Env, relier: Address, id: u64);
+    fn unpin_attestation(env: Env, relier: Address, id: u64);
+    fn get_relier_notices(env: Env, relier: Address) -> Vec<u64>;
+    fn take_relier_notices(env: Env, relier: Address) -> Vec<u64>;
+}
+
+/// Entry point AnchorKit calls on the arbitration court it escalates
+/// challenges to.
+///
+/// `open_case` tells the court that challenge `challenge_id` on the AnchorKit
+/// contract `registry` awaits its ruling. The court answers later by calling
+/// `rule_on_challenge(challenge_id, upheld)` on `registry`.
+#[contractclient(name = "ArbitrationCourtClient")]
+pub trait ArbitrationCourtInterface {
+    fn open_case(env: Env, registry: Address, challenge_id: u64, evidence_hash: BytesN<32>);
+}
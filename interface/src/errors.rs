@@ -0,0 +1,250 @@
+use soroban_sdk::contracterror;
+
+// ---------------------------------------------------------------------------
+// ErrorCode — the canonical list of all error kinds (replaces the old Error enum)
+// ---------------------------------------------------------------------------
+
+/// Numeric error codes for every AnchorKit error kind.
+///
+/// The `#[contracterror]` attribute keeps Soroban on-chain compatibility.
+///
+/// ## Migration note
+///
+/// Prior to this fix the codes were non-contiguous: values 1-19 were followed
+/// by a gap (20-47) and then 48-54, with `NotInitialized` at 101.
+/// All codes have been renumbered to the contiguous range **1-30**.
+/// Clients that matched on raw numeric values must update their mappings:
+///
+/// | Old code | New code | Name                    |
+/// |----------|----------|-------------------------|
+/// | 1        | 1        | AlreadyInitialized      |
+/// | 2        | 2        | AttestorAlreadyRegistered |
+/// | 3        | 3        | AttestorNotRegistered   |
+/// | 4        | 4        | UnauthorizedAttestor    |
+/// | 5        | 5        | InvalidTimestamp        |
+/// | 6        | 6        | ReplayAttack            |
+/// | 7        | 7        | InvalidQuote            |
+/// | 8        | 8        | InvalidServiceType      |
+/// | 9        | 9        | InvalidTransactionIntent |
+/// | 10       | 10       | StaleQuote              |
+/// | 11       | 11       | ComplianceNotMet        |
+/// | 12       | 12       | InvalidEndpointFormat   |
+/// | 13       | 13       | NoQuotesAvailable       |
+/// | 14       | 14       | ServicesNotConfigured   |
+/// | 15       | 15       | ValidationError         |
+/// | 16       | 16       | RateLimitExceeded       |
+/// | 17       | 17       | AttestationNotFound     |
+/// | 18       | 18       | InvalidSep10Token       |
+/// | 19       | 19       | StorageCorrupted        |
+/// | 48       | 20       | CacheExpired            |
+/// | 49       | 21       | CacheNotFound           |
+/// | 51       | 22       | AuditLogMaxSizeInvalid  |
+/// | 52       | 23       | UnauthorizedProposeAdmin |
+/// | 53       | 24       | NoPendingAdmin          |
+/// | 54       | 25       | NotPendingAdmin         |
+/// | 101      | 26       | NotInitialized          |
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ErrorCode {
+    AlreadyInitialized = 1,
+    AttestorAlreadyRegistered = 2,
+    AttestorNotRegistered = 3,
+    UnauthorizedAttestor = 4,
+    InvalidTimestamp = 5,
+    ReplayAttack = 6,
+    InvalidQuote = 7,
+    InvalidServiceType = 8,
+    InvalidTransactionIntent = 9,
+    StaleQuote = 10,
+    ComplianceNotMet = 11,
+    InvalidEndpointFormat = 12,
+    NoQuotesAvailable = 13,
+    ServicesNotConfigured = 14,
+    ValidationError = 15,
+    RateLimitExceeded = 16,
+    AttestationNotFound = 17,
+    InvalidSep10Token = 18,
+    StorageCorrupted = 19,
+    CacheExpired = 48,
+    CacheNotFound = 49,
+    AuditLogMaxSizeInvalid = 51,
+    PendingAdminAlreadyExists = 52,
+    NoPendingAdmin = 53,
+    NotPendingAdmin = 54,
+    SessionNotFound = 55,
+    SessionExpired = 56,
+    MissingSigningKey = 57,
+    OrgNotFound = 58,
+    AttestorNotInOrg = 59,
+    OrgQuotaExceeded = 60,
+    InvalidPolicy = 61,
+    PolicyNotFound = 62,
+    BundleNotFound = 63,
+    BundleClaimNotFound = 64,
+    NotAttestationSubject = 65,
+    IssuerNotAuthorizedForSchema = 66,
+    AttestationPinned = 67,
+    SchemaNotFound = 68,
+    NotSchemaAdmin = 69,
+    ReadOnlyMode = 70,
+    InvalidWeight = 71,
+    SchemaGrantExpired = 72,
+    SchemaCapReached = 73,
+    AttestorSuspended = 74,
+    FieldTooLarge = 75,
+    AttestationRevoked = 76,
+    NotInitialized = 101,
+}
+
+impl ErrorCode {
+    /// Returns the canonical human-readable message for this error code.
+    pub fn default_message(&self) -> &'static str {
+        match self {
+            ErrorCode::AlreadyInitialized => "Contract is already initialized",
+            ErrorCode::AttestorAlreadyRegistered => "Attestor is already registered",
+            ErrorCode::AttestorNotRegistered => "Attestor is not registered",
+            ErrorCode::UnauthorizedAttestor => "Attestor is not authorized",
+            ErrorCode::InvalidTimestamp => "Timestamp is invalid",
+            ErrorCode::ReplayAttack => "Replay attack detected",
+            ErrorCode::InvalidQuote => "Quote is invalid",
+            ErrorCode::InvalidServiceType => "Service type is invalid",
+            ErrorCode::InvalidTransactionIntent => "Transaction intent is invalid",
+            ErrorCode::StaleQuote => "Quote has expired",
+            ErrorCode::ComplianceNotMet => "Compliance requirements not met",
+            ErrorCode::InvalidEndpointFormat => "Endpoint format is invalid",
+            ErrorCode::NoQuotesAvailable => "No quotes are available",
+            ErrorCode::ServicesNotConfigured => "Services are not configured",
+            ErrorCode::ValidationError => "Response schema validation failed",
+            ErrorCode::RateLimitExceeded => "Rate limit exceeded",
+            ErrorCode::NotInitialized => "Contract is not initialized",
+            ErrorCode::AttestationNotFound => "Attestation not found",
+            ErrorCode::InvalidSep10Token => "SEP-10 JWT is missing, expired, or invalid",
+            ErrorCode::StorageCorrupted => "On-chain storage entry is corrupted or unreadable",
+            ErrorCode::CacheExpired => "Cache entry has expired",
+            ErrorCode::CacheNotFound => "Cache entry not found",
+            ErrorCode::AuditLogMaxSizeInvalid => "max_audit_log_size must be at least 1",
+            ErrorCode::PendingAdminAlreadyExists => "An admin transfer is already pending",
+            ErrorCode::NoPendingAdmin => "No pending admin transfer found",
+            ErrorCode::NotPendingAdmin => "Caller is not the pending admin",
+            ErrorCode::SessionNotFound => "Session not found",
+            ErrorCode::SessionExpired => "Session has expired",
+            ErrorCode::MissingSigningKey => "Anchor TOML does not publish a signing key",
+            ErrorCode::OrgNotFound => "Organization not found",
+            ErrorCode::AttestorNotInOrg => "Attestor does not belong to this organization",
+            ErrorCode::OrgQuotaExceeded => "Organization issuance quota exceeded",
+            ErrorCode::InvalidPolicy => "Policy definition is invalid",
+            ErrorCode::PolicyNotFound => "Policy not found",
+            ErrorCode::BundleNotFound => "Attestation bundle not found",
+            ErrorCode::BundleClaimNotFound => "Bundle claim index out of range",
+            ErrorCode::NotAttestationSubject => "Caller is not the subject of this attestation",
+            ErrorCode::IssuerNotAuthorizedForSchema => "Issuer is not authorized for this schema",
+            ErrorCode::AttestationPinned => "Attestation is pinned by one or more reliers",
+            ErrorCode::SchemaNotFound => "Schema not found",
+            ErrorCode::NotSchemaAdmin => "Caller lacks admin rights over this schema",
+            ErrorCode::ReadOnlyMode => "Contract is in read-only sunset mode",
+            ErrorCode::InvalidWeight => "Confidence weight exceeds 10000 basis points",
+            ErrorCode::SchemaGrantExpired => "Attestor's grant for this schema has expired",
+            ErrorCode::SchemaCapReached => "Schema has reached its maximum number of attestations",
+            ErrorCode::AttestorSuspended => "Attestor's trust tier is suspended",
+            ErrorCode::FieldTooLarge => "Field exceeds the configured size bound",
+            ErrorCode::AttestationRevoked => "Attestation has been revoked",
+        }
+    }
+
+}
+
+// ---------------------------------------------------------------------------
+// ErrorCodeExt — codes added once ErrorCode was full
+// ---------------------------------------------------------------------------
+
+/// Error codes from 77 onwards.
+///
+/// The contract spec limits a single `#[contracterror]` enum to 50 cases,
+/// which `ErrorCode` has used up. Numbering continues where `ErrorCode`
+/// stops, so a numeric code still identifies exactly one error kind.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ErrorCodeExt {
+    InvalidSignature = 77,
+    AttestationExpired = 78,
+    SchemaAlreadyRegistered = 79,
+    SubsystemPaused = 80,
+    ContractPaused = 81,
+    MissingRole = 82,
+    MovedToSuccessor = 83,
+    IssuerTierTooLow = 84,
+    SubjectConsentRequired = 85,
+    FeePaymentFailed = 86,
+    InsufficientStake = 87,
+    StakeLocked = 88,
+    ChallengeNotFound = 89,
+    ChallengeResolved = 90,
+    AttestationDisputed = 91,
+    ProposalNotFound = 92,
+    AlreadyCosigned = 93,
+    InvalidStatusTransition = 94,
+    SchemaNotAllowed = 95,
+    HashAlreadyUsedByIssuer = 96,
+    SubjectNotEligible = 97,
+    AttestorDeactivated = 98,
+    NoMigrationPath = 99,
+    AttestationLimitReached = 100,
+    OrgAlreadyExists = 102,
+    ArbitrationUnavailable = 103,
+    ChallengeEscalated = 104,
+    AppealClosed = 105,
+    FeeEscrowLocked = 106,
+    FeeNotEscrowed = 107,
+    RelayerNotAllowed = 108,
+    AttestationNotValid = 109,
+    PinLimitReached = 110,
+    ChallengeCooldown = 111,
+    MigrationPending = 112,
+    DisputePending = 113,
+}
+
+impl ErrorCodeExt {
+    /// Returns the canonical human-readable message for this error code.
+    pub fn default_message(&self) -> &'static str {
+        match self {
+            ErrorCodeExt::InvalidSignature => "Attestation signature is malformed or does not verify",
+            ErrorCodeExt::AttestationExpired => "Attestation expiry is already in the past",
+            ErrorCodeExt::SchemaAlreadyRegistered => "A schema with this definition hash is already registered",
+            ErrorCodeExt::SubsystemPaused => "Subsystem is paused",
+            ErrorCodeExt::ContractPaused => "Contract is paused",
+            ErrorCodeExt::MissingRole => "Caller does not hold the required role",
+            ErrorCodeExt::MovedToSuccessor => "Contract has moved; see successor()",
+            ErrorCodeExt::IssuerTierTooLow => "Issuer's trust tier is below the schema minimum",
+            ErrorCodeExt::SubjectConsentRequired => "Subject has not consented to attestations from this issuer",
+            ErrorCodeExt::FeePaymentFailed => "Attestation fee could not be transferred",
+            ErrorCodeExt::InsufficientStake => "Stake is below the configured minimum",
+            ErrorCodeExt::StakeLocked => "Stake stays locked until the attestor's wind-down completes",
+            ErrorCodeExt::ChallengeNotFound => "Challenge not found",
+            ErrorCodeExt::ChallengeResolved => "Challenge has already been resolved",
+            ErrorCodeExt::AttestationDisputed => "Attestation already has an open or successful challenge",
+            ErrorCodeExt::ProposalNotFound => "Attestation proposal not found or expired",
+            ErrorCodeExt::AlreadyCosigned => "Attestor has already approved this proposal",
+            ErrorCodeExt::InvalidStatusTransition => "Attestation status cannot move to the requested state",
+            ErrorCodeExt::SchemaNotAllowed => "Attestor is not allowed to issue under this schema",
+            ErrorCodeExt::HashAlreadyUsedByIssuer => "Payload hash already used by this issuer",
+            ErrorCodeExt::SubjectNotEligible => "Subject does not hold enough of the gate token",
+            ErrorCodeExt::AttestorDeactivated => "Attestor has been deactivated",
+            ErrorCodeExt::NoMigrationPath => "No storage migration path from the stored layout version",
+            ErrorCodeExt::AttestationLimitReached => "Attestation ID space exhausted",
+            ErrorCodeExt::OrgAlreadyExists => "Organization ID is already taken",
+            ErrorCodeExt::ArbitrationUnavailable => "No arbitration court is configured",
+            ErrorCodeExt::ChallengeEscalated => "Challenge awaits the arbitration court's ruling",
+            ErrorCodeExt::AppealClosed => "No appeal is open against this revocation",
+            ErrorCodeExt::FeeEscrowLocked => "Escrowed fee cannot be released yet",
+            ErrorCodeExt::FeeNotEscrowed => "No escrowed fee is held for this attestation",
+            ErrorCodeExt::RelayerNotAllowed => "Relayer is not on the relayer allowlist",
+            ErrorCodeExt::AttestationNotValid => "Attestation is not currently valid",
+            ErrorCodeExt::PinLimitReached => "Attestation already holds the maximum number of pins",
+            ErrorCodeExt::ChallengeCooldown => "Attestation was recently upheld against a challenge",
+            ErrorCodeExt::MigrationPending => "Stored data is at an older layout version; run migrate() first",
+            ErrorCodeExt::DisputePending => "Attestation has an unsettled challenge or an open appeal window",
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Event bodies consumers decode when indexing attestation activity.
+
+use soroban_sdk::{contracttype, Address, Bytes, Symbol};
+
+use crate::types::{AttestationData, Status};
+
+/// Layout version carried in the body of `attest/recorded` and
+/// `attest/revoked` events.
+pub const EVENT_VERSION: u32 = 2;
+
+/// Body of the `("attest", "recorded", issuer, subject)` event at `Standard`
+/// verbosity.
+#[contracttype]
+#[derive(Clone)]
+pub struct AttestEvent {
+    /// [`EVENT_VERSION`] of the layout this event was published with.
+    pub version: u32,
+    pub id: u64,
+    pub payload_hash: Bytes,
+    pub timestamp: u64,
+    /// Time at which an embargoed attestation becomes active; `0` if it is
+    /// active immediately.
+    pub not_before: u64,
+    /// Inline claim content, or `HashOnly`; see [`AttestationData`].
+    pub data: AttestationData,
+    /// Off-chain metadata URI, if any.
+    pub uri: Option<Bytes>,
+}
+
+/// Companion event published under `("subject", <topic>)` for subjects that
+/// registered a topic tag. `kind` mirrors the second topic of the primary
+/// event (`recorded`, `revoked`, `erased`, `primary`).
+#[contracttype]
+#[derive(Clone)]
+pub struct SubjectTopicEvent {
+    pub kind: Symbol,
+    pub subject: Address,
+    pub attestation_id: u64,
+}
+
+/// Body of the `("attest", "status", id)` event.
+#[contracttype]
+#[derive(Clone)]
+pub struct StatusChanged {
+    pub from: Status,
+    pub to: Status,
+}
+
+/// Body of the `("attest", "amended", id)` event.
+#[contracttype]
+#[derive(Clone)]
+pub struct AttestationAmended {
+    /// Version the amendment created.
+    pub version: u32,
+    pub old_hash: Bytes,
+    pub new_hash: Bytes,
+}
+
+/// Body of the `("attest", "uri", id)` event.
+#[contracttype]
+#[derive(Clone)]
+pub struct UriUpdated {
+    pub old_uri: Option<Bytes>,
+    pub new_uri: Option<Bytes>,
+}
+
+/// Body of the `("attest", "revoked", issuer, subject)` event.
+#[contracttype]
+#[derive(Clone)]
+pub struct AttestationRevoked {
+    /// [`EVENT_VERSION`] of the layout this event was published with.
+    pub version: u32,
+    pub id: u64,
+    /// Issuer or admin that performed the revocation.
+    pub revoked_by: Address,
+    pub revoked_at: u64,
+}
@@ -0,0 +1,27 @@
+#![no_std]
+//! Consumer-facing interface of the AnchorKit contract.
+//!
+//! Contracts that read or pin AnchorKit attestations depend on this crate
+//! instead of the full `anchorkit` implementation. It holds the attestation
+//! record and validity types, [`ErrorCode`], the event bodies,
+//! [`AnchorKitClient`] for cross-contract calls, and the callback interface
+//! implemented by arbitration courts ([`ArbitrationCourtInterface`]). The `anchorkit` crate
+//! re-exports everything here, so both sides always agree on the encoding.
+
+mod client;
+mod errors;
+mod events;
+mod types;
+
+pub use client::{
+    AnchorKitClient, AnchorKitInterface, ArbitrationCourtClient, ArbitrationCourtInterface,
+};
+pub use errors::{ErrorCode, ErrorCodeExt};
+pub use events::{
+    AttestEvent, AttestationAmended, AttestationRevoked, StatusChanged, SubjectTopicEvent, UriUpdated, EVENT_VERSION,
+};
+pub use types::{
+    Attestation, AttestationData, AttestationInput, AttestationReceipt, AttestationStatus,
+    AttestationValidity, AttestationView, ChainLink, IssuerSnapshot, ProofBundle, Status,
+    SubjectKind, MAX_INLINE_DATA_LEN, MAX_URI_LEN, MAX_WEIGHT_BPS,
+};
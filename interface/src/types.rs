@@ -0,0 +1,398 @@
+//! Attestation records and the views consumers read them through.
+
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Symbol};
+
+use crate::ErrorCode;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Attestation {
+    pub id: u64,
+    pub issuer: Address,
+    pub subject: Address,
+    pub timestamp: u64,
+    pub payload_hash: Bytes,
+    pub signature: Bytes,
+    /// Set to `true` when the issuer attestor has been revoked after this
+    /// attestation was submitted. Historical attestations are preserved for
+    /// audit purposes; callers should treat `issuer_revoked = true` as a
+    /// signal that the issuer's authority has been withdrawn.
+    pub issuer_revoked: bool,
+    /// Organization the issuing attestor belonged to at submission time, if any.
+    pub org_id: Option<u64>,
+    /// Schema the attestation was issued under, if any.
+    pub schema_id: Option<u64>,
+    /// Ledger sequence at which the contract recorded the attestation.
+    pub ledger: u32,
+    /// Issuer's revocation epoch for `schema_id` at submission time.
+    pub schema_epoch: u32,
+    /// `false` (the default) binds the claim strictly to `subject`; `true`
+    /// lets it be honored for the subject's linked successor addresses.
+    pub transferable: bool,
+    /// Activation time (Unix seconds). Until then the attestation is hidden
+    /// from subject-facing list views and reported as `Pending`. `0` means
+    /// active immediately.
+    pub not_before: u64,
+    /// Market the credential was issued for (e.g. `US`, `EU`), if any.
+    pub jurisdiction: Option<Symbol>,
+    /// Issuer's confidence in the claim, in basis points (`0..=MAX_WEIGHT_BPS`).
+    /// Binary attestations carry `MAX_WEIGHT_BPS`.
+    pub weight_bps: u32,
+    /// Time (Unix seconds) the issuer or admin revoked this attestation via
+    /// `revoke_attestation`; `None` while it has not been revoked.
+    pub revoked_at: Option<u64>,
+    /// Quantitative result of the claim (e.g. an audit score); `None` for
+    /// binary attestations. Counted in the schema's value buckets when the
+    /// schema enables them.
+    pub value: Option<u32>,
+    /// Time (Unix seconds) from which the attestation is reported as
+    /// expired; `0` means it never expires on its own.
+    pub expires_at: u64,
+    /// What `subject` identifies.
+    pub subject_kind: SubjectKind,
+    /// Earlier attestation about the same subject that this one renews or
+    /// amends, if any. Walk the links with `get_attestation_chain`.
+    pub ref_id: Option<u64>,
+    /// The claim itself when it was recorded inline; otherwise `HashOnly`
+    /// and `payload_hash` is all that is on-chain.
+    pub data: AttestationData,
+    /// Stored lifecycle state. Changes with revocations and challenges;
+    /// expiry and supersession are applied by `refresh_status`.
+    pub status: Status,
+    /// Starts at `1` and increases with each `amend_attestation`. Earlier
+    /// versions stay readable through `get_attestation_version`.
+    pub version: u32,
+    /// Off-chain metadata (JSON over IPFS or HTTPS) describing the claim,
+    /// at most [`MAX_URI_LEN`] bytes. The issuer may change it with
+    /// `set_attestation_uri`.
+    pub uri: Option<Bytes>,
+}
+
+/// Largest [`AttestationData::Inline`] claim accepted, in bytes.
+pub const MAX_INLINE_DATA_LEN: u32 = 256;
+
+/// Longest metadata URI accepted on an attestation, in bytes.
+pub const MAX_URI_LEN: u32 = 256;
+
+/// Claim content carried by an attestation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AttestationData {
+    /// Only the attestation's `payload_hash` is on-chain.
+    HashOnly,
+    /// The claim itself, at most [`MAX_INLINE_DATA_LEN`] bytes.
+    Inline(Bytes),
+}
+
+/// What an attestation's `subject` address identifies.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SubjectKind {
+    /// An account or contract acting as a holder (default).
+    Account,
+    /// A Stellar asset, identified by its Stellar Asset Contract (or other
+    /// token contract) address. As asserted by the issuer; not verified.
+    Asset,
+}
+
+/// Full confidence for [`Attestation::weight_bps`].
+pub const MAX_WEIGHT_BPS: u32 = 10_000;
+
+/// Proof-of-anchoring artifact returned by `record_attestation`.
+///
+/// `chain_hash` is the contract's running hash after this attestation was
+/// appended: `sha256(previous_chain_hash || id (8-byte BE) || payload_hash)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationReceipt {
+    pub id: u64,
+    pub ledger: u32,
+    pub chain_hash: BytesN<32>,
+    pub fee_paid: i128,
+}
+
+/// Caller-supplied fields of a new attestation.
+///
+/// Contract-assigned fields (`id`, `ledger`, `org_id`, ...) are deliberately
+/// absent. Build with [`AttestationInput::new`] and the `with_*` methods.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationInput {
+    pub subject: Address,
+    pub timestamp: u64,
+    pub payload_hash: Bytes,
+    pub signature: Bytes,
+    pub schema_id: Option<u64>,
+    pub transferable: bool,
+    pub not_before: u64,
+    pub jurisdiction: Option<Symbol>,
+    pub weight_bps: u32,
+    pub value: Option<u32>,
+    pub expires_at: u64,
+    pub subject_kind: SubjectKind,
+    pub ref_id: Option<u64>,
+    /// Claim to store on-chain; its SHA-256 must equal `payload_hash`.
+    pub inline_data: Option<Bytes>,
+    /// Off-chain metadata URI, at most [`MAX_URI_LEN`] bytes.
+    pub uri: Option<Bytes>,
+}
+
+impl AttestationInput {
+    pub fn new(subject: Address, timestamp: u64, payload_hash: Bytes, signature: Bytes) -> Self {
+        AttestationInput {
+            subject,
+            timestamp,
+            payload_hash,
+            signature,
+            schema_id: None,
+            transferable: false,
+            not_before: 0,
+            jurisdiction: None,
+            weight_bps: MAX_WEIGHT_BPS,
+            value: None,
+            expires_at: 0,
+            subject_kind: SubjectKind::Account,
+            ref_id: None,
+            inline_data: None,
+            uri: None,
+        }
+    }
+
+    pub fn with_schema(mut self, schema_id: u64) -> Self {
+        self.schema_id = Some(schema_id);
+        self
+    }
+
+    /// Allow the claim to be honored after a subject-successor link.
+    pub fn with_transferable(mut self, transferable: bool) -> Self {
+        self.transferable = transferable;
+        self
+    }
+
+    /// Embargo the attestation until `not_before` (Unix seconds).
+    pub fn with_not_before(mut self, not_before: u64) -> Self {
+        self.not_before = not_before;
+        self
+    }
+
+    /// Tag the attestation with a jurisdiction code and index it under it.
+    pub fn with_jurisdiction(mut self, jurisdiction: Symbol) -> Self {
+        self.jurisdiction = Some(jurisdiction);
+        self
+    }
+
+    /// Record a probabilistic claim with confidence `weight_bps`.
+    pub fn with_weight(mut self, weight_bps: u32) -> Self {
+        self.weight_bps = weight_bps;
+        self
+    }
+
+    /// Attach a quantitative result (e.g. an audit score) to the claim.
+    pub fn with_value(mut self, value: u32) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Let the attestation expire at `expires_at` (Unix seconds).
+    pub fn with_expiry(mut self, expires_at: u64) -> Self {
+        self.expires_at = expires_at;
+        self
+    }
+
+    /// Mark `subject` as a token asset (SAC address) rather than a holder,
+    /// so the attestation is also indexed by asset.
+    pub fn with_asset_subject(mut self) -> Self {
+        self.subject_kind = SubjectKind::Asset;
+        self
+    }
+
+    /// Link the claim to attestation `ref_id` (a renewal or amendment of it),
+    /// which must share this input's issuer and subject. The signature must
+    /// then cover `compute_ref_binding_hash(payload_hash, ref_id)` instead
+    /// of the bare payload hash.
+    pub fn with_ref(mut self, ref_id: u64) -> Self {
+        self.ref_id = Some(ref_id);
+        self
+    }
+
+    /// Store `data` on-chain with the record. `payload_hash` must be its
+    /// SHA-256 digest so the issuer's signature covers the content.
+    pub fn with_inline_data(mut self, data: Bytes) -> Self {
+        self.inline_data = Some(data);
+        self
+    }
+
+    /// Point the attestation at off-chain metadata (IPFS or HTTPS JSON).
+    pub fn with_uri(mut self, uri: Bytes) -> Self {
+        self.uri = Some(uri);
+        self
+    }
+
+    /// Structural checks that need no contract state.
+    pub fn validate(&self) -> Result<(), ErrorCode> {
+        if self.timestamp == 0 {
+            return Err(ErrorCode::InvalidTimestamp);
+        }
+        if self.expires_at != 0 && self.expires_at <= self.timestamp {
+            return Err(ErrorCode::InvalidTimestamp);
+        }
+        if self.payload_hash.is_empty() {
+            return Err(ErrorCode::ValidationError);
+        }
+        if self.weight_bps > MAX_WEIGHT_BPS {
+            return Err(ErrorCode::InvalidWeight);
+        }
+        Ok(())
+    }
+}
+
+/// Canonical lifecycle state stored on an [`Attestation`]. Every change is
+/// announced with a `StatusChanged` event.
+///
+/// Expiry is only persisted once `refresh_status` runs, but
+/// [`AttestationValidity`] applies it at read time, so validity never
+/// reports a lapsed record as live while its stored status catches up.
+/// Supersession depends on the successor attestation staying valid, so it
+/// is only ever derived at read time and never stored.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    Active,
+    /// Revoked by its issuer or the admin, or struck down by a challenge.
+    Revoked,
+    Expired,
+    /// Under an open challenge.
+    Disputed,
+    /// The subject holds a valid attestation under the schema's successor.
+    /// Read-time only; a stored `Superseded` predates that rule.
+    Superseded,
+}
+
+impl Status {
+    /// Whether the lifecycle allows moving from `self` to `to`. `Revoked` is
+    /// terminal; an expired record can still be revoked. A stored
+    /// `Superseded` may return to `Active`. The one way back from `Revoked`
+    /// is outside this table: a successful appeal against an admin
+    /// revocation reinstates the record as `Active`.
+    pub fn can_transition_to(self, to: Status) -> bool {
+        use Status::*;
+        matches!(
+            (self, to),
+            (Active, Revoked | Expired | Disputed | Superseded)
+                | (Disputed, Active | Revoked | Expired)
+                | (Superseded, Active | Revoked | Expired)
+                | (Expired, Revoked)
+        )
+    }
+}
+
+/// Read-time status of an attestation record: its lifecycle [`Status`]
+/// (`Revoked`, `Superseded`, `Disputed`) refined with the conditions that
+/// sit outside the lifecycle, such as issuer or schema revocation and
+/// embargo.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AttestationStatus {
+    Active,
+    /// Individually revoked by its issuer or the admin.
+    Revoked,
+    /// Recorded with a `not_before` that has not been reached yet.
+    Pending,
+    /// The issuing attestor has been revoked since submission.
+    IssuerRevoked,
+    /// The issuer revoked everything under the attestation's schema.
+    SchemaRevoked,
+    /// The schema has been superseded and the subject already holds an
+    /// active attestation under the successor schema.
+    Superseded,
+    /// Under an open challenge. Still counts as valid until resolved, so a
+    /// bond alone cannot take a credential offline.
+    Disputed,
+    /// A challenge against it was resolved in the challenger's favor.
+    Invalidated,
+}
+
+/// Validity of an attestation computed at read time.
+///
+/// `is_valid` is `true` only when the record's lifecycle [`Status`], with
+/// due expiry and supersession applied, is `Active` or `Disputed`, its
+/// `status` is `Active` or `Disputed` (so past any `not_before` embargo and
+/// not issuer- or schema-revoked), it is not invalidated by an epoch cutoff,
+/// and its issuer is still a registered attestor meeting any tier minimum
+/// its schema enforces at verification. Where the stored [`Status`] and a
+/// time-based check disagree, the lifecycle status wins.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationValidity {
+    pub status: AttestationStatus,
+    /// Lifecycle status is `Expired`, or the attestation is past its own
+    /// `expires_at` or the contract's max age.
+    pub expired: bool,
+    /// Timestamp predates the global or per-issuer invalidation epoch.
+    pub invalidated: bool,
+    pub issuer_registered: bool,
+    /// The schema enforces its minimum issuer tier at verification time and
+    /// the issuer currently sits below it.
+    pub issuer_below_min_tier: bool,
+    pub is_valid: bool,
+    /// Issuer confidence copied from the attestation; `is_valid` does not
+    /// depend on it, relying parties apply their own threshold.
+    pub weight_bps: u32,
+    /// Last ledger through which a positive result may be cached: the
+    /// earliest of the attestation's `expires_at`, max-age expiry, the
+    /// issuer's schema grant expiry, and the
+    /// attestation entry's guaranteed TTL. Time-based deadlines are converted
+    /// at ~5 s/ledger. Revocations and epoch changes can still end validity
+    /// earlier. `0` when `is_valid` is `false`.
+    pub valid_until_ledger: u32,
+}
+
+/// An attestation together with its computed validity.
+#[contracttype]
+#[derive(Clone)]
+pub struct AttestationView {
+    pub attestation: Attestation,
+    pub validity: AttestationValidity,
+}
+
+/// Issuer state as of the query, not as of issuance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssuerSnapshot {
+    pub registered: bool,
+    pub revoked: bool,
+    pub tier: u32,
+    pub org_id: Option<u64>,
+    /// ed25519 key the issuer signs payloads with; all zeroes when none is
+    /// registered or the issuer signs with `secp256k1_key`.
+    pub signing_key: BytesN<32>,
+    /// 65-byte uncompressed secp256k1 key the issuer signs payloads with;
+    /// empty when it does not sign with one.
+    pub secp256k1_key: Bytes,
+}
+
+/// Position of an attestation in the contract's running hash chain.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChainLink {
+    /// 1-based issuance sequence number across the whole contract; `0` for
+    /// attestations recorded before chain links were stored.
+    pub sequence: u64,
+    /// Chain head before this attestation was appended.
+    pub prev_hash: BytesN<32>,
+    /// Chain head after it; the `chain_hash` of its receipt.
+    pub hash: BytesN<32>,
+}
+
+/// Everything a wallet needs to render one credential, from a single call.
+#[contracttype]
+#[derive(Clone)]
+pub struct ProofBundle {
+    pub attestation: Attestation,
+    pub issuer: IssuerSnapshot,
+    /// Definition hash of the attestation's registered schema; all zeroes
+    /// when it has none.
+    pub schema_hash: BytesN<32>,
+    pub validity: AttestationValidity,
+    pub chain: ChainLink,
+}
@@ -0,0 +1,105 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::errors::ErrorCode;
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+
+const NOW: u64 = 1_700_000_000;
+
+struct Fixture<'a> {
+    client: AnchorKitContractClient<'a>,
+    attestor: Address,
+    sk: SigningKey,
+    id: u64,
+}
+
+fn setup(env: &Env) -> Fixture<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    let payload = Bytes::from_array(env, &[1u8; 32]);
+    let sig = sign_payload(env, &sk, &payload);
+    let id = client.submit_attestation(&attestor, &Address::generate(env), &NOW, &payload, &sig);
+    Fixture { client, attestor, sk, id }
+}
+
+fn amend(env: &Env, f: &Fixture, seed: u8) -> u32 {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, &f.sk, &payload);
+    f.client.amend_attestation(&f.attestor, &f.id, &payload, &sig)
+}
+
+#[test]
+fn test_amend_keeps_history() {
+    let env = Env::default();
+    let f = setup(&env);
+    assert_eq!(amend(&env, &f, 2), 2);
+    assert_eq!(amend(&env, &f, 3), 3);
+
+    let current = f.client.get_attestation(&f.id).unwrap();
+    assert_eq!(current.version, 3);
+    assert_eq!(current.payload_hash, Bytes::from_array(&env, &[3u8; 32]));
+    let first = f.client.get_attestation_version(&f.id, &1).unwrap();
+    assert_eq!(first.payload_hash, Bytes::from_array(&env, &[1u8; 32]));
+    assert_eq!(f.client.get_attestation_version(&f.id, &2).unwrap().version, 2);
+    assert!(f.client.get_attestation_version(&f.id, &4).is_none());
+}
+
+#[test]
+fn test_amend_rejects_used_hash() {
+    let env = Env::default();
+    let f = setup(&env);
+    amend(&env, &f, 2);
+    let payload = Bytes::from_array(&env, &[1u8; 32]);
+    let sig = sign_payload(&env, &f.sk, &payload);
+    let err = f.client.try_amend_attestation(&f.attestor, &f.id, &payload, &sig).unwrap_err();
+    assert_eq!(err, Ok(ErrorCode::ReplayAttack.into()));
+}
+
+#[test]
+fn test_amend_requires_issuer_and_active() {
+    let env = Env::default();
+    let f = setup(&env);
+    let payload = Bytes::from_array(&env, &[2u8; 32]);
+    let sig = sign_payload(&env, &f.sk, &payload);
+    let err = f.client.try_amend_attestation(&Address::generate(&env), &f.id, &payload, &sig).unwrap_err();
+    assert_eq!(err, Ok(ErrorCode::UnauthorizedAttestor.into()));
+
+    f.client.revoke_attestation(&f.attestor, &f.id);
+    let err = f.client.try_amend_attestation(&f.attestor, &f.id, &payload, &sig).unwrap_err();
+    assert_eq!(err, Ok(ErrorCode::AttestationRevoked.into()));
+}
+
+#[test]
+fn test_amend_advances_chain() {
+    let env = Env::default();
+    let f = setup(&env);
+    let head = f.client.get_chain_head();
+    amend(&env, &f, 2);
+    assert_ne!(f.client.get_chain_head(), head);
+    let link = f.client.get_proof_bundle(&f.id).unwrap().chain;
+    assert_eq!((link.sequence, link.prev_hash, link.hash), (2, head, f.client.get_chain_head()));
+}
+
+#[test]
+fn test_amend_rejects_lapsed_attestation() {
+    let env = Env::default();
+    let f = setup(&env);
+    f.client.set_attestation_max_age(&100);
+    env.ledger().with_mut(|li| li.timestamp = NOW + 101);
+    let payload = Bytes::from_array(&env, &[2u8; 32]);
+    let sig = sign_payload(&env, &f.sk, &payload);
+    assert!(f.client.try_amend_attestation(&f.attestor, &f.id, &payload, &sig).is_err());
+}
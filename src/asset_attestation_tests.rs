@@ -0,0 +1,37 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::types::{AttestationInput, SubjectKind};
+
+const NOW: u64 = 1_700_000_000;
+
+fn input(env: &Env, sk: &SigningKey, subject: &Address, seed: u8) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(subject.clone(), NOW, payload, sig)
+}
+
+#[test]
+fn test_asset_attestations_are_indexed_by_asset() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let usdc = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    let eurc = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+
+    let first = client.record_attestation(&attestor, &input(&env, &sk, &usdc, 1).with_asset_subject()).id;
+    client.record_attestation(&attestor, &input(&env, &sk, &eurc, 2).with_asset_subject());
+    let second = client.record_attestation(&attestor, &input(&env, &sk, &usdc, 3).with_asset_subject()).id;
+    // Holder-level attestations are not part of the asset index.
+    client.record_attestation(&attestor, &input(&env, &sk, &usdc, 4));
+
+    assert_eq!(client.get_asset_attestation_count(&usdc), 2);
+    let listed = client.list_asset_attestations(&usdc, &0, &10);
+    assert_eq!(listed.len(), 2);
+    assert_eq!((listed.get(0).unwrap().id, listed.get(1).unwrap().id), (first, second));
+    assert_eq!(listed.get(0).unwrap().subject_kind, SubjectKind::Asset);
+    assert_eq!(client.list_attested_assets(&0, &10), vec![&env, usdc.clone(), eurc]);
+    assert_eq!(client.list_attested_assets(&1, &10).len(), 1);
+}
@@ -0,0 +1,69 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    xdr::ToXdr,
+    Address, Bytes, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use anchorkit_interface::MAX_WEIGHT_BPS;
+
+use crate::types::{Attestation, AttestationData, Status, SubjectKind};
+
+const NOW: u64 = 1_700_000_000;
+
+#[test]
+fn test_export_matches_reconstructed_record() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env), &100_u64, &None);
+    let attestor = Address::generate(&env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(&env, &client, &attestor, &attestor, &sk);
+
+    let subject = Address::generate(&env);
+    let payload = Bytes::from_array(&env, &[4u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    let id = client.submit_attestation(&attestor, &subject, &NOW, &payload, &sig);
+
+    let reconstructed = Attestation {
+        id,
+        issuer: attestor,
+        subject,
+        timestamp: NOW,
+        payload_hash: payload,
+        signature: sig,
+        issuer_revoked: false,
+        org_id: None,
+        schema_id: None,
+        ledger: env.ledger().sequence(),
+        schema_epoch: 0,
+        transferable: false,
+        not_before: 0,
+        jurisdiction: None,
+        weight_bps: MAX_WEIGHT_BPS,
+        revoked_at: None,
+        value: None,
+        expires_at: 0,
+        subject_kind: SubjectKind::Account,
+        ref_id: None,
+        data: AttestationData::HashOnly,
+        status: Status::Active,
+        version: 1,
+        uri: None,
+    };
+    let exported = client.export_attestation(&id);
+    assert_eq!(exported, reconstructed.to_xdr(&env));
+    assert_eq!(
+        env.crypto().sha256(&exported).to_bytes(),
+        env.crypto().sha256(&client.get_attestation(&id).unwrap().to_xdr(&env)).to_bytes()
+    );
+    assert!(client.try_export_attestation(&(id + 1)).is_err());
+}
@@ -0,0 +1,89 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, Env};
+use ed25519_dalek::SigningKey;
+
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::types::{AttestationInput, AttestationStatus};
+
+const NOW: u64 = 1_700_000_000;
+
+fn input(env: &Env, sk: &SigningKey, byte: u8) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[byte; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(Address::generate(env), NOW, payload, sig)
+}
+
+#[test]
+fn test_record_attestation_assigns_contract_fields() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let input = input(&env, &sk, 1).with_schema(7);
+
+    let id = client.record_attestation(&attestor, &input).id;
+    let att = client.get_attestation(&id).unwrap();
+    assert_eq!(att.subject, input.subject);
+    assert_eq!(att.schema_id, Some(7));
+    assert_eq!(att.ledger, env.ledger().sequence());
+}
+
+#[test]
+fn test_plain_submission_has_no_schema() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let i = input(&env, &sk, 2);
+    let id = client.submit_attestation(&attestor, &i.subject, &NOW, &i.payload_hash, &i.signature);
+    assert_eq!(client.get_attestation(&id).unwrap().schema_id, None);
+}
+
+#[test]
+fn test_revoke_all_by_schema_applies_to_attestations() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let kyc = client.record_attestation(&attestor, &input(&env, &sk, 1).with_schema(7)).id;
+    let other = client.record_attestation(&attestor, &input(&env, &sk, 2).with_schema(8)).id;
+
+    client.revoke_all_by_schema(&attestor, &7);
+    let validity = client.get_attestation_v2(&kyc).unwrap().validity;
+    assert_eq!(validity.status, AttestationStatus::SchemaRevoked);
+    assert!(!validity.is_valid);
+    assert!(client.get_attestation_v2(&other).unwrap().validity.is_valid);
+
+    let fresh = client.record_attestation(&attestor, &input(&env, &sk, 3).with_schema(7)).id;
+    assert!(client.get_attestation_v2(&fresh).unwrap().validity.is_valid);
+}
+
+#[test]
+fn test_validate_rejects_structurally_bad_input() {
+    let env = Env::default();
+    let subject = Address::generate(&env);
+    let sig = Bytes::from_array(&env, &[0u8; 64]);
+    let ok = AttestationInput::new(subject.clone(), NOW, Bytes::from_array(&env, &[1u8; 32]), sig.clone());
+    assert!(ok.validate().is_ok());
+    let zero_ts = AttestationInput::new(subject.clone(), 0, Bytes::from_array(&env, &[1u8; 32]), sig.clone());
+    assert!(zero_ts.validate().is_err());
+    let empty = AttestationInput::new(subject, NOW, Bytes::new(&env), sig);
+    assert!(empty.validate().is_err());
+}
+
+#[test]
+fn test_receipt_chains_attestations() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    assert_eq!(client.get_chain_head(), BytesN::from_array(&env, &[0u8; 32]));
+
+    let first = client.record_attestation(&attestor, &input(&env, &sk, 1));
+    assert_eq!(first.ledger, env.ledger().sequence());
+    assert_eq!(first.fee_paid, 0);
+    assert_eq!(client.get_chain_head(), first.chain_hash);
+
+    let mut preimage = Bytes::from_array(&env, &[0u8; 32]);
+    preimage.extend_from_array(&first.id.to_be_bytes());
+    preimage.append(&Bytes::from_array(&env, &[1u8; 32]));
+    let expected: BytesN<32> = env.crypto().sha256(&preimage).into();
+    assert_eq!(first.chain_hash, expected);
+
+    let second = client.record_attestation(&attestor, &input(&env, &sk, 2));
+    assert_ne!(second.chain_hash, first.chain_hash);
+    assert_eq!(client.get_chain_head(), second.chain_hash);
+}
@@ -0,0 +1,117 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::AnchorKitContractClient;
+use crate::sep10_test_util::{register_attestor_with_sep10, setup_attestor, sign_payload};
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+
+fn input(env: &Env, sk: &SigningKey, subject: &Address, seed: u8) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(subject.clone(), NOW, payload, sig)
+}
+
+/// Input linked to `ref_id`, signed over the ref-binding hash.
+fn linked(
+    env: &Env,
+    client: &AnchorKitContractClient,
+    sk: &SigningKey,
+    subject: &Address,
+    seed: u8,
+    ref_id: u64,
+) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let bound = client.compute_ref_binding_hash_public(&payload, &ref_id);
+    let sig = sign_payload(env, sk, &Bytes::from(bound));
+    AttestationInput::new(subject.clone(), NOW, payload, sig).with_ref(ref_id)
+}
+
+#[test]
+fn test_chain_walks_renewals_newest_first() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let subject = Address::generate(&env);
+    let first = client.record_attestation(&attestor, &input(&env, &sk, &subject, 1)).id;
+    let second = client
+        .record_attestation(&attestor, &linked(&env, &client, &sk, &subject, 2, first))
+        .id;
+    let third = client
+        .record_attestation(&attestor, &linked(&env, &client, &sk, &subject, 3, second))
+        .id;
+
+    assert_eq!(client.get_attestation(&third).unwrap().ref_id, Some(second));
+    let chain = client.get_attestation_chain(&third);
+    assert_eq!(chain.len(), 3);
+    assert_eq!(chain.get(0).unwrap().id, third);
+    assert_eq!(chain.get(1).unwrap().id, second);
+    assert_eq!(chain.get(2).unwrap().id, first);
+
+    assert_eq!(client.get_attestation_chain(&first).len(), 1);
+    assert_eq!(client.get_attestation_chain(&(third + 1)).len(), 0);
+}
+
+#[test]
+fn test_chain_is_bounded() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let subject = Address::generate(&env);
+    let mut head = client.record_attestation(&attestor, &input(&env, &sk, &subject, 0)).id;
+    for seed in 1..20u8 {
+        head = client
+            .record_attestation(&attestor, &linked(&env, &client, &sk, &subject, seed, head))
+            .id;
+    }
+    assert_eq!(client.get_attestation_chain(&head).len(), 16);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+fn test_ref_to_unknown_attestation_rejected() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let subject = Address::generate(&env);
+    client.record_attestation(&attestor, &linked(&env, &client, &sk, &subject, 1, 42));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+fn test_ref_to_other_subject_rejected() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let first = client
+        .record_attestation(&attestor, &input(&env, &sk, &Address::generate(&env), 1))
+        .id;
+    client.record_attestation(
+        &attestor,
+        &linked(&env, &client, &sk, &Address::generate(&env), 2, first),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+fn test_ref_to_other_issuer_rejected() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let other = Address::generate(&env);
+    let other_sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(&env, &client, &other, &other, &other_sk);
+    let subject = Address::generate(&env);
+    let theirs = client.record_attestation(&other, &input(&env, &other_sk, &subject, 1)).id;
+    client.record_attestation(&attestor, &linked(&env, &client, &sk, &subject, 2, theirs));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #77)")]
+fn test_ref_not_covered_by_signature_rejected() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let subject = Address::generate(&env);
+    let first = client.record_attestation(&attestor, &input(&env, &sk, &subject, 1)).id;
+    // Signed over the bare payload hash, so the link was never authorized.
+    client.record_attestation(&attestor, &input(&env, &sk, &subject, 2).with_ref(first));
+}
@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Bytes, Env, Symbol, TryFromVal,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::events::AttestationRevoked;
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::AttestationStatus;
+
+const NOW: u64 = 1_700_000_000;
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, Address, u64) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin, &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    let payload = Bytes::from_array(env, &[1u8; 32]);
+    let sig = sign_payload(env, &sk, &payload);
+    let id = client.submit_attestation(&attestor, &Address::generate(env), &NOW, &payload, &sig);
+    (client, admin, attestor, id)
+}
+
+#[test]
+fn test_issuer_revokes_attestation() {
+    let env = Env::default();
+    let (client, _, attestor, id) = setup(&env);
+    assert_eq!(client.get_attestation(&id).unwrap().revoked_at, None);
+
+    client.revoke_attestation(&attestor, &id);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(Symbol::try_from_val(&env, &topics.get(1).unwrap()), Ok(symbol_short!("revoked")));
+    let event = AttestationRevoked::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.revoked_by, attestor);
+    assert_eq!(event.revoked_at, NOW);
+    assert_eq!(event.id, id);
+    assert_eq!(Address::try_from_val(&env, &topics.get(2).unwrap()), Ok(attestor.clone()));
+
+    assert_eq!(client.get_attestation(&id).unwrap().revoked_at, Some(NOW));
+    let validity = client.get_attestation_v2(&id).unwrap().validity;
+    assert_eq!(validity.status, AttestationStatus::Revoked);
+    assert!(!validity.is_valid);
+}
+
+#[test]
+fn test_admin_may_revoke() {
+    let env = Env::default();
+    let (client, admin, _, id) = setup(&env);
+    client.revoke_attestation(&admin, &id);
+    assert!(client.get_attestation(&id).unwrap().revoked_at.is_some());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_stranger_cannot_revoke() {
+    let env = Env::default();
+    let (client, _, _, id) = setup(&env);
+    client.revoke_attestation(&Address::generate(&env), &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #76)")]
+fn test_double_revocation_rejected() {
+    let env = Env::default();
+    let (client, _, attestor, id) = setup(&env);
+    client.revoke_attestation(&attestor, &id);
+    client.revoke_attestation(&attestor, &id);
+}
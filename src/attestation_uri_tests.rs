@@ -0,0 +1,89 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Bytes, Env, Symbol, TryFromVal,
+};
+use ed25519_dalek::SigningKey;
+
+use crate::errors::ErrorCode;
+#[cfg(not(feature = "legacy-events"))]
+use crate::events::AttestEvent;
+use crate::events::UriUpdated;
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::types::{AttestationInput, MAX_URI_LEN};
+
+const NOW: u64 = 1_700_000_000;
+
+fn input(env: &Env, sk: &SigningKey, seed: u8) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(Address::generate(env), NOW, payload, sig)
+}
+
+/// Body of the latest event whose second topic is `kind`.
+fn last_event<T: TryFromVal<Env, soroban_sdk::Val>>(env: &Env, kind: Symbol) -> T {
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| Symbol::try_from_val(env, &topics.get(1).unwrap()) == Ok(kind.clone()))
+        .last()
+        .and_then(|(_, _, data)| T::try_from_val(env, &data).ok())
+        .unwrap()
+}
+
+#[cfg(not(feature = "legacy-events"))]
+#[test]
+fn test_uri_stored_and_announced() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let uri = Bytes::from_slice(&env, b"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi");
+    let id = client.record_attestation(&attestor, &input(&env, &sk, 1).with_uri(uri.clone())).id;
+    assert_eq!(client.get_attestation(&id).unwrap().uri, Some(uri.clone()));
+    assert_eq!(last_event::<AttestEvent>(&env, symbol_short!("recorded")).uri, Some(uri));
+}
+
+#[test]
+fn test_oversized_uri_rejected() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let uri = Bytes::from_slice(&env, &[b'a'; MAX_URI_LEN as usize + 1]);
+    let err = client.try_record_attestation(&attestor, &input(&env, &sk, 1).with_uri(uri.clone())).err();
+    assert_eq!(err, Some(Ok(ErrorCode::FieldTooLarge.into())));
+
+    let id = client.record_attestation(&attestor, &input(&env, &sk, 2)).id;
+    let err = client.try_set_attestation_uri(&id, &Some(uri)).err();
+    assert_eq!(err, Some(Ok(ErrorCode::FieldTooLarge.into())));
+}
+
+#[test]
+fn test_issuer_updates_uri() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let id = client.record_attestation(&attestor, &input(&env, &sk, 1)).id;
+    let uri = Bytes::from_slice(&env, b"https://example.com/claims/1.json");
+    client.set_attestation_uri(&id, &Some(uri.clone()));
+    assert_eq!(client.get_attestation(&id).unwrap().uri, Some(uri.clone()));
+    let event: UriUpdated = last_event(&env, symbol_short!("uri"));
+    assert_eq!(event.old_uri, None);
+    assert_eq!(event.new_uri, Some(uri));
+
+    client.set_attestation_uri(&id, &None);
+    assert!(client.get_attestation(&id).unwrap().uri.is_none());
+}
+
+#[test]
+fn test_uri_frozen_once_issuer_or_record_lapses() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let uri = Some(Bytes::from_slice(&env, b"https://example.com/claims/2.json"));
+    let expiring = client.record_attestation(&attestor, &input(&env, &sk, 1).with_expiry(NOW + 60)).id;
+    let id = client.record_attestation(&attestor, &input(&env, &sk, 2)).id;
+
+    env.ledger().with_mut(|li| li.timestamp = NOW + 60);
+    assert!(client.try_set_attestation_uri(&expiring, &uri).is_err());
+
+    client.revoke_attestor(&attestor);
+    assert!(client.try_set_attestation_uri(&id, &uri).is_err());
+}
@@ -0,0 +1,127 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::AttestationStatus;
+
+const NOW: u64 = 1_700_000_000;
+
+fn make_env() -> Env {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    env
+}
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, u64) {
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let attestor = Address::generate(env);
+    client.initialize(&admin, &100_u64, &None);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+
+    let payload = Bytes::from_array(env, &[9u8; 32]);
+    let sig = sign_payload(env, &sk, &payload);
+    let id = client.submit_attestation(&attestor, &Address::generate(env), &NOW, &payload, &sig);
+    (client, attestor, id)
+}
+
+#[test]
+fn test_fresh_attestation_is_valid() {
+    let env = make_env();
+    let (client, _, id) = setup(&env);
+    let view = client.get_attestation_v2(&id).unwrap();
+    assert_eq!(view.attestation.id, id);
+    assert_eq!(view.validity.status, AttestationStatus::Active);
+    assert!(view.validity.issuer_registered);
+    assert!(!view.validity.expired);
+    assert!(view.validity.is_valid);
+}
+
+#[test]
+fn test_revoked_issuer_invalidates() {
+    let env = make_env();
+    let (client, attestor, id) = setup(&env);
+    client.revoke_attestor(&attestor);
+    let validity = client.get_attestation_v2(&id).unwrap().validity;
+    assert_eq!(validity.status, AttestationStatus::IssuerRevoked);
+    assert!(!validity.issuer_registered);
+    assert!(!validity.is_valid);
+}
+
+#[test]
+fn test_max_age_expires_attestation() {
+    let env = make_env();
+    let (client, _, id) = setup(&env);
+    client.set_attestation_max_age(&3_600);
+    assert!(client.get_attestation_v2(&id).unwrap().validity.is_valid);
+
+    env.ledger().with_mut(|li| li.timestamp = NOW + 3_601);
+    let validity = client.get_attestation_v2(&id).unwrap().validity;
+    assert!(validity.expired);
+    assert!(!validity.is_valid);
+}
+
+#[test]
+fn test_missing_attestation_returns_none() {
+    let env = make_env();
+    let (client, _, _) = setup(&env);
+    assert!(client.get_attestation_v2(&999).is_none());
+}
+
+#[test]
+fn test_global_invalidation_epoch() {
+    let env = make_env();
+    let (client, _, id) = setup(&env);
+    client.set_global_invalidation_epoch(&NOW);
+    assert!(client.get_attestation_v2(&id).unwrap().validity.is_valid);
+
+    client.set_global_invalidation_epoch(&(NOW + 1));
+    let validity = client.get_attestation_v2(&id).unwrap().validity;
+    assert!(validity.invalidated);
+    assert!(!validity.is_valid);
+
+    client.set_global_invalidation_epoch(&0);
+    assert!(client.get_attestation_v2(&id).unwrap().validity.is_valid);
+}
+
+#[test]
+fn test_issuer_invalidation_epoch_is_scoped() {
+    let env = make_env();
+    let (client, attestor, id) = setup(&env);
+    client.set_issuer_invalidation_epoch(&Address::generate(&env), &(NOW + 1));
+    assert!(client.get_attestation_v2(&id).unwrap().validity.is_valid);
+
+    client.set_issuer_invalidation_epoch(&attestor, &(NOW + 1));
+    assert_eq!(client.get_issuer_invalidation_epoch(&attestor), NOW + 1);
+    assert!(!client.get_attestation_v2(&id).unwrap().validity.is_valid);
+}
+
+#[test]
+fn test_valid_until_ledger_hint() {
+    let env = make_env();
+    let (client, attestor, id) = setup(&env);
+    let recorded = client.get_attestation(&id).unwrap().ledger;
+
+    // Without a max age the hint is bounded by the entry's TTL.
+    let validity = client.get_attestation_v2(&id).unwrap().validity;
+    assert_eq!(validity.valid_until_ledger, recorded + 1_555_200);
+
+    // A one-hour max age caps it at ~720 ledgers from now.
+    client.set_attestation_max_age(&3_600);
+    let sequence = env.ledger().sequence();
+    let validity = client.get_attestation_v2(&id).unwrap().validity;
+    assert_eq!(validity.valid_until_ledger, sequence + 720);
+
+    client.revoke_attestor(&attestor);
+    assert_eq!(client.get_attestation_v2(&id).unwrap().validity.valid_until_ledger, 0);
+}
@@ -0,0 +1,50 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+
+use crate::contract::AnchorKitContractClient;
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+
+const NOW: u64 = 1_700_000_000;
+const DAY: u64 = 86_400;
+
+fn submit(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey, seed: u8) {
+    let now = env.ledger().timestamp();
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    client.submit_attestation(attestor, &Address::generate(env), &now, &payload, &sig);
+}
+
+#[test]
+fn test_activity_is_bucketed_by_day() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let today = NOW / DAY;
+
+    submit(&env, &client, &attestor, &sk, 1);
+    submit(&env, &client, &attestor, &sk, 2);
+    client.revoke_all_by_schema(&attestor, &7);
+    env.ledger().with_mut(|li| li.timestamp = NOW + 2 * DAY);
+    submit(&env, &client, &attestor, &sk, 3);
+
+    let activity = client.get_attestor_activity(&attestor, &today, &(today + 2));
+    assert_eq!(activity.len(), 2);
+    let first = activity.get(0).unwrap();
+    assert_eq!((first.day, first.issued, first.revoked), (today, 2, 1));
+    let second = activity.get(1).unwrap();
+    assert_eq!((second.day, second.issued, second.revoked), (today + 2, 1, 0));
+
+    assert!(client.get_attestor_activity(&attestor, &(today + 1), &(today + 1)).is_empty());
+    assert!(client.get_attestor_activity(&Address::generate(&env), &today, &today).is_empty());
+}
+
+#[test]
+fn test_activity_range_is_bounded() {
+    let env = Env::default();
+    let (client, attestor, _) = setup_attestor(&env, NOW);
+    let today = NOW / DAY;
+    assert!(client.try_get_attestor_activity(&attestor, &today, &(today - 1)).is_err());
+    assert!(client.try_get_attestor_activity(&attestor, &today, &(today + 31)).is_err());
+    assert!(client.try_get_attestor_activity(&attestor, &today, &(today + 30)).is_ok());
+}
@@ -0,0 +1,88 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::AnchorKitContractClient;
+use crate::errors::ErrorCodeExt;
+use crate::sep10_test_util::{register_attestor_with_sep10, sep10_token_for, setup_attestor, sign_payload};
+
+const NOW: u64 = 1_700_000_000;
+
+fn try_submit(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey, seed: u8) -> Option<u64> {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    let now = env.ledger().timestamp();
+    client.try_submit_attestation(attestor, &Address::generate(env), &now, &payload, &sig).ok().map(|id| id.unwrap())
+}
+
+#[test]
+fn test_history_survives_deactivation() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let id = try_submit(&env, &client, &attestor, &sk, 1).unwrap();
+    env.ledger().with_mut(|li| li.timestamp = NOW + 100);
+    client.deactivate_attestor(&attestor);
+
+    assert!(!client.is_attestor(&attestor));
+    assert_eq!(client.get_attestor_deactivated_at(&attestor), Some(NOW + 100));
+    assert!(!client.get_attestation(&id).unwrap().issuer_revoked);
+    assert!(client.is_valid(&id));
+    assert!(try_submit(&env, &client, &attestor, &sk, 2).is_none());
+}
+
+#[test]
+fn test_was_attestor_at() {
+    let env = Env::default();
+    let (client, attestor, _) = setup_attestor(&env, NOW);
+    env.ledger().with_mut(|li| li.timestamp = NOW + 100);
+    assert!(client.was_attestor_at(&attestor, &(NOW + 50)));
+    client.deactivate_attestor(&attestor);
+    assert!(!client.was_attestor_at(&attestor, &(NOW - 1)));
+    assert!(client.was_attestor_at(&attestor, &(NOW + 99)));
+    assert!(!client.was_attestor_at(&attestor, &(NOW + 100)));
+    assert!(!client.was_attestor_at(&Address::generate(&env), &NOW));
+}
+
+#[test]
+fn test_deactivated_attestor_cannot_reregister() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.deactivate_attestor(&attestor);
+    let token = sep10_token_for(&env, &client, &attestor, &attestor, &sk);
+    let err = client.try_add_attestors(&vec![&env, (attestor.clone(), token, attestor)], &true).err();
+    assert_eq!(
+        err,
+        Some(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::AttestorDeactivated as u32)))
+    );
+}
+
+#[test]
+fn test_deactivation_leaves_org() {
+    let env = Env::default();
+    let (client, _, _) = setup_attestor(&env, NOW);
+    client.create_org(&Address::generate(&env), &1);
+    let member = Address::generate(&env);
+    let sk = SigningKey::generate(&mut OsRng);
+    let token = sep10_token_for(&env, &client, &member, &member, &sk);
+    client.add_org_attestor(&1, &member, &token, &member);
+    assert_eq!(client.get_org(&1).unwrap().attestor_count, 1);
+
+    client.deactivate_attestor(&member);
+    assert_eq!(client.get_org(&1).unwrap().attestor_count, 0);
+}
+
+#[test]
+fn test_was_attestor_at_uses_latest_registration() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    env.ledger().with_mut(|li| li.timestamp = NOW + 10);
+    client.revoke_attestor(&attestor);
+    env.ledger().with_mut(|li| li.timestamp = NOW + 20);
+    register_attestor_with_sep10(&env, &client, &attestor, &attestor, &sk);
+
+    assert!(!client.was_attestor_at(&attestor, &(NOW + 5)));
+    assert!(!client.was_attestor_at(&attestor, &(NOW + 15)));
+    assert!(client.was_attestor_at(&attestor, &(NOW + 20)));
+}
@@ -0,0 +1,68 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Bytes, Env};
+
+use crate::sep10_test_util::{register_attestor_with_sep10, setup_attestor, sign_payload};
+
+const NOW: u64 = 1_700_000_000;
+
+#[test]
+fn test_deregister_self_records_wind_down() {
+    let env = Env::default();
+    let (client, attestor, _) = setup_attestor(&env, NOW);
+    client.set_exit_challenge_window(&3_600);
+
+    let wd = client.deregister_self(&attestor);
+    assert_eq!(wd.started_at, NOW);
+    assert_eq!(wd.release_at, NOW + 3_600);
+    assert_eq!(client.get_wind_down(&attestor), Some(wd));
+    assert!(!client.is_attestor(&attestor));
+}
+
+#[test]
+fn test_exit_marks_issuer_revoked() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let payload = Bytes::from_array(&env, &[1u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    let id = client.submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &sig);
+    client.deregister_self(&attestor);
+    assert!(client.get_attestation(&id).unwrap().issuer_revoked);
+}
+
+#[test]
+fn test_default_challenge_window() {
+    let env = Env::default();
+    let (client, attestor, _) = setup_attestor(&env, NOW);
+    let wd = client.deregister_self(&attestor);
+    assert_eq!(wd.release_at - wd.started_at, client.get_exit_challenge_window());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_issuance_stops_after_exit() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.deregister_self(&attestor);
+    let payload = Bytes::from_array(&env, &[1u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    client.submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &sig);
+}
+
+#[test]
+fn test_admin_removal_starts_wind_down() {
+    let env = Env::default();
+    let (client, attestor, _) = setup_attestor(&env, NOW);
+    client.revoke_attestor(&attestor);
+    assert_eq!(client.get_wind_down(&attestor).unwrap().started_at, NOW);
+}
+
+#[test]
+fn test_reregistration_clears_wind_down() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.deregister_self(&attestor);
+    register_attestor_with_sep10(&env, &client, &attestor, &attestor, &sk);
+    assert_eq!(client.get_wind_down(&attestor), None);
+    assert!(client.try_withdraw_stake(&attestor).is_err());
+}
@@ -0,0 +1,84 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Bytes, BytesN, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::register_attestor_with_sep10;
+
+const NOW: u64 = 1_700_000_000;
+
+fn setup(env: &Env) -> AnchorKitContractClient<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    client
+}
+
+fn attestor(env: &Env, client: &AnchorKitContractClient) -> Address {
+    let addr = Address::generate(env);
+    register_attestor_with_sep10(env, client, &addr, &addr, &SigningKey::generate(&mut OsRng));
+    addr
+}
+
+#[test]
+fn test_attestor_publishes_profile() {
+    let env = Env::default();
+    let client = setup(&env);
+    let anchor = attestor(&env, &client);
+
+    let info = client.get_attestor_info(&anchor).unwrap();
+    assert_eq!(info.registered_at, NOW);
+    assert!(info.name.is_empty());
+
+    let name = Bytes::from_slice(&env, b"Example Anchor");
+    let uri = Bytes::from_slice(&env, b"https://anchor.example/.well-known/stellar.toml");
+    client.update_attestor_info(&anchor, &name, &uri);
+    let key = BytesN::from_array(&env, &[7; 32]);
+    client.set_attestor_key(&anchor, &key);
+
+    let info = client.get_attestor_info(&anchor).unwrap();
+    assert_eq!((info.name, info.uri, info.public_key), (name, uri, key));
+    assert!(client.get_attestor_info(&Address::generate(&env)).is_none());
+}
+
+#[test]
+fn test_list_attestors_skips_revoked() {
+    let env = Env::default();
+    let client = setup(&env);
+    let a = attestor(&env, &client);
+    let b = attestor(&env, &client);
+    let c = attestor(&env, &client);
+    client.revoke_attestor(&b);
+
+    assert_eq!(client.list_attestors(&0, &10), vec![&env, a.clone(), c.clone()]);
+    assert_eq!(client.list_attestors(&1, &1), vec![&env]);
+    assert_eq!(client.list_attestors(&2, &10), vec![&env, c]);
+    // The profile outlives the registration.
+    assert!(client.get_attestor_info(&b).is_some());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #75)")]
+fn test_oversized_profile_rejected() {
+    let env = Env::default();
+    let client = setup(&env);
+    let anchor = attestor(&env, &client);
+    client.update_attestor_info(&anchor, &Bytes::from_array(&env, &[b'a'; 257]), &Bytes::new(&env));
+}
+
+#[test]
+fn test_list_attestors_page_is_capped() {
+    let env = Env::default();
+    let client = setup(&env);
+    for _ in 0..30 {
+        attestor(&env, &client);
+    }
+    assert_eq!(client.list_attestors(&0, &50).len(), 25);
+}
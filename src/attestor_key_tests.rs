@@ -0,0 +1,83 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, BytesN, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::{sep10_token_for, sign_payload};
+
+const NOW: u64 = 1_700_000_000;
+
+/// Register an attestor whose SEP-10 issuer is a separate account and whose
+/// attestation key differs from the SEP-10 key.
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, SigningKey) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sep10_issuer = Address::generate(env);
+    let sep10_sk = SigningKey::generate(&mut OsRng);
+    let token = sep10_token_for(env, &client, &attestor, &sep10_issuer, &sep10_sk);
+    let attest_sk = SigningKey::generate(&mut OsRng);
+    let public_key = BytesN::from_array(env, &attest_sk.verifying_key().to_bytes());
+    client.register_attestor_with_key(&attestor, &token, &sep10_issuer, &public_key);
+    (client, attestor, attest_sk)
+}
+
+#[test]
+fn test_signature_verified_against_attestor_key() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup(&env);
+    assert_eq!(
+        client.get_attestor_key(&attestor),
+        Some(BytesN::from_array(&env, &sk.verifying_key().to_bytes()))
+    );
+
+    let payload = Bytes::from_array(&env, &[1u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    client.submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &sig);
+
+    let other = SigningKey::generate(&mut OsRng);
+    let payload = Bytes::from_array(&env, &[2u8; 32]);
+    let forged = sign_payload(&env, &other, &payload);
+    assert!(client.try_submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &forged).is_err());
+}
+
+#[test]
+fn test_attestor_rotates_key() {
+    let env = Env::default();
+    let (client, attestor, old) = setup(&env);
+    let new = SigningKey::generate(&mut OsRng);
+    client.set_attestor_key(&attestor, &BytesN::from_array(&env, &new.verifying_key().to_bytes()));
+
+    let payload = Bytes::from_array(&env, &[1u8; 32]);
+    let stale = sign_payload(&env, &old, &payload);
+    assert!(client.try_submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &stale).is_err());
+    let sig = sign_payload(&env, &new, &payload);
+    client.submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &sig);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #77)")]
+fn test_malformed_signature_rejected() {
+    let env = Env::default();
+    let (client, attestor, _) = setup(&env);
+    let payload = Bytes::from_array(&env, &[1u8; 32]);
+    client.submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &Bytes::from_array(&env, &[0u8; 10]));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #77)")]
+fn test_signature_from_other_key_rejected() {
+    let env = Env::default();
+    let (client, attestor, _) = setup(&env);
+    let payload = Bytes::from_array(&env, &[1u8; 32]);
+    let forged = sign_payload(&env, &SigningKey::generate(&mut OsRng), &payload);
+    client.submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &forged);
+}
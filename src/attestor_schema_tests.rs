@@ -0,0 +1,78 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Bytes, Env, String,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::errors::ErrorCodeExt;
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+
+struct Setup<'a> {
+    client: AnchorKitContractClient<'a>,
+    attestor: Address,
+    sk: SigningKey,
+    kyc: u64,
+    credit: u64,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    let owner = Address::generate(env);
+    let kyc = client.register_schema(&owner, &Bytes::from_array(env, &[1u8; 32]), &String::from_str(env, "kyc"));
+    let credit = client.register_schema(&owner, &Bytes::from_array(env, &[2u8; 32]), &String::from_str(env, "credit"));
+    Setup { client, attestor, sk, kyc, credit }
+}
+
+fn input(env: &Env, sk: &SigningKey, seed: u8) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(Address::generate(env), NOW, payload, sig)
+}
+
+fn not_allowed() -> Option<Result<soroban_sdk::Error, soroban_sdk::InvokeError>> {
+    Some(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::SchemaNotAllowed as u32)))
+}
+
+#[test]
+fn test_unrestricted_by_default() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert!(s.client.get_attestor_schemas(&s.attestor).is_empty());
+    s.client.record_attestation(&s.attestor, &input(&env, &s.sk, 1).with_schema(s.credit));
+}
+
+#[test]
+fn test_allow_list_enforced_at_record_time() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_attestor_schemas(&s.attestor, &vec![&env, s.kyc]);
+    s.client.record_attestation(&s.attestor, &input(&env, &s.sk, 1).with_schema(s.kyc));
+
+    let err = s.client.try_record_attestation(&s.attestor, &input(&env, &s.sk, 2).with_schema(s.credit));
+    assert_eq!(err.err(), not_allowed());
+    let err = s.client.try_record_attestation(&s.attestor, &input(&env, &s.sk, 3));
+    assert_eq!(err.err(), not_allowed());
+}
+
+#[test]
+fn test_empty_list_lifts_restriction() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_attestor_schemas(&s.attestor, &vec![&env, s.kyc]);
+    s.client.set_attestor_schemas(&s.attestor, &vec![&env]);
+    s.client.record_attestation(&s.attestor, &input(&env, &s.sk, 1).with_schema(s.credit));
+}
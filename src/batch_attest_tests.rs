@@ -0,0 +1,125 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Bytes, Env, Vec};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::errors::{ErrorCode, ErrorCodeExt};
+use crate::types::{AttestationInput, BatchItemResult};
+
+const NOW: u64 = 1_700_000_000;
+
+fn batch(env: &Env, sk: &SigningKey, seeds: &[u8]) -> Vec<AttestationInput> {
+    let mut inputs = Vec::new(env);
+    for seed in seeds {
+        let payload = Bytes::from_array(env, &[*seed; 32]);
+        let sig = sign_payload(env, sk, &payload);
+        inputs.push_back(AttestationInput::new(Address::generate(env), NOW, payload, sig));
+    }
+    inputs
+}
+
+#[test]
+fn test_batch_records_every_entry_in_order() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let inputs = batch(&env, &sk, &[1, 2, 3]);
+    let receipts = client.batch_attest(&attestor, &inputs);
+
+    assert_eq!(receipts.len(), 3);
+    for (i, receipt) in receipts.iter().enumerate() {
+        let attestation = client.get_attestation(&receipt.id).unwrap();
+        assert_eq!(attestation.payload_hash, inputs.get(i as u32).unwrap().payload_hash);
+    }
+    assert_eq!(receipts.get(1).unwrap().id, receipts.get(0).unwrap().id + 1);
+    assert_eq!(client.get_attestation_count(), 3);
+}
+
+#[test]
+fn test_batch_is_atomic_on_duplicate_entry() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    assert!(client.try_batch_attest(&attestor, &batch(&env, &sk, &[1, 2, 1])).is_err());
+    assert_eq!(client.get_attestation_count(), 0);
+    assert_eq!(client.count_by_issuer(&attestor), 0);
+}
+
+#[test]
+fn test_batch_size_is_bounded() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    assert!(client.try_batch_attest(&attestor, &Vec::new(&env)).is_err());
+    let seeds: [u8; 21] = core::array::from_fn(|i| i as u8);
+    assert!(client.try_batch_attest(&attestor, &batch(&env, &sk, &seeds)).is_err());
+}
+
+/// CPU cost of one `batch_attest` call over `count` entries under a schema
+/// with an issuer allowlist, or of a lone `record_attestation` when `count`
+/// is zero.
+fn issuance_cost(count: u8) -> u64 {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.authorize_schema_issuer(&client.get_admin(), &7, &attestor);
+    let seeds: [u8; 8] = core::array::from_fn(|i| i as u8 + 1);
+    let mut inputs = Vec::new(&env);
+    for input in batch(&env, &sk, &seeds[..count.max(1) as usize]).iter() {
+        inputs.push_back(input.with_schema(7));
+    }
+    env.budget().reset_unlimited();
+    if count == 0 {
+        client.record_attestation(&attestor, &inputs.get(0).unwrap());
+    } else {
+        client.batch_attest(&attestor, &inputs);
+    }
+    env.budget().cpu_instruction_cost()
+}
+
+#[test]
+fn test_batch_entry_costs_less_than_single_call() {
+    // Each extra entry skips the issuer and schema grant checks a
+    // standalone call repeats.
+    let per_entry = (issuance_cost(6) - issuance_cost(1)) / 5;
+    let single = issuance_cost(0);
+    assert!(per_entry < single, "per entry {per_entry} vs single {single}");
+}
+
+#[test]
+fn test_partial_batch_records_valid_entries() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let mut inputs = batch(&env, &sk, &[1, 2, 1, 3]);
+    let mut stale = inputs.get(3).unwrap();
+    stale.timestamp = 1;
+    inputs.set(3, stale);
+
+    let results = client.batch_attest_partial(&attestor, &inputs);
+    assert_eq!(results.len(), 4);
+    assert!(matches!(results.get(0).unwrap(), BatchItemResult::Recorded(_)));
+    assert!(matches!(results.get(1).unwrap(), BatchItemResult::Recorded(_)));
+    assert_eq!(results.get(2).unwrap(), BatchItemResult::Rejected(ErrorCode::ReplayAttack as u32));
+    assert_eq!(results.get(3).unwrap(), BatchItemResult::Rejected(ErrorCode::InvalidTimestamp as u32));
+    assert_eq!(client.get_attestation_count(), 2);
+}
+
+#[test]
+fn test_partial_batch_skips_bad_signature() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let mut inputs = batch(&env, &sk, &[1, 2]);
+    let mut forged = inputs.get(0).unwrap();
+    forged.signature = sign_payload(&env, &SigningKey::generate(&mut OsRng), &forged.payload_hash);
+    inputs.set(0, forged);
+
+    let results = client.batch_attest_partial(&attestor, &inputs);
+    assert_eq!(results.get(0).unwrap(), BatchItemResult::Rejected(ErrorCodeExt::InvalidSignature as u32));
+    assert!(matches!(results.get(1).unwrap(), BatchItemResult::Recorded(_)));
+    assert_eq!(client.get_attestation_count(), 1);
+}
+
+#[test]
+fn test_partial_batch_still_bounded() {
+    let env = Env::default();
+    let (client, attestor, _) = setup_attestor(&env, NOW);
+    assert!(client.try_batch_attest_partial(&attestor, &Vec::new(&env)).is_err());
+}
@@ -0,0 +1,123 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    vec, Address, Env, String, Symbol, TryFromVal,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::errors::{ErrorCode, ErrorCodeExt};
+use crate::roles::ROLE_ATTESTOR_MANAGER;
+use crate::sep10_test_util::sep10_token_for;
+
+fn setup(env: &Env) -> AnchorKitContractClient<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    client
+}
+
+/// A `(attestor, sep10_token, sep10_issuer)` entry with a valid proof.
+fn entry(env: &Env, client: &AnchorKitContractClient, attestor: &Address) -> (Address, String, Address) {
+    let token = sep10_token_for(env, client, attestor, attestor, &SigningKey::generate(&mut OsRng));
+    (attestor.clone(), token, attestor.clone())
+}
+
+#[test]
+fn test_add_attestors_registers_each() {
+    let env = Env::default();
+    let client = setup(&env);
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+    let batch = vec![&env, entry(&env, &client, &a), entry(&env, &client, &b)];
+    assert_eq!(client.add_attestors(&batch, &true), 2);
+    assert!(client.is_attestor(&a) && client.is_attestor(&b));
+    let registrations = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| Symbol::try_from_val(&env, &topics.get(1).unwrap()) == Ok(symbol_short!("reg")))
+        .count();
+    assert_eq!(registrations, 2);
+}
+
+#[test]
+fn test_duplicates_skipped_unless_strict() {
+    let env = Env::default();
+    let client = setup(&env);
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+    client.add_attestors(&vec![&env, entry(&env, &client, &a)], &true);
+
+    let b_entry = entry(&env, &client, &b);
+    let batch = vec![&env, entry(&env, &client, &a), b_entry.clone(), b_entry];
+    let err = client.try_add_attestors(&batch, &true);
+    assert_eq!(err.err(), Some(Ok(soroban_sdk::Error::from_contract_error(ErrorCode::AttestorAlreadyRegistered as u32))));
+    assert!(!client.is_attestor(&b));
+    assert_eq!(client.add_attestors(&batch, &false), 1);
+    assert!(client.is_attestor(&b));
+}
+
+#[test]
+fn test_each_entry_needs_a_sep10_proof() {
+    let env = Env::default();
+    let client = setup(&env);
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+    // `a`'s token is presented for `b`.
+    let (_, token, issuer) = entry(&env, &client, &a);
+    assert!(client.try_add_attestors(&vec![&env, (b.clone(), token, issuer)], &false).is_err());
+    assert!(!client.is_attestor(&b));
+}
+
+#[test]
+fn test_deactivated_skipped_unless_strict() {
+    let env = Env::default();
+    let client = setup(&env);
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+    client.add_attestors(&vec![&env, entry(&env, &client, &a)], &true);
+    client.deactivate_attestor(&a);
+
+    let batch = vec![&env, entry(&env, &client, &a), entry(&env, &client, &b)];
+    let err = client.try_add_attestors(&batch, &true);
+    assert_eq!(err.err(), Some(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::AttestorDeactivated as u32))));
+    assert_eq!(client.add_attestors(&batch, &false), 1);
+    assert!(!client.is_attestor(&a));
+}
+
+#[test]
+fn test_attestor_manager_onboards_in_bulk() {
+    let env = Env::default();
+    let client = setup(&env);
+    let manager = Address::generate(&env);
+    let a = Address::generate(&env);
+    let batch = vec![&env, entry(&env, &client, &a)];
+    assert!(client.try_onboard_attestors(&manager, &batch, &true).is_err());
+
+    client.grant_role(&client.get_admin(), &ROLE_ATTESTOR_MANAGER, &manager);
+    assert_eq!(client.onboard_attestors(&manager, &batch, &true), 1);
+    assert!(client.is_attestor(&a));
+}
+
+#[test]
+fn test_remove_attestors() {
+    let env = Env::default();
+    let client = setup(&env);
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+    client.add_attestors(&vec![&env, entry(&env, &client, &a)], &true);
+    let batch = vec![&env, a.clone(), b.clone()];
+    let err = client.try_remove_attestors(&batch, &true);
+    assert_eq!(err.err(), Some(Ok(soroban_sdk::Error::from_contract_error(ErrorCode::AttestorNotRegistered as u32))));
+    assert_eq!(client.remove_attestors(&batch, &false), 1);
+    assert!(!client.is_attestor(&a));
+}
+
+#[test]
+fn test_batch_bounds() {
+    let env = Env::default();
+    let client = setup(&env);
+    assert!(client.try_add_attestors(&vec![&env], &false).is_err());
+    let own = (client.address.clone(), String::from_str(&env, "token"), client.address.clone());
+    assert!(client.try_add_attestors(&vec![&env, own], &false).is_err());
+}
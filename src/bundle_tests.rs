@@ -0,0 +1,146 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, Env, String, Vec};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::AnchorKitContractClient;
+use crate::deterministic_hash::compute_bundle_hash;
+use crate::sep10_test_util::{register_attestor_with_sep10, setup_attestor, sign_payload};
+
+const NOW: u64 = 1_700_000_000;
+
+fn claims(env: &Env) -> Vec<(u64, Bytes)> {
+    vec![
+        env,
+        (1u64, Bytes::from_array(env, &[1u8; 32])),
+        (2u64, Bytes::from_array(env, &[2u8; 32])),
+        (3u64, Bytes::from_array(env, &[3u8; 32])),
+    ]
+}
+
+fn submit(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey, subject: &Address) -> u64 {
+    let claims = claims(env);
+    let hash = compute_bundle_hash(env, subject, NOW, &claims);
+    let sig = sign_payload(env, sk, &Bytes::from(hash));
+    client.submit_bundle(attestor, subject, &NOW, &claims, &sig)
+}
+
+#[test]
+fn test_bundle_claims_are_addressable() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let subject = Address::generate(&env);
+    let id = submit(&env, &client, &attestor, &sk, &subject);
+
+    let bundle = client.get_bundle(&id).unwrap();
+    assert_eq!(bundle.subject, subject);
+    assert_eq!(bundle.claims.len(), 3);
+    let claim = client.get_bundle_claim(&id, &1).unwrap();
+    assert_eq!(claim.schema_id, 2);
+    assert!(client.is_bundle_claim_valid(&id, &1));
+    assert!(client.get_bundle_claim(&id, &3).is_none());
+}
+
+#[test]
+fn test_revoke_single_claim() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let id = submit(&env, &client, &attestor, &sk, &Address::generate(&env));
+
+    client.revoke_bundle_claim(&attestor, &id, &1);
+    assert!(client.is_bundle_claim_valid(&id, &0));
+    assert!(!client.is_bundle_claim_valid(&id, &1));
+    assert!(client.is_bundle_claim_valid(&id, &2));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_bundle_replay_rejected() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let subject = Address::generate(&env);
+    submit(&env, &client, &attestor, &sk, &subject);
+    submit(&env, &client, &attestor, &sk, &subject);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_only_bundle_issuer_can_revoke() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let id = submit(&env, &client, &attestor, &sk, &Address::generate(&env));
+    client.revoke_bundle_claim(&Address::generate(&env), &id, &0);
+}
+
+#[test]
+fn test_revoke_all_by_schema_voids_existing_claims_only() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let old = submit(&env, &client, &attestor, &sk, &Address::generate(&env));
+
+    client.revoke_all_by_schema(&attestor, &2);
+    assert_eq!(client.get_schema_revocation_epoch(&attestor, &2), 1);
+    assert!(client.is_bundle_claim_valid(&old, &0));
+    assert!(!client.is_bundle_claim_valid(&old, &1));
+    assert!(client.is_bundle_claim_valid(&old, &2));
+
+    let fresh = submit(&env, &client, &attestor, &sk, &Address::generate(&env));
+    assert!(client.is_bundle_claim_valid(&fresh, &1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #66)")]
+fn test_bundle_claims_require_schema_grant() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let other = Address::generate(&env);
+    register_attestor_with_sep10(&env, &client, &other, &other, &SigningKey::generate(&mut OsRng));
+    client.authorize_schema_issuer(&client.get_admin(), &2, &other);
+    submit(&env, &client, &attestor, &sk, &Address::generate(&env));
+}
+
+fn submit_under(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey, schema_id: u64, seed: u8) -> u64 {
+    let subject = Address::generate(env);
+    let claims = vec![env, (schema_id, Bytes::from_array(env, &[seed; 32]))];
+    let hash = compute_bundle_hash(env, &subject, NOW, &claims);
+    let sig = sign_payload(env, sk, &Bytes::from(hash));
+    client.submit_bundle(attestor, &subject, &NOW, &claims, &sig)
+}
+
+fn schema(env: &Env, client: &AnchorKitContractClient) -> u64 {
+    client.register_schema(&Address::generate(env), &Bytes::from_array(env, &[7u8; 32]), &String::from_str(env, "kyc"))
+}
+
+#[test]
+fn test_bundle_claims_respect_schema_tier() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let schema_id = schema(&env, &client);
+    client.set_schema_min_tier(&schema_id, &2, &true);
+    let subject = Address::generate(&env);
+    let claims = vec![&env, (schema_id, Bytes::from_array(&env, &[1u8; 32]))];
+    let sig = sign_payload(&env, &sk, &Bytes::from(compute_bundle_hash(&env, &subject, NOW, &claims)));
+    assert!(client.try_submit_bundle(&attestor, &subject, &NOW, &claims, &sig).is_err());
+
+    client.set_attestor_tier(&attestor, &2);
+    let id = submit_under(&env, &client, &attestor, &sk, schema_id, 1);
+    assert!(client.is_bundle_claim_valid(&id, &0));
+    client.set_attestor_tier(&attestor, &1);
+    assert!(!client.is_bundle_claim_valid(&id, &0));
+}
+
+#[test]
+fn test_bundle_claims_count_against_schema_cap() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let schema_id = schema(&env, &client);
+    client.set_schema_cap(&schema_id, &1);
+    submit_under(&env, &client, &attestor, &sk, schema_id, 1);
+    assert_eq!(client.get_schema_attestation_count(&schema_id), 1);
+
+    let subject = Address::generate(&env);
+    let claims = vec![&env, (schema_id, Bytes::from_array(&env, &[2u8; 32]))];
+    let sig = sign_payload(&env, &sk, &Bytes::from(compute_bundle_hash(&env, &subject, NOW, &claims)));
+    assert!(client.try_submit_bundle(&attestor, &subject, &NOW, &claims, &sig).is_err());
+}
@@ -0,0 +1,60 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+
+use crate::errors::ErrorCodeExt;
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+
+const NOW: u64 = 1_700_000_000;
+
+fn signed(env: &Env, sk: &SigningKey, seed: u8) -> (Bytes, Bytes) {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    (payload, sig)
+}
+
+#[test]
+fn test_consent_not_required_by_default() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    assert!(!client.is_consent_required());
+    let (payload, sig) = signed(&env, &sk, 1);
+    client.submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &sig);
+}
+
+#[test]
+fn test_standing_consent_gates_issuance() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.set_consent_required(&true);
+    let subject = Address::generate(&env);
+
+    let (payload, sig) = signed(&env, &sk, 1);
+    let err = client.try_submit_attestation(&attestor, &subject, &NOW, &payload, &sig);
+    assert_eq!(
+        err.err().unwrap().unwrap(),
+        soroban_sdk::Error::from_contract_error(ErrorCodeExt::SubjectConsentRequired as u32)
+    );
+
+    client.grant_consent(&subject, &attestor);
+    assert!(client.has_consent(&subject, &attestor));
+    client.submit_attestation(&attestor, &subject, &NOW, &payload, &sig);
+
+    client.revoke_consent(&subject, &attestor);
+    assert!(!client.has_consent(&subject, &attestor));
+    let (payload, sig) = signed(&env, &sk, 2);
+    assert!(client.try_submit_attestation(&attestor, &subject, &NOW, &payload, &sig).is_err());
+}
+
+#[test]
+fn test_subject_authorization_counts_as_consent() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.set_consent_required(&true);
+    let subject = Address::generate(&env);
+    let (payload, sig) = signed(&env, &sk, 1);
+    let id = client.submit_attestation_with_consent(&attestor, &subject, &NOW, &payload, &sig);
+    assert_eq!(client.get_attestation(&id).unwrap().subject, subject);
+    assert!(!client.has_consent(&subject, &attestor));
+}
@@ -0,0 +1,83 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+
+const NOW: u64 = 1_700_000_000;
+
+fn make_env() -> Env {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    env
+}
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, SigningKey) {
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let attestor = Address::generate(env);
+    client.initialize(&admin, &100_u64, &None);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    (client, attestor, sk)
+}
+
+#[test]
+fn test_consent_receipt_recorded() {
+    let env = make_env();
+    let (client, attestor, sk) = setup(&env);
+    let subject = Address::generate(&env);
+    let payload = Bytes::from_array(&env, &[5u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+
+    let id = client.submit_attestation_with_consent(&attestor, &subject, &NOW, &payload, &sig);
+
+    let receipt = client.get_consent_receipt(&id).unwrap();
+    assert_eq!(receipt.subject, subject);
+    assert_eq!(receipt.attestation_id, id);
+    assert_eq!(receipt.scope_hash, payload);
+    assert_eq!(receipt.consent_ledger, env.ledger().sequence());
+
+    let all = client.get_consent_receipts(&subject, &0, &10);
+    assert_eq!(all.len(), 1);
+    assert_eq!(all.get(0).unwrap(), receipt);
+}
+
+#[test]
+fn test_plain_submission_has_no_receipt() {
+    let env = make_env();
+    let (client, attestor, sk) = setup(&env);
+    let subject = Address::generate(&env);
+    let payload = Bytes::from_array(&env, &[6u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+
+    let id = client.submit_attestation(&attestor, &subject, &NOW, &payload, &sig);
+    assert!(client.get_consent_receipt(&id).is_none());
+    assert_eq!(client.get_consent_receipts(&subject, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_consent_receipts_are_paged() {
+    let env = make_env();
+    let (client, attestor, sk) = setup(&env);
+    let subject = Address::generate(&env);
+    let mut ids = soroban_sdk::Vec::new(&env);
+    for i in 0..3u8 {
+        let payload = Bytes::from_array(&env, &[10 + i; 32]);
+        let sig = sign_payload(&env, &sk, &payload);
+        ids.push_back(client.submit_attestation_with_consent(&attestor, &subject, &NOW, &payload, &sig));
+    }
+
+    let page = client.get_consent_receipts(&subject, &1, &5);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().attestation_id, ids.get(1).unwrap());
+    assert_eq!(client.get_consent_receipts(&subject, &3, &5).len(), 0);
+}
@@ -1,16 +1,29 @@
 use soroban_sdk::{
-    contract, contractimpl, contracttype, panic_with_error, symbol_short, Address, Bytes, BytesN,
-    Env, String, Symbol, Vec,
+    contract, contractimpl, contracttype, panic_with_error, symbol_short, token, Address, Bytes, BytesN,
+    Env, String, Symbol, TryFromVal, Val, Vec,
 };
 
-use crate::deterministic_hash::{compute_payload_hash, verify_payload_hash};
-use crate::errors::ErrorCode;
+use anchorkit_interface::ArbitrationCourtClient;
+
+use crate::deterministic_hash::{
+    canonical_attestation_bytes, compute_bundle_hash, compute_delegation_hash, compute_payload_hash,
+    compute_ref_binding_hash, compute_revocation_hash, domain_message, DELEGATION_DOMAIN, REVOCATION_DOMAIN,
+    schema_id_from_hash, REGISTERED_SCHEMA_BIT,
+    verify_payload_hash,
+};
+use crate::errors::{ErrorCode, ErrorCodeExt};
 use crate::sep10_jwt;
+use crate::roles::{self, ROLE_ARBITER, ROLE_ATTESTOR_MANAGER, ROLE_PAUSER};
+use crate::validation;
 use crate::storage::{
-    StorageKey,
+    AmendmentKey, AssetKey, AttestorKey, DisputeKey, LineageKey, OrgKey, EphemeralKey, PinKey, ProposalKey, RelayerKey, RentKey, RootKey, SchemaKey, StatsKey, StorageKey, VisibilityKey,
     key_admin, key_counter, key_session_counter, key_quote_counter,
     key_audit_counter, key_anchor_list, key_health_threshold, key_replay_window,
-    key_audit_log_offset,
+    key_audit_log_offset, key_metrics_interval, key_attestor_count, key_revoked_attestor_count,
+    key_attestation_max_age, key_policy_counter,
+    key_bundle_counter, key_invalidation_epoch, key_replay_scope,
+    key_chain_head, key_challenge_cooldown, key_event_verbosity, key_exit_challenge_window,
+    key_sunset, key_suspended_tier, key_field_bounds, key_schema_change_delay, key_paused, key_paused_subsystems, key_schema_required, key_successor, key_storage_version, key_consent_required, key_relayer_allowlist, key_rent_sponsor, key_fee_config, key_fee_escrow, key_timestamp_drift, key_stake_config, key_challenge_bond, key_arbitration_court, key_pin_deposit, key_ruling_window, key_appeal_window, key_ephemeral_count, key_proposal_count, key_subject_gate, retention_ttl,
 };
 
 // ---------------------------------------------------------------------------
@@ -18,19 +31,49 @@ use crate::storage::{
 // ---------------------------------------------------------------------------
 
 pub use crate::types::{
-    AnchorMetadata, AnchorServices, AssetInfo, Attestation, AuditLog, CapabilitiesCache,
-    CachedToml, FiatCurrency, HealthStatus, MetadataCache, OperationContext, Quote, RequestId,
-    RoutingOptions, RoutingRequest, Session, StellarToml, TracingSpan,
-    SERVICE_DEPOSITS, SERVICE_WITHDRAWALS, SERVICE_QUOTES, SERVICE_KYC, ServiceType,
+    AnchorMetadata, AnchorServices, AnchoredRoot, AssetInfo, Attestation, AttestationBundle, AttestationData, AttestationDisclosure, AttestationInput, AttestationProposal,
+    AttestationReceipt, AttestationStatus, AttestationValidity, AttestationView, AttestorActivity, AttestorInfo, AuditLog,
+    BatchItemResult, BundleClaim, CachedToml, ChainLink, Challenge, ChallengeBondConfig, ChallengeStatus, CapabilitiesCache, PinDeposit, ConsentReceipt, EphemeralAttestation, EscrowedFee, EventVerbosity, FeeConfig, FeeEscrowConfig, FieldBounds, FiatCurrency,
+    HealthStatus, IssuerSnapshot, KeyType, MetadataCache, OperationContext, OrgLimits, OrgStats, Organization, Policy,
+    PendingSchemaChange, PolicyOp, PolicyRequirement, ProofBundle, Quote, RentSponsorConfig, RentTarget, ReplayScope, RequestId, RetentionClass, RoutingOptions,
+    RedactedAttestation, RoutingRequest, Schema, SchemaChange, Session, StakeConfig, Status, SubjectGate, StellarToml, SubjectKind, TimestampDrift, TracingSpan, WindDown,
+    SCHEMA_RIGHT_FEE, SCHEMA_RIGHT_ISSUERS, SCHEMA_RIGHT_RESOLVER, SERVICE_DEPOSITS,
+    SERVICE_WITHDRAWALS, SERVICE_QUOTES, SERVICE_KYC, SUBSYSTEM_DISPUTES, SUBSYSTEM_FEEDS, SUBSYSTEM_FEES, SUBSYSTEM_ISSUANCE,
+    SUBSYSTEM_REVOCATION, ServiceType, ORG_ID_BITS,
 };
 
 const MIN_TEMP_TTL: u32 = 15; // min_temp_entry_ttl - 1
 const LEDGER_PERIOD_SECS: u64 = 5; // approximate seconds per ledger
+const MAX_SUCCESSOR_HOPS: u32 = 8; // bound on subject-successor chain walks
+const MAX_SUPERSESSION_DEPTH: u32 = 4; // schema-successor hops followed to validate a superseding attestation
+const DEFAULT_EXIT_CHALLENGE_WINDOW: u64 = 7 * 24 * 60 * 60; // 7 days
+const DEFAULT_RULING_WINDOW: u64 = 30 * 24 * 60 * 60; // 30 days for a court to rule
+const DEFAULT_CHALLENGE_COOLDOWN: u64 = 7 * 24 * 60 * 60; // 7 days between an upheld challenge and the next
+const SECS_PER_DAY: u64 = 86_400;
+const MAX_ACTIVITY_DAYS: u64 = 31; // widest day range the activity and stats views scan
+const VALUE_BUCKETS: u32 = 16; // per-schema value buckets; the last one is open-ended
+const INBOX_SIZE: u32 = 16; // attestation IDs kept in each subject's inbox
+const MAX_BATCH_SIZE: u32 = 20; // entries accepted by one batch_attest call
+const MAX_ATTESTOR_INFO_LEN: u32 = 256; // bytes allowed in an attestor's name or URI
+const MAX_REF_DEPTH: u32 = 16; // attestations returned by get_attestation_chain
+const MAX_ATTESTOR_SCHEMAS: u32 = 32; // entries in one attestor's schema allow-list
+const MAX_RELIERS: u32 = 8; // pins one attestation can hold
+const MAX_ATTESTOR_PAGE: u32 = 25; // list_attestors entries per call; each costs two reads
+const MAX_SYNC_PAGE: u32 = 20; // list_attestations_by_id IDs per call; each also resolves its status
+const MAX_PENDING_SCHEMA_CHANGES: u32 = 8; // queued changes one schema can hold under a change delay
+const CONTRACT_VERSION: u32 = 1; // storage layout this code expects; bump with a migrate step
 
 use crate::events::{
-    AnchorDeactivated, AttestEvent, AuditLogEvent, AuditLogPruned, EndpointUpdated,
-    QuoteReceivedEvent, QuoteSubmitEvent, SessionCreatedEvent,
+    AnchorDeactivated, AttestationAmended, AttestorSlashed, AuditLogEvent, AuditLogPruned, ConsentGranted, ConsentRevoked, ContractUpgraded, EndpointUpdated, FeeUpdated,
+    LineageTainted, MetricsSnapshot, OrgAdminChanged, OrgAttestorUpdated, OrgCreated, QuotaExceeded, QuoteReceivedEvent, QuoteSubmitEvent, RentSponsored, ReplayRejected,
+    SchemaChangePending, SchemaRegistered, SchemaIssuerUpdated,
+    SessionCreatedEvent, StatusChanged, SubjectTopicEvent, TimestampRejected, UriUpdated, VisibilityChanged,
 };
+use crate::events::{AttestationRevoked, EVENT_VERSION};
+#[cfg(not(feature = "legacy-events"))]
+use crate::events::AttestEvent;
+#[cfg(feature = "legacy-events")]
+use crate::events::AttestEventV1;
 
 // ---------------------------------------------------------------------------
 // Contract-local event structs (not shared with events.rs)
@@ -57,6 +100,20 @@ struct AdminTransferred {
     new_admin: Address,
 }
 
+#[contracttype]
+#[derive(Clone)]
+struct Paused {
+    by: Address,
+    paused_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+struct Unpaused {
+    by: Address,
+    unpaused_at: u64,
+}
+
 #[contracttype]
 #[derive(Clone)]
 struct AttestorRegistered(Address);
@@ -86,6 +143,10 @@ const INSTANCE_TTL: u32 = 518_400;
 const SESSION_TTL: u64 = 86_400;
 /// Session storage TTL in ledgers (~24 hours at 5s/ledger).
 const SESSION_LEDGER_TTL: u32 = 17_280;
+/// Lifetime of a co-signing proposal in seconds (~24 hours).
+const PROPOSAL_TTL: u64 = 86_400;
+/// Proposal storage TTL in ledgers (~24 hours at 5s/ledger).
+const PROPOSAL_LEDGER_TTL: u32 = 17_280;
 
 fn pending_admin_key(env: &Env) -> soroban_sdk::Vec<soroban_sdk::Symbol> {
     soroban_sdk::vec![env, symbol_short!("PADMIN")]
@@ -114,7 +175,8 @@ impl AnchorKitContract {
     ///
     /// `replay_window_seconds` sets the tolerance window for timestamp-based
     /// replay attack detection.  Attestations whose timestamp falls outside
-    /// `[now - window, now + window]` are rejected.
+    /// `[now - window, now]` are rejected until `set_timestamp_drift`
+    /// configures a different window.
     ///
     /// Defaults to **300 seconds** (5 minutes) when `None` is supplied.
     pub fn initialize(env: Env, admin: Address, max_audit_log_size: u64, replay_window_seconds: Option<u64>) {
@@ -135,6 +197,7 @@ impl AnchorKitContract {
         // Default replay window: 300 seconds (5 minutes).
         let window = replay_window_seconds.unwrap_or(300u64);
         inst.set(&key_replay_window(&env), &window);
+        inst.set(&key_storage_version(&env), &CONTRACT_VERSION);
         inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
     }
 
@@ -162,6 +225,7 @@ impl AnchorKitContract {
 
     /// Accept admin transfer (pending admin only). Updates admin, clears pending.
     pub fn accept_admin(env: Env) {
+        Self::require_writable(&env);
         let inst = env.storage().instance();
         let pending: Address = inst
             .get(&pending_admin_key(&env))
@@ -180,6 +244,33 @@ impl AnchorKitContract {
         );
     }
 
+    /// Grant `role` (one of `roles::ROLE_*`) to `account`. `caller` must be
+    /// the admin or hold `ROLE_ADMIN`.
+    pub fn grant_role(env: Env, caller: Address, role: Symbol, account: Address) {
+        Self::require_role(&env, &caller, roles::ROLE_ADMIN);
+        if !roles::is_known_role(&role) {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        let key = StorageKey::Role(role.clone(), account.clone());
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish((symbol_short!("role"), symbol_short!("granted"), role), account);
+    }
+
+    /// Revoke an explicit grant of `role` from `account`. Same caller rules
+    /// as `grant_role`; the admin key's implicit roles cannot be revoked.
+    pub fn revoke_role(env: Env, caller: Address, role: Symbol, account: Address) {
+        Self::require_role(&env, &caller, roles::ROLE_ADMIN);
+        env.storage().persistent().remove(&StorageKey::Role(role.clone(), account.clone()));
+        env.events().publish((symbol_short!("role"), symbol_short!("revoked"), role), account);
+    }
+
+    /// Whether `account` may act as `role`, counting the admin key and
+    /// `ROLE_ADMIN` holders, who pass every role check.
+    pub fn has_role(env: Env, role: Symbol, account: Address) -> bool {
+        roles::has_role(&env, &role, &account)
+    }
+
     pub fn get_admin(env: Env) -> Address {
         env.storage()
             .instance()
@@ -207,12 +298,277 @@ impl AnchorKitContract {
         );
     }
 
+    /// Emit a `MetricsSnapshot` event every `interval` attestations.
+    ///
+    /// Pass `0` to disable periodic snapshots (the default).
+    pub fn set_metrics_interval(env: Env, interval: u64) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&key_metrics_interval(&env), &interval);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    }
+
+    pub fn get_metrics_interval(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&key_metrics_interval(&env))
+            .unwrap_or(0u64)
+    }
+
+    /// Set how much data record events carry (admin only).
+    pub fn set_event_verbosity(env: Env, level: EventVerbosity) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&key_event_verbosity(&env), &level);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    }
+
+    pub fn get_event_verbosity(env: Env) -> EventVerbosity {
+        env.storage()
+            .instance()
+            .get(&key_event_verbosity(&env))
+            .unwrap_or(EventVerbosity::Standard)
+    }
+
+    /// Set the boundary within which a payload hash may only be used once
+    /// (admin only).
+    ///
+    /// Under the per-issuer scopes independent attestors can each anchor the
+    /// same artifact, and a reused hash fails with `HashAlreadyUsedByIssuer`
+    /// rather than `ReplayAttack`.
+    ///
+    /// The scope is fixed once the first attestation is recorded, since
+    /// markers written under one scope are not found under another; later
+    /// calls fail with `ValidationError`.
+    pub fn set_replay_scope(env: Env, scope: ReplayScope) {
+        Self::require_admin(&env);
+        if Self::get_attestation_count(env.clone()) > 0 {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        env.storage().instance().set(&key_replay_scope(&env), &scope);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    }
+
+    pub fn get_replay_scope(env: Env) -> ReplayScope {
+        env.storage()
+            .instance()
+            .get(&key_replay_scope(&env))
+            .unwrap_or(ReplayScope::Global)
+    }
+
+    /// Accept attestation timestamps from `max_past_drift` seconds before to
+    /// `max_future_drift` seconds after the ledger time (admin only).
+    pub fn set_timestamp_drift(env: Env, max_past_drift: u64, max_future_drift: u64) {
+        Self::require_admin(&env);
+        let drift = TimestampDrift { max_past_drift, max_future_drift };
+        env.storage().instance().set(&key_timestamp_drift(&env), &drift);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        env.events().publish((symbol_short!("drift"), symbol_short!("updated")), drift);
+    }
+
+    /// Configured drift window. Until set, the past drift is the replay
+    /// window given at initialization and no future drift is allowed.
+    pub fn get_timestamp_drift(env: Env) -> TimestampDrift {
+        env.storage().instance().get(&key_timestamp_drift(&env)).unwrap_or_else(|| TimestampDrift {
+            max_past_drift: env.storage().instance().get(&key_replay_window(&env)).unwrap_or(300u64),
+            max_future_drift: 0,
+        })
+    }
+
+    /// Permanently switch the contract to read-only "sunset" mode (admin only).
+    ///
+    /// Every state-changing entrypoint fails with `ReadOnlyMode` afterwards,
+    /// while all views keep working so existing verifiers are not broken.
+    /// The exceptions settle what is already held: challenge rulings,
+    /// escrowed fee releases and stake withdrawals. Views that prune caches
+    /// or report expiries skip their side effects. There is no way to leave
+    /// sunset mode.
+    pub fn enable_sunset(env: Env) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&key_sunset(&env), &true);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        env.events().publish((symbol_short!("sunset"), symbol_short!("enabled")), env.ledger().timestamp());
+    }
+
+    pub fn is_sunset(env: Env) -> bool {
+        env.storage().instance().get(&key_sunset(&env)).unwrap_or(false)
+    }
+
+    /// Point integrators at the contract replacing this one (admin only).
+    ///
+    /// While set, every state-changing entrypoint fails with
+    /// `MovedToSuccessor` so clients can look up `successor()` and switch
+    /// over; views keep working, as do the settlements listed under
+    /// `enable_sunset`. The admin can repoint or clear it (`None`).
+    pub fn set_successor(env: Env, successor: Option<Address>) {
+        let admin = Self::get_admin(env.clone());
+        admin.require_auth();
+        if successor.as_ref() == Some(&env.current_contract_address()) {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        match &successor {
+            Some(successor) => env.storage().instance().set(&key_successor(&env), successor),
+            None => env.storage().instance().remove(&key_successor(&env)),
+        }
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        env.events().publish((symbol_short!("migrate"), symbol_short!("successor")), successor);
+    }
+
+    /// Replace the contract's code with the uploaded Wasm `new_wasm_hash`
+    /// (admin only). Storage is kept as is; call `migrate` on the new code
+    /// to bring it up to that code's layout. Allowed while a migration is
+    /// pending, so code without a migration path can be replaced.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        Self::require_layout_admin(&env);
+        env.events().publish(
+            (symbol_short!("contract"), symbol_short!("upgraded")),
+            ContractUpgraded { from_version: CONTRACT_VERSION, new_wasm_hash: new_wasm_hash.clone() },
+        );
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    /// Storage layout version this code expects.
+    pub fn version(_env: Env) -> u32 {
+        CONTRACT_VERSION
+    }
+
+    /// Storage layout version the contract's data is currently at.
+    /// Deployments that predate versioning report `0`: their layout is not
+    /// version 1's, and no migration step leads from it.
+    pub fn storage_version(env: Env) -> u32 {
+        env.storage().instance().get(&key_storage_version(&env)).unwrap_or(0)
+    }
+
+    /// Run the storage migrations between `storage_version()` and
+    /// `version()` after an upgrade (admin only). A no-op when already
+    /// current; fails with `NoMigrationPath` if the data is newer than the
+    /// code or a step is missing. Until it succeeds, every state-changing
+    /// entrypoint fails with `MigrationPending`. Returns the new storage
+    /// version.
+    pub fn migrate(env: Env) -> u32 {
+        Self::require_layout_admin(&env);
+        let from = Self::storage_version(env.clone());
+        if from > CONTRACT_VERSION {
+            panic_with_error!(&env, ErrorCodeExt::NoMigrationPath);
+        }
+        for version in from..CONTRACT_VERSION {
+            Self::migrate_storage(&env, version);
+        }
+        env.storage().instance().set(&key_storage_version(&env), &CONTRACT_VERSION);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        if from != CONTRACT_VERSION {
+            env.events().publish((symbol_short!("contract"), symbol_short!("migrated")), (from, CONTRACT_VERSION));
+        }
+        CONTRACT_VERSION
+    }
+
+    /// Address of the contract that replaced this one, if any.
+    pub fn successor(env: Env) -> Option<Address> {
+        env.storage().instance().get(&key_successor(&env))
+    }
+
+    /// Emergency stop (`ROLE_PAUSER`), e.g. when an attestor key is compromised.
+    ///
+    /// While paused, every call gated by a subsystem (issuance, revocation,
+    /// fee configuration, quote feeds) fails with `ContractPaused`. Views and
+    /// administration keep working. Undo with `unpause`.
+    pub fn pause(env: Env, caller: Address) {
+        Self::require_role(&env, &caller, ROLE_PAUSER);
+        Self::put_paused(&env, true);
+        env.events().publish(
+            (symbol_short!("pause"), symbol_short!("paused")),
+            Paused { by: caller, paused_at: env.ledger().timestamp() },
+        );
+    }
+
+    /// Lift an emergency stop set by `pause` (`ROLE_PAUSER`). Subsystems
+    /// paused individually stay paused.
+    pub fn unpause(env: Env, caller: Address) {
+        Self::require_role(&env, &caller, ROLE_PAUSER);
+        Self::put_paused(&env, false);
+        env.events().publish(
+            (symbol_short!("pause"), symbol_short!("unpaused")),
+            Unpaused { by: caller, unpaused_at: env.ledger().timestamp() },
+        );
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        env.storage().instance().get(&key_paused(&env)).unwrap_or(false)
+    }
+
+    /// Pause the `SUBSYSTEM_*` bits in `subsystems` (`ROLE_PAUSER`), leaving the
+    /// rest of the contract running. Calls into a paused subsystem fail with
+    /// `SubsystemPaused` until it is resumed.
+    pub fn pause_subsystems(env: Env, caller: Address, subsystems: u32) {
+        Self::require_role(&env, &caller, ROLE_PAUSER);
+        let paused = Self::get_paused_subsystems(env.clone()) | subsystems;
+        Self::put_paused_subsystems(&env, paused);
+    }
+
+    /// Resume the `SUBSYSTEM_*` bits in `subsystems` (`ROLE_PAUSER`).
+    pub fn resume_subsystems(env: Env, caller: Address, subsystems: u32) {
+        Self::require_role(&env, &caller, ROLE_PAUSER);
+        let paused = Self::get_paused_subsystems(env.clone()) & !subsystems;
+        Self::put_paused_subsystems(&env, paused);
+    }
+
+    /// Bitmask of the currently paused `SUBSYSTEM_*` bits.
+    pub fn get_paused_subsystems(env: Env) -> u32 {
+        env.storage().instance().get(&key_paused_subsystems(&env)).unwrap_or(0)
+    }
+
+    /// Set the size limits on caller-supplied attestation fields (admin only).
+    ///
+    /// Applies to every issuance path; a bound of `0` disables that check.
+    pub fn set_field_bounds(env: Env, bounds: FieldBounds) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&key_field_bounds(&env), &bounds);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    }
+
+    pub fn get_field_bounds(env: Env) -> FieldBounds {
+        validation::field_bounds(&env)
+    }
+
     /// Returns `true` if the contract has been initialized, `false` otherwise.
     /// Safe to call at any time — never panics.
     pub fn is_initialized(env: Env) -> bool {
         env.storage().instance().has(&key_admin(&env))
     }
 
+    // -----------------------------------------------------------------------
+    // Interface introspection
+    // -----------------------------------------------------------------------
+
+    /// Identifiers of the interfaces this deployment implements.
+    ///
+    /// * `core`     - attestor registry and attestation recording/reads
+    /// * `schemas`  - schema registry, issuer allowlists and governance
+    /// * `orgs`     - organizations, quotas and reserved ID ranges
+    /// * `policies` - composite credential policies
+    /// * `bundles`  - multi-claim attestation bundles
+    /// * `sessions` - session-scoped operations and audit log
+    /// * `quotes`   - anchor quotes and routing
+    /// * `fees`     - issuance fees, overrides and escrow
+    pub fn get_interfaces(env: Env) -> Vec<Symbol> {
+        soroban_sdk::vec![
+            &env,
+            symbol_short!("core"),
+            symbol_short!("schemas"),
+            symbol_short!("orgs"),
+            symbol_short!("policies"),
+            symbol_short!("bundles"),
+            symbol_short!("sessions"),
+            symbol_short!("quotes"),
+            symbol_short!("fees"),
+        ]
+    }
+
+    /// ERC-165-style check for a single interface identifier. Unknown
+    /// identifiers (including `feeds`, which this build does not implement)
+    /// return `false`.
+    pub fn supports(env: Env, interface: Symbol) -> bool {
+        Self::get_interfaces(env).contains(&interface)
+    }
+
     // -----------------------------------------------------------------------
     // Request ID generation
     // -----------------------------------------------------------------------
@@ -389,1715 +745,6863 @@ impl AnchorKitContract {
     pub fn register_attestor(env: Env, attestor: Address, sep10_token: String, sep10_issuer: Address) {
         Self::require_admin(&env);
         Self::verify_sep10_token_matches_attestor(&env, &sep10_token, &sep10_issuer, &attestor);
-        let key = StorageKey::Attestor(attestor.clone());
-        if env.storage().persistent().has(&key) {
-            panic_with_error!(&env, ErrorCode::AttestorAlreadyRegistered);
+        Self::add_attestor_record(&env, &attestor);
+    }
+
+    /// `register_attestor` for holders of `ROLE_ATTESTOR_MANAGER`.
+    pub fn onboard_attestor(env: Env, caller: Address, attestor: Address, sep10_token: String, sep10_issuer: Address) {
+        Self::require_role(&env, &caller, ROLE_ATTESTOR_MANAGER);
+        Self::verify_sep10_token_matches_attestor(&env, &sep10_token, &sep10_issuer, &attestor);
+        Self::add_attestor_record(&env, &attestor);
+    }
+
+    /// Profile of an attestor, or `None` if it was never registered. Kept
+    /// after revocation; check `is_attestor` for current standing.
+    pub fn get_attestor_info(env: Env, attestor: Address) -> Option<AttestorInfo> {
+        let mut info: AttestorInfo = env.storage().persistent().get(&AttestorKey::Info(attestor.clone()))?;
+        // Signing keys are managed by `set_attestor_key*`; reflect them at read time.
+        if let Some(key) = Self::get_attestor_key(env.clone(), attestor.clone()) {
+            info.public_key = key;
         }
-        env.storage().persistent().set(&key, &true);
-        env.storage()
+        info.secp256k1_key = Self::secp256k1_key_bytes(&env, attestor);
+        Some(info)
+    }
+
+    /// Publish the attestor's display name and URI (attestor only). Each is
+    /// capped at `MAX_ATTESTOR_INFO_LEN` bytes.
+    pub fn update_attestor_info(env: Env, attestor: Address, name: Bytes, uri: Bytes) {
+        attestor.require_auth();
+        Self::check_attestor(&env, &attestor);
+        if name.len() > MAX_ATTESTOR_INFO_LEN || uri.len() > MAX_ATTESTOR_INFO_LEN {
+            panic_with_error!(&env, ErrorCode::FieldTooLarge);
+        }
+        let key = AttestorKey::Info(attestor.clone());
+        let mut info: AttestorInfo = env
+            .storage()
             .persistent()
-            .extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
-        env.events().publish(
-(symbol_short!("attestor"), symbol_short!("reg")),
-            AttestorRegistered(attestor),
-        );
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestorNotRegistered));
+        info.name = name;
+        info.uri = uri;
+        env.storage().persistent().set(&key, &info);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish((symbol_short!("attestor"), symbol_short!("info"), attestor), ());
     }
 
-    pub fn revoke_attestor(env: Env, attestor: Address) {
-        Self::require_admin(&env);
-        let key = StorageKey::Attestor(attestor.clone());
-        if !env.storage().persistent().has(&key) {
-            panic_with_error!(&env, ErrorCode::AttestorNotRegistered);
+    /// Page through currently registered attestors in registration order.
+    /// Revoked attestors are skipped without shifting later pages. A page
+    /// covers at most `MAX_ATTESTOR_PAGE` entries (or the max page size, if
+    /// smaller), since each entry is checked against the attestor record.
+    pub fn list_attestors(env: Env, offset: u64, limit: u32) -> Vec<Address> {
+        let actual_limit = limit.min(Self::get_max_page_size(env.clone())).min(MAX_ATTESTOR_PAGE);
+        let mut attestors = Vec::new(&env);
+        let total: u64 = env.storage().persistent().get(&AttestorKey::ListCount).unwrap_or(0);
+        for i in offset..total.min(offset.saturating_add(actual_limit as u64)) {
+            if let Some(attestor) = env.storage().persistent().get::<_, Address>(&AttestorKey::ListEntry(i)) {
+                if Self::is_attestor(env.clone(), attestor.clone()) {
+                    attestors.push_back(attestor);
+                }
+            }
         }
-        env.storage().persistent().remove(&key);
-        // Mark the attestor as revoked so historical attestations surface issuer_revoked=true.
-        let revoked_key = StorageKey::AttestorRevoked(attestor.clone());
-        env.storage().persistent().set(&revoked_key, &true);
-        env.storage().persistent().extend_ttl(&revoked_key, PERSISTENT_TTL, PERSISTENT_TTL);
-        env.events().publish(
-            (symbol_short!("attestor"), symbol_short!("revoked")),
-            AttestorRevoked(attestor),
-        );
+        attestors
     }
 
-    pub fn is_attestor(env: Env, attestor: Address) -> bool {
-        env.storage()
-            .persistent()
-            .get::<_, bool>(&StorageKey::Attestor(attestor))
-            .unwrap_or(false)
+    /// `revoke_attestor` for holders of `ROLE_ATTESTOR_MANAGER`.
+    pub fn offboard_attestor(env: Env, caller: Address, attestor: Address) {
+        Self::require_role(&env, &caller, ROLE_ATTESTOR_MANAGER);
+        Self::revoke_attestor_record(&env, &attestor);
     }
 
-    // -----------------------------------------------------------------------
-    // Attestor endpoint management
-    // -----------------------------------------------------------------------
+    /// Like `register_attestor`, additionally recording the ed25519 key the
+    /// attestor signs attestation payloads with.
+    pub fn register_attestor_with_key(
+        env: Env,
+        attestor: Address,
+        sep10_token: String,
+        sep10_issuer: Address,
+        public_key: BytesN<32>,
+    ) {
+        Self::register_attestor(env.clone(), attestor.clone(), sep10_token, sep10_issuer);
+        Self::put_attestor_key(&env, &attestor, &public_key);
+    }
 
-    pub fn set_endpoint(env: Env, attestor: Address, endpoint: String) {
+    /// Like `register_attestor_with_key`, for a key of any `KeyType`:
+    /// 32 bytes for ed25519, 65 bytes (uncompressed, `0x04` prefix) for
+    /// secp256k1. An attestor has one signing key at a time.
+    pub fn register_attestor_with_key_type(
+        env: Env,
+        attestor: Address,
+        sep10_token: String,
+        sep10_issuer: Address,
+        key_type: KeyType,
+        public_key: Bytes,
+    ) {
+        Self::register_attestor(env.clone(), attestor.clone(), sep10_token, sep10_issuer);
+        Self::put_typed_key(&env, &attestor, key_type, public_key);
+    }
+
+    /// Scheme of `attestor`'s signing key. Attestors without a registered
+    /// key sign with their SEP-10 ed25519 keys, so this defaults to `Ed25519`.
+    pub fn get_attestor_key_type(env: Env, attestor: Address) -> KeyType {
+        if env.storage().persistent().has(&AttestorKey::Secp256k1Key(attestor)) {
+            KeyType::Secp256k1
+        } else {
+            KeyType::Ed25519
+        }
+    }
+
+    /// Rotate the caller's attestation signing key (attestor only).
+    pub fn set_attestor_key(env: Env, attestor: Address, public_key: BytesN<32>) {
+        attestor.require_auth();
+        Self::check_attestor(&env, &attestor);
+        Self::put_attestor_key(&env, &attestor, &public_key);
+    }
+
+    /// Rotate the caller's attestation signing key to a key of any
+    /// `KeyType`, with the lengths `register_attestor_with_key_type` takes
+    /// (attestor only). Replaces the previous key whatever its type.
+    pub fn set_attestor_key_with_type(env: Env, attestor: Address, key_type: KeyType, public_key: Bytes) {
         attestor.require_auth();
         Self::check_attestor(&env, &attestor);
+        Self::put_typed_key(&env, &attestor, key_type, public_key);
+    }
 
-        let len = endpoint.len() as usize;
-        let mut rust_buf = [0u8; 128];
-        if len > 128 {
-            panic_with_error!(&env, ErrorCode::InvalidEndpointFormat);
-        }
-        endpoint.copy_into_slice(&mut rust_buf[..len]);
-        let endpoint_str = core::str::from_utf8(&rust_buf[..len]).unwrap_or("");
+    /// secp256k1 signing key registered for `attestor`, if it signs with one.
+    pub fn get_attestor_secp256k1_key(env: Env, attestor: Address) -> Option<BytesN<65>> {
+        env.storage().persistent().get(&AttestorKey::Secp256k1Key(attestor))
+    }
 
-        if crate::validate_anchor_domain(endpoint_str).is_err() {
-            panic_with_error!(&env, ErrorCode::InvalidEndpointFormat);
+    /// ed25519 signing key registered for `attestor`; when neither it nor a
+    /// secp256k1 key is registered, signatures are checked against the
+    /// SEP-10 keys published under the attestor's address.
+    pub fn get_attestor_key(env: Env, attestor: Address) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&AttestorKey::SigningKey(attestor))
+    }
+
+    /// Let `keeper` call `renew_attestation` on the attestor's behalf, and
+    /// nothing else; `None` removes the keeper (attestor only). TTL
+    /// extension needs no delegation, since anyone may pay for it.
+    pub fn set_renewal_keeper(env: Env, attestor: Address, keeper: Option<Address>) {
+        Self::require_writable(&env);
+        attestor.require_auth();
+        Self::check_attestor(&env, &attestor);
+        let key = AttestorKey::RenewalKeeper(attestor.clone());
+        match &keeper {
+            Some(keeper) => {
+                env.storage().persistent().set(&key, keeper);
+                env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+            }
+            None => env.storage().persistent().remove(&key),
         }
+        env.events().publish((symbol_short!("attestor"), symbol_short!("keeper"), attestor), keeper);
+    }
 
-        let key = StorageKey::Endpoint(attestor.clone());
-        env.storage().persistent().set(&key, &endpoint);
-        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
-        env.events().publish(
-            (symbol_short!("endpoint"), symbol_short!("updated")),
-            EndpointUpdated { attestor, endpoint },
-        );
+    pub fn get_renewal_keeper(env: Env, attestor: Address) -> Option<Address> {
+        env.storage().persistent().get(&AttestorKey::RenewalKeeper(attestor))
     }
 
-    pub fn get_endpoint(env: Env, attestor: Address) -> String {
-        if !Self::is_attestor(env.clone(), attestor.clone()) {
+    pub fn revoke_attestor(env: Env, attestor: Address) {
+        Self::require_admin(&env);
+        Self::revoke_attestor_record(&env, &attestor);
+    }
+
+    /// Retire `attestor` for good (admin only).
+    ///
+    /// Unlike `revoke_attestor`, attestations it issued before now keep
+    /// their validity and report `issuer_revoked = false`; the attestor just
+    /// can no longer issue, and registering it again fails with
+    /// `AttestorDeactivated`. Like a removal, it leaves its organization,
+    /// counts towards the revoked-attestor total and starts a wind-down.
+    pub fn deactivate_attestor(env: Env, attestor: Address) {
+        Self::require_admin(&env);
+        let key = StorageKey::Attestor(attestor.clone());
+        if !env.storage().persistent().has(&key) {
             panic_with_error!(&env, ErrorCode::AttestorNotRegistered);
         }
-        let endpoint: String = env
+        env.storage().persistent().remove(&key);
+        Self::bump_instance_counter(&env, &key_attestor_count(&env), false);
+        Self::bump_instance_counter(&env, &key_revoked_attestor_count(&env), true);
+        Self::leave_org(&env, &attestor);
+        let now = env.ledger().timestamp();
+        let deactivated_key = AttestorKey::DeactivatedAt(attestor.clone());
+        env.storage().persistent().set(&deactivated_key, &now);
+        env.storage().persistent().extend_ttl(&deactivated_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish((symbol_short!("attestor"), symbol_short!("disabled"), attestor.clone()), now);
+        Self::start_wind_down(&env, &attestor);
+    }
+
+    pub fn get_attestor_deactivated_at(env: Env, attestor: Address) -> Option<u64> {
+        env.storage().persistent().get(&AttestorKey::DeactivatedAt(attestor))
+    }
+
+    /// `true` when `attestor` was registered at `timestamp`: at or after its
+    /// latest registration and either still registered or deactivated later.
+    /// Revoked attestors, those that left via `deregister_self`, and
+    /// timestamps from before a re-registration report `false`.
+    pub fn was_attestor_at(env: Env, attestor: Address, timestamp: u64) -> bool {
+        let registered_at = env
             .storage()
             .persistent()
-            .get::<_, String>(&StorageKey::Endpoint(attestor))
-            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestorNotRegistered));
+            .get::<_, u64>(&AttestorKey::RegisteredAt(attestor.clone()))
+            .or_else(|| {
+                env.storage()
+                    .persistent()
+                    .get::<_, AttestorInfo>(&AttestorKey::Info(attestor.clone()))
+                    .map(|info| info.registered_at)
+            });
+        let Some(registered_at) = registered_at else {
+            return false;
+        };
+        registered_at <= timestamp
+            && (Self::is_attestor(env.clone(), attestor.clone())
+                || Self::deactivated_after(&env, &attestor, timestamp))
+    }
 
-        // Re-validate on read: a storage entry written by an older contract version
-        // with looser validation rules must not be returned as a trusted URL.
-        let len = endpoint.len() as usize;
-        if len > 128 {
-            panic_with_error!(&env, ErrorCode::InvalidEndpointFormat);
-        }
-        let mut rust_buf = [0u8; 128];
-        endpoint.copy_into_slice(&mut rust_buf[..len]);
-        let endpoint_str = core::str::from_utf8(&rust_buf[..len]).unwrap_or("");
-        if crate::validate_anchor_domain(endpoint_str).is_err() {
-            panic_with_error!(&env, ErrorCode::InvalidEndpointFormat);
+    /// Register up to `MAX_BATCH_SIZE` attestors at once (admin only).
+    ///
+    /// Each entry is `(attestor, sep10_token, sep10_issuer)` and is verified
+    /// like a `register_attestor` call. Entries already registered, or
+    /// repeated in the list, fail the whole call with
+    /// `AttestorAlreadyRegistered` when `strict` and are skipped otherwise;
+    /// deactivated attestors likewise fail with `AttestorDeactivated` or are
+    /// skipped. Emits one `attestor/reg` event per added attestor and
+    /// returns how many were added.
+    pub fn add_attestors(env: Env, entries: Vec<(Address, String, Address)>, strict: bool) -> u32 {
+        Self::require_admin(&env);
+        Self::add_attestor_batch(&env, &entries, strict)
+    }
+
+    /// `add_attestors` for holders of `ROLE_ATTESTOR_MANAGER`.
+    pub fn onboard_attestors(env: Env, caller: Address, entries: Vec<(Address, String, Address)>, strict: bool) -> u32 {
+        Self::require_role(&env, &caller, ROLE_ATTESTOR_MANAGER);
+        Self::add_attestor_batch(&env, &entries, strict)
+    }
+
+    /// Revoke up to `MAX_BATCH_SIZE` attestors at once (admin only).
+    /// Entries not currently registered fail the whole call with
+    /// `AttestorNotRegistered` when `strict` and are skipped otherwise.
+    /// Returns how many were revoked.
+    pub fn remove_attestors(env: Env, attestors: Vec<Address>, strict: bool) -> u32 {
+        Self::require_admin(&env);
+        Self::check_attestor_batch(&env, &attestors);
+        let mut removed = 0;
+        for attestor in attestors.iter() {
+            if !env.storage().persistent().has(&StorageKey::Attestor(attestor.clone())) {
+                if strict {
+                    panic_with_error!(&env, ErrorCode::AttestorNotRegistered);
+                }
+                continue;
+            }
+            Self::revoke_attestor_record(&env, &attestor);
+            removed += 1;
         }
+        removed
+    }
 
-        endpoint
+    /// Leave the attestor registry without admin involvement.
+    ///
+    /// Issuance stops immediately and the attestor is marked revoked, like
+    /// an admin removal. As on every removal, a `WindDown` record is stored
+    /// whose `release_at` is the end of the exit challenge window, after
+    /// which any bond held for the attestor may be released.
+    pub fn deregister_self(env: Env, attestor: Address) -> WindDown {
+        attestor.require_auth();
+        Self::check_attestor(&env, &attestor);
+        Self::revoke_attestor_record(&env, &attestor)
+    }
+
+    pub fn get_wind_down(env: Env, attestor: Address) -> Option<WindDown> {
+        env.storage().persistent().get(&StorageKey::WindDown(attestor))
     }
 
     // -----------------------------------------------------------------------
-    // Service configuration
+    // Disputes
     // -----------------------------------------------------------------------
 
-    pub fn configure_services(env: Env, anchor: Address, services: Vec<u32>) {
-        anchor.require_auth();
-        if !env
-            .storage()
-            .persistent()
-            .has(&StorageKey::Attestor(anchor.clone()))
-        {
-            panic_with_error!(&env, ErrorCode::AttestorNotRegistered);
-        }
-        if services.is_empty() {
-            panic_with_error!(&env, ErrorCode::InvalidServiceType);
-        }
-        let mut seen = Vec::new(&env);
-        for s in services.iter() {
-            // Reject any value that is not one of the four known service constants.
-            if s != SERVICE_DEPOSITS
-                && s != SERVICE_WITHDRAWALS
-                && s != SERVICE_QUOTES
-                && s != SERVICE_KYC
-            {
-                panic_with_error!(&env, ErrorCode::InvalidServiceType);
-            }
-            if seen.contains(s) {
-                panic_with_error!(&env, ErrorCode::InvalidServiceType);
-            }
-            seen.push_back(s);
+    /// Require challengers to post at least `min_bond` of `token` (admin
+    /// only). Without a configuration, challenges carry no bond.
+    pub fn set_challenge_bond(env: Env, token: Address, min_bond: i128) {
+        Self::require_admin(&env);
+        if min_bond < 0 {
+            panic_with_error!(&env, ErrorCode::ValidationError);
         }
-        let record = AnchorServices {
-            anchor: anchor.clone(),
-            services: services.clone(),
-        };
-        let key = StorageKey::Services(anchor.clone());
-        env.storage().persistent().set(&key, &record);
-        env.storage()
-            .persistent()
-            .extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
-        env.events()
-            .publish((symbol_short!("services"), symbol_short!("config")), record);
+        let config = ChallengeBondConfig { token, min_bond };
+        env.storage().instance().set(&key_challenge_bond(&env), &config);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        env.events().publish((symbol_short!("dispute"), symbol_short!("bond")), config);
     }
 
-    pub fn get_supported_services(env: Env, anchor: Address) -> AnchorServices {
+    pub fn get_challenge_bond(env: Env) -> Option<ChallengeBondConfig> {
+        env.storage().instance().get(&key_challenge_bond(&env))
+    }
+
+    /// Set how long, in seconds, an attestation that was upheld against a
+    /// challenge is shielded from the next one (admin only). Each challenge
+    /// holds back its escrowed fee, so this bounds how long unbonded
+    /// challenges can freeze it.
+    pub fn set_challenge_cooldown(env: Env, seconds: u64) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&key_challenge_cooldown(&env), &seconds);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    }
+
+    pub fn get_challenge_cooldown(env: Env) -> u64 {
         env.storage()
-            .persistent()
-            .get::<_, AnchorServices>(&StorageKey::Services(anchor))
-            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::ServicesNotConfigured))
+            .instance()
+            .get(&key_challenge_cooldown(&env))
+            .unwrap_or(DEFAULT_CHALLENGE_COOLDOWN)
     }
 
-    pub fn supports_service(env: Env, anchor: Address, service: u32) -> bool {
-        let record = env
+    /// Dispute attestation `id`, posting `bond` (at least the configured
+    /// minimum) from `challenger`. The attestation reports `Disputed` until
+    /// an arbiter resolves the challenge. Only one challenge per attestation
+    /// may be open, invalidated or revoked attestations cannot be
+    /// challenged, and after a challenge is upheld the next one must wait
+    /// out the challenge cooldown (`ChallengeCooldown`). Returns the
+    /// challenge ID.
+    pub fn challenge_attestation(
+        env: Env,
+        challenger: Address,
+        id: u64,
+        evidence_hash: BytesN<32>,
+        bond: i128,
+    ) -> u64 {
+        Self::require_writable(&env);
+        Self::require_subsystem_active(&env, SUBSYSTEM_DISPUTES);
+        challenger.require_auth();
+        let mut attestation: Attestation = env
             .storage()
             .persistent()
-            .get::<_, AnchorServices>(&StorageKey::Services(anchor))
-            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::ServicesNotConfigured));
-        record.services.contains(service)
+            .get(&StorageKey::Attest(id))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+        if attestation.revoked_at.is_some() {
+            panic_with_error!(&env, ErrorCode::AttestationRevoked);
+        }
+        if let Some(latest) = Self::get_attestation_challenge(env.clone(), id) {
+            if latest.status != ChallengeStatus::Upheld {
+                panic_with_error!(&env, ErrorCodeExt::AttestationDisputed);
+            }
+            let cooldown = Self::get_challenge_cooldown(env.clone());
+            if env.ledger().timestamp() < latest.resolved_at.saturating_add(cooldown) {
+                panic_with_error!(&env, ErrorCodeExt::ChallengeCooldown);
+            }
+        }
+        let challenge = Self::open_challenge(&env, id, challenger, evidence_hash, bond, false);
+        Self::transition_status(&env, &mut attestation, Status::Disputed);
+        env.events().publish((symbol_short!("dispute"), symbol_short!("opened"), id), challenge.clone());
+        challenge.id
     }
 
-    // -----------------------------------------------------------------------
-    // Attestation submission (plain)
-    // -----------------------------------------------------------------------
+    /// Close an open challenge (`ROLE_ARBITER`) as `Upheld` (the attestation
+    /// stands and the bond goes to its issuer) or `Invalidated` (the
+    /// attestation stops validating and the bond returns to the challenger).
+    /// The bond is paid out in the token it was posted in, whatever the
+    /// bond configuration says by then. Escalated challenges are left to the
+    /// arbitration court (`ChallengeEscalated`) until its ruling deadline
+    /// passes, after which an arbiter may close them instead.
+    ///
+    /// Settlement only releases funds already held, so it stays available
+    /// in sunset mode and while disputes are paused; only the emergency stop
+    /// blocks it.
+    pub fn resolve_challenge(env: Env, caller: Address, challenge_id: u64, outcome: ChallengeStatus) {
+        Self::check_role(&env, &caller, ROLE_ARBITER);
+        Self::require_not_stopped(&env);
+        let challenge = Self::load_challenge(&env, challenge_id);
+        match challenge.status {
+            ChallengeStatus::Open => {}
+            ChallengeStatus::Escalated => {
+                let deadline = Self::get_ruling_deadline(env.clone(), challenge_id).unwrap_or(u64::MAX);
+                if env.ledger().timestamp() <= deadline {
+                    panic_with_error!(&env, ErrorCodeExt::ChallengeEscalated);
+                }
+            }
+            _ => panic_with_error!(&env, ErrorCodeExt::ChallengeResolved),
+        }
+        Self::settle_challenge(&env, challenge, outcome, caller);
+    }
 
-    pub fn submit_attestation(
-        env: Env,
-        issuer: Address,
-        subject: Address,
-        timestamp: u64,
-        payload_hash: Bytes,
-        signature: Bytes,
-    ) -> u64 {
-        issuer.require_auth();
-        Self::check_attestor(&env, &issuer);
-        if let Err(e) = crate::rate_limiter::RateLimiter::check_and_increment(&env, &issuer) {
-            panic_with_error!(&env, e);
+    /// Set or clear the external arbitration court that challenges can be
+    /// escalated to (admin only).
+    pub fn set_arbitration_court(env: Env, court: Option<Address>) {
+        Self::require_admin(&env);
+        match &court {
+            Some(court) => env.storage().instance().set(&key_arbitration_court(&env), court),
+            None => env.storage().instance().remove(&key_arbitration_court(&env)),
         }
-        Self::check_timestamp(&env, timestamp);
-        Self::verify_attestation_signature(&env, &issuer, &payload_hash, &signature);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        env.events().publish((symbol_short!("dispute"), symbol_short!("court")), court);
+    }
 
-        let used_key = StorageKey::Used(payload_hash.clone());
-        if env.storage().persistent().has(&used_key) {
-            panic_with_error!(&env, ErrorCode::ReplayAttack);
+    pub fn get_arbitration_court(env: Env) -> Option<Address> {
+        env.storage().instance().get(&key_arbitration_court(&env))
+    }
+
+    /// Set how long (seconds) the court has to rule on an escalated
+    /// challenge before `ROLE_ARBITER` may close it (admin only). Applies to
+    /// challenges escalated afterwards.
+    pub fn set_ruling_window(env: Env, window: u64) {
+        Self::require_admin(&env);
+        if window == 0 {
+            panic_with_error!(&env, ErrorCode::ValidationError);
         }
+        env.storage().instance().set(&key_ruling_window(&env), &window);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    }
 
-        let id = Self::next_attestation_id(&env);
-        Self::store_attestation(&env, id, issuer.clone(), subject.clone(), timestamp, payload_hash.clone(), signature);
+    pub fn get_ruling_window(env: Env) -> u64 {
+        env.storage().instance().get(&key_ruling_window(&env)).unwrap_or(DEFAULT_RULING_WINDOW)
+    }
 
-        env.storage().persistent().set(&used_key, &true);
-        env.storage().persistent().extend_ttl(&used_key, PERSISTENT_TTL, PERSISTENT_TTL);
+    /// Time until which only the court may rule on escalated challenge
+    /// `challenge_id`.
+    pub fn get_ruling_deadline(env: Env, challenge_id: u64) -> Option<u64> {
+        env.storage().persistent().get(&DisputeKey::RulingDeadline(challenge_id))
+    }
 
+    /// Hand open challenge `challenge_id` to the arbitration court (its
+    /// challenger or the attestation's issuer).
+    ///
+    /// The court is told through `open_case` (see
+    /// `ArbitrationCourtInterface`) and is recorded on the challenge, so a
+    /// later court change does not move the case. From then on only that
+    /// court's `rule_on_challenge` closes the challenge, or an arbiter once
+    /// the ruling deadline (`get_ruling_window` after escalation) has passed.
+    pub fn escalate_challenge(env: Env, caller: Address, challenge_id: u64) {
+        Self::require_writable(&env);
+        Self::require_subsystem_active(&env, SUBSYSTEM_DISPUTES);
+        caller.require_auth();
+        let court = Self::get_arbitration_court(env.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCodeExt::ArbitrationUnavailable));
+        let mut challenge = Self::load_challenge(&env, challenge_id);
+        match challenge.status {
+            ChallengeStatus::Open => {}
+            ChallengeStatus::Escalated => panic_with_error!(&env, ErrorCodeExt::ChallengeEscalated),
+            _ => panic_with_error!(&env, ErrorCodeExt::ChallengeResolved),
+        }
+        let issuer = Self::load_attestation(&env, challenge.attestation_id).map(|a| a.issuer);
+        if caller != challenge.challenger && Some(caller) != issuer {
+            panic_with_error!(&env, ErrorCode::UnauthorizedAttestor);
+        }
+        challenge.status = ChallengeStatus::Escalated;
+        Self::save_challenge(&env, &challenge);
+        Self::send_to_court(&env, &court, &challenge);
         env.events().publish(
-            (symbol_short!("attest"), symbol_short!("recorded"), id, subject),
-            AttestEvent { payload_hash, timestamp },
+            (symbol_short!("dispute"), symbol_short!("escalated"), challenge.attestation_id),
+            challenge,
         );
+    }
 
-        id
+    /// Set how long (seconds) an issuer may appeal the admin's revocation of
+    /// one of its attestations (admin only). `0`, the default, makes admin
+    /// revocations final at once.
+    pub fn set_appeal_window(env: Env, window: u64) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&key_appeal_window(&env), &window);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
     }
 
-    // -----------------------------------------------------------------------
-    // Attestation submission with request ID + tracing span
-    // -----------------------------------------------------------------------
+    pub fn get_appeal_window(env: Env) -> u64 {
+        env.storage().instance().get(&key_appeal_window(&env)).unwrap_or(0)
+    }
 
-    pub fn submit_with_request_id(
-        env: Env,
-        request_id: RequestId,
-        issuer: Address,
-        subject: Address,
-        timestamp: u64,
-        payload_hash: Bytes,
-        signature: Bytes,
-    ) -> u64 {
+    /// Until when the issuer of attestation `id` may appeal its revocation by
+    /// the admin; `None` once the revocation is final or under appeal.
+    pub fn get_appeal_deadline(env: Env, id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get::<_, u64>(&DisputeKey::AppealDeadline(id))
+            .filter(|deadline| env.ledger().timestamp() <= *deadline)
+    }
+
+    /// Contest the admin's revocation of attestation `id` (its issuer, within
+    /// the appeal window). Returns the appeal's challenge ID.
+    ///
+    /// `deposit` follows the challenge bond configuration. The appeal is
+    /// opened as an `appeal` challenge and escalated to the arbitration
+    /// court straight away. If the court upholds the attestation it is
+    /// reinstated and the deposit refunded; otherwise the revocation becomes
+    /// final and the deposit goes to the admin.
+    pub fn appeal_revocation(env: Env, issuer: Address, id: u64, evidence_hash: BytesN<32>, deposit: i128) -> u64 {
+        Self::require_writable(&env);
+        Self::require_subsystem_active(&env, SUBSYSTEM_DISPUTES);
         issuer.require_auth();
-        Self::check_attestor(&env, &issuer);
-        if let Err(e) = crate::rate_limiter::RateLimiter::check_and_increment(&env, &issuer) {
-            panic_with_error!(&env, e);
+        let attestation = Self::load_attestation(&env, id)
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+        if attestation.issuer != issuer {
+            panic_with_error!(&env, ErrorCode::UnauthorizedAttestor);
         }
-        Self::check_timestamp(&env, timestamp);
-        Self::verify_attestation_signature(&env, &issuer, &payload_hash, &signature);
-
-        let used_key = StorageKey::Used(payload_hash.clone());
-        if env.storage().persistent().has(&used_key) {
-            panic_with_error!(&env, ErrorCode::ReplayAttack);
+        if Self::get_appeal_deadline(env.clone(), id).is_none() {
+            panic_with_error!(&env, ErrorCodeExt::AppealClosed);
         }
+        let court = Self::get_arbitration_court(env.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCodeExt::ArbitrationUnavailable));
+        env.storage().persistent().remove(&DisputeKey::AppealDeadline(id));
+        let mut challenge = Self::open_challenge(&env, id, issuer, evidence_hash, deposit, true);
+        challenge.status = ChallengeStatus::Escalated;
+        Self::save_challenge(&env, &challenge);
+        Self::send_to_court(&env, &court, &challenge);
+        env.events().publish((symbol_short!("dispute"), symbol_short!("appealed"), id), challenge.clone());
+        challenge.id
+    }
 
-        let id = Self::next_attestation_id(&env);
-        Self::store_attestation(&env, id, issuer.clone(), subject.clone(), timestamp, payload_hash.clone(), signature);
+    /// Ruling on escalated challenge `challenge_id`, callable only by the
+    /// court it was escalated to. Settles the challenge like
+    /// `resolve_challenge` with `Upheld` when `upheld`, otherwise with
+    /// `Invalidated`. Like `resolve_challenge`, it works in sunset mode.
+    pub fn rule_on_challenge(env: Env, challenge_id: u64, upheld: bool) {
+        Self::require_not_stopped(&env);
+        let challenge = Self::load_challenge(&env, challenge_id);
+        if challenge.status != ChallengeStatus::Escalated {
+            panic_with_error!(&env, ErrorCodeExt::ChallengeResolved);
+        }
+        let court: Address = env
+            .storage()
+            .persistent()
+            .get(&DisputeKey::Court(challenge_id))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::StorageCorrupted));
+        court.require_auth();
+        let outcome = if upheld { ChallengeStatus::Upheld } else { ChallengeStatus::Invalidated };
+        Self::settle_challenge(&env, challenge, outcome, court);
+    }
 
-        env.storage().persistent().set(&used_key, &true);
-        env.storage().persistent().extend_ttl(&used_key, PERSISTENT_TTL, PERSISTENT_TTL);
+    /// Persist a due expiry of attestation `id` (past `expires_at` or the
+    /// max age). Callable by anyone; returns the current status, which may
+    /// be `Superseded`. Supersession is never stored, since it lapses with
+    /// the successor attestation; a `Superseded` stored by earlier versions
+    /// is reset to `Active`.
+    pub fn refresh_status(env: Env, id: u64) -> Status {
+        Self::require_writable(&env);
+        let mut attestation: Attestation = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::Attest(id))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+        let to = Self::current_status(&env, &attestation);
+        let stored = if to == Status::Superseded { Status::Active } else { to };
+        if stored != attestation.status {
+            Self::transition_status(&env, &mut attestation, stored);
+        }
+        to
+    }
 
-        let now = env.ledger().timestamp();
-        Self::store_span(&env, &request_id, String::from_str(&env, "submit_attestation"), issuer.clone(), now, String::from_str(&env, "success"));
+    pub fn get_challenge(env: Env, challenge_id: u64) -> Option<Challenge> {
+        env.storage().persistent().get(&DisputeKey::Challenge(challenge_id))
+    }
 
-        env.events().publish(
-            (symbol_short!("attest"), symbol_short!("recorded"), id, subject),
-            AttestEvent { payload_hash, timestamp },
-        );
+    /// Most recent challenge against attestation `id`, if any.
+    pub fn get_attestation_challenge(env: Env, id: u64) -> Option<Challenge> {
+        let challenge_id: u64 = env.storage().persistent().get(&DisputeKey::Latest(id))?;
+        Self::get_challenge(env, challenge_id)
+    }
 
-        id
+    /// Require attestors registered through `register_attestor_with_stake`
+    /// to lock at least `min_stake` of `token`; slashed stake is sent to
+    /// `treasury` (admin only). Stakes already locked keep the token they
+    /// were locked in; a new `token` applies to later registrations.
+    pub fn set_stake_config(env: Env, token: Address, min_stake: i128, treasury: Address) {
+        Self::require_admin(&env);
+        if min_stake < 0 {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        let config = StakeConfig { token, min_stake, treasury };
+        env.storage().instance().set(&key_stake_config(&env), &config);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        env.events().publish((symbol_short!("stake"), symbol_short!("config")), config);
     }
 
-    // -----------------------------------------------------------------------
-    // Quote submission with request ID + tracing span
-    // -----------------------------------------------------------------------
+    pub fn get_stake_config(env: Env) -> Option<StakeConfig> {
+        env.storage().instance().get(&key_stake_config(&env))
+    }
 
-    #[allow(unused_variables)]
-    #[allow(clippy::too_many_arguments)]
-    pub fn quote_with_request_id(
+    /// `register_attestor`, additionally locking `amount` of the stake token
+    /// from `attestor` in the contract. Requires the admin's and the
+    /// attestor's authorization; `amount` must meet the configured minimum
+    /// (`InsufficientStake`). Stake left over from an earlier registration in
+    /// another token must be withdrawn first (`StakeLocked`).
+    pub fn register_attestor_with_stake(
         env: Env,
-        request_id: RequestId,
-        anchor: Address,
-        from_asset: String,
-        to_asset: String,
-        amount: u64,
-        fee_bps: u32,
-        min_amount: u64,
-        max_amount: u64,
-        expires_at: u64,
+        attestor: Address,
+        sep10_token: String,
+        sep10_issuer: Address,
+        amount: i128,
     ) {
-        anchor.require_auth();
-
-        let services_record = env
-            .storage()
-            .persistent()
-            .get::<_, AnchorServices>(&StorageKey::Services(anchor.clone()))
-            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::ServicesNotConfigured));
-        if !services_record.services.contains(SERVICE_QUOTES) {
-            panic_with_error!(&env, ErrorCode::ServicesNotConfigured);
+        let config = Self::get_stake_config(env.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::ValidationError));
+        if amount < config.min_stake || amount <= 0 {
+            panic_with_error!(&env, ErrorCodeExt::InsufficientStake);
         }
+        let staked = Self::get_stake(env.clone(), attestor.clone());
+        if staked > 0 && Self::stake_token(&env, &attestor) != config.token {
+            panic_with_error!(&env, ErrorCodeExt::StakeLocked);
+        }
+        Self::register_attestor(env.clone(), attestor.clone(), sep10_token, sep10_issuer);
+        attestor.require_auth();
+        token::Client::new(&env, &config.token).transfer(&attestor, &env.current_contract_address(), &amount);
+        let key = AttestorKey::Stake(attestor.clone());
+        env.storage().persistent().set(&key, &(staked + amount));
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        let token_key = AttestorKey::StakeToken(attestor.clone());
+        env.storage().persistent().set(&token_key, &config.token);
+        env.storage().persistent().extend_ttl(&token_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish((symbol_short!("stake"), symbol_short!("locked"), attestor), amount);
+    }
 
-        let inst = env.storage().instance();
-        let qcnt_key = key_quote_counter(&env);
-        let next: u64 = inst.get(&qcnt_key).unwrap_or(0u64) + 1;
-        inst.set(&qcnt_key, &next);
-        inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
-
-        let quote = Quote {
-            quote_id: next,
-            anchor: anchor.clone(),
-            base_asset: from_asset,
-            quote_asset: to_asset,
-            rate: amount,
-            fee_percentage: fee_bps,
-            minimum_amount: min_amount,
-            maximum_amount: max_amount,
-            valid_until: expires_at,
-        };
-        let q_key = StorageKey::Quote(anchor.clone(), next);
-        env.storage().persistent().set(&q_key, &quote);
-        env.storage().persistent().extend_ttl(&q_key, PERSISTENT_TTL, PERSISTENT_TTL);
+    /// Stake currently locked for `attestor`.
+    pub fn get_stake(env: Env, attestor: Address) -> i128 {
+        env.storage().persistent().get(&AttestorKey::Stake(attestor)).unwrap_or(0)
+    }
 
-        let lq_key = StorageKey::LatestQuote(anchor.clone());
-        env.storage().persistent().set(&lq_key, &next);
-        env.storage().persistent().extend_ttl(&lq_key, PERSISTENT_TTL, PERSISTENT_TTL);
+    /// Move `amount` of `attestor`'s stake to the treasury (admin only),
+    /// e.g. after a proven false attestation. Also possible during the
+    /// attestor's exit challenge window.
+    pub fn slash(env: Env, attestor: Address, amount: i128, reason_hash: BytesN<32>) {
+        Self::require_admin(&env);
+        let staked = Self::get_stake(env.clone(), attestor.clone());
+        if amount <= 0 || amount > staked {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        let config = Self::get_stake_config(env.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::ValidationError));
+        let stake_token = Self::stake_token(&env, &attestor);
+        token::Client::new(&env, &stake_token).transfer(&env.current_contract_address(), &config.treasury, &amount);
+        let remaining = staked - amount;
+        let key = AttestorKey::Stake(attestor.clone());
+        env.storage().persistent().set(&key, &remaining);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish(
+            (symbol_short!("attestor"), symbol_short!("slashed"), attestor.clone()),
+            AttestorSlashed { attestor, amount, reason_hash, remaining },
+        );
+    }
 
-        let now = env.ledger().timestamp();
-        Self::store_span(&env, &request_id, String::from_str(&env, "submit_quote"), anchor, now, String::from_str(&env, "success"));
+    /// Return the attestor's remaining stake once the wind-down started by
+    /// its removal (`deregister_self`, any admin or manager removal, or
+    /// deactivation) has passed its `release_at` (attestor only). Returns
+    /// the amount paid out. Works in sunset mode and after a successor is
+    /// set, so bonds never stay locked.
+    pub fn withdraw_stake(env: Env, attestor: Address) -> i128 {
+        Self::require_not_stopped(&env);
+        attestor.require_auth();
+        let released = Self::get_wind_down(env.clone(), attestor.clone())
+            .is_some_and(|wind_down| env.ledger().timestamp() >= wind_down.release_at);
+        if !released || Self::is_attestor(env.clone(), attestor.clone()) {
+            panic_with_error!(&env, ErrorCodeExt::StakeLocked);
+        }
+        let staked = Self::get_stake(env.clone(), attestor.clone());
+        let stake_token = (staked > 0).then(|| Self::stake_token(&env, &attestor));
+        // Clear the stake before paying out so the token contract cannot re-enter a withdrawal.
+        env.storage().persistent().remove(&AttestorKey::Stake(attestor.clone()));
+        env.storage().persistent().remove(&AttestorKey::StakeToken(attestor.clone()));
+        if let Some(stake_token) = stake_token {
+            token::Client::new(&env, &stake_token).transfer(&env.current_contract_address(), &attestor, &staked);
+        }
+        env.events().publish((symbol_short!("stake"), symbol_short!("released"), attestor), staked);
+        staked
     }
 
-    // -----------------------------------------------------------------------
-    // Tracing span retrieval
-    // -----------------------------------------------------------------------
+    /// Set the challenge window, in seconds, between an attestor's removal
+    /// and bond release (admin only).
+    pub fn set_exit_challenge_window(env: Env, seconds: u64) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&key_exit_challenge_window(&env), &seconds);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    }
 
-    pub fn get_tracing_span(env: Env, request_id_bytes: Bytes) -> Option<TracingSpan> {
+    pub fn get_exit_challenge_window(env: Env) -> u64 {
         env.storage()
-            .temporary()
-            .get::<_, TracingSpan>(&StorageKey::Span(request_id_bytes))
+            .instance()
+            .get(&key_exit_challenge_window(&env))
+            .unwrap_or(DEFAULT_EXIT_CHALLENGE_WINDOW)
     }
 
-    // -----------------------------------------------------------------------
-    // Attestation retrieval
-    // -----------------------------------------------------------------------
+    /// Assign `attestor` a trust tier (admin only). Higher tiers are more
+    /// trusted; unassigned attestors are tier 0 (provisional).
+    pub fn set_attestor_tier(env: Env, attestor: Address, tier: u32) {
+        Self::require_admin(&env);
+        let key = StorageKey::AttestorTier(attestor.clone());
+        env.storage().persistent().set(&key, &tier);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish((symbol_short!("attestor"), symbol_short!("tier"), attestor), tier);
+    }
 
-    pub fn get_attestation(env: Env, id: u64) -> Option<Attestation> {
-        let mut attestation = env.storage()
+    pub fn get_attestor_tier(env: Env, attestor: Address) -> u32 {
+        env.storage()
             .persistent()
-            .get::<_, Attestation>(&StorageKey::Attest(id))?;
-        // Reflect current revocation status without rewriting every stored attestation.
-        if env.storage().persistent().has(&StorageKey::AttestorRevoked(attestation.issuer.clone())) {
-            attestation.issuer_revoked = true;
-        }
-        Some(attestation)
+            .get(&StorageKey::AttestorTier(attestor))
+            .unwrap_or(0)
     }
 
-    pub fn list_attestations(env: Env, subject: Address, offset: u64, limit: u32) -> Vec<Attestation> {
-        let max_page_size = Self::get_max_page_size(env.clone());
-        let actual_limit = if limit > max_page_size { max_page_size } else { limit };
-        let mut results = Vec::new(&env);
-
-        let count_key = StorageKey::SubjectCount(subject.clone());
-        let total_count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
-
-        if offset >= total_count || actual_limit == 0 {
-            return results;
+    /// Restrict `attestor` to issuing under `schema_ids` (admin only), at
+    /// most `MAX_ATTESTOR_SCHEMAS` of them. A restricted attestor cannot
+    /// issue schema-less attestations either. An empty list lifts the
+    /// restriction.
+    pub fn set_attestor_schemas(env: Env, attestor: Address, schema_ids: Vec<u64>) {
+        Self::require_admin(&env);
+        if schema_ids.len() > MAX_ATTESTOR_SCHEMAS {
+            panic_with_error!(&env, ErrorCode::ValidationError);
         }
-
-        let end = if offset + (actual_limit as u64) > total_count {
-            total_count
+        let key = AttestorKey::AllowedSchemas(attestor.clone());
+        if schema_ids.is_empty() {
+            env.storage().persistent().remove(&key);
         } else {
-            offset + (actual_limit as u64)
-        };
+            env.storage().persistent().set(&key, &schema_ids);
+            env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        }
+        env.events().publish((symbol_short!("attestor"), symbol_short!("schemas"), attestor), schema_ids);
+    }
 
-        for i in offset..end {
-            let index_key = StorageKey::SubjectAttestation(subject.clone(), i);
-            if let Some(attestation_id) = env.storage().persistent().get::<_, u64>(&index_key) {
-                let main_key = StorageKey::Attest(attestation_id);
-                if let Some(mut attestation) = env.storage().persistent().get::<_, Attestation>(&main_key) {
-                    if env.storage().persistent().has(&StorageKey::AttestorRevoked(attestation.issuer.clone())) {
-                        attestation.issuer_revoked = true;
-                    }
-                    results.push_back(attestation);
-                }
+    /// Schemas `attestor` is restricted to; empty when unrestricted.
+    pub fn get_attestor_schemas(env: Env, attestor: Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&AttestorKey::AllowedSchemas(attestor))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Emergency brake: freeze issuance by every attestor at or below `tier`
+    /// (admin only). Checked lazily at record time, so no attestor list is
+    /// walked. Replaces any previous suspension level.
+    pub fn suspend_tier(env: Env, tier: u32) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&key_suspended_tier(&env), &tier);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        env.events().publish((symbol_short!("tier"), symbol_short!("suspend")), tier);
+    }
+
+    /// Lift the tier suspension set by `suspend_tier` (admin only).
+    pub fn lift_tier_suspension(env: Env) {
+        Self::require_admin(&env);
+        env.storage().instance().remove(&key_suspended_tier(&env));
+        env.events().publish((symbol_short!("tier"), symbol_short!("lift")), ());
+    }
+
+    /// Highest suspended tier, or `None` when no suspension is active.
+    pub fn get_suspended_tier(env: Env) -> Option<u32> {
+        env.storage().instance().get(&key_suspended_tier(&env))
+    }
+
+    /// Day-bucketed issuance and revocation counts for `attestor` over the
+    /// inclusive day range `[day_from, day_to]` (days since the Unix epoch).
+    ///
+    /// Days without activity are omitted. The range may span at most
+    /// `MAX_ACTIVITY_DAYS`; wider or inverted ranges fail with `ValidationError`.
+    pub fn get_attestor_activity(env: Env, attestor: Address, day_from: u64, day_to: u64) -> Vec<AttestorActivity> {
+        if day_to < day_from || day_to - day_from >= MAX_ACTIVITY_DAYS {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        let mut days = Vec::new(&env);
+        for day in day_from..=day_to {
+            let key = AttestorKey::Activity(attestor.clone(), day);
+            if let Some(activity) = env.storage().persistent().get::<_, AttestorActivity>(&key) {
+                days.push_back(activity);
             }
         }
+        days
+    }
 
-        results
+    pub fn is_attestor(env: Env, attestor: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get::<_, bool>(&StorageKey::Attestor(attestor))
+            .unwrap_or(false)
     }
 
     // -----------------------------------------------------------------------
-    // Deterministic hash utilities
+    // Attestor endpoint management
     // -----------------------------------------------------------------------
 
-    pub fn compute_payload_hash(env: Env, subject: Address, timestamp: u64, data: Bytes) -> BytesN<32> {
-        compute_payload_hash(&env, &subject, timestamp, &data)
-    }
+    pub fn set_endpoint(env: Env, attestor: Address, endpoint: String) {
+        attestor.require_auth();
+        Self::check_attestor(&env, &attestor);
 
-    /// Compute the canonical payload hash via the contract method.
-    ///
-    /// Off-chain callers should prefer this method over calling
-    /// `deterministic_hash::compute_payload_hash` directly. Going through the
-    /// Soroban host environment ensures that `Address` XDR serialisation uses
-    /// the same host-side encoding as on-chain attestation submission, avoiding
-    /// divergence that can occur when the module function is called outside the
-    /// host context.
-    pub fn compute_payload_hash_public(env: Env, subject: Address, timestamp: u64, data: Bytes) -> BytesN<32> {
-        compute_payload_hash(&env, &subject, timestamp, &data)
+        let len = endpoint.len() as usize;
+        let mut rust_buf = [0u8; 128];
+        if len > 128 {
+            panic_with_error!(&env, ErrorCode::InvalidEndpointFormat);
+        }
+        endpoint.copy_into_slice(&mut rust_buf[..len]);
+        let endpoint_str = core::str::from_utf8(&rust_buf[..len]).unwrap_or("");
+
+        if crate::validate_anchor_domain(endpoint_str).is_err() {
+            panic_with_error!(&env, ErrorCode::InvalidEndpointFormat);
+        }
+
+        let key = StorageKey::Endpoint(attestor.clone());
+        env.storage().persistent().set(&key, &endpoint);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish(
+            (symbol_short!("endpoint"), symbol_short!("updated")),
+            EndpointUpdated { attestor, endpoint },
+        );
     }
 
-    pub fn verify_payload_hash(env: Env, attestation_id: u64, expected_hash: BytesN<32>) -> bool {
-        let attestation = env
+    pub fn get_endpoint(env: Env, attestor: Address) -> String {
+        if !Self::is_attestor(env.clone(), attestor.clone()) {
+            panic_with_error!(&env, ErrorCode::AttestorNotRegistered);
+        }
+        let endpoint: String = env
             .storage()
             .persistent()
-            .get::<_, Attestation>(&StorageKey::Attest(attestation_id))
-            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+            .get::<_, String>(&StorageKey::Endpoint(attestor))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestorNotRegistered));
 
-        let stored: BytesN<32> = attestation.payload_hash.try_into().unwrap_or_else(|_| {
-            panic_with_error!(&env, ErrorCode::StorageCorrupted)
-        });
-        verify_payload_hash(&stored, &expected_hash)
+        // Re-validate on read: a storage entry written by an older contract version
+        // with looser validation rules must not be returned as a trusted URL.
+        let len = endpoint.len() as usize;
+        if len > 128 {
+            panic_with_error!(&env, ErrorCode::InvalidEndpointFormat);
+        }
+        let mut rust_buf = [0u8; 128];
+        endpoint.copy_into_slice(&mut rust_buf[..len]);
+        let endpoint_str = core::str::from_utf8(&rust_buf[..len]).unwrap_or("");
+        if crate::validate_anchor_domain(endpoint_str).is_err() {
+            panic_with_error!(&env, ErrorCode::InvalidEndpointFormat);
+        }
+
+        endpoint
     }
 
     // -----------------------------------------------------------------------
-    // Session management
+    // Organizations
     // -----------------------------------------------------------------------
 
-    pub fn create_session(env: Env, initiator: Address) -> u64 {
-        initiator.require_auth();
-        let inst = env.storage().instance();
-        let scnt_key = key_session_counter(&env);
-        let session_id: u64 = inst.get(&scnt_key).unwrap_or(0u64);
-        inst.set(&scnt_key, &(session_id + 1));
-        inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    /// Create organization `org_id` administered by `org_admin` (global admin
+    /// only).
+    ///
+    /// The caller picks the ID so a tenant keeps it across deployments; it
+    /// must fall in `1..(1 << (64 - ORG_ID_BITS)) - 1`, the IDs with a
+    /// reserved attestation range (see `get_org_id_range`), and must not be
+    /// taken already (`OrgAlreadyExists`).
+    pub fn create_org(env: Env, org_admin: Address, org_id: u64) {
+        Self::require_admin(&env);
+        if org_id == 0 || org_id >= (1u64 << (64 - ORG_ID_BITS)) - 1 {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        if env.storage().persistent().has(&StorageKey::Org(org_id)) {
+            panic_with_error!(&env, ErrorCodeExt::OrgAlreadyExists);
+        }
 
-        let now = env.ledger().timestamp();
-        let nonce: u64 = env.prng().gen_range(u64::MIN..=u64::MAX);
-        let session = Session {
-            session_id,
-            initiator: initiator.clone(),
-            created_at: now,
-            nonce,
-            operation_count: 0,
-            expires_at: now + SESSION_TTL,
+        let org = Organization {
+            id: org_id,
+            admin: org_admin.clone(),
+            created_at: env.ledger().timestamp(),
+            attestor_count: 0,
         };
-        let sess_key = StorageKey::Session(session_id);
-        env.storage().persistent().set(&sess_key, &session);
-        env.storage().persistent().extend_ttl(&sess_key, SESSION_LEDGER_TTL, SESSION_LEDGER_TTL);
-
+        Self::save_org(&env, &org);
         env.events().publish(
-            (symbol_short!("session"), symbol_short!("created"), session_id),
-            SessionCreatedEvent { session_id, initiator, timestamp: now },
+            (symbol_short!("org"), symbol_short!("created"), org_id),
+            OrgCreated { org_id, admin: org_admin },
         );
+    }
 
-        session_id
+    /// Hand org administration over to `new_admin` (current org admin only).
+    pub fn set_org_admin(env: Env, org_id: u64, new_admin: Address) {
+        Self::require_writable(&env);
+        let mut org = Self::load_org(&env, org_id);
+        org.admin.require_auth();
+        let old_admin = org.admin.clone();
+        org.admin = new_admin.clone();
+        Self::save_org(&env, &org);
+        env.events().publish(
+            (symbol_short!("org"), symbol_short!("admin"), org_id),
+            OrgAdminChanged { org_id, old_admin, new_admin },
+        );
     }
 
-    // get_session, get_audit_log, get_session_operation_count defined later in the session-aware section.
+    /// Register `attestor` as an operating key of `org_id` (org admin only).
+    ///
+    /// Performs the same SEP-10 check as `register_attestor`; the global admin
+    /// is not involved.
+    pub fn add_org_attestor(
+        env: Env,
+        org_id: u64,
+        attestor: Address,
+        sep10_token: String,
+        sep10_issuer: Address,
+    ) {
+        let mut org = Self::load_org(&env, org_id);
+        org.admin.require_auth();
+        Self::verify_sep10_token_matches_attestor(&env, &sep10_token, &sep10_issuer, &attestor);
+        Self::add_attestor_record(&env, &attestor);
 
-    // -----------------------------------------------------------------------
-    // Quote management
-    // -----------------------------------------------------------------------
+        let org_key = StorageKey::AttestorOrg(attestor.clone());
+        env.storage().persistent().set(&org_key, &org_id);
+        env.storage().persistent().extend_ttl(&org_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        org.attestor_count += 1;
+        Self::save_org(&env, &org);
 
-    #[allow(clippy::too_many_arguments)]
-    pub fn submit_quote(
-        env: Env,
-        anchor: Address,
-        base_asset: String,
-        quote_asset: String,
-        rate: u64,
-        fee_percentage: u32,
-        minimum_amount: u64,
-        maximum_amount: u64,
-        valid_until: u64,
-    ) -> u64 {
-        anchor.require_auth();
-        Self::check_attestor(&env, &anchor);
+        env.events().publish(
+            (symbol_short!("org"), symbol_short!("att_add"), org_id),
+            OrgAttestorUpdated { org_id, attestor, added: true },
+        );
+    }
 
-        // Validate quote parameters
-        if rate == 0 {
-            panic_with_error!(&env, ErrorCode::InvalidQuote);
+    /// Revoke one of the org's own attestor keys (org admin only).
+    pub fn remove_org_attestor(env: Env, org_id: u64, attestor: Address) {
+        Self::load_org(&env, org_id).admin.require_auth();
+        let org_key = StorageKey::AttestorOrg(attestor.clone());
+        if env.storage().persistent().get::<_, u64>(&org_key) != Some(org_id) {
+            panic_with_error!(&env, ErrorCode::AttestorNotInOrg);
         }
-        if minimum_amount > maximum_amount {
-            panic_with_error!(&env, ErrorCode::InvalidQuote);
-        }
-        let now = env.ledger().timestamp();
-        if valid_until <= now {
-            panic_with_error!(&env, ErrorCode::InvalidQuote);
+        Self::revoke_attestor_record(&env, &attestor);
+
+        env.events().publish(
+            (symbol_short!("org"), symbol_short!("att_rm"), org_id),
+            OrgAttestorUpdated { org_id, attestor, added: false },
+        );
+    }
+
+    /// Set `org_id`'s issuance caps (global admin only, not the org admin).
+    ///
+    /// These apply on top of per-attestor rate limits, so an org cannot exceed
+    /// its caps by spreading issuance across many keys. A per-window cap needs
+    /// a non-zero `window_length`, since a zero-length window would never reset.
+    pub fn set_org_limits(env: Env, org_id: u64, limits: OrgLimits) {
+        Self::require_admin(&env);
+        Self::load_org(&env, org_id);
+        if limits.max_per_window > 0 && limits.window_length == 0 {
+            panic_with_error!(&env, ErrorCode::ValidationError);
         }
+        let key = StorageKey::OrgLimits(org_id);
+        env.storage().persistent().set(&key, &limits);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+    }
 
-        let inst = env.storage().instance();
-        let qcnt_key = key_quote_counter(&env);
-        let next: u64 = inst.get(&qcnt_key).unwrap_or(0u64) + 1;
-        inst.set(&qcnt_key, &next);
-        inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    pub fn get_org_limits(env: Env, org_id: u64) -> Option<OrgLimits> {
+        env.storage().persistent().get(&StorageKey::OrgLimits(org_id))
+    }
 
-        let quote = Quote {
-            quote_id: next,
-            anchor: anchor.clone(),
-            base_asset: base_asset.clone(),
-            quote_asset: quote_asset.clone(),
-            rate,
-            fee_percentage,
-            minimum_amount,
-            maximum_amount,
-            valid_until,
-        };
-        let q_key = StorageKey::Quote(anchor.clone(), next);
-        env.storage().persistent().set(&q_key, &quote);
-        env.storage().persistent().extend_ttl(&q_key, PERSISTENT_TTL, PERSISTENT_TTL);
+    pub fn get_org_stats(env: Env, org_id: u64) -> OrgStats {
+        Self::load_org_stats(&env, org_id)
+    }
 
-        let lq_key = StorageKey::LatestQuote(anchor.clone());
-        env.storage().persistent().set(&lq_key, &next);
-        env.storage().persistent().extend_ttl(&lq_key, PERSISTENT_TTL, PERSISTENT_TTL);
+    pub fn get_org(env: Env, org_id: u64) -> Option<Organization> {
+        env.storage().persistent().get(&StorageKey::Org(org_id))
+    }
 
-        env.events().publish(
-            (symbol_short!("quote"), symbol_short!("submit"), next),
-            QuoteSubmitEvent { quote_id: next, anchor, base_asset, quote_asset, rate, valid_until },
-        );
+    /// Org ID that `attestor` currently belongs to, if any.
+    pub fn get_attestor_org(env: Env, attestor: Address) -> Option<u64> {
+        env.storage().persistent().get(&StorageKey::AttestorOrg(attestor))
+    }
 
-        next
+    /// Set (`Some`, zero allowed) or clear (`None`) the fee charged for
+    /// attestations by `org_id`'s attestors (global admin only). Collected
+    /// in the global fee token; see `get_effective_fee` for precedence.
+    pub fn set_org_fee(env: Env, org_id: u64, fee: Option<i128>) {
+        Self::require_admin(&env);
+        Self::require_subsystem_active(&env, SUBSYSTEM_FEES);
+        Self::load_org(&env, org_id);
+        let key = OrgKey::Fee(org_id);
+        match fee {
+            Some(fee) => {
+                if fee < 0 {
+                    panic_with_error!(&env, ErrorCode::ValidationError);
+                }
+                env.storage().persistent().set(&key, &fee);
+                env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+        env.events().publish((symbol_short!("org"), symbol_short!("fee"), org_id), fee);
     }
 
-    pub fn receive_quote(env: Env, receiver: Address, anchor: Address, quote_id: u64) -> Quote {
-        receiver.require_auth();
-        let q_key = StorageKey::Quote(anchor.clone(), quote_id);
-        let quote: Quote = env.storage().persistent().get(&q_key)
-            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+    pub fn get_org_fee(env: Env, org_id: u64) -> Option<i128> {
+        env.storage().persistent().get(&OrgKey::Fee(org_id))
+    }
 
-        env.events().publish(
-            (symbol_short!("quote"), symbol_short!("received"), quote_id),
-            QuoteReceivedEvent { quote_id, receiver, timestamp: env.ledger().timestamp() },
-        );
+    /// `record_attestation`, additionally requiring `issuer` to belong to
+    /// `org_id` (`AttestorNotInOrg`), for tenants that must never write into
+    /// another org's registry.
+    pub fn record_org_attestation(env: Env, org_id: u64, issuer: Address, input: AttestationInput) -> AttestationReceipt {
+        issuer.require_auth();
+        if Self::get_attestor_org(env.clone(), issuer.clone()) != Some(org_id) {
+            panic_with_error!(&env, ErrorCode::AttestorNotInOrg);
+        }
+        Self::record_submission(&env, issuer, &input, false)
+    }
 
-        quote
+    /// Number of attestations recorded in `org_id`'s ID range.
+    pub fn get_org_attestation_count(env: Env, org_id: u64) -> u64 {
+        env.storage().persistent().get(&StorageKey::OrgIdSeq(org_id)).unwrap_or(0)
+    }
+
+    /// `list_attestations_by_id` confined to `org_id`'s ID range: up to
+    /// `limit` of the org's attestations starting at its `start`-th, with
+    /// the same skipping rules. Resume from `start + limit`.
+    pub fn list_org_attestations(env: Env, org_id: u64, start: u64, limit: u32) -> Vec<Attestation> {
+        let (first, _) = Self::get_org_id_range(env.clone(), org_id);
+        let count = Self::get_org_attestation_count(env.clone(), org_id);
+        if start >= count {
+            return Vec::new(&env);
+        }
+        let limit = (count - start).min(limit as u64) as u32;
+        Self::list_attestations_by_id(env, first + start, limit)
+    }
+
+    /// Half-open attestation ID range `[start, end)` reserved for `org_id`.
+    pub fn get_org_id_range(env: Env, org_id: u64) -> (u64, u64) {
+        if org_id == 0 || org_id >= (1u64 << (64 - ORG_ID_BITS)) - 1 {
+            panic_with_error!(&env, ErrorCode::OrgNotFound);
+        }
+        (org_id << ORG_ID_BITS, (org_id + 1) << ORG_ID_BITS)
     }
 
     // -----------------------------------------------------------------------
-    // Audit log pruning helper
+    // Schema registry and governance
     // -----------------------------------------------------------------------
 
-    /// Prune oldest audit log entries if the log has exceeded `max_audit_log_size`.
+    /// Register a new schema owned by `creator`. Returns the schema ID.
     ///
-    /// Uses a monotonically increasing `log_id` counter (total entries ever written)
-    /// and a separate `offset` (first live entry). The live window is
-    /// `[offset, log_id)`. When `log_id - offset > max_size`, we advance `offset`
-    /// and delete the stale entries, then emit `AuditLogPruned`.
-    fn maybe_prune_audit_log(env: &Env, log_id: u64) {
-        let inst = env.storage().instance();
-        let max_size: u64 = inst
-            .get(&StorageKey::AuditLogMaxSize)
-            .unwrap_or(u64::MAX);
-        let offset: u64 = inst.get(&key_audit_log_offset(env)).unwrap_or(0u64);
-        let live_count = log_id.saturating_sub(offset); // entries [offset, log_id)
-        if live_count < max_size {
-            return;
+    /// The contract hashes `definition` (SHA-256) itself and derives the ID
+    /// from that hash (see `deterministic_hash::schema_id_from_hash`), so a
+    /// definition keeps its ID across deployments and nobody can pick an ID.
+    /// Registering the same definition twice, or one whose ID already has
+    /// schema state, fails with `SchemaAlreadyRegistered`.
+    pub fn register_schema(env: Env, creator: Address, definition: Bytes, metadata_uri: String) -> u64 {
+        Self::require_writable(&env);
+        creator.require_auth();
+        let schema_hash: BytesN<32> = env.crypto().sha256(&definition).into();
+        let id = schema_id_from_hash(&schema_hash);
+        let hash_key = SchemaKey::ByHash(schema_hash.clone());
+        if env.storage().persistent().has(&hash_key) || Self::schema_state_exists(&env, id) {
+            panic_with_error!(&env, ErrorCodeExt::SchemaAlreadyRegistered);
         }
-        // Number of entries to remove so live_count == max_size - 1 (leaving room for the new one)
-        let to_prune = live_count - max_size + 1;
-        for i in 0..to_prune {
-            let old_key = StorageKey::AuditLog(offset + i);
-            env.storage().persistent().remove(&old_key);
-        }
-        let new_offset = offset + to_prune;
-        inst.set(&key_audit_log_offset(env), &new_offset);
+        env.storage().persistent().set(&hash_key, &id);
+        env.storage().persistent().extend_ttl(&hash_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        let schema = Schema {
+            id,
+            creator: creator.clone(),
+            owner: creator.clone(),
+            schema_hash: schema_hash.clone(),
+            metadata_uri: metadata_uri.clone(),
+            created_at: env.ledger().timestamp(),
+            fee: 0,
+            resolver: None,
+            max_attestations: 0,
+            retention: RetentionClass::Standard,
+            value_bucket_width: 0,
+            min_issuer_tier: 0,
+            min_tier_on_verify: false,
+        };
+        Self::save_schema(&env, &schema);
         env.events().publish(
-            (symbol_short!("audit"), symbol_short!("pruned")),
-            AuditLogPruned { pruned_count: to_prune, new_offset },
+            (symbol_short!("schema"), symbol_short!("created"), id),
+            SchemaRegistered { schema_id: id, creator, schema_hash, metadata_uri },
         );
+        id
     }
 
-    // -----------------------------------------------------------------------
-    // Session-aware attestation
-    // -----------------------------------------------------------------------
+    /// ID registered for the definition whose SHA-256 is `schema_hash`.
+    pub fn get_schema_id_by_hash(env: Env, schema_hash: BytesN<32>) -> Option<u64> {
+        env.storage().persistent().get(&SchemaKey::ByHash(schema_hash))
+    }
 
-    pub fn submit_attestation_with_session(
-        env: Env,
-        session_id: u64,
-        issuer: Address,
-        subject: Address,
-        timestamp: u64,
-        payload_hash: Bytes,
-        signature: Bytes,
-    ) -> u64 {
-        Self::check_session_expiry(&env, session_id);
-        let session = Self::get_session(env.clone(), session_id);
-        if session.initiator != issuer {
-            panic_with_error!(&env, ErrorCode::UnauthorizedAttestor);
+    /// Require every new attestation, and every bundle claim, to reference a
+    /// registered schema (admin only). Off by default, in which case a
+    /// `schema_id` is optional and may name an unregistered schema.
+    pub fn set_schema_required(env: Env, required: bool) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&key_schema_required(&env), &required);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    }
+
+    pub fn is_schema_required(env: Env) -> bool {
+        env.storage().instance().get(&key_schema_required(&env)).unwrap_or(false)
+    }
+
+    /// Schema `schema_id` with any queued fee or resolver change that is
+    /// already due reflected, whether or not it has been applied yet.
+    pub fn get_schema(env: Env, schema_id: u64) -> Option<Schema> {
+        let mut schema: Schema = env.storage().persistent().get(&SchemaKey::Record(schema_id))?;
+        for change in Self::due_schema_changes(&env, schema_id).iter() {
+            match change {
+                SchemaChange::Fee(fee) => schema.fee = fee,
+                SchemaChange::Resolver(resolver) => schema.resolver = resolver,
+                SchemaChange::AuthorizeIssuer(..) | SchemaChange::RemoveIssuer(_) => {}
+            }
         }
-        issuer.require_auth();
-        Self::check_attestor(&env, &issuer);
-        Self::check_timestamp(&env, timestamp);
-        Self::verify_attestation_signature(&env, &issuer, &payload_hash, &signature);
+        Some(schema)
+    }
 
-        let used_key = StorageKey::Used(payload_hash.clone());
-        if env.storage().persistent().has(&used_key) {
-            panic_with_error!(&env, ErrorCode::ReplayAttack);
+    /// Hand schema ownership to `new_owner` (current owner only).
+    pub fn transfer_schema_ownership(env: Env, schema_id: u64, new_owner: Address) {
+        Self::require_writable(&env);
+        let mut schema = Self::load_schema(&env, schema_id);
+        schema.owner.require_auth();
+        let previous = schema.owner.clone();
+        schema.owner = new_owner.clone();
+        Self::save_schema(&env, &schema);
+        env.events().publish(
+            (symbol_short!("schema"), symbol_short!("owner"), schema_id),
+            (previous, new_owner),
+        );
+    }
+
+    /// Grant `delegate` the `SCHEMA_RIGHT_*` bits in `rights` over the schema
+    /// (owner only). Passing `0` removes the delegate.
+    pub fn set_schema_delegate(env: Env, schema_id: u64, delegate: Address, rights: u32) {
+        Self::require_writable(&env);
+        let schema = Self::load_schema(&env, schema_id);
+        schema.owner.require_auth();
+        let key = SchemaKey::Delegate(schema_id, delegate.clone());
+        if rights == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &rights);
+            env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
         }
+        env.events().publish(
+            (symbol_short!("schema"), symbol_short!("delegate"), schema_id, delegate),
+            rights,
+        );
+    }
 
-        let id = Self::next_attestation_id(&env);
-        Self::store_attestation(&env, id, issuer.clone(), subject.clone(), timestamp, payload_hash.clone(), signature);
+    pub fn get_schema_delegate_rights(env: Env, schema_id: u64, delegate: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&SchemaKey::Delegate(schema_id, delegate))
+            .unwrap_or(0)
+    }
 
-        env.storage().persistent().set(&used_key, &true);
-        env.storage().persistent().extend_ttl(&used_key, PERSISTENT_TTL, PERSISTENT_TTL);
+    /// Set the schema's per-attestation fee. Requires `SCHEMA_RIGHT_FEE`.
+    /// Subject to the schema change delay.
+    pub fn set_schema_fee(env: Env, caller: Address, schema_id: u64, fee: i128) {
+        Self::require_schema_right(&env, &caller, schema_id, SCHEMA_RIGHT_FEE);
+        Self::require_subsystem_active(&env, SUBSYSTEM_FEES);
+        if fee < 0 {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        Self::load_schema(&env, schema_id);
+        Self::schedule_schema_change(&env, schema_id, SchemaChange::Fee(fee));
+    }
 
-        // Get and increment session operation count
-        let sopcnt_key = StorageKey::SessionOpCount(session_id);
-        let op_index: u64 = env.storage().persistent().get(&sopcnt_key).unwrap_or(0u64);
-        env.storage().persistent().set(&sopcnt_key, &(op_index + 1));
-        env.storage().persistent().extend_ttl(&sopcnt_key, PERSISTENT_TTL, PERSISTENT_TTL);
+    /// Set or clear the schema's resolver contract. Requires `SCHEMA_RIGHT_RESOLVER`.
+    /// Subject to the schema change delay.
+    pub fn set_schema_resolver(env: Env, caller: Address, schema_id: u64, resolver: Option<Address>) {
+        Self::require_schema_right(&env, &caller, schema_id, SCHEMA_RIGHT_RESOLVER);
+        Self::load_schema(&env, schema_id);
+        Self::schedule_schema_change(&env, schema_id, SchemaChange::Resolver(resolver));
+    }
 
-        let inst = env.storage().instance();
-        let acnt_key = key_audit_counter(&env);
-        let log_id: u64 = inst.get(&acnt_key).unwrap_or(0u64);
-        inst.set(&acnt_key, &(log_id + 1));
-        inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
-        Self::maybe_prune_audit_log(&env, log_id);
+    /// Delay (seconds) before fee, resolver, and issuer allowlist changes
+    /// take effect (admin only). `0`, the default, applies them immediately.
+    ///
+    /// While a delay is set, each change is queued with a `schema/pending`
+    /// event so integrators can react before it lands. Queued changes apply
+    /// in order on the schema's next submission or via `apply_schema_changes`.
+    pub fn set_schema_change_delay(env: Env, seconds: u64) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&key_schema_change_delay(&env), &seconds);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    }
 
-        let now = env.ledger().timestamp();
-        let audit = AuditLog {
-            log_id,
-            session_id,
-            actor: issuer.clone(),
-            operation: OperationContext {
-                session_id,
-                operation_index: op_index,
-                operation_type: String::from_str(&env, "attest"),
-                timestamp: now,
-                status: String::from_str(&env, "success"),
-                result_summary: String::from_str(&env, &alloc::format!("attestation_id={}", id)),
-            },
-        };
-        let audit_key = StorageKey::AuditLog(log_id);
-        env.storage().persistent().set(&audit_key, &audit);
-        env.storage().persistent().extend_ttl(&audit_key, PERSISTENT_TTL, PERSISTENT_TTL);
+    pub fn get_schema_change_delay(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&key_schema_change_delay(&env))
+            .unwrap_or(0)
+    }
+
+    /// Changes queued on `schema_id` that have not been applied yet, oldest first.
+    pub fn get_pending_schema_changes(env: Env, schema_id: u64) -> Vec<PendingSchemaChange> {
+        env.storage()
+            .persistent()
+            .get(&SchemaKey::PendingChanges(schema_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
 
+    /// Apply every queued change on `schema_id` whose delay has elapsed.
+    /// Callable by anyone; returns the number of changes applied.
+    pub fn apply_schema_changes(env: Env, schema_id: u64) -> u32 {
+        Self::require_writable(&env);
+        Self::apply_due_schema_changes(&env, schema_id)
+    }
+
+    /// Cap the total number of attestations that may ever be issued under
+    /// the schema (owner only). `0` removes the cap. The cap cannot be set
+    /// below the number already issued.
+    pub fn set_schema_cap(env: Env, schema_id: u64, max_attestations: u64) {
+        Self::require_writable(&env);
+        let mut schema = Self::load_schema(&env, schema_id);
+        schema.owner.require_auth();
+        if max_attestations != 0
+            && max_attestations < Self::get_schema_attestation_count(env.clone(), schema_id)
+        {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        schema.max_attestations = max_attestations;
+        Self::save_schema(&env, &schema);
         env.events().publish(
-            (symbol_short!("attest"), symbol_short!("recorded"), id, subject),
-            AttestEvent { payload_hash, timestamp },
+            (symbol_short!("schema"), symbol_short!("cap"), schema_id),
+            max_attestations,
         );
+    }
+
+    /// Require issuers under the schema to hold at least trust tier
+    /// `min_tier` (owner only); `0` lifts the requirement. With
+    /// `on_verify`, attestations also stop validating while their issuer's
+    /// current tier is below it.
+    pub fn set_schema_min_tier(env: Env, schema_id: u64, min_tier: u32, on_verify: bool) {
+        Self::require_writable(&env);
+        let mut schema = Self::load_schema(&env, schema_id);
+        schema.owner.require_auth();
+        schema.min_issuer_tier = min_tier;
+        schema.min_tier_on_verify = on_verify;
+        Self::save_schema(&env, &schema);
         env.events().publish(
-            (symbol_short!("audit"), symbol_short!("logged"), log_id),
-            AuditLogEvent {
-                log_id,
-                session_id,
-                operation_index: op_index,
-                operation_type: String::from_str(&env, "attest"),
-                status: String::from_str(&env, "success"),
-            },
+            (symbol_short!("schema"), symbol_short!("min_tier"), schema_id),
+            (min_tier, on_verify),
         );
-
-        id
     }
 
-    pub fn register_attestor_with_session(env: Env, session_id: u64, attestor: Address, sep10_token: String, sep10_issuer: Address) {
-        Self::check_session_expiry(&env, session_id);
-        Self::require_admin(&env);
-        Self::verify_sep10_token_matches_attestor(&env, &sep10_token, &sep10_issuer, &attestor);
-        let key = StorageKey::Attestor(attestor.clone());
-        if env.storage().persistent().has(&key) {
-            panic_with_error!(&env, ErrorCode::AttestorAlreadyRegistered);
+    /// Declare that attestations under `successor` supersede those under
+    /// `schema_id` for the same subject (owner of `schema_id` only).
+    ///
+    /// While set, an attestation under `schema_id` reports `Superseded` once
+    /// its subject holds an active attestation under `successor`; subjects
+    /// not yet migrated are unaffected. Pass `None` to end the migration.
+    pub fn set_schema_successor(env: Env, schema_id: u64, successor: Option<u64>) {
+        Self::require_writable(&env);
+        let schema = Self::load_schema(&env, schema_id);
+        schema.owner.require_auth();
+        let key = SchemaKey::SupersededBy(schema_id);
+        match successor {
+            Some(successor) => {
+                if successor == schema_id {
+                    panic_with_error!(&env, ErrorCode::ValidationError);
+                }
+                Self::load_schema(&env, successor);
+                env.storage().persistent().set(&key, &successor);
+                env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+            }
+            None => env.storage().persistent().remove(&key),
         }
-        env.storage().persistent().set(&key, &true);
-        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
-
-        let sopcnt_key = StorageKey::SessionOpCount(session_id);
-        let op_index: u64 = env.storage().persistent().get(&sopcnt_key).unwrap_or(0u64);
-        env.storage().persistent().set(&sopcnt_key, &(op_index + 1));
-        env.storage().persistent().extend_ttl(&sopcnt_key, PERSISTENT_TTL, PERSISTENT_TTL);
-
-        let inst = env.storage().instance();
-        let acnt_key = key_audit_counter(&env);
-        let log_id: u64 = inst.get(&acnt_key).unwrap_or(0u64);
-        inst.set(&acnt_key, &(log_id + 1));
-        inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
-        Self::maybe_prune_audit_log(&env, log_id);
+        env.events().publish(
+            (symbol_short!("schema"), symbol_short!("supersede"), schema_id),
+            successor,
+        );
+    }
 
-        let admin: Address = inst
-            .get::<_, Address>(&key_admin(&env))
-            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::NotInitialized));
-        let now = env.ledger().timestamp();
-        let audit = AuditLog {
-            log_id,
-            session_id,
-            actor: admin,
-            operation: OperationContext {
-                session_id,
-                operation_index: op_index,
-                operation_type: String::from_str(&env, "register"),
-                timestamp: now,
-                status: String::from_str(&env, "success"),
-                result_summary: String::from_str(&env, "attestor_registered"),
-            },
-        };
-        let audit_key = StorageKey::AuditLog(log_id);
-        env.storage().persistent().set(&audit_key, &audit);
-        env.storage().persistent().extend_ttl(&audit_key, PERSISTENT_TTL, PERSISTENT_TTL);
+    pub fn get_schema_successor(env: Env, schema_id: u64) -> Option<u64> {
+        env.storage().persistent().get(&SchemaKey::SupersededBy(schema_id))
+    }
 
-        env.events().publish((symbol_short!("attestor"), symbol_short!("added"), attestor), ());
+    /// Set the retention class applied to attestations issued under the
+    /// schema from now on (owner only). Existing records keep their TTL until
+    /// next bumped.
+    pub fn set_schema_retention(env: Env, schema_id: u64, retention: RetentionClass) {
+        Self::require_writable(&env);
+        let mut schema = Self::load_schema(&env, schema_id);
+        schema.owner.require_auth();
+        schema.retention = retention;
+        Self::save_schema(&env, &schema);
         env.events().publish(
-            (symbol_short!("audit"), symbol_short!("logged"), log_id),
-            AuditLogEvent {
-                log_id,
-                session_id,
-                operation_index: op_index,
-                operation_type: String::from_str(&env, "register"),
-                status: String::from_str(&env, "success"),
-            },
+            (symbol_short!("schema"), symbol_short!("retention"), schema_id),
+            retention,
         );
     }
 
-    pub fn revoke_attestor_with_session(env: Env, session_id: u64, attestor: Address) {
-        Self::check_session_expiry(&env, session_id);
-        Self::require_admin(&env);
-        let key = StorageKey::Attestor(attestor.clone());
-        if !env.storage().persistent().has(&key) {
-            panic_with_error!(&env, ErrorCode::AttestorNotRegistered);
+    /// Enable value buckets of `bucket_width` for threshold queries on the
+    /// schema (owner only); `0` disables them.
+    ///
+    /// Values `>= bucket_width * (VALUE_BUCKETS - 1)` share the last bucket. The
+    /// width can only change before any attestation is issued under the schema.
+    pub fn set_schema_value_buckets(env: Env, schema_id: u64, bucket_width: u32) {
+        Self::require_writable(&env);
+        let mut schema = Self::load_schema(&env, schema_id);
+        schema.owner.require_auth();
+        if Self::get_schema_attestation_count(env.clone(), schema_id) != 0 {
+            panic_with_error!(&env, ErrorCode::ValidationError);
         }
-        env.storage().persistent().remove(&key);
-        // Mark the attestor as revoked so historical attestations surface issuer_revoked=true.
-        let revoked_key = StorageKey::AttestorRevoked(attestor.clone());
-        env.storage().persistent().set(&revoked_key, &true);
-        env.storage().persistent().extend_ttl(&revoked_key, PERSISTENT_TTL, PERSISTENT_TTL);
-
-        let sopcnt_key = StorageKey::SessionOpCount(session_id);
-        let op_index: u64 = env.storage().persistent().get(&sopcnt_key).unwrap_or(0u64);
-        env.storage().persistent().set(&sopcnt_key, &(op_index + 1));
-        env.storage().persistent().extend_ttl(&sopcnt_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        schema.value_bucket_width = bucket_width;
+        Self::save_schema(&env, &schema);
+        env.events().publish(
+            (symbol_short!("schema"), symbol_short!("buckets"), schema_id),
+            bucket_width,
+        );
+    }
 
-        let inst = env.storage().instance();
-        let acnt_key = key_audit_counter(&env);
-        let log_id: u64 = inst.get(&acnt_key).unwrap_or(0u64);
-        inst.set(&acnt_key, &(log_id + 1));
-        inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
-        Self::maybe_prune_audit_log(&env, log_id);
+    /// Number of subjects whose current value under `schema_id` is at or
+    /// above `threshold`. Only a subject's latest attestation counts, and it
+    /// stops counting once revoked, expired through `refresh_status`, or
+    /// struck down by a challenge. Invalidation the contract applies lazily
+    /// (schema or issuer revocation, expiry, invalidation epochs) is
+    /// reflected once someone calls `release_subject_value`.
+    ///
+    /// `threshold` must be a multiple of the schema's bucket width, at most
+    /// `width * (VALUE_BUCKETS - 1)`: the last bucket is open-ended, so no
+    /// higher threshold can be answered and it fails with `ValidationError`.
+    pub fn count_subjects_above(env: Env, schema_id: u64, threshold: u32) -> u64 {
+        let width = Self::load_schema(&env, schema_id).value_bucket_width;
+        if width == 0 || !threshold.is_multiple_of(width) || threshold / width >= VALUE_BUCKETS {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        (threshold / width..VALUE_BUCKETS)
+            .map(|bucket| Self::value_bucket_count(&env, schema_id, bucket))
+            .sum()
+    }
 
-        let admin: Address = inst
-            .get::<_, Address>(&key_admin(&env))
-            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::NotInitialized));
-        let now = env.ledger().timestamp();
-        let audit = AuditLog {
-            log_id,
-            session_id,
-            actor: admin,
-            operation: OperationContext {
-                session_id,
-                operation_index: op_index,
-                operation_type: String::from_str(&env, "revoke"),
-                timestamp: now,
-                status: String::from_str(&env, "success"),
-                result_summary: String::from_str(&env, "attestor_revoked"),
-            },
+    /// Drop `subject`'s value from `schema_id`'s buckets if its latest
+    /// attestation under the schema is no longer valid. Anyone may call it.
+    pub fn release_subject_value(env: Env, subject: Address, schema_id: u64) {
+        Self::require_writable(&env);
+        let latest: Option<u64> = env.storage().persistent().get(&SchemaKey::SubjectLatest(subject, schema_id));
+        let Some(attestation) = latest.and_then(|id| Self::load_attestation(&env, id)) else {
+            return;
         };
-        let audit_key = StorageKey::AuditLog(log_id);
-        env.storage().persistent().set(&audit_key, &audit);
-        env.storage().persistent().extend_ttl(&audit_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        if !Self::evaluate_validity(&env, &attestation).is_valid {
+            Self::sync_subject_value(&env, &attestation, None);
+        }
+    }
 
-        env.events().publish((symbol_short!("attestor"), symbol_short!("removed"), attestor), ());
-        env.events().publish(
-            (symbol_short!("audit"), symbol_short!("logged"), log_id),
-            AuditLogEvent {
-                log_id,
-                session_id,
-                operation_index: op_index,
-                operation_type: String::from_str(&env, "revoke"),
-                status: String::from_str(&env, "success"),
-            },
-        );
+    /// Per-bucket subject counts for `schema_id`; entry `i` covers values
+    /// `[i * width, (i + 1) * width)`, except the last, which holds every
+    /// value from `(VALUE_BUCKETS - 1) * width` up. Empty when buckets are
+    /// disabled.
+    pub fn get_value_buckets(env: Env, schema_id: u64) -> Vec<u64> {
+        let mut counts = Vec::new(&env);
+        if Self::load_schema(&env, schema_id).value_bucket_width == 0 {
+            return counts;
+        }
+        for bucket in 0..VALUE_BUCKETS {
+            counts.push_back(Self::value_bucket_count(&env, schema_id, bucket));
+        }
+        counts
     }
 
-    pub fn get_session(env: Env, session_id: u64) -> Session {
+    /// Number of attestations issued under `schema_id` so far.
+    pub fn get_schema_attestation_count(env: Env, schema_id: u64) -> u64 {
         env.storage()
             .persistent()
-            .get::<_, Session>(&StorageKey::Session(session_id))
-            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound))
+            .get(&SchemaKey::AttestationCount(schema_id))
+            .unwrap_or(0)
     }
 
-    pub fn get_audit_log(env: Env, log_id: u64) -> AuditLog {
-        env.storage()
-            .persistent()
-            .get::<_, AuditLog>(&StorageKey::AuditLog(log_id))
-            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound))
+    // -----------------------------------------------------------------------
+    // Fees
+    // -----------------------------------------------------------------------
+
+    /// Charge `amount` of `token` per attestation, paid by the issuer to
+    /// `recipient` (admin only). Once set, fees from overrides and schemas
+    /// are collected in the same token; `amount` `0` leaves only those.
+    pub fn set_fee(env: Env, token: Address, amount: i128, recipient: Address) {
+        Self::require_admin(&env);
+        Self::require_subsystem_active(&env, SUBSYSTEM_FEES);
+        if amount < 0 {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        let config = FeeConfig { token: token.clone(), amount, recipient: recipient.clone() };
+        env.storage().instance().set(&key_fee_config(&env), &config);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        env.events().publish((symbol_short!("fee"), symbol_short!("updated")), FeeUpdated { token, amount, recipient });
     }
 
-    /// Return audit log entries in [from_id, to_id], capped at 100 entries.
-    /// IDs that have no stored entry are silently skipped.
-    pub fn get_audit_log_range(env: Env, from_id: u64, to_id: u64) -> Vec<AuditLog> {
-        let mut result = Vec::new(&env);
-        if from_id > to_id {
-            return result;
+    /// Global fee configuration, or `None` while fees are not collected.
+    pub fn get_fee(env: Env) -> Option<FeeConfig> {
+        env.storage().instance().get(&key_fee_config(&env))
+    }
+
+    /// Turn fee escrow on (`Some`) or off (`None`) (admin only).
+    ///
+    /// While on, issuance fees are held by the contract for `window` seconds
+    /// and then paid to the fee recipient by `release_escrowed_fee`. A fee
+    /// stays held while its attestation is under challenge, and goes to the
+    /// `challenger_pool` instead if the challenge invalidates it. Fees
+    /// already escrowed keep the terms they were taken under.
+    pub fn set_fee_escrow(env: Env, config: Option<FeeEscrowConfig>) {
+        Self::require_admin(&env);
+        Self::require_subsystem_active(&env, SUBSYSTEM_FEES);
+        match &config {
+            Some(config) => env.storage().instance().set(&key_fee_escrow(&env), config),
+            None => env.storage().instance().remove(&key_fee_escrow(&env)),
         }
-        let cap: u64 = 100;
-        let end = if to_id - from_id + 1 > cap { from_id + cap - 1 } else { to_id };
-        let mut id = from_id;
-        while id <= end {
-            if let Some(log) = env.storage().persistent().get::<_, AuditLog>(&StorageKey::AuditLog(id)) {
-                result.push_back(log);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        env.events().publish((symbol_short!("fee"), symbol_short!("escrow")), config);
+    }
+
+    pub fn get_fee_escrow(env: Env) -> Option<FeeEscrowConfig> {
+        env.storage().instance().get(&key_fee_escrow(&env))
+    }
+
+    /// Fee held in escrow for attestation `id`, if any.
+    pub fn get_escrowed_fee(env: Env, id: u64) -> Option<EscrowedFee> {
+        env.storage().persistent().get(&DisputeKey::EscrowedFee(id))
+    }
+
+    /// Pay attestation `id`'s escrowed fee to its recipient (anyone may
+    /// call). Fails with `FeeEscrowLocked` before `release_at` or while a
+    /// challenge against the attestation is open. Works in sunset mode and
+    /// while fees are paused, so held fees never stay locked.
+    pub fn release_escrowed_fee(env: Env, id: u64) {
+        Self::require_not_stopped(&env);
+        let key = DisputeKey::EscrowedFee(id);
+        let held: EscrowedFee = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCodeExt::FeeNotEscrowed));
+        let disputed = Self::get_attestation_challenge(env.clone(), id)
+            .is_some_and(|c| matches!(c.status, ChallengeStatus::Open | ChallengeStatus::Escalated));
+        if env.ledger().timestamp() < held.release_at || disputed {
+            panic_with_error!(&env, ErrorCodeExt::FeeEscrowLocked);
+        }
+        env.storage().persistent().remove(&key);
+        token::Client::new(&env, &held.token).transfer(&env.current_contract_address(), &held.recipient, &held.amount);
+        env.events().publish((symbol_short!("fee"), symbol_short!("released"), id), held);
+    }
+
+    /// Set (`Some`, zero allowed) or clear (`None`) a fee override for
+    /// `attestor` (admin only), e.g. to waive fees for public-sector issuers.
+    pub fn set_attestor_fee_override(env: Env, attestor: Address, fee: Option<i128>) {
+        Self::require_admin(&env);
+        Self::require_subsystem_active(&env, SUBSYSTEM_FEES);
+        let key = StorageKey::AttestorFeeOverride(attestor.clone());
+        match fee {
+            Some(fee) => {
+                if fee < 0 {
+                    panic_with_error!(&env, ErrorCode::ValidationError);
+                }
+                env.storage().persistent().set(&key, &fee);
+                env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
             }
-            id += 1;
+            None => env.storage().persistent().remove(&key),
         }
-        result
+        env.events().publish((symbol_short!("fee"), symbol_short!("override"), attestor), fee);
     }
 
-    pub fn get_session_operation_count(env: Env, session_id: u64) -> Option<u64> {
-        let sess_key = StorageKey::Session(session_id);
-        if !env.storage().persistent().has(&sess_key) {
-            return None;
+    pub fn get_attestor_fee_override(env: Env, attestor: Address) -> Option<i128> {
+        env.storage().persistent().get(&StorageKey::AttestorFeeOverride(attestor))
+    }
+
+    /// Fee `attestor` owes for an attestation under `schema_id`.
+    ///
+    /// Precedence, first match wins:
+    /// 1. the attestor's fee override, even when it is zero;
+    /// 2. the schema's non-zero `fee`;
+    /// 3. the fee of the attestor's org, even when it is zero;
+    /// 4. the global fee set with `set_fee` (`0` when none is set).
+    pub fn get_effective_fee(env: Env, attestor: Address, schema_id: Option<u64>) -> i128 {
+        if let Some(fee) = Self::get_attestor_fee_override(env.clone(), attestor.clone()) {
+            return fee;
         }
-        Self::check_session_expiry(&env, session_id);
-        Some(
-            env.storage()
-                .persistent()
-                .get::<_, u64>(&StorageKey::SessionOpCount(session_id))
-                .unwrap_or(0),
-        )
+        if let Some(schema) = schema_id.and_then(|id| Self::get_schema(env.clone(), id)) {
+            if schema.fee != 0 {
+                return schema.fee;
+            }
+        }
+        if let Some(fee) = Self::get_attestor_org(env.clone(), attestor).and_then(|org_id| Self::get_org_fee(env.clone(), org_id)) {
+            return fee;
+        }
+        Self::get_fee(env).map_or(0, |config| config.amount)
     }
 
-    /// Returns the current audit log pruning offset — the ID of the first live
-    /// audit log entry.  Any log ID below this value has been pruned and is no
-    /// longer available in storage.
+    // -----------------------------------------------------------------------
+    // Per-schema issuer allowlist
+    // -----------------------------------------------------------------------
+
+    /// Authorize a registered attestor to issue under `schema_id`.
     ///
-    /// Callers can use this to detect gaps in a session's audit trail:
-    /// if `log_id < get_audit_log_offset()` the entry was intentionally pruned,
-    /// not lost.  Returns `0` when no pruning has occurred yet.
-    pub fn get_audit_log_offset(env: Env) -> u64 {
-        env.storage()
-            .instance()
-            .get(&key_audit_log_offset(&env))
-            .unwrap_or(0u64)
+    /// `caller` must be the global admin, the schema owner, or a delegate
+    /// holding `SCHEMA_RIGHT_ISSUERS`.
+    /// The grant is permanent; authorizing an attestor that holds a
+    /// time-boxed grant clears its expiry.
+    pub fn authorize_schema_issuer(env: Env, caller: Address, schema_id: u64, attestor: Address) {
+        Self::require_schema_right(&env, &caller, schema_id, SCHEMA_RIGHT_ISSUERS);
+        Self::check_attestor(&env, &attestor);
+        Self::schedule_schema_change(&env, schema_id, SchemaChange::AuthorizeIssuer(attestor, 0));
+    }
+
+    /// Authorize `attestor` to issue under `schema_id` until `expires_at`
+    /// (Unix seconds). Same caller rules as `authorize_schema_issuer`.
+    ///
+    /// Calling again renews the grant with the new expiry. Once expired,
+    /// the attestor drops out of `get_issuers_for_schema` and its
+    /// submissions under the schema fail with `SchemaGrantExpired`.
+    pub fn grant_schema_issuer(env: Env, caller: Address, schema_id: u64, attestor: Address, expires_at: u64) {
+        Self::require_schema_right(&env, &caller, schema_id, SCHEMA_RIGHT_ISSUERS);
+        if expires_at <= env.ledger().timestamp() {
+            panic_with_error!(&env, ErrorCode::InvalidTimestamp);
+        }
+        Self::check_attestor(&env, &attestor);
+        Self::schedule_schema_change(&env, schema_id, SchemaChange::AuthorizeIssuer(attestor, expires_at));
+    }
+
+    /// Expiry of `attestor`'s grant for `schema_id`, or `None` when the
+    /// grant is permanent or absent. Due queued changes are reflected.
+    pub fn get_schema_grant_expiry(env: Env, schema_id: u64, attestor: Address) -> Option<u64> {
+        let mut expiry = env
+            .storage()
+            .persistent()
+            .get(&SchemaKey::GrantExpiry(schema_id, attestor.clone()));
+        for change in Self::due_schema_changes(&env, schema_id).iter() {
+            match change {
+                SchemaChange::AuthorizeIssuer(granted, expires_at) if granted == attestor => {
+                    expiry = (expires_at != 0).then_some(expires_at);
+                }
+                SchemaChange::RemoveIssuer(removed) if removed == attestor => expiry = None,
+                _ => {}
+            }
+        }
+        expiry
+    }
+
+    /// Remove an attestor from the allowlist of `schema_id`. Same caller rules
+    /// as `authorize_schema_issuer`. Subject to the schema change delay; the
+    /// attestor must be on the allowlist when the removal is requested.
+    pub fn remove_schema_issuer(env: Env, caller: Address, schema_id: u64, attestor: Address) {
+        Self::require_schema_right(&env, &caller, schema_id, SCHEMA_RIGHT_ISSUERS);
+        let issuers: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&SchemaKey::Issuers(schema_id))
+            .unwrap_or_else(|| Vec::new(&env));
+        if !issuers.contains(&attestor) {
+            panic_with_error!(&env, ErrorCode::AttestorNotRegistered);
+        }
+        Self::schedule_schema_change(&env, schema_id, SchemaChange::RemoveIssuer(attestor));
+    }
+
+    /// Authoritative list of attestors allowed to issue under `schema_id`.
+    ///
+    /// Verifiers that only accept a schema from specific issuers should check
+    /// an attestation's issuer against this list. Attestors whose time-boxed
+    /// grant has expired are omitted, and due queued changes are reflected.
+    /// Returns an empty vec when no issuer has been authorized for the
+    /// schema.
+    pub fn get_issuers_for_schema(env: Env, schema_id: u64) -> Vec<Address> {
+        let mut issuers: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&SchemaKey::Issuers(schema_id))
+            .unwrap_or_else(|| Vec::new(&env));
+        for change in Self::due_schema_changes(&env, schema_id).iter() {
+            match change {
+                SchemaChange::AuthorizeIssuer(attestor, _) if !issuers.contains(&attestor) => {
+                    issuers.push_back(attestor);
+                }
+                SchemaChange::RemoveIssuer(attestor) => {
+                    if let Some(idx) = issuers.first_index_of(&attestor) {
+                        issuers.remove(idx);
+                    }
+                }
+                _ => {}
+            }
+        }
+        let mut live = Vec::new(&env);
+        for issuer in issuers.iter() {
+            if !Self::is_schema_grant_expired(&env, schema_id, &issuer) {
+                live.push_back(issuer);
+            }
+        }
+        live
     }
 
     // -----------------------------------------------------------------------
-    // Metadata cache
+    // Credential policies
     // -----------------------------------------------------------------------
 
-    pub fn cache_metadata(env: Env, anchor: Address, metadata: AnchorMetadata, ttl_seconds: u64) {
-        Self::require_admin(&env);
-        // Issue #259: skip write if metadata is unchanged.
-        // ttl_seconds=0 entries live in persistent storage (never network-evicted);
-        // all other entries live in temporary storage.
-        if metadata.reputation_score > 10000 || metadata.uptime_percentage > 10000 {
-            panic_with_error!(&env, ErrorCode::ValidationError);
+    /// Register a composite credential policy. Anyone may create a policy.
+    ///
+    /// Nested `PolicyRequirement::Policy` entries must reference existing
+    /// policies, which keeps the policy graph acyclic. A `Threshold(k)` op needs
+    /// `1 <= k <= requirements.len()`.
+    pub fn create_policy(
+        env: Env,
+        creator: Address,
+        op: PolicyOp,
+        requirements: Vec<PolicyRequirement>,
+    ) -> u64 {
+        Self::require_writable(&env);
+        creator.require_auth();
+        if requirements.is_empty() {
+            panic_with_error!(&env, ErrorCode::InvalidPolicy);
         }
-        let key = StorageKey::MetadataCache(anchor.clone());
-        let existing: Option<MetadataCache> = env.storage().persistent().get(&key)
-            .or_else(|| env.storage().temporary().get(&key));
-        if let Some(existing) = existing {
-            let m = &existing.metadata;
-            if m.anchor == metadata.anchor
-                && m.reputation_score == metadata.reputation_score
-                && m.liquidity_score == metadata.liquidity_score
-                && m.uptime_percentage == metadata.uptime_percentage
-                && m.total_volume == metadata.total_volume
-                && m.average_settlement_time == metadata.average_settlement_time
-                && m.is_active == metadata.is_active
-            {
-                return;
+        if let PolicyOp::Threshold(k) = op {
+            if k == 0 || k > requirements.len() {
+                panic_with_error!(&env, ErrorCode::InvalidPolicy);
             }
         }
-        let now = env.ledger().timestamp();
-        let entry = MetadataCache { metadata, cached_at: now, ttl_seconds };
-        if ttl_seconds == 0 {
-            // Persistent storage is not subject to network-level eviction within
-            // its TTL window, so ttl_seconds=0 ("never expire at app level") is
-            // backed by storage that won't disappear under the contract's feet.
-            env.storage().persistent().set(&key, &entry);
-            env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
-        } else {
-            let ledger_ttl = if ttl_seconds as u32 > MIN_TEMP_TTL { ttl_seconds as u32 } else { MIN_TEMP_TTL };
-            env.storage().temporary().set(&key, &entry);
-            env.storage().temporary().extend_ttl(&key, ledger_ttl, ledger_ttl);
+        for req in requirements.iter() {
+            if let PolicyRequirement::Policy(inner) = req {
+                if !env.storage().persistent().has(&StorageKey::Policy(inner)) {
+                    panic_with_error!(&env, ErrorCode::PolicyNotFound);
+                }
+            }
         }
 
-        Self::add_to_cached_anchors(&env, &anchor);
+        let inst = env.storage().instance();
+        let id: u64 = inst.get(&key_policy_counter(&env)).unwrap_or(0u64) + 1;
+        inst.set(&key_policy_counter(&env), &id);
+        inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+
+        let policy = Policy { id, creator, op, requirements };
+        let key = StorageKey::Policy(id);
+        env.storage().persistent().set(&key, &policy);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish((symbol_short!("policy"), symbol_short!("created"), id), policy.creator);
+        id
     }
 
-    pub fn get_cached_metadata(env: Env, anchor: Address) -> AnchorMetadata {
-        let key = StorageKey::MetadataCache(anchor);
-        // ttl_seconds=0 entries are stored persistently; all others are temporary.
-        let entry: MetadataCache = env.storage().persistent().get(&key)
-            .or_else(|| env.storage().temporary().get(&key))
-            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::CacheNotFound));
-        let now = env.ledger().timestamp();
-        // ttl_seconds = 0 means "never expire at app level" — skip the expiry check
-        if entry.ttl_seconds != 0 && entry.cached_at + entry.ttl_seconds <= now {
-            panic_with_error!(&env, ErrorCode::CacheExpired);
+    pub fn get_policy(env: Env, policy_id: u64) -> Option<Policy> {
+        env.storage().persistent().get(&StorageKey::Policy(policy_id))
+    }
+
+    /// Evaluate `policy_id` for `subject`. A `Schema` requirement is met as
+    /// in `verify_for_subject`; an `Issuer` requirement when the latest
+    /// attestation that issuer recorded about the subject is valid. Each
+    /// requirement reads at most two records, however many attestations the
+    /// subject holds.
+    pub fn check_policy(env: Env, policy_id: u64, subject: Address) -> bool {
+        Self::eval_policy(&env, policy_id, &subject)
+    }
+
+    // -----------------------------------------------------------------------
+    // Service configuration
+    // -----------------------------------------------------------------------
+
+    pub fn configure_services(env: Env, anchor: Address, services: Vec<u32>) {
+        Self::require_writable(&env);
+        anchor.require_auth();
+        if !env
+            .storage()
+            .persistent()
+            .has(&StorageKey::Attestor(anchor.clone()))
+        {
+            panic_with_error!(&env, ErrorCode::AttestorNotRegistered);
         }
-        entry.metadata
+        if services.is_empty() {
+            panic_with_error!(&env, ErrorCode::InvalidServiceType);
+        }
+        let mut seen = Vec::new(&env);
+        for s in services.iter() {
+            // Reject any value that is not one of the four known service constants.
+            if s != SERVICE_DEPOSITS
+                && s != SERVICE_WITHDRAWALS
+                && s != SERVICE_QUOTES
+                && s != SERVICE_KYC
+            {
+                panic_with_error!(&env, ErrorCode::InvalidServiceType);
+            }
+            if seen.contains(s) {
+                panic_with_error!(&env, ErrorCode::InvalidServiceType);
+            }
+            seen.push_back(s);
+        }
+        let record = AnchorServices {
+            anchor: anchor.clone(),
+            services: services.clone(),
+        };
+        let key = StorageKey::Services(anchor.clone());
+        env.storage().persistent().set(&key, &record);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events()
+            .publish((symbol_short!("services"), symbol_short!("config")), record);
     }
 
-    /// Issue #260: returns seconds elapsed since the metadata cache entry was written,
-    /// or `Err(CacheNotFound)` if no cache entry exists for the anchor.
-    /// `Ok(0)` means the entry was just cached at the current ledger timestamp.
-    pub fn get_cache_age_seconds(env: Env, anchor: Address) -> Result<u64, ErrorCode> {
-        let key = StorageKey::MetadataCache(anchor);
-        let entry: MetadataCache = env.storage().persistent().get(&key)
-            .or_else(|| env.storage().temporary().get(&key))?;
+    pub fn get_supported_services(env: Env, anchor: Address) -> AnchorServices {
+        env.storage()
+            .persistent()
+            .get::<_, AnchorServices>(&StorageKey::Services(anchor))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::ServicesNotConfigured))
+    }
+
+    pub fn supports_service(env: Env, anchor: Address, service: u32) -> bool {
+        let record = env
+            .storage()
+            .persistent()
+            .get::<_, AnchorServices>(&StorageKey::Services(anchor))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::ServicesNotConfigured));
+        record.services.contains(service)
+    }
+
+    // -----------------------------------------------------------------------
+    // Attestation submission (plain)
+    // -----------------------------------------------------------------------
+
+    pub fn submit_attestation(
+        env: Env,
+        issuer: Address,
+        subject: Address,
+        timestamp: u64,
+        payload_hash: Bytes,
+        signature: Bytes,
+    ) -> u64 {
+        issuer.require_auth();
+        let input = AttestationInput::new(subject, timestamp, payload_hash, signature);
+        Self::record_submission(&env, issuer, &input, false).id
+    }
+
+    /// Record an attestation from a validated [`AttestationInput`].
+    ///
+    /// Equivalent to `submit_attestation`, but accepts optional fields such as
+    /// `schema_id` through the input type, and returns a full
+    /// [`AttestationReceipt`] instead of just the ID.
+    pub fn record_attestation(env: Env, issuer: Address, input: AttestationInput) -> AttestationReceipt {
+        issuer.require_auth();
+        Self::record_submission(&env, issuer, &input, false)
+    }
+
+    /// Record `input` on `issuer`'s behalf; `relayer` submits and pays, and
+    /// must be allowlisted while the relayer allowlist is enforced.
+    ///
+    /// `auth_signature` must be the issuer's signature over
+    /// `delegation_message_public(issuer, input, deadline, nonce)`, the
+    /// delegation hash behind the `anchorkit-delegate-v1` prefix, verified like an attestation signature. The delegation is rejected
+    /// once the ledger time passes `deadline`, and `nonce` must equal
+    /// `get_delegation_nonce(issuer)`; each accepted delegation advances it.
+    /// `input` then goes through the same checks as `record_attestation`.
+    pub fn attest_delegated(
+        env: Env,
+        relayer: Address,
+        issuer: Address,
+        input: AttestationInput,
+        deadline: u64,
+        nonce: u64,
+        auth_signature: Bytes,
+    ) -> AttestationReceipt {
+        relayer.require_auth();
+        Self::check_relayer(&env, &relayer);
         let now = env.ledger().timestamp();
-        Ok(now.saturating_sub(entry.cached_at))
+        if now > deadline {
+            Self::reject_timestamp(&env, "deadline", deadline, now, u64::MAX, ErrorCode::InvalidTimestamp);
+        }
+        if nonce != Self::get_delegation_nonce(env.clone(), issuer.clone()) {
+            panic_with_error!(&env, ErrorCode::ReplayAttack);
+        }
+        let hash = compute_delegation_hash(&env, &env.current_contract_address(), &issuer, &input, deadline, nonce);
+        let message = domain_message(&env, DELEGATION_DOMAIN, &hash);
+        Self::verify_attestation_signature(&env, &issuer, &message, &auth_signature);
+        let nonce_key = AttestorKey::DelegationNonce(issuer.clone());
+        env.storage().persistent().set(&nonce_key, &(nonce + 1));
+        env.storage().persistent().extend_ttl(&nonce_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        Self::check_new_input(&env, &issuer, &input, false);
+        Self::check_signed(&env, &issuer, &input);
+        Self::commit_record(&env, issuer, &relayer, &input)
     }
 
-    // #272: return the cached data so callers avoid a second storage read.
-    pub fn refresh_metadata_cache(env: Env, anchor: Address) -> AnchorMetadata {
+    /// Only accept relayed submissions (`attest_delegated`,
+    /// `revoke_attestation_signed`) from allowlisted relayers (admin only).
+    /// Off by default, when any relayer may submit.
+    pub fn set_relayer_allowlist_enforced(env: Env, enforced: bool) {
         Self::require_admin(&env);
-        let key = StorageKey::MetadataCache(anchor.clone());
-        let entry: MetadataCache = env.storage().persistent().get(&key)
-            .or_else(|| env.storage().temporary().get(&key))
-            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::CacheNotFound));
-        let metadata = entry.metadata.clone();
-        // Remove from whichever storage tier holds the entry.
-        env.storage().persistent().remove(&key);
-        env.storage().temporary().remove(&key);
+        env.storage().instance().set(&key_relayer_allowlist(&env), &enforced);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        env.events().publish((symbol_short!("relayer"), symbol_short!("enforced")), enforced);
+    }
+
+    pub fn is_relayer_allowlist_enforced(env: Env) -> bool {
+        env.storage().instance().get(&key_relayer_allowlist(&env)).unwrap_or(false)
+    }
+
+    /// Put `relayer` on the relayer allowlist (admin only).
+    pub fn add_relayer(env: Env, relayer: Address) {
+        Self::require_admin(&env);
+        let key = RelayerKey::Allowed(relayer.clone());
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish((symbol_short!("relayer"), symbol_short!("added"), relayer), ());
+    }
+
+    /// Take `relayer` off the relayer allowlist (admin only).
+    pub fn remove_relayer(env: Env, relayer: Address) {
+        Self::require_admin(&env);
+        env.storage().persistent().remove(&RelayerKey::Allowed(relayer.clone()));
+        env.events().publish((symbol_short!("relayer"), symbol_short!("removed"), relayer), ());
+    }
+
+    /// Whether `relayer` is on the allowlist, whether or not it is enforced.
+    pub fn is_relayer_allowed(env: Env, relayer: Address) -> bool {
+        env.storage().persistent().has(&RelayerKey::Allowed(relayer))
+    }
+
+    /// Nonce `issuer`'s next delegated attestation must carry.
+    pub fn get_delegation_nonce(env: Env, issuer: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&AttestorKey::DelegationNonce(issuer))
+            .unwrap_or(0)
+    }
+
+    /// Hash behind an `attest_delegated` authorization.
+    pub fn compute_delegation_hash_public(
+        env: Env,
+        issuer: Address,
+        input: AttestationInput,
+        deadline: u64,
+        nonce: u64,
+    ) -> BytesN<32> {
+        compute_delegation_hash(&env, &env.current_contract_address(), &issuer, &input, deadline, nonce)
+    }
+
+    /// Bytes an issuer signs to authorize `attest_delegated`.
+    pub fn delegation_message_public(
+        env: Env,
+        issuer: Address,
+        input: AttestationInput,
+        deadline: u64,
+        nonce: u64,
+    ) -> Bytes {
+        let hash = compute_delegation_hash(&env, &env.current_contract_address(), &issuer, &input, deadline, nonce);
+        domain_message(&env, DELEGATION_DOMAIN, &hash)
+    }
+
+    /// Propose an attestation that needs `threshold` of `signers` to approve
+    /// before it is recorded with `proposer` as issuer.
+    ///
+    /// `proposer` must be among `signers` and counts as the first approval;
+    /// `threshold` must be between 2 and the number of signers. `input` goes
+    /// through the same checks as `record_attestation` now, and the replay
+    /// check is repeated on finalization. The proposal lapses after
+    /// `PROPOSAL_TTL` seconds. Returns the proposal ID.
+    pub fn propose_attestation(
+        env: Env,
+        proposer: Address,
+        input: AttestationInput,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> u64 {
+        proposer.require_auth();
+        if threshold < 2 || threshold > signers.len() || !signers.contains(&proposer) {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        Self::check_new_input(&env, &proposer, &input, false);
+        Self::check_signed(&env, &proposer, &input);
+        if env.storage().persistent().has(&Self::replay_key(&env, &proposer, &input.subject, &input.payload_hash)) {
+            Self::reject_replay(&env, &proposer, &input.subject, &input.payload_hash);
+        }
+
+        let count_key = key_proposal_count(&env);
+        let id: u64 = env.storage().instance().get(&count_key).unwrap_or(0);
+        env.storage().instance().set(&count_key, &(id + 1));
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        let proposal = AttestationProposal {
+            id,
+            proposer: proposer.clone(),
+            input,
+            signers,
+            threshold,
+            approvals: soroban_sdk::vec![&env, proposer.clone()],
+            expires_at: env.ledger().timestamp() + PROPOSAL_TTL,
+        };
+        Self::save_proposal(&env, &proposal);
+        env.events().publish((symbol_short!("proposal"), symbol_short!("opened"), id), proposer);
+        id
+    }
+
+    /// Approve proposal `proposal_id` as `attestor`, who must be one of its
+    /// registered signers. The approval that meets the threshold re-runs the
+    /// proposer's input checks, records the attestation, paying its fee, and
+    /// keeps the approving signers for `get_cosigners`; it returns the
+    /// receipt. Earlier approvals return `None`.
+    pub fn cosign(env: Env, attestor: Address, proposal_id: u64) -> Option<AttestationReceipt> {
+        attestor.require_auth();
+        Self::require_writable(&env);
+        let mut proposal = Self::get_proposal(env.clone(), proposal_id)
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCodeExt::ProposalNotFound));
+        if !proposal.signers.contains(&attestor) {
+            panic_with_error!(&env, ErrorCode::UnauthorizedAttestor);
+        }
+        Self::check_attestor(&env, &attestor);
+        if proposal.approvals.contains(&attestor) {
+            panic_with_error!(&env, ErrorCodeExt::AlreadyCosigned);
+        }
+        proposal.approvals.push_back(attestor.clone());
+        env.events().publish((symbol_short!("proposal"), symbol_short!("cosigned"), proposal_id), attestor);
+        if proposal.approvals.len() < proposal.threshold {
+            Self::save_proposal(&env, &proposal);
+            return None;
+        }
+        env.storage().temporary().remove(&ProposalKey::Proposal(proposal_id));
+        Self::check_new_input(&env, &proposal.proposer, &proposal.input, false);
+        let receipt = Self::commit_record(&env, proposal.proposer, &attestor, &proposal.input);
+        let approvals_key = ProposalKey::Approvals(receipt.id);
+        env.storage().persistent().set(&approvals_key, &proposal.approvals);
+        env.storage().persistent().extend_ttl(&approvals_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        Some(receipt)
+    }
+
+    /// Signers who approved co-signed attestation `id`, proposer first;
+    /// empty for attestations not recorded through `cosign`.
+    pub fn get_cosigners(env: Env, id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&ProposalKey::Approvals(id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Pending proposal `proposal_id`; `None` once finalized or lapsed.
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Option<AttestationProposal> {
+        let proposal: AttestationProposal = env.storage().temporary().get(&ProposalKey::Proposal(proposal_id))?;
+        if env.ledger().timestamp() > proposal.expires_at {
+            return None;
+        }
+        Some(proposal)
+    }
+
+    /// Record a short-lived attestation, such as a session proof or one-time
+    /// code, readable for `ttl_ledgers` ledgers (at most the network's max
+    /// TTL) through `get_ephemeral_attestation`.
+    ///
+    /// `input` goes through the same checks as `record_attestation`,
+    /// including the schema's tier minimum and cap, and the issuer pays the
+    /// effective fee straight to the fee recipient. The record lives in
+    /// temporary storage under its own ID counter: it is not indexed and
+    /// leaves no replay marker. Returns the ephemeral ID.
+    pub fn record_ephemeral_attestation(env: Env, issuer: Address, input: AttestationInput, ttl_ledgers: u32) -> u64 {
+        issuer.require_auth();
+        Self::require_writable(&env);
+        Self::require_subsystem_active(&env, SUBSYSTEM_ISSUANCE);
+        if ttl_ledgers == 0 || ttl_ledgers > env.storage().max_ttl() {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        Self::check_schema_reference(&env, input.schema_id);
+        Self::check_attestor_schema(&env, &issuer, input.schema_id);
+        Self::check_new_input(&env, &issuer, &input, false);
+        Self::check_signed(&env, &issuer, &input);
+        if let Some(schema_id) = input.schema_id {
+            Self::check_schema_tier(&env, schema_id, &issuer);
+            Self::charge_schema_cap(&env, schema_id);
+        }
+        Self::collect_fee(&env, None, &issuer, &issuer, input.schema_id);
+
+        let count_key = key_ephemeral_count(&env);
+        let id: u64 = env.storage().instance().get(&count_key).unwrap_or(0);
+        env.storage().instance().set(&count_key, &(id + 1));
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        let entry = EphemeralAttestation {
+            attestation: Self::new_attestation(&env, id, issuer, &input, None, 0),
+            expires_ledger: env.ledger().sequence() + ttl_ledgers,
+        };
+        let key = EphemeralKey::Attestation(id);
+        env.storage().temporary().set(&key, &entry);
+        env.storage().temporary().extend_ttl(&key, ttl_ledgers, ttl_ledgers);
+        env.events().publish((symbol_short!("ephem"), symbol_short!("recorded"), id), input.subject);
+        id
+    }
+
+    /// Ephemeral attestation `id`; `AttestationNotFound` once its TTL has
+    /// run out.
+    pub fn get_ephemeral_attestation(env: Env, id: u64) -> Result<Attestation, ErrorCode> {
+        let entry: EphemeralAttestation = env
+            .storage()
+            .temporary()
+            .get(&EphemeralKey::Attestation(id))
+            .ok_or(ErrorCode::AttestationNotFound)?;
+        if env.ledger().sequence() > entry.expires_ledger {
+            return Err(ErrorCode::AttestationNotFound);
+        }
+        Ok(entry.attestation)
+    }
+
+    /// Record several attestations from `issuer` in one call, atomically.
+    ///
+    /// Every entry goes through the same checks as `record_attestation`
+    /// (including replay, also between entries of the batch); if any entry
+    /// fails, the whole batch is rejected. Issuer registration and schema
+    /// grants are checked once per batch. Accepts at most `MAX_BATCH_SIZE`
+    /// entries and emits one `attest/recorded` event per entry.
+    pub fn batch_attest(env: Env, issuer: Address, inputs: Vec<AttestationInput>) -> Vec<AttestationReceipt> {
+        issuer.require_auth();
+        if inputs.is_empty() || inputs.len() > MAX_BATCH_SIZE {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        Self::check_issuer(&env, &issuer);
+        let mut granted_schemas: Vec<u64> = Vec::new(&env);
+        let mut receipts = Vec::new(&env);
+        for input in inputs.iter() {
+            Self::check_input(&env, &input);
+            Self::check_subject(&env, &input.subject, &issuer, false);
+            if let Some(schema_id) = input.schema_id {
+                if !granted_schemas.contains(schema_id) {
+                    Self::check_schema_grant(&env, schema_id, &issuer);
+                    granted_schemas.push_back(schema_id);
+                }
+            }
+            receipts.push_back(Self::record_checked(&env, issuer.clone(), &input));
+        }
+        receipts
+    }
+
+    /// Like `batch_attest`, but an entry that fails its own checks is
+    /// skipped instead of reverting the call. Returns one result per entry,
+    /// in order: its receipt, or the error code it was rejected with.
+    ///
+    /// Entries are rejected individually for invalid fields, a timestamp
+    /// outside the drift window, a bad signature, a reused payload hash
+    /// (including repeats within the batch), missing subject consent, the
+    /// subject gate, the issuer's schema allow-list, schema grants, tiers
+    /// and caps. Everything else still reverts the whole call: issuer and
+    /// pause checks, rate limits, fee collection, org quotas, and the schema
+    /// registration and `ref_id` lookups made while storing.
+    pub fn batch_attest_partial(env: Env, issuer: Address, inputs: Vec<AttestationInput>) -> Vec<BatchItemResult> {
+        issuer.require_auth();
+        if inputs.is_empty() || inputs.len() > MAX_BATCH_SIZE {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        Self::check_issuer(&env, &issuer);
+        let mut results = Vec::new(&env);
+        for input in inputs.iter() {
+            let result = match Self::screen_batch_entry(&env, &issuer, &input) {
+                Some(code) => BatchItemResult::Rejected(code),
+                None => {
+                    // Screening already covered the timestamp and signature.
+                    Self::charge_rate_limit(&env, &issuer);
+                    BatchItemResult::Recorded(Self::commit_record(&env, issuer.clone(), &issuer, &input))
+                }
+            };
+            results.push_back(result);
+        }
+        results
+    }
+
+    /// Current head of the running attestation chain hash (all zeroes before
+    /// the first attestation).
+    pub fn get_chain_head(env: Env) -> BytesN<32> {
+        env.storage()
+            .instance()
+            .get(&key_chain_head(&env))
+            .unwrap_or_else(|| BytesN::from_array(&env, &[0u8; 32]))
+    }
+
+    /// Submit an attestation that the subject has explicitly consented to.
+    ///
+    /// Requires authorization from both the issuer and the subject. A
+    /// `ConsentReceipt` is stored alongside the attestation so the subject can
+    /// later prove exactly what they agreed to be attested about.
+    pub fn submit_attestation_with_consent(
+        env: Env,
+        issuer: Address,
+        subject: Address,
+        timestamp: u64,
+        payload_hash: Bytes,
+        signature: Bytes,
+    ) -> u64 {
+        issuer.require_auth();
+        subject.require_auth();
+        let input = AttestationInput::new(subject.clone(), timestamp, payload_hash.clone(), signature);
+        // The subject's own authorization stands in for a standing consent.
+        let id = Self::record_submission(&env, issuer, &input, true).id;
+
+        let receipt = ConsentReceipt {
+            subject: subject.clone(),
+            attestation_id: id,
+            consent_ledger: env.ledger().sequence(),
+            scope_hash: payload_hash,
+        };
+        let receipt_key = StorageKey::ConsentReceipt(id);
+        env.storage().persistent().set(&receipt_key, &receipt);
+        env.storage().persistent().extend_ttl(&receipt_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        let count_key = StorageKey::SubjectConsentCount(subject.clone());
+        let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        let entry_key = StorageKey::SubjectConsent(subject.clone(), count);
+        env.storage().persistent().set(&entry_key, &id);
+        env.storage().persistent().extend_ttl(&entry_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.storage().persistent().set(&count_key, &(count + 1));
+        env.storage().persistent().extend_ttl(&count_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        env.events().publish(
+            (symbol_short!("consent"), symbol_short!("given"), id, subject),
+            receipt,
+        );
+        id
+    }
+
+    /// Require subject consent for every attestation (admin only). While
+    /// enabled, recording fails with `SubjectConsentRequired` unless the
+    /// subject authorizes the call (`submit_attestation_with_consent`) or has
+    /// granted the issuer standing consent via `grant_consent`.
+    pub fn set_consent_required(env: Env, required: bool) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&key_consent_required(&env), &required);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        env.events().publish((symbol_short!("consent"), symbol_short!("required")), required);
+    }
+
+    pub fn is_consent_required(env: Env) -> bool {
+        env.storage().instance().get(&key_consent_required(&env)).unwrap_or(false)
+    }
+
+    /// Only record attestations about subjects holding at least
+    /// `min_balance` of `token` (admin only); `min_balance` `1` requires
+    /// owning any of the asset. Other subjects are rejected with
+    /// `SubjectNotEligible`.
+    pub fn set_subject_gate(env: Env, token: Address, min_balance: i128) {
+        Self::require_admin(&env);
+        if min_balance <= 0 {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        let gate = SubjectGate { token, min_balance };
+        env.storage().instance().set(&key_subject_gate(&env), &gate);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        env.events().publish((symbol_short!("gate"), symbol_short!("updated")), gate);
+    }
+
+    /// Stop requiring subjects to hold a token (admin only).
+    pub fn clear_subject_gate(env: Env) {
+        Self::require_admin(&env);
+        env.storage().instance().remove(&key_subject_gate(&env));
+        env.events().publish((symbol_short!("gate"), symbol_short!("cleared")), ());
+    }
+
+    pub fn get_subject_gate(env: Env) -> Option<SubjectGate> {
+        env.storage().instance().get(&key_subject_gate(&env))
+    }
+
+    /// Let `issuer` record attestations about `subject` until revoked
+    /// (subject only).
+    pub fn grant_consent(env: Env, subject: Address, issuer: Address) {
+        Self::require_writable(&env);
+        subject.require_auth();
+        let key = AttestorKey::Consent(issuer.clone(), subject.clone());
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish(
+            (symbol_short!("consent"), symbol_short!("granted"), subject.clone()),
+            ConsentGranted { subject, issuer },
+        );
+    }
+
+    /// Withdraw a standing consent given with `grant_consent` (subject only).
+    /// Attestations already recorded are unaffected.
+    pub fn revoke_consent(env: Env, subject: Address, issuer: Address) {
+        Self::require_writable(&env);
+        subject.require_auth();
+        env.storage().persistent().remove(&AttestorKey::Consent(issuer.clone(), subject.clone()));
+        env.events().publish(
+            (symbol_short!("consent"), symbol_short!("revoked"), subject.clone()),
+            ConsentRevoked { subject, issuer },
+        );
+    }
+
+    pub fn has_consent(env: Env, subject: Address, issuer: Address) -> bool {
+        env.storage().persistent().has(&AttestorKey::Consent(issuer, subject))
+    }
+
+    pub fn get_consent_receipt(env: Env, attestation_id: u64) -> Option<ConsentReceipt> {
+        env.storage().persistent().get(&StorageKey::ConsentReceipt(attestation_id))
+    }
+
+    /// Page through the consent receipts held by `subject`, oldest first.
+    /// `offset` indexes the subject's consent index and `limit` is capped at
+    /// the max page size.
+    pub fn get_consent_receipts(env: Env, subject: Address, offset: u64, limit: u32) -> Vec<ConsentReceipt> {
+        let limit = limit.min(Self::get_max_page_size(env.clone()));
+        let mut receipts = Vec::new(&env);
+        let total: u64 = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::SubjectConsentCount(subject.clone()))
+            .unwrap_or(0);
+        let end = total.min(offset.saturating_add(limit as u64));
+        for i in offset..end {
+            let Some(id) = env.storage().persistent().get::<_, u64>(&StorageKey::SubjectConsent(subject.clone(), i)) else {
+                continue;
+            };
+            if let Some(r) = env.storage().persistent().get(&StorageKey::ConsentReceipt(id)) {
+                receipts.push_back(r);
+            }
+        }
+        receipts
+    }
+
+    // -----------------------------------------------------------------------
+    // Attestation submission with request ID + tracing span
+    // -----------------------------------------------------------------------
+
+    pub fn submit_with_request_id(
+        env: Env,
+        request_id: RequestId,
+        issuer: Address,
+        subject: Address,
+        timestamp: u64,
+        payload_hash: Bytes,
+        signature: Bytes,
+    ) -> u64 {
+        issuer.require_auth();
+        let input = AttestationInput::new(subject, timestamp, payload_hash, signature);
+        let id = Self::record_submission(&env, issuer.clone(), &input, false).id;
+
+        let now = env.ledger().timestamp();
+        Self::store_span(&env, &request_id, String::from_str(&env, "submit_attestation"), issuer, now, String::from_str(&env, "success"));
+
+        id
+    }
+
+    // -----------------------------------------------------------------------
+    // Quote submission with request ID + tracing span
+    // -----------------------------------------------------------------------
+
+    #[allow(unused_variables)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn quote_with_request_id(
+        env: Env,
+        request_id: RequestId,
+        anchor: Address,
+        from_asset: String,
+        to_asset: String,
+        amount: u64,
+        fee_bps: u32,
+        min_amount: u64,
+        max_amount: u64,
+        expires_at: u64,
+    ) {
+        Self::require_writable(&env);
+        anchor.require_auth();
+
+        let services_record = env
+            .storage()
+            .persistent()
+            .get::<_, AnchorServices>(&StorageKey::Services(anchor.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::ServicesNotConfigured));
+        if !services_record.services.contains(SERVICE_QUOTES) {
+            panic_with_error!(&env, ErrorCode::ServicesNotConfigured);
+        }
+
+        let inst = env.storage().instance();
+        let qcnt_key = key_quote_counter(&env);
+        let next: u64 = inst.get(&qcnt_key).unwrap_or(0u64) + 1;
+        inst.set(&qcnt_key, &next);
+        inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+
+        let quote = Quote {
+            quote_id: next,
+            anchor: anchor.clone(),
+            base_asset: from_asset,
+            quote_asset: to_asset,
+            rate: amount,
+            fee_percentage: fee_bps,
+            minimum_amount: min_amount,
+            maximum_amount: max_amount,
+            valid_until: expires_at,
+        };
+        let q_key = StorageKey::Quote(anchor.clone(), next);
+        env.storage().persistent().set(&q_key, &quote);
+        env.storage().persistent().extend_ttl(&q_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        let lq_key = StorageKey::LatestQuote(anchor.clone());
+        env.storage().persistent().set(&lq_key, &next);
+        env.storage().persistent().extend_ttl(&lq_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        let now = env.ledger().timestamp();
+        Self::store_span(&env, &request_id, String::from_str(&env, "submit_quote"), anchor, now, String::from_str(&env, "success"));
+    }
+
+    // -----------------------------------------------------------------------
+    // Tracing span retrieval
+    // -----------------------------------------------------------------------
+
+    pub fn get_tracing_span(env: Env, request_id_bytes: Bytes) -> Option<TracingSpan> {
+        env.storage()
+            .temporary()
+            .get::<_, TracingSpan>(&StorageKey::Span(request_id_bytes))
+    }
+
+    // -----------------------------------------------------------------------
+    // Attestation bundles
+    // -----------------------------------------------------------------------
+
+    /// Anchor several `(schema_id, payload_hash)` claims about `subject` under
+    /// one issuer signature.
+    ///
+    /// `signature` must cover `compute_bundle_hash(subject, timestamp, claims)`.
+    /// The issuer pays the effective fee of each claim's schema, straight to
+    /// the fee recipient. Returns the bundle ID; individual claims are
+    /// addressed by their index.
+    pub fn submit_bundle(
+        env: Env,
+        issuer: Address,
+        subject: Address,
+        timestamp: u64,
+        claims: Vec<(u64, Bytes)>,
+        signature: Bytes,
+    ) -> u64 {
+        issuer.require_auth();
+        Self::require_subsystem_active(&env, SUBSYSTEM_ISSUANCE);
+        Self::check_attestor(&env, &issuer);
+        Self::check_tier_not_suspended(&env, &issuer);
+        if claims.is_empty() {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        if let Err(e) = validation::check_bundle(&env, &claims, &signature) {
+            panic_with_error!(&env, e);
+        }
+        for (schema_id, _) in claims.iter() {
+            Self::check_schema_reference(&env, Some(schema_id));
+            Self::check_attestor_schema(&env, &issuer, Some(schema_id));
+            Self::check_schema_grant(&env, schema_id, &issuer);
+            Self::check_schema_tier(&env, schema_id, &issuer);
+        }
+        Self::check_subject(&env, &subject, &issuer, false);
+        if let Err(e) = crate::rate_limiter::RateLimiter::check_and_increment(&env, &issuer) {
+            panic_with_error!(&env, e);
+        }
+        Self::check_timestamp(&env, timestamp);
+
+        let bundle_hash = compute_bundle_hash(&env, &subject, timestamp, &claims);
+        let hash_bytes = Bytes::from(bundle_hash.clone());
+        Self::verify_attestation_signature(&env, &issuer, &hash_bytes, &signature);
+
+        let used_key = Self::replay_key(&env, &issuer, &subject, &hash_bytes);
+        if env.storage().persistent().has(&used_key) {
+            Self::reject_replay(&env, &issuer, &subject, &hash_bytes);
+        }
+        env.storage().persistent().set(&used_key, &true);
+        env.storage().persistent().extend_ttl(&used_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        if let Some(org_id) = Self::get_attestor_org(env.clone(), issuer.clone()) {
+            for _ in 0..claims.len() {
+                Self::charge_org_quota(&env, org_id);
+            }
+        }
+
+        let mut bundle_claims = Vec::new(&env);
+        for (schema_id, payload_hash) in claims.iter() {
+            Self::charge_schema_cap(&env, schema_id);
+            Self::collect_fee(&env, None, &issuer, &issuer, Some(schema_id));
+            let schema_epoch = Self::get_schema_revocation_epoch(env.clone(), issuer.clone(), schema_id);
+            bundle_claims.push_back(BundleClaim { schema_id, payload_hash, revoked: false, schema_epoch });
+        }
+
+        let inst = env.storage().instance();
+        let id: u64 = inst.get(&key_bundle_counter(&env)).unwrap_or(0u64) + 1;
+        inst.set(&key_bundle_counter(&env), &id);
+        inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        Self::record_activity(&env, &issuer, true);
+
+        let bundle = AttestationBundle {
+            id,
+            issuer,
+            subject: subject.clone(),
+            timestamp,
+            bundle_hash: bundle_hash.clone(),
+            signature,
+            claims: bundle_claims,
+        };
+        Self::save_bundle(&env, &bundle);
+
+        let topics = (symbol_short!("bundle"), symbol_short!("recorded"), id, subject);
+        match Self::get_event_verbosity(env.clone()) {
+            EventVerbosity::Minimal => env.events().publish(topics, ()),
+            EventVerbosity::Standard => env.events().publish(topics, bundle_hash),
+            EventVerbosity::Verbose => env.events().publish(topics, bundle),
+        }
+        id
+    }
+
+    pub fn get_bundle(env: Env, bundle_id: u64) -> Option<AttestationBundle> {
+        env.storage().persistent().get(&StorageKey::Bundle(bundle_id))
+    }
+
+    pub fn get_bundle_claim(env: Env, bundle_id: u64, index: u32) -> Option<BundleClaim> {
+        Self::get_bundle(env, bundle_id)?.claims.get(index)
+    }
+
+    /// Revoke a single claim of a bundle (bundle issuer only).
+    pub fn revoke_bundle_claim(env: Env, issuer: Address, bundle_id: u64, index: u32) {
+        Self::require_writable(&env);
+        Self::require_subsystem_active(&env, SUBSYSTEM_REVOCATION);
+        issuer.require_auth();
+        let mut bundle = Self::load_bundle(&env, bundle_id);
+        if bundle.issuer != issuer {
+            panic_with_error!(&env, ErrorCode::UnauthorizedAttestor);
+        }
+        let mut claim = bundle
+            .claims
+            .get(index)
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::BundleClaimNotFound));
+        claim.revoked = true;
+        bundle.claims.set(index, claim);
+        Self::save_bundle(&env, &bundle);
+        Self::record_activity(&env, &issuer, false);
+        env.events().publish(
+            (symbol_short!("bundle"), symbol_short!("revoked"), bundle_id),
+            index,
+        );
+    }
+
+    /// Revoke everything `issuer` has attested under `schema_id` (issuer only).
+    ///
+    /// Advances the issuer's revocation epoch for the schema in O(1); records
+    /// anchored under an earlier epoch are treated as revoked at verify time.
+    pub fn revoke_all_by_schema(env: Env, issuer: Address, schema_id: u64) {
+        Self::require_writable(&env);
+        Self::require_subsystem_active(&env, SUBSYSTEM_REVOCATION);
+        issuer.require_auth();
+        let key = SchemaKey::RevocationEpoch(issuer.clone(), schema_id);
+        let epoch: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(epoch + 1));
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        Self::record_activity(&env, &issuer, false);
+        env.events().publish(
+            (symbol_short!("schema"), symbol_short!("rev_all"), issuer, schema_id),
+            epoch + 1,
+        );
+    }
+
+    pub fn get_schema_revocation_epoch(env: Env, issuer: Address, schema_id: u64) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&SchemaKey::RevocationEpoch(issuer, schema_id))
+            .unwrap_or(0)
+    }
+
+    /// Returns `true` when the claim exists, has not been revoked individually
+    /// or via `revoke_all_by_schema`, predates no invalidation epoch, the
+    /// issuer still meets a schema tier minimum enforced at verification, and
+    /// the bundle issuer is still a registered attestor.
+    pub fn is_bundle_claim_valid(env: Env, bundle_id: u64, index: u32) -> bool {
+        let Some(bundle) = Self::get_bundle(env.clone(), bundle_id) else {
+            return false;
+        };
+        let Some(claim) = bundle.claims.get(index) else {
+            return false;
+        };
+        !claim.revoked
+            && !Self::is_invalidated(&env, &bundle.issuer, bundle.timestamp)
+            && claim.schema_epoch
+                == Self::get_schema_revocation_epoch(env.clone(), bundle.issuer.clone(), claim.schema_id)
+            && !Self::is_below_schema_tier(&env, Some(claim.schema_id), &bundle.issuer)
+            && env
+                .storage()
+                .persistent()
+                .has(&StorageKey::Attestor(bundle.issuer))
+    }
+
+    // -----------------------------------------------------------------------
+    // Attestation retrieval
+    // -----------------------------------------------------------------------
+
+    /// Attestation `id`. For records their subject has hidden, the content
+    /// is withheld here and in the views built on it (see
+    /// `withhold_hidden`), while validity and lifecycle fields stay
+    /// accurate. This call has no caller to authorize, so the subject's and
+    /// issuer's full view of hidden records is served by `get_attestation_as`.
+    pub fn get_attestation(env: Env, id: u64) -> Option<Attestation> {
+        let attestation = Self::load_attestation(&env, id)?;
+        Some(Self::withhold_hidden(&env, attestation))
+    }
+
+    /// Attestation `id` as disclosed to `viewer`. Visible records are
+    /// returned in full to anyone. Hidden records are returned in full only
+    /// to their subject or issuer, who must authorize the call; everyone
+    /// else gets the redacted form.
+    pub fn get_attestation_as(env: Env, viewer: Address, id: u64) -> Option<AttestationDisclosure> {
+        let attestation = Self::load_attestation(&env, id)?;
+        if !Self::is_hidden(env.clone(), id) {
+            return Some(AttestationDisclosure::Full(attestation));
+        }
+        if viewer == attestation.subject || viewer == attestation.issuer {
+            viewer.require_auth();
+            return Some(AttestationDisclosure::Full(attestation));
+        }
+        Some(AttestationDisclosure::Redacted(RedactedAttestation {
+            id,
+            status: Self::current_status(&env, &attestation),
+            issuer: attestation.issuer,
+        }))
+    }
+
+    /// Hide or reveal attestation `id`; only its subject may call this.
+    /// Hidden records stay valid and verifiable but their content is
+    /// withheld from public reads and listings.
+    pub fn set_visibility(env: Env, subject: Address, id: u64, hidden: bool) {
+        Self::require_writable(&env);
+        subject.require_auth();
+        let attestation = Self::load_attestation(&env, id)
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+        if attestation.subject != subject {
+            panic_with_error!(&env, ErrorCode::NotAttestationSubject);
+        }
+        let key = VisibilityKey::Hidden(id);
+        if hidden {
+            env.storage().persistent().set(&key, &true);
+            env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events().publish(
+            (symbol_short!("attest"), symbol_short!("visible"), id),
+            VisibilityChanged { subject, hidden },
+        );
+    }
+
+    pub fn is_hidden(env: Env, id: u64) -> bool {
+        env.storage().persistent().has(&VisibilityKey::Hidden(id))
+    }
+
+    /// `attestation` with its content withheld when its subject has hidden
+    /// it: the subject reads as this contract, the payload hash and
+    /// signature as empty, the data as `HashOnly`, and the value,
+    /// jurisdiction and URI as unset.
+    fn withhold_hidden(env: &Env, attestation: Attestation) -> Attestation {
+        if !Self::is_hidden(env.clone(), attestation.id) {
+            return attestation;
+        }
+        Attestation {
+            subject: env.current_contract_address(),
+            payload_hash: Bytes::new(env),
+            signature: Bytes::new(env),
+            data: AttestationData::HashOnly,
+            value: None,
+            jurisdiction: None,
+            uri: None,
+            ..attestation
+        }
+    }
+
+    /// Resolve the attestation recorded with payload `hash` through its
+    /// global replay marker. Markers under per-issuer replay scopes, bundle
+    /// markers and markers written before they carried the attestation ID
+    /// do not resolve and yield `AttestationNotFound`.
+    pub fn get_attestation_by_hash(env: Env, hash: Bytes) -> Result<Attestation, ErrorCode> {
+        let marker: Val = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::Used(hash))
+            .ok_or(ErrorCode::AttestationNotFound)?;
+        let id = u64::try_from_val(&env, &marker).map_err(|_| ErrorCode::AttestationNotFound)?;
+        Self::get_attestation(env, id).ok_or(ErrorCode::AttestationNotFound)
+    }
+
+    /// Keep attestation `id` alive for at least `extend_to` more ledgers
+    /// (capped at the network maximum). Callable by anyone, who pays the
+    /// rent; also allowed in read-only mode. Extends the record, its chain
+    /// link and its replay marker under the current replay scope.
+    pub fn extend_attestation_ttl(env: Env, id: u64, extend_to: u32) {
+        if Self::extend_attestation_entries(&env, id, extend_to).is_none() {
+            panic_with_error!(&env, ErrorCode::AttestationNotFound);
+        }
+    }
+
+    /// Bulk `extend_attestation_ttl` for up to `MAX_BATCH_SIZE` IDs. IDs no
+    /// longer stored are skipped; returns how many were extended.
+    pub fn extend_attestation_ttls(env: Env, ids: Vec<u64>, extend_to: u32) -> u32 {
+        if ids.len() > MAX_BATCH_SIZE {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        let mut extended = 0;
+        for id in ids.iter() {
+            if Self::extend_attestation_entries(&env, id, extend_to).is_some() {
+                extended += 1;
+            }
+        }
+        extended
+    }
+
+    /// Set the token and keeper reward of the rent sponsorship pools (admin
+    /// only). The token cannot change once set, since pools already funded
+    /// are held in it.
+    pub fn set_rent_sponsorship(env: Env, config: RentSponsorConfig) {
+        Self::require_admin(&env);
+        if config.reward < 0 {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        if Self::get_rent_sponsorship(env.clone()).is_some_and(|current| current.token != config.token) {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        env.storage().instance().set(&key_rent_sponsor(&env), &config);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        env.events().publish((symbol_short!("rent"), symbol_short!("config")), config);
+    }
+
+    pub fn get_rent_sponsorship(env: Env) -> Option<RentSponsorConfig> {
+        env.storage().instance().get(&key_rent_sponsor(&env))
+    }
+
+    /// Deposit `amount` of the sponsorship token from `depositor` into the
+    /// pool for `target`, to pay keepers who keep its attestations alive.
+    /// Deposits cannot be withdrawn.
+    pub fn sponsor_rent(env: Env, depositor: Address, target: RentTarget, amount: i128) {
+        Self::require_writable(&env);
+        depositor.require_auth();
+        let config = Self::get_rent_sponsorship(env.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::NotInitialized));
+        if amount <= 0 {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        token::Client::new(&env, &config.token).transfer(&depositor, &env.current_contract_address(), &amount);
+        let key = RentKey::Pool(target.clone());
+        let balance = Self::get_rent_pool(env.clone(), target.clone()) + amount;
+        env.storage().persistent().set(&key, &balance);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish(
+            (symbol_short!("rent"), symbol_short!("funded")),
+            RentSponsored { target, depositor, amount, balance },
+        );
+    }
+
+    /// Unspent deposits in the sponsorship pool for `target`.
+    pub fn get_rent_pool(env: Env, target: RentTarget) -> i128 {
+        env.storage().persistent().get(&RentKey::Pool(target)).unwrap_or(0)
+    }
+
+    /// `extend_attestation_ttls` for keepers of sponsored attestations.
+    ///
+    /// Each extended attestation that is not revoked earns `keeper` the
+    /// configured reward per whole `interval` of lifetime added beyond what
+    /// earlier sponsored extensions already paid for, from its schema's
+    /// pool, or its subject's if the schema pool is short. Returns the total
+    /// paid to `keeper`.
+    pub fn extend_sponsored_ttls(env: Env, keeper: Address, ids: Vec<u64>, extend_to: u32) -> i128 {
+        Self::require_writable(&env);
+        keeper.require_auth();
+        if ids.len() > MAX_BATCH_SIZE {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        let config = Self::get_rent_sponsorship(env.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::NotInitialized));
+        let now = env.ledger().sequence();
+        let live_until = now.saturating_add(extend_to.min(env.storage().max_ttl()));
+        let mut paid = 0i128;
+        for id in ids.iter() {
+            let Some(attestation) = Self::extend_attestation_entries(&env, id, extend_to) else {
+                continue;
+            };
+            if config.interval == 0 || Self::current_status(&env, &attestation) == Status::Revoked {
+                continue;
+            }
+            let paid_key = RentKey::PaidUntil(id);
+            let paid_until: u32 = env.storage().persistent().get(&paid_key).unwrap_or(now).max(now);
+            let intervals = live_until.saturating_sub(paid_until) / config.interval;
+            let reward = config.reward.saturating_mul(intervals as i128);
+            if intervals == 0 || !Self::draw_rent_reward(&env, &attestation, reward) {
+                continue;
+            }
+            env.storage().persistent().set(&paid_key, &(paid_until + intervals * config.interval));
+            env.storage().persistent().extend_ttl(&paid_key, PERSISTENT_TTL, PERSISTENT_TTL);
+            paid += reward;
+        }
+        if paid > 0 {
+            token::Client::new(&env, &config.token).transfer(&env.current_contract_address(), &keeper, &paid);
+            env.events().publish((symbol_short!("rent"), symbol_short!("paid"), keeper), paid);
+        }
+        paid
+    }
+
+    /// Keep the global replay marker for `hash` alive for at least
+    /// `extend_to` more ledgers. Returns `false` when no such marker exists;
+    /// markers under per-issuer scopes are extended through
+    /// `extend_attestation_ttl`.
+    pub fn extend_hash_ttl(env: Env, hash: Bytes, extend_to: u32) -> bool {
+        let key = StorageKey::Used(hash);
+        if !env.storage().persistent().has(&key) {
+            return false;
+        }
+        let extend_to = extend_to.min(env.storage().max_ttl());
+        env.storage().persistent().extend_ttl(&key, extend_to, extend_to);
+        true
+    }
+
+    /// Attestation `id` followed by the attestations it renews or amends,
+    /// newest first, following `ref_id` links for at most `MAX_REF_DEPTH`
+    /// records. The walk stops early at a record that is no longer stored.
+    pub fn get_attestation_chain(env: Env, id: u64) -> Vec<Attestation> {
+        let mut chain = Vec::new(&env);
+        let mut next = Some(id);
+        while let Some(current) = next {
+            if chain.len() >= MAX_REF_DEPTH {
+                break;
+            }
+            let Some(attestation) = Self::get_attestation(env.clone(), current) else {
+                break;
+            };
+            next = attestation.ref_id;
+            chain.push_back(attestation);
+        }
+        chain
+    }
+
+    /// Attestation `id` in canonical byte form (its XDR encoding) for audits.
+    ///
+    /// Exports the record exactly as stored, without the read-time
+    /// `issuer_revoked` overlay applied by `get_attestation`, so hashing the
+    /// result can be compared against an independently reconstructed record.
+    /// Hidden records fail with `AttestationNotFound`, since their content is
+    /// withheld.
+    pub fn export_attestation(env: Env, id: u64) -> Bytes {
+        if Self::is_hidden(env.clone(), id) {
+            panic_with_error!(&env, ErrorCode::AttestationNotFound);
+        }
+        let attestation: Attestation = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::Attest(id))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+        canonical_attestation_bytes(&env, &attestation)
+    }
+
+    /// Like `get_attestation`, but also returns the record's computed validity
+    /// so consumers don't mistake stale records for valid ones.
+    pub fn get_attestation_v2(env: Env, id: u64) -> Option<AttestationView> {
+        let attestation = Self::load_attestation(&env, id)?;
+        let validity = Self::evaluate_validity(&env, &attestation);
+        let attestation = Self::withhold_hidden(&env, attestation);
+        Some(AttestationView { attestation, validity })
+    }
+
+    /// Self-describing credential view for wallets: the attestation, a
+    /// snapshot of its issuer, the schema definition hash, the computed
+    /// validity, and its place in the attestation hash chain.
+    pub fn get_proof_bundle(env: Env, id: u64) -> Option<ProofBundle> {
+        let attestation = Self::load_attestation(&env, id)?;
+        let validity = Self::evaluate_validity(&env, &attestation);
+        let attestation = Self::withhold_hidden(&env, attestation);
+        let zero = BytesN::from_array(&env, &[0u8; 32]);
+        let issuer = attestation.issuer.clone();
+        let issuer = IssuerSnapshot {
+            registered: Self::is_attestor(env.clone(), issuer.clone()),
+            revoked: attestation.issuer_revoked,
+            tier: Self::get_attestor_tier(env.clone(), issuer.clone()),
+            org_id: Self::get_attestor_org(env.clone(), issuer.clone()),
+            signing_key: Self::get_attestor_key(env.clone(), issuer.clone()).unwrap_or_else(|| zero.clone()),
+            secp256k1_key: Self::secp256k1_key_bytes(&env, issuer),
+        };
+        let schema_hash = attestation
+            .schema_id
+            .and_then(|schema_id| Self::get_schema(env.clone(), schema_id))
+            .map(|schema| schema.schema_hash)
+            .unwrap_or_else(|| zero.clone());
+        let chain = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::ChainLink(id))
+            .unwrap_or_else(|| ChainLink { sequence: 0, prev_hash: zero.clone(), hash: zero });
+        Some(ProofBundle { attestation, issuer, schema_hash, validity, chain })
+    }
+
+    /// `true` when attestation `id` exists and is currently valid: not
+    /// revoked (individually, by schema or via its issuer), not expired, and
+    /// past any embargo. Shorthand for `get_attestation_v2(id).validity.is_valid`.
+    pub fn is_valid(env: Env, id: u64) -> bool {
+        Self::get_attestation_v2(env, id).is_some_and(|view| view.validity.is_valid)
+    }
+
+    /// `true` when attestation `id` is currently valid and its payload digest
+    /// (the 32-byte `payload_hash` itself, otherwise its SHA-256) equals
+    /// `expected_hash`. Never fails, so consumer contracts can gate on it
+    /// without decoding an `Attestation`.
+    pub fn verify(env: Env, id: u64, expected_hash: BytesN<32>) -> bool {
+        let Some(attestation) = Self::load_attestation(&env, id) else {
+            return false;
+        };
+        Self::evaluate_validity(&env, &attestation).is_valid
+            && Self::payload_digest(&env, &attestation.payload_hash) == expected_hash
+    }
+
+    /// `true` when `subject`'s primary attestation for `schema_id`, or failing
+    /// that its latest one under the schema, is currently valid. Reads at most
+    /// two records, however many attestations the subject holds.
+    pub fn verify_for_subject(env: Env, subject: Address, schema_id: u64) -> bool {
+        let primary: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::PrimaryAttestation(subject.clone(), schema_id));
+        let latest: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&SchemaKey::SubjectLatest(subject, schema_id));
+        primary.into_iter().chain(latest.filter(|id| primary != Some(*id))).any(|id| {
+            Self::load_attestation(&env, id).is_some_and(|att| {
+                att.schema_id == Some(schema_id) && Self::evaluate_validity(&env, &att).is_valid
+            })
+        })
+    }
+
+    /// Register (`Some`) or clear (`None`) a topic tag for events about
+    /// `subject` (subject only).
+    ///
+    /// While set, every attestation recorded for, erased from, or designated
+    /// primary by the subject is mirrored as a `SubjectTopicEvent` under the
+    /// topics `("subject", topic)`, so a backend can subscribe to one stable
+    /// topic per user.
+    pub fn set_subject_topic(env: Env, subject: Address, topic: Option<Symbol>) {
+        Self::require_writable(&env);
+        subject.require_auth();
+        let key = StorageKey::SubjectTopic(subject);
+        match topic {
+            Some(topic) => {
+                env.storage().persistent().set(&key, &topic);
+                env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
+    }
+
+    pub fn get_subject_topic(env: Env, subject: Address) -> Option<Symbol> {
+        env.storage().persistent().get(&StorageKey::SubjectTopic(subject))
+    }
+
+    /// Link `subject` to the address it migrated to. Both addresses must
+    /// authorize. Transferable claims follow the link; subject-bound claims
+    /// do not.
+    pub fn link_subject_successor(env: Env, subject: Address, successor: Address) {
+        Self::require_writable(&env);
+        subject.require_auth();
+        successor.require_auth();
+        if subject == successor {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        let key = StorageKey::SubjectSuccessor(subject.clone());
+        env.storage().persistent().set(&key, &successor);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish((symbol_short!("subject"), symbol_short!("migrated"), subject), successor);
+    }
+
+    pub fn get_subject_successor(env: Env, subject: Address) -> Option<Address> {
+        env.storage().persistent().get(&StorageKey::SubjectSuccessor(subject))
+    }
+
+    /// Returns `true` when attestation `id` is valid and may be presented by
+    /// `holder`: either the original subject, or — for transferable claims
+    /// only — an address reachable through the subject's successor links.
+    pub fn verify_for_holder(env: Env, id: u64, holder: Address) -> bool {
+        let Some(attestation) = Self::load_attestation(&env, id) else {
+            return false;
+        };
+        if !Self::evaluate_validity(&env, &attestation).is_valid {
+            return false;
+        }
+        if attestation.subject == holder {
+            return true;
+        }
+        if !attestation.transferable {
+            return false;
+        }
+        let mut current = attestation.subject;
+        for _ in 0..MAX_SUCCESSOR_HOPS {
+            match Self::get_subject_successor(env.clone(), current) {
+                Some(next) if next == holder => return true,
+                Some(next) => current = next,
+                None => return false,
+            }
+        }
+        false
+    }
+
+    /// Designate attestation `id` as the subject's primary credential for
+    /// `schema_id` (subject only).
+    ///
+    /// The attestation must be recorded under `schema_id` (`ValidationError`),
+    /// currently valid (`AttestationNotValid`), and its issuer must be on the
+    /// schema's issuer allowlist. Replaces any previous designation for the
+    /// same schema.
+    pub fn set_primary(env: Env, subject: Address, schema_id: u64, id: u64) {
+        Self::require_writable(&env);
+        subject.require_auth();
+        let attestation = Self::load_attestation(&env, id)
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+        if attestation.subject != subject {
+            panic_with_error!(&env, ErrorCode::NotAttestationSubject);
+        }
+        if attestation.schema_id != Some(schema_id) {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        if !Self::evaluate_validity(&env, &attestation).is_valid {
+            panic_with_error!(&env, ErrorCodeExt::AttestationNotValid);
+        }
+        if !Self::get_issuers_for_schema(env.clone(), schema_id).contains(&attestation.issuer) {
+            panic_with_error!(&env, ErrorCode::IssuerNotAuthorizedForSchema);
+        }
+        let key = StorageKey::PrimaryAttestation(subject.clone(), schema_id);
+        env.storage().persistent().set(&key, &id);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish(
+            (symbol_short!("primary"), symbol_short!("set"), subject.clone(), schema_id),
+            id,
+        );
+        Self::emit_subject_topic(&env, symbol_short!("primary"), subject, id);
+    }
+
+    /// Remove the subject's primary designation for `schema_id`, if any.
+    pub fn clear_primary(env: Env, subject: Address, schema_id: u64) {
+        Self::require_writable(&env);
+        subject.require_auth();
+        env.storage()
+            .persistent()
+            .remove(&StorageKey::PrimaryAttestation(subject, schema_id));
+    }
+
+    /// The attestation ID the subject presents by default for `schema_id`.
+    pub fn get_primary(env: Env, subject: Address, schema_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::PrimaryAttestation(subject, schema_id))
+    }
+
+    /// Prove a single field of a Merkle-committed attestation.
+    ///
+    /// For attestations whose `payload_hash` is the root of a Merkle tree over
+    /// per-field hashes, returns `true` when `field_hash` and `proof` hash up to
+    /// that root. See `merkle` for the tree construction, including the leaf
+    /// and node prefixes. Panics with
+    /// `AttestationNotFound` if `id` does not exist.
+    pub fn verify_field(env: Env, id: u64, field_hash: BytesN<32>, proof: Vec<BytesN<32>>) -> bool {
+        let attestation: Attestation = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::Attest(id))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+        if attestation.payload_hash.len() != 32 {
+            return false;
+        }
+        let mut root = [0u8; 32];
+        attestation.payload_hash.copy_into_slice(&mut root);
+        crate::merkle::verify_proof(&env, &BytesN::from_array(&env, &root), &field_hash, &proof)
+    }
+
+    /// Anchor the Merkle `root` of an off-chain batch of `leaf_count`
+    /// attestation hashes from `issuer`, so thousands of attestations cost a
+    /// single storage entry. The tree is built as described in `merkle`.
+    /// Returns the root ID to pass to `verify_inclusion`.
+    pub fn anchor_root(env: Env, issuer: Address, root: BytesN<32>, leaf_count: u32) -> u64 {
+        issuer.require_auth();
+        Self::check_issuer(&env, &issuer);
+        if leaf_count == 0 {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        let id: u64 = env.storage().persistent().get(&RootKey::Count).unwrap_or(0);
+        env.storage().persistent().set(&RootKey::Count, &(id + 1));
+        env.storage().persistent().extend_ttl(&RootKey::Count, PERSISTENT_TTL, PERSISTENT_TTL);
+        let anchored = AnchoredRoot { id, issuer, root, leaf_count, anchored_at: env.ledger().timestamp() };
+        let key = RootKey::Root(id);
+        env.storage().persistent().set(&key, &anchored);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish((symbol_short!("root"), symbol_short!("anchored"), id), anchored);
+        id
+    }
+
+    pub fn get_anchored_root(env: Env, root_id: u64) -> Option<AnchoredRoot> {
+        env.storage().persistent().get(&RootKey::Root(root_id))
+    }
+
+    /// `true` when `leaf_hash` and `proof` hash up to anchored root
+    /// `root_id`. Proofs longer than a tree of the root's `leaf_count` can
+    /// be deep are rejected, as are unknown root IDs. Shorter proofs are
+    /// allowed since unbalanced trees have leaves at several depths; the
+    /// leaf/node hash prefixes in `merkle` keep an internal node from
+    /// passing as a leaf.
+    pub fn verify_inclusion(env: Env, root_id: u64, leaf_hash: BytesN<32>, proof: Vec<BytesN<32>>) -> bool {
+        let Some(anchored) = Self::get_anchored_root(env.clone(), root_id) else {
+            return false;
+        };
+        let max_depth = u32::BITS - (anchored.leaf_count - 1).leading_zeros();
+        proof.len() <= max_depth && crate::merkle::verify_proof(&env, &anchored.root, &leaf_hash, &proof)
+    }
+
+    /// Set the maximum age in seconds after which an attestation is reported
+    /// as expired by `get_attestation_v2` (admin only). `0` disables expiry.
+    pub fn set_attestation_max_age(env: Env, max_age_seconds: u64) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&key_attestation_max_age(&env), &max_age_seconds);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    }
+
+    /// Treat every attestation with a timestamp before `cutoff` as invalid
+    /// (admin only). Evaluated lazily at verify time; `0` clears the cutoff.
+    pub fn set_global_invalidation_epoch(env: Env, cutoff: u64) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&key_invalidation_epoch(&env), &cutoff);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        env.events().publish((symbol_short!("invalid"), symbol_short!("global")), cutoff);
+    }
+
+    /// Treat attestations by `issuer` with a timestamp before `cutoff` as
+    /// invalid (admin only). `0` clears the cutoff.
+    pub fn set_issuer_invalidation_epoch(env: Env, issuer: Address, cutoff: u64) {
+        Self::require_admin(&env);
+        let key = StorageKey::IssuerInvalidationEpoch(issuer.clone());
+        env.storage().persistent().set(&key, &cutoff);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish((symbol_short!("invalid"), symbol_short!("issuer"), issuer), cutoff);
+    }
+
+    pub fn get_global_invalidation_epoch(env: Env) -> u64 {
+        env.storage().instance().get(&key_invalidation_epoch(&env)).unwrap_or(0u64)
+    }
+
+    pub fn get_issuer_invalidation_epoch(env: Env, issuer: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::IssuerInvalidationEpoch(issuer))
+            .unwrap_or(0u64)
+    }
+
+    pub fn get_attestation_max_age(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&key_attestation_max_age(&env))
+            .unwrap_or(0u64)
+    }
+
+    // -----------------------------------------------------------------------
+    // Attestation pinning and erasure
+    // -----------------------------------------------------------------------
+
+    /// Require reliers to lock `deposit` per pin, refunded when the pin is
+    /// dropped (admin only); `None` makes pinning free. Pins already held
+    /// keep the deposit they were taken with.
+    pub fn set_pin_deposit(env: Env, deposit: Option<PinDeposit>) {
+        Self::require_admin(&env);
+        match &deposit {
+            Some(deposit) if deposit.amount <= 0 => panic_with_error!(&env, ErrorCode::ValidationError),
+            Some(deposit) => env.storage().instance().set(&key_pin_deposit(&env), deposit),
+            None => env.storage().instance().remove(&key_pin_deposit(&env)),
+        }
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        env.events().publish((symbol_short!("pin"), symbol_short!("deposit")), deposit);
+    }
+
+    pub fn get_pin_deposit(env: Env) -> Option<PinDeposit> {
+        env.storage().instance().get(&key_pin_deposit(&env))
+    }
+
+    /// Record that `relier` depends on valid attestation `id`, locking the
+    /// configured pin deposit from it. While the attestation is valid and
+    /// any pin exists it cannot be erased. An attestation holds at most
+    /// `MAX_RELIERS` pins (`PinLimitReached`). Revocation is unaffected; it
+    /// queues a notice reliers collect with `take_relier_notices`.
+    pub fn pin_attestation(env: Env, relier: Address, id: u64) {
+        Self::require_writable(&env);
+        relier.require_auth();
+        let attestation = Self::load_attestation(&env, id)
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+        if !Self::evaluate_validity(&env, &attestation).is_valid {
+            panic_with_error!(&env, ErrorCodeExt::AttestationNotValid);
+        }
+        let mut reliers = Self::get_reliers(env.clone(), id);
+        if reliers.contains(&relier) {
+            return;
+        }
+        if reliers.len() >= MAX_RELIERS {
+            panic_with_error!(&env, ErrorCodeExt::PinLimitReached);
+        }
+        if let Some(deposit) = Self::get_pin_deposit(env.clone()) {
+            token::Client::new(&env, &deposit.token).transfer(&relier, &env.current_contract_address(), &deposit.amount);
+            let key = PinKey::Deposit(id, relier.clone());
+            env.storage().persistent().set(&key, &deposit);
+            env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        }
+        reliers.push_back(relier.clone());
+        Self::save_reliers(&env, id, &reliers);
+        env.events().publish((symbol_short!("attest"), symbol_short!("pinned"), id), relier);
+    }
+
+    /// Drop `relier`'s pin on attestation `id` and refund its deposit.
+    pub fn unpin_attestation(env: Env, relier: Address, id: u64) {
+        Self::require_writable(&env);
+        relier.require_auth();
+        let mut reliers = Self::get_reliers(env.clone(), id);
+        if let Some(idx) = reliers.first_index_of(&relier) {
+            reliers.remove(idx);
+            Self::save_reliers(&env, id, &reliers);
+            Self::refund_pin_deposit(&env, id, &relier);
+            env.events().publish((symbol_short!("attest"), symbol_short!("unpinned"), id), relier);
+        }
+    }
+
+    /// IDs of pinned attestations revoked since `relier` last called
+    /// `take_relier_notices`, oldest first. At most `INBOX_SIZE` are kept.
+    pub fn get_relier_notices(env: Env, relier: Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&PinKey::Notices(relier))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Return and clear `relier`'s pending revocation notices.
+    pub fn take_relier_notices(env: Env, relier: Address) -> Vec<u64> {
+        Self::require_writable(&env);
+        relier.require_auth();
+        let notices = Self::get_relier_notices(env.clone(), relier.clone());
+        env.storage().persistent().remove(&PinKey::Notices(relier));
+        notices
+    }
+
+    /// Contracts currently pinning attestation `id`.
+    pub fn get_reliers(env: Env, id: u64) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::AttestationReliers(id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Replace the payload of attestation `id` with `new_payload_hash`,
+    /// signed by its issuer like a fresh submission, and bump its `version`.
+    ///
+    /// The record as it stood before is kept under its old version, and the
+    /// new hash is claimed for replay protection like any other payload; the
+    /// old one stays used. Amended claims are always stored as a digest.
+    /// Each version advances the hash chain, and the attestation's chain
+    /// link then points at the latest one. Only attestations whose current
+    /// status is `Active` can be amended. Returns the new version.
+    pub fn amend_attestation(
+        env: Env,
+        issuer: Address,
+        id: u64,
+        new_payload_hash: Bytes,
+        new_signature: Bytes,
+    ) -> u32 {
+        issuer.require_auth();
+        Self::require_writable(&env);
+        Self::require_subsystem_active(&env, SUBSYSTEM_ISSUANCE);
+        let key = StorageKey::Attest(id);
+        let mut attestation: Attestation = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+        if attestation.issuer != issuer {
+            panic_with_error!(&env, ErrorCode::UnauthorizedAttestor);
+        }
+        match Self::current_status(&env, &attestation) {
+            Status::Active => {}
+            Status::Disputed => panic_with_error!(&env, ErrorCodeExt::AttestationDisputed),
+            Status::Revoked => panic_with_error!(&env, ErrorCode::AttestationRevoked),
+            Status::Expired | Status::Superseded => panic_with_error!(&env, ErrorCodeExt::InvalidStatusTransition),
+        }
+        Self::check_issuer(&env, &issuer);
+        if let Err(e) = validation::check_submission(&env, &new_payload_hash, &new_signature) {
+            panic_with_error!(&env, e);
+        }
+        Self::verify_attestation_signature(&env, &issuer, &new_payload_hash, &new_signature);
+        let used_key = Self::replay_key(&env, &issuer, &attestation.subject, &new_payload_hash);
+        if env.storage().persistent().has(&used_key) {
+            Self::reject_replay(&env, &issuer, &attestation.subject, &new_payload_hash);
+        }
+
+        let ttl = Self::attestation_ttl(&env, attestation.schema_id);
+        let version_key = AmendmentKey::Version(id, attestation.version);
+        env.storage().persistent().set(&version_key, &attestation);
+        env.storage().persistent().extend_ttl(&version_key, ttl, ttl);
+
+        let old_hash = attestation.payload_hash.clone();
+        attestation.data = AttestationData::HashOnly;
+        attestation.payload_hash = new_payload_hash.clone();
+        attestation.signature = new_signature;
+        attestation.version += 1;
+        env.storage().persistent().set(&key, &attestation);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+        env.storage().persistent().set(&used_key, &id);
+        env.storage().persistent().extend_ttl(&used_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        let total: u64 = env.storage().instance().get(&symbol_short!("TOTALCNT")).unwrap_or(0);
+        let amend_key = symbol_short!("AMENDCNT");
+        let amended: u64 = env.storage().instance().get(&amend_key).unwrap_or(0) + 1;
+        env.storage().instance().set(&amend_key, &amended);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        Self::advance_chain(&env, id, total + amended, &new_payload_hash);
+
+        env.events().publish(
+            (symbol_short!("attest"), symbol_short!("amended"), id),
+            AttestationAmended { version: attestation.version, old_hash, new_hash: new_payload_hash },
+        );
+        attestation.version
+    }
+
+    /// Point attestation `id` at new off-chain metadata, or clear it with
+    /// `None` (issuer only, while it may still issue). `uri` is at most
+    /// `MAX_URI_LEN` bytes; revoked and expired attestations cannot be
+    /// changed. Publishes `UriUpdated`.
+    pub fn set_attestation_uri(env: Env, id: u64, uri: Option<Bytes>) {
+        Self::require_writable(&env);
+        Self::require_subsystem_active(&env, SUBSYSTEM_ISSUANCE);
+        let key = StorageKey::Attest(id);
+        let mut attestation: Attestation = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+        attestation.issuer.require_auth();
+        Self::check_issuer(&env, &attestation.issuer);
+        match Self::current_status(&env, &attestation) {
+            Status::Revoked => panic_with_error!(&env, ErrorCode::AttestationRevoked),
+            Status::Expired => panic_with_error!(&env, ErrorCodeExt::AttestationExpired),
+            Status::Active | Status::Disputed | Status::Superseded => {}
+        }
+        if let Some(uri) = &uri {
+            if let Err(e) = validation::check_uri(&env, uri) {
+                panic_with_error!(&env, e);
+            }
+        }
+        let old_uri = attestation.uri.clone();
+        attestation.uri = uri.clone();
+        env.storage().persistent().set(&key, &attestation);
+        let ttl = Self::attestation_ttl(&env, attestation.schema_id);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+        env.events().publish(
+            (symbol_short!("attest"), symbol_short!("uri"), id),
+            UriUpdated { old_uri, new_uri: uri },
+        );
+    }
+
+    /// Push the expiry of attestation `id` back to `expires_at` (its issuer
+    /// or the issuer's renewal keeper). The attestation must be valid and
+    /// already expire, and `expires_at` must be later than its current
+    /// expiry (`InvalidTimestamp`). The payload and signature are unchanged.
+    pub fn renew_attestation(env: Env, caller: Address, id: u64, expires_at: u64) {
+        Self::require_writable(&env);
+        Self::require_subsystem_active(&env, SUBSYSTEM_ISSUANCE);
+        caller.require_auth();
+        let mut attestation = Self::load_attestation(&env, id)
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+        let issuer = attestation.issuer.clone();
+        if caller != issuer && Self::get_renewal_keeper(env.clone(), issuer.clone()) != Some(caller) {
+            panic_with_error!(&env, ErrorCode::UnauthorizedAttestor);
+        }
+        Self::check_issuer(&env, &issuer);
+        if !Self::evaluate_validity(&env, &attestation).is_valid {
+            panic_with_error!(&env, ErrorCodeExt::AttestationNotValid);
+        }
+        if attestation.expires_at == 0 || expires_at <= attestation.expires_at {
+            panic_with_error!(&env, ErrorCode::InvalidTimestamp);
+        }
+        let previous = attestation.expires_at;
+        attestation.expires_at = expires_at;
+        let key = StorageKey::Attest(id);
+        env.storage().persistent().set(&key, &attestation);
+        let ttl = Self::attestation_ttl(&env, attestation.schema_id);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+        env.events().publish((symbol_short!("attest"), symbol_short!("renewed"), id), (previous, expires_at));
+    }
+
+    /// Attestation `id` as it stood at `version`; the current record when
+    /// `version` is its latest. `None` when the attestation does not exist,
+    /// or for a version that never existed. Content of hidden records is
+    /// withheld as in `get_attestation`.
+    pub fn get_attestation_version(env: Env, id: u64, version: u32) -> Option<Attestation> {
+        let current = Self::get_attestation(env.clone(), id)?;
+        if version == current.version {
+            return Some(current);
+        }
+        let past = env.storage().persistent().get(&AmendmentKey::Version(id, version))?;
+        Some(Self::withhold_hidden(&env, past))
+    }
+
+    /// Revoke attestation `id`. `caller` must be its issuer or the admin.
+    ///
+    /// The record is kept with `revoked_at` set and reports `Revoked` status
+    /// from then on. Revoking twice fails with `AttestationRevoked`. When the
+    /// admin revokes another issuer's attestation and an appeal window is
+    /// set, the issuer may contest it with `appeal_revocation` until the
+    /// window closes.
+    pub fn revoke_attestation(env: Env, caller: Address, id: u64) {
+        Self::require_writable(&env);
+        Self::require_subsystem_active(&env, SUBSYSTEM_REVOCATION);
+        caller.require_auth();
+        let attestation: Attestation = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::Attest(id))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+        let admin: Option<Address> = env.storage().instance().get(&key_admin(&env));
+        if caller != attestation.issuer && Some(caller.clone()) != admin {
+            panic_with_error!(&env, ErrorCode::UnauthorizedAttestor);
+        }
+        let overridden = caller != attestation.issuer;
+        Self::mark_revoked(&env, id, attestation, caller, false);
+        let window = Self::get_appeal_window(env.clone());
+        if overridden && window > 0 {
+            let key = DisputeKey::AppealDeadline(id);
+            env.storage().persistent().set(&key, &env.ledger().timestamp().saturating_add(window));
+            env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        }
+    }
+
+    /// Revoke attestation `id` on the issuer's behalf, for issuers whose keys
+    /// never touch a Stellar transaction. Any `relayer` may submit unless the
+    /// relayer allowlist is enforced.
+    ///
+    /// `signature` must be the issuer's signature over
+    /// `revocation_message_public(id, nonce)`, the revocation hash behind the
+    /// `anchorkit-revoke-v1` prefix, verified like an attestation signature. `nonce` must equal
+    /// `get_revocation_nonce(issuer)`; each accepted revocation advances it,
+    /// so a signed message can be used at most once.
+    pub fn revoke_attestation_signed(env: Env, relayer: Address, id: u64, nonce: u64, signature: Bytes) {
+        Self::require_writable(&env);
+        Self::require_subsystem_active(&env, SUBSYSTEM_REVOCATION);
+        relayer.require_auth();
+        Self::check_relayer(&env, &relayer);
+        let attestation: Attestation = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::Attest(id))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+        let issuer = attestation.issuer.clone();
+        let nonce_key = AttestorKey::RevocationNonce(issuer.clone());
+        if nonce != Self::get_revocation_nonce(env.clone(), issuer.clone()) {
+            panic_with_error!(&env, ErrorCode::ReplayAttack);
+        }
+        let hash = compute_revocation_hash(&env, &env.current_contract_address(), id, nonce);
+        let message = domain_message(&env, REVOCATION_DOMAIN, &hash);
+        Self::verify_attestation_signature(&env, &issuer, &message, &signature);
+        env.storage().persistent().set(&nonce_key, &(nonce + 1));
+        env.storage().persistent().extend_ttl(&nonce_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        Self::mark_revoked(&env, id, attestation, issuer, false);
+    }
+
+    /// Revoke attestation `id` for cause, such as fraud (issuer or admin).
+    ///
+    /// Revokes like `revoke_attestation`. With `taint_lineage`, the record
+    /// and the older attestations it renews or amends (its `ref_id` chain,
+    /// at most `MAX_REF_DEPTH` records) are flagged as tainted, each with an
+    /// `attest/tainted` event. Newer records built on any of them report the
+    /// taint through `is_lineage_tainted`.
+    pub fn revoke_attestation_for_cause(env: Env, caller: Address, id: u64, taint_lineage: bool) {
+        Self::revoke_attestation(env.clone(), caller, id);
+        if !taint_lineage {
+            return;
+        }
+        let mut next = Some(id);
+        let mut depth = 0u32;
+        while let Some(current) = next {
+            if depth >= MAX_REF_DEPTH {
+                break;
+            }
+            let Some(attestation) = Self::load_attestation(&env, current) else {
+                break;
+            };
+            let key = LineageKey::Tainted(current);
+            if !env.storage().persistent().has(&key) {
+                env.storage().persistent().set(&key, &id);
+                env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+                env.events().publish(
+                    (symbol_short!("attest"), symbol_short!("tainted"), current),
+                    LineageTainted { id: current, source: id },
+                );
+            }
+            next = attestation.ref_id;
+            depth += 1;
+        }
+    }
+
+    /// `true` when attestation `id` or any record in its `ref_id` chain (at
+    /// most `MAX_REF_DEPTH` records) was tainted by a revocation for cause.
+    pub fn is_lineage_tainted(env: Env, id: u64) -> bool {
+        let mut next = Some(id);
+        let mut depth = 0u32;
+        while let Some(current) = next {
+            if depth >= MAX_REF_DEPTH {
+                break;
+            }
+            if env.storage().persistent().has(&LineageKey::Tainted(current)) {
+                return true;
+            }
+            next = Self::load_attestation(&env, current).and_then(|a| a.ref_id);
+            depth += 1;
+        }
+        false
+    }
+
+    /// Nonce `issuer`'s next signed revocation must carry.
+    pub fn get_revocation_nonce(env: Env, issuer: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&AttestorKey::RevocationNonce(issuer))
+            .unwrap_or(0)
+    }
+
+    /// Hash behind a `revoke_attestation_signed` authorization for `id`.
+    pub fn compute_revocation_hash_public(env: Env, id: u64, nonce: u64) -> BytesN<32> {
+        compute_revocation_hash(&env, &env.current_contract_address(), id, nonce)
+    }
+
+    /// Bytes an issuer signs to revoke attestation `id` via
+    /// `revoke_attestation_signed`.
+    pub fn revocation_message_public(env: Env, id: u64, nonce: u64) -> Bytes {
+        let hash = compute_revocation_hash(&env, &env.current_contract_address(), id, nonce);
+        domain_message(&env, REVOCATION_DOMAIN, &hash)
+    }
+
+    /// Message an issuer signs for an attestation whose `ref_id` is set.
+    pub fn compute_ref_binding_hash_public(env: Env, payload_hash: Bytes, ref_id: u64) -> BytesN<32> {
+        compute_ref_binding_hash(&env, &payload_hash, ref_id)
+    }
+
+    /// Move `attestation` to `Revoked` and notify its issuer's activity, its
+    /// subject and its reliers. A challenge invalidation (`by_challenge`)
+    /// leaves `revoked_at` unset, which is how validity tells it apart.
+    fn mark_revoked(env: &Env, id: u64, mut attestation: Attestation, revoked_by: Address, by_challenge: bool) {
+        if attestation.status == Status::Revoked {
+            panic_with_error!(env, ErrorCode::AttestationRevoked);
+        }
+        let now = env.ledger().timestamp();
+        if !by_challenge {
+            attestation.revoked_at = Some(now);
+        }
+        Self::transition_status(env, &mut attestation, Status::Revoked);
+        Self::record_activity(env, &attestation.issuer, false);
+        Self::sync_subject_value(env, &attestation, None);
+        // No pre-0.1.0 layout exists for this event, so `legacy-events`
+        // leaves it alone.
+        env.events().publish(
+            (symbol_short!("attest"), symbol_short!("revoked"), attestation.issuer.clone(), attestation.subject.clone()),
+            AttestationRevoked { version: EVENT_VERSION, id, revoked_by, revoked_at: now },
+        );
+        Self::emit_subject_topic(env, symbol_short!("revoked"), attestation.subject, id);
+        for relier in Self::get_reliers(env.clone(), id).iter() {
+            Self::push_relier_notice(env, &relier, id);
+        }
+    }
+
+    /// Permanently delete attestation `id` from storage (admin only).
+    ///
+    /// Fails with `AttestationPinned` while the attestation is valid and any
+    /// relier holds a pin. Once it is no longer valid (e.g. revoked), pins
+    /// are dropped with their deposits refunded. Fails with
+    /// `DisputePending` while a challenge against it is open or escalated,
+    /// or its issuer may still appeal its revocation, since settling those
+    /// needs the record. The payload hash stays marked as used, so erasure
+    /// does not re-open replay.
+    pub fn erase_attestation(env: Env, id: u64) {
+        Self::require_admin(&env);
+        let key = StorageKey::Attest(id);
+        let attestation = Self::load_attestation(&env, id)
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+        let disputed = Self::get_attestation_challenge(env.clone(), id)
+            .is_some_and(|c| matches!(c.status, ChallengeStatus::Open | ChallengeStatus::Escalated));
+        if disputed || Self::get_appeal_deadline(env.clone(), id).is_some() {
+            panic_with_error!(&env, ErrorCodeExt::DisputePending);
+        }
+        let reliers = Self::get_reliers(env.clone(), id);
+        if !reliers.is_empty() {
+            if Self::evaluate_validity(&env, &attestation).is_valid {
+                panic_with_error!(&env, ErrorCode::AttestationPinned);
+            }
+            for relier in reliers.iter() {
+                Self::refund_pin_deposit(&env, id, &relier);
+            }
+            Self::save_reliers(&env, id, &Vec::new(&env));
+        }
+        Self::sync_subject_value(&env, &attestation, None);
+        env.storage().persistent().remove(&key);
+        env.events().publish((symbol_short!("attest"), symbol_short!("erased"), id), ());
+        Self::emit_subject_topic(&env, symbol_short!("erased"), attestation.subject, id);
+    }
+
+    pub fn list_attestations(env: Env, subject: Address, offset: u64, limit: u32) -> Vec<Attestation> {
+        let max_page_size = Self::get_max_page_size(env.clone());
+        let actual_limit = if limit > max_page_size { max_page_size } else { limit };
+        let mut results = Vec::new(&env);
+
+        let count_key = StorageKey::SubjectCount(subject.clone());
+        let total_count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
+
+        if offset >= total_count || actual_limit == 0 {
+            return results;
+        }
+
+        let end = if offset + (actual_limit as u64) > total_count {
+            total_count
+        } else {
+            offset + (actual_limit as u64)
+        };
+
+        for i in offset..end {
+            let index_key = StorageKey::SubjectAttestation(subject.clone(), i);
+            if let Some(attestation_id) = env.storage().persistent().get::<_, u64>(&index_key) {
+                let main_key = StorageKey::Attest(attestation_id);
+                if let Some(mut attestation) = env.storage().persistent().get::<_, Attestation>(&main_key) {
+                    // Embargoed attestations stay hidden until activation.
+                    if env.ledger().timestamp() < attestation.not_before
+                        || Self::is_hidden(env.clone(), attestation_id)
+                    {
+                        continue;
+                    }
+                    if env.storage().persistent().has(&StorageKey::AttestorRevoked(attestation.issuer.clone())) {
+                        attestation.issuer_revoked = true;
+                    }
+                    results.push_back(attestation);
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Attestations with IDs in `[start_id, start_id + limit)`, in ID order,
+    /// for clients syncing the whole store. `limit` is capped at
+    /// `MAX_SYNC_PAGE` (or the max page size, if smaller); erased, archived,
+    /// embargoed and hidden IDs are skipped, so a page may hold fewer than
+    /// `limit` records. Each record carries its current status, as
+    /// `get_attestation_v2` would report it. Resume from `start_id + limit`.
+    pub fn list_attestations_by_id(env: Env, start_id: u64, limit: u32) -> Vec<Attestation> {
+        let limit = limit.min(Self::get_max_page_size(env.clone())).min(MAX_SYNC_PAGE);
+        let end = start_id.saturating_add(limit as u64);
+        let now = env.ledger().timestamp();
+        let mut results = Vec::new(&env);
+        for id in start_id..end {
+            let Some(mut attestation) = Self::load_attestation(&env, id) else {
+                continue;
+            };
+            if now < attestation.not_before || Self::is_hidden(env.clone(), id) {
+                continue;
+            }
+            attestation.status = Self::current_status(&env, &attestation);
+            results.push_back(attestation);
+        }
+        results
+    }
+
+    /// Number of attestations ever recorded; same as `get_attestation_count`.
+    pub fn total_attestations(env: Env) -> u64 {
+        Self::get_attestation_count(env)
+    }
+
+    /// Attestations recorded from the start of `timestamp`'s UTC day until
+    /// now, read from day buckets (so the whole first day counts). The
+    /// range may span at most `MAX_ACTIVITY_DAYS`; older starts fail with
+    /// `ValidationError`.
+    pub fn attestations_since(env: Env, timestamp: u64) -> u64 {
+        let day_from = timestamp / SECS_PER_DAY;
+        let today = env.ledger().timestamp() / SECS_PER_DAY;
+        if day_from > today {
+            return 0;
+        }
+        if today - day_from >= MAX_ACTIVITY_DAYS {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        let mut count = 0;
+        for day in day_from..=today {
+            count += env
+                .storage()
+                .persistent()
+                .get::<_, u64>(&StatsKey::DailyAttestations(day))
+                .unwrap_or(0);
+        }
+        count
+    }
+
+    /// Number of attestations issued to `subject`; same as
+    /// `get_subject_attestation_count`.
+    pub fn count_by_subject(env: Env, subject: Address) -> u64 {
+        Self::get_subject_attestation_count(env, subject)
+    }
+
+    /// Number of currently registered (not revoked) attestors.
+    pub fn attestor_count(env: Env) -> u64 {
+        env.storage().instance().get(&key_attestor_count(&env)).unwrap_or(0)
+    }
+
+    /// Number of attestations `issuer` has recorded, including since-revoked
+    /// and erased ones.
+    pub fn count_by_issuer(env: Env, issuer: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&AttestorKey::AttestationCount(issuer))
+            .unwrap_or(0)
+    }
+
+    /// Page through the IDs of attestations recorded by `issuer`, oldest first.
+    ///
+    /// Issuer-side mirror of `get_attestations_by_subject`: erased entries
+    /// are skipped without shifting later pages, revoked ones are included.
+    pub fn get_attestations_by_issuer(env: Env, issuer: Address, offset: u64, limit: u32) -> Vec<u64> {
+        let max_page_size = Self::get_max_page_size(env.clone());
+        let actual_limit = if limit > max_page_size { max_page_size } else { limit };
+        let mut ids = Vec::new(&env);
+
+        let total_count = Self::count_by_issuer(env.clone(), issuer.clone());
+        if offset >= total_count || actual_limit == 0 {
+            return ids;
+        }
+        let end = total_count.min(offset + actual_limit as u64);
+
+        for i in offset..end {
+            let index_key = AttestorKey::Attestation(issuer.clone(), i);
+            let Some(id) = env.storage().persistent().get::<_, u64>(&index_key) else {
+                continue;
+            };
+            if env.storage().persistent().has(&StorageKey::Attest(id)) {
+                ids.push_back(id);
+            }
+        }
+        ids
+    }
+
+    /// The last `INBOX_SIZE` attestations issued to `subject`, oldest first.
+    ///
+    /// Lets wallets show what arrived since a previously seen ID without
+    /// scanning events. Erased and still-embargoed attestations are left out.
+    pub fn get_inbox(env: Env, subject: Address) -> Vec<u64> {
+        let inbox: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::SubjectInbox(subject))
+            .unwrap_or_else(|| Vec::new(&env));
+        let now = env.ledger().timestamp();
+        let mut visible = Vec::new(&env);
+        for id in inbox.iter() {
+            let attestation: Option<Attestation> = env.storage().persistent().get(&StorageKey::Attest(id));
+            if attestation.is_some_and(|a| now >= a.not_before) {
+                visible.push_back(id);
+            }
+        }
+        visible
+    }
+
+    /// Number of entries in `subject`'s attestation index, including erased
+    /// and embargoed ones.
+    pub fn get_subject_attestation_count(env: Env, subject: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::SubjectCount(subject))
+            .unwrap_or(0)
+    }
+
+    /// Page through the IDs of attestations issued to `subject`, oldest first.
+    ///
+    /// Lighter than `list_attestations` when callers only need IDs. As there,
+    /// `offset` indexes the subject's index, so erased or embargoed entries
+    /// are skipped without shifting later pages. Revoked attestations are
+    /// included; use `is_valid` to filter them.
+    pub fn get_attestations_by_subject(env: Env, subject: Address, offset: u64, limit: u32) -> Vec<u64> {
+        let max_page_size = Self::get_max_page_size(env.clone());
+        let actual_limit = if limit > max_page_size { max_page_size } else { limit };
+        let mut ids = Vec::new(&env);
+
+        let total_count = Self::get_subject_attestation_count(env.clone(), subject.clone());
+        if offset >= total_count || actual_limit == 0 {
+            return ids;
+        }
+        let end = total_count.min(offset + actual_limit as u64);
+
+        for i in offset..end {
+            let index_key = StorageKey::SubjectAttestation(subject.clone(), i);
+            let Some(id) = env.storage().persistent().get::<_, u64>(&index_key) else {
+                continue;
+            };
+            let Some(attestation) = env.storage().persistent().get::<_, Attestation>(&StorageKey::Attest(id)) else {
+                continue;
+            };
+            if env.ledger().timestamp() < attestation.not_before {
+                continue;
+            }
+            ids.push_back(id);
+        }
+        ids
+    }
+
+    /// Number of asset-level attestations ever recorded about `asset`.
+    pub fn get_asset_attestation_count(env: Env, asset: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&AssetKey::AttestationCount(asset))
+            .unwrap_or(0)
+    }
+
+    /// Page through asset-level attestations about `asset` (a token contract
+    /// address), oldest first. Erased, embargoed and hidden entries are
+    /// skipped without shifting later pages.
+    pub fn list_asset_attestations(env: Env, asset: Address, offset: u64, limit: u32) -> Vec<Attestation> {
+        let max_page_size = Self::get_max_page_size(env.clone());
+        let actual_limit = if limit > max_page_size { max_page_size } else { limit };
+        let mut results = Vec::new(&env);
+
+        let total_count = Self::get_asset_attestation_count(env.clone(), asset.clone());
+        if offset >= total_count || actual_limit == 0 {
+            return results;
+        }
+        let end = total_count.min(offset + actual_limit as u64);
+
+        for i in offset..end {
+            let index_key = AssetKey::Attestation(asset.clone(), i);
+            let Some(id) = env.storage().persistent().get::<_, u64>(&index_key) else {
+                continue;
+            };
+            let Some(mut attestation) = env.storage().persistent().get::<_, Attestation>(&StorageKey::Attest(id)) else {
+                continue;
+            };
+            if env.ledger().timestamp() < attestation.not_before || Self::is_hidden(env.clone(), id) {
+                continue;
+            }
+            if env.storage().persistent().has(&StorageKey::AttestorRevoked(attestation.issuer.clone())) {
+                attestation.issuer_revoked = true;
+            }
+            results.push_back(attestation);
+        }
+
+        results
+    }
+
+    /// Page through the assets that have at least one asset-level
+    /// attestation, in order of their first attestation.
+    pub fn list_attested_assets(env: Env, offset: u64, limit: u32) -> Vec<Address> {
+        let max_page_size = Self::get_max_page_size(env.clone());
+        let actual_limit = if limit > max_page_size { max_page_size } else { limit };
+        let mut assets = Vec::new(&env);
+        let total: u64 = env.storage().persistent().get(&AssetKey::AssetCount).unwrap_or(0);
+        for i in offset..total.min(offset.saturating_add(actual_limit as u64)) {
+            if let Some(asset) = env.storage().persistent().get::<_, Address>(&AssetKey::Asset(i)) {
+                assets.push_back(asset);
+            }
+        }
+        assets
+    }
+
+    /// Number of attestations ever tagged with `jurisdiction`.
+    pub fn get_jurisdiction_count(env: Env, jurisdiction: Symbol) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::JurisdictionCount(jurisdiction))
+            .unwrap_or(0)
+    }
+
+    /// Page through attestations tagged with `jurisdiction`, oldest first.
+    ///
+    /// `offset` indexes the jurisdiction's index, so erased, embargoed or
+    /// hidden entries are skipped without shifting later pages.
+    pub fn list_by_jurisdiction(
+        env: Env,
+        jurisdiction: Symbol,
+        offset: u64,
+        limit: u32,
+    ) -> Vec<Attestation> {
+        let max_page_size = Self::get_max_page_size(env.clone());
+        let actual_limit = if limit > max_page_size { max_page_size } else { limit };
+        let mut results = Vec::new(&env);
+
+        let total_count = Self::get_jurisdiction_count(env.clone(), jurisdiction.clone());
+        if offset >= total_count || actual_limit == 0 {
+            return results;
+        }
+        let end = total_count.min(offset + actual_limit as u64);
+
+        for i in offset..end {
+            let index_key = StorageKey::JurisdictionAttestation(jurisdiction.clone(), i);
+            let Some(id) = env.storage().persistent().get::<_, u64>(&index_key) else {
+                continue;
+            };
+            let Some(mut attestation) = env.storage().persistent().get::<_, Attestation>(&StorageKey::Attest(id)) else {
+                continue;
+            };
+            if env.ledger().timestamp() < attestation.not_before || Self::is_hidden(env.clone(), id) {
+                continue;
+            }
+            if env.storage().persistent().has(&StorageKey::AttestorRevoked(attestation.issuer.clone())) {
+                attestation.issuer_revoked = true;
+            }
+            results.push_back(attestation);
+        }
+
+        results
+    }
+
+    // -----------------------------------------------------------------------
+    // Deterministic hash utilities
+    // -----------------------------------------------------------------------
+
+    pub fn compute_payload_hash(env: Env, subject: Address, timestamp: u64, data: Bytes) -> BytesN<32> {
+        compute_payload_hash(&env, &subject, timestamp, &data)
+    }
+
+    /// Compute the canonical payload hash via the contract method.
+    ///
+    /// Off-chain callers should prefer this method over calling
+    /// `deterministic_hash::compute_payload_hash` directly. Going through the
+    /// Soroban host environment ensures that `Address` XDR serialisation uses
+    /// the same host-side encoding as on-chain attestation submission, avoiding
+    /// divergence that can occur when the module function is called outside the
+    /// host context.
+    pub fn compute_payload_hash_public(env: Env, subject: Address, timestamp: u64, data: Bytes) -> BytesN<32> {
+        compute_payload_hash(&env, &subject, timestamp, &data)
+    }
+
+    pub fn verify_payload_hash(env: Env, attestation_id: u64, expected_hash: BytesN<32>) -> bool {
+        let attestation = env
+            .storage()
+            .persistent()
+            .get::<_, Attestation>(&StorageKey::Attest(attestation_id))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+
+        let stored: BytesN<32> = attestation.payload_hash.try_into().unwrap_or_else(|_| {
+            panic_with_error!(&env, ErrorCode::StorageCorrupted)
+        });
+        verify_payload_hash(&stored, &expected_hash)
+    }
+
+    // -----------------------------------------------------------------------
+    // Session management
+    // -----------------------------------------------------------------------
+
+    pub fn create_session(env: Env, initiator: Address) -> u64 {
+        Self::require_writable(&env);
+        initiator.require_auth();
+        let inst = env.storage().instance();
+        let scnt_key = key_session_counter(&env);
+        let session_id: u64 = inst.get(&scnt_key).unwrap_or(0u64);
+        inst.set(&scnt_key, &(session_id + 1));
+        inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+
+        let now = env.ledger().timestamp();
+        let nonce: u64 = env.prng().gen_range(u64::MIN..=u64::MAX);
+        let session = Session {
+            session_id,
+            initiator: initiator.clone(),
+            created_at: now,
+            nonce,
+            operation_count: 0,
+            expires_at: now + SESSION_TTL,
+        };
+        let sess_key = StorageKey::Session(session_id);
+        env.storage().persistent().set(&sess_key, &session);
+        env.storage().persistent().extend_ttl(&sess_key, SESSION_LEDGER_TTL, SESSION_LEDGER_TTL);
+
+        env.events().publish(
+            (symbol_short!("session"), symbol_short!("created"), session_id),
+            SessionCreatedEvent { session_id, initiator, timestamp: now },
+        );
+
+        session_id
+    }
+
+    // get_session, get_audit_log, get_session_operation_count defined later in the session-aware section.
+
+    // -----------------------------------------------------------------------
+    // Quote management
+    // -----------------------------------------------------------------------
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn submit_quote(
+        env: Env,
+        anchor: Address,
+        base_asset: String,
+        quote_asset: String,
+        rate: u64,
+        fee_percentage: u32,
+        minimum_amount: u64,
+        maximum_amount: u64,
+        valid_until: u64,
+    ) -> u64 {
+        anchor.require_auth();
+        Self::require_subsystem_active(&env, SUBSYSTEM_FEEDS);
+        Self::check_attestor(&env, &anchor);
+
+        // Validate quote parameters
+        if rate == 0 {
+            panic_with_error!(&env, ErrorCode::InvalidQuote);
+        }
+        if minimum_amount > maximum_amount {
+            panic_with_error!(&env, ErrorCode::InvalidQuote);
+        }
+        let now = env.ledger().timestamp();
+        if valid_until <= now {
+            panic_with_error!(&env, ErrorCode::InvalidQuote);
+        }
+
+        let inst = env.storage().instance();
+        let qcnt_key = key_quote_counter(&env);
+        let next: u64 = inst.get(&qcnt_key).unwrap_or(0u64) + 1;
+        inst.set(&qcnt_key, &next);
+        inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+
+        let quote = Quote {
+            quote_id: next,
+            anchor: anchor.clone(),
+            base_asset: base_asset.clone(),
+            quote_asset: quote_asset.clone(),
+            rate,
+            fee_percentage,
+            minimum_amount,
+            maximum_amount,
+            valid_until,
+        };
+        let q_key = StorageKey::Quote(anchor.clone(), next);
+        env.storage().persistent().set(&q_key, &quote);
+        env.storage().persistent().extend_ttl(&q_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        let lq_key = StorageKey::LatestQuote(anchor.clone());
+        env.storage().persistent().set(&lq_key, &next);
+        env.storage().persistent().extend_ttl(&lq_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        env.events().publish(
+            (symbol_short!("quote"), symbol_short!("submit"), next),
+            QuoteSubmitEvent { quote_id: next, anchor, base_asset, quote_asset, rate, valid_until },
+        );
+
+        next
+    }
+
+    pub fn receive_quote(env: Env, receiver: Address, anchor: Address, quote_id: u64) -> Quote {
+        Self::require_writable(&env);
+        receiver.require_auth();
+        let q_key = StorageKey::Quote(anchor.clone(), quote_id);
+        let quote: Quote = env.storage().persistent().get(&q_key)
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound));
+
+        env.events().publish(
+            (symbol_short!("quote"), symbol_short!("received"), quote_id),
+            QuoteReceivedEvent { quote_id, receiver, timestamp: env.ledger().timestamp() },
+        );
+
+        quote
+    }
+
+    // -----------------------------------------------------------------------
+    // Audit log pruning helper
+    // -----------------------------------------------------------------------
+
+    /// Prune oldest audit log entries if the log has exceeded `max_audit_log_size`.
+    ///
+    /// Uses a monotonically increasing `log_id` counter (total entries ever written)
+    /// and a separate `offset` (first live entry). The live window is
+    /// `[offset, log_id)`. When `log_id - offset > max_size`, we advance `offset`
+    /// and delete the stale entries, then emit `AuditLogPruned`.
+    fn maybe_prune_audit_log(env: &Env, log_id: u64) {
+        let inst = env.storage().instance();
+        let max_size: u64 = inst
+            .get(&StorageKey::AuditLogMaxSize)
+            .unwrap_or(u64::MAX);
+        let offset: u64 = inst.get(&key_audit_log_offset(env)).unwrap_or(0u64);
+        let live_count = log_id.saturating_sub(offset); // entries [offset, log_id)
+        if live_count < max_size {
+            return;
+        }
+        // Number of entries to remove so live_count == max_size - 1 (leaving room for the new one)
+        let to_prune = live_count - max_size + 1;
+        for i in 0..to_prune {
+            let old_key = StorageKey::AuditLog(offset + i);
+            env.storage().persistent().remove(&old_key);
+        }
+        let new_offset = offset + to_prune;
+        inst.set(&key_audit_log_offset(env), &new_offset);
+        env.events().publish(
+            (symbol_short!("audit"), symbol_short!("pruned")),
+            AuditLogPruned { pruned_count: to_prune, new_offset },
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Session-aware attestation
+    // -----------------------------------------------------------------------
+
+    pub fn submit_attestation_with_session(
+        env: Env,
+        session_id: u64,
+        issuer: Address,
+        subject: Address,
+        timestamp: u64,
+        payload_hash: Bytes,
+        signature: Bytes,
+    ) -> u64 {
+        Self::check_session_expiry(&env, session_id);
+        let session = Self::get_session(env.clone(), session_id);
+        if session.initiator != issuer {
+            panic_with_error!(&env, ErrorCode::UnauthorizedAttestor);
+        }
+        issuer.require_auth();
+        let input = AttestationInput::new(subject, timestamp, payload_hash, signature);
+        let id = Self::record_submission(&env, issuer.clone(), &input, false).id;
+
+        // Get and increment session operation count
+        let sopcnt_key = StorageKey::SessionOpCount(session_id);
+        let op_index: u64 = env.storage().persistent().get(&sopcnt_key).unwrap_or(0u64);
+        env.storage().persistent().set(&sopcnt_key, &(op_index + 1));
+        env.storage().persistent().extend_ttl(&sopcnt_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        let inst = env.storage().instance();
+        let acnt_key = key_audit_counter(&env);
+        let log_id: u64 = inst.get(&acnt_key).unwrap_or(0u64);
+        inst.set(&acnt_key, &(log_id + 1));
+        inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        Self::maybe_prune_audit_log(&env, log_id);
+
+        let now = env.ledger().timestamp();
+        let audit = AuditLog {
+            log_id,
+            session_id,
+            actor: issuer.clone(),
+            operation: OperationContext {
+                session_id,
+                operation_index: op_index,
+                operation_type: String::from_str(&env, "attest"),
+                timestamp: now,
+                status: String::from_str(&env, "success"),
+                result_summary: String::from_str(&env, &alloc::format!("attestation_id={}", id)),
+            },
+        };
+        let audit_key = StorageKey::AuditLog(log_id);
+        env.storage().persistent().set(&audit_key, &audit);
+        env.storage().persistent().extend_ttl(&audit_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        env.events().publish(
+            (symbol_short!("audit"), symbol_short!("logged"), log_id),
+            AuditLogEvent {
+                log_id,
+                session_id,
+                operation_index: op_index,
+                operation_type: String::from_str(&env, "attest"),
+                status: String::from_str(&env, "success"),
+            },
+        );
+
+        id
+    }
+
+    pub fn register_attestor_with_session(env: Env, session_id: u64, attestor: Address, sep10_token: String, sep10_issuer: Address) {
+        Self::check_session_expiry(&env, session_id);
+        Self::require_admin(&env);
+        Self::verify_sep10_token_matches_attestor(&env, &sep10_token, &sep10_issuer, &attestor);
+        let key = StorageKey::Attestor(attestor.clone());
+        if env.storage().persistent().has(&key) {
+            panic_with_error!(&env, ErrorCode::AttestorAlreadyRegistered);
+        }
+        Self::check_not_deactivated(&env, &attestor);
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        Self::bump_instance_counter(&env, &key_attestor_count(&env), true);
+        Self::index_attestor(&env, &attestor);
+
+        let sopcnt_key = StorageKey::SessionOpCount(session_id);
+        let op_index: u64 = env.storage().persistent().get(&sopcnt_key).unwrap_or(0u64);
+        env.storage().persistent().set(&sopcnt_key, &(op_index + 1));
+        env.storage().persistent().extend_ttl(&sopcnt_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        let inst = env.storage().instance();
+        let acnt_key = key_audit_counter(&env);
+        let log_id: u64 = inst.get(&acnt_key).unwrap_or(0u64);
+        inst.set(&acnt_key, &(log_id + 1));
+        inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        Self::maybe_prune_audit_log(&env, log_id);
+
+        let admin: Address = inst
+            .get::<_, Address>(&key_admin(&env))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::NotInitialized));
+        let now = env.ledger().timestamp();
+        let audit = AuditLog {
+            log_id,
+            session_id,
+            actor: admin,
+            operation: OperationContext {
+                session_id,
+                operation_index: op_index,
+                operation_type: String::from_str(&env, "register"),
+                timestamp: now,
+                status: String::from_str(&env, "success"),
+                result_summary: String::from_str(&env, "attestor_registered"),
+            },
+        };
+        let audit_key = StorageKey::AuditLog(log_id);
+        env.storage().persistent().set(&audit_key, &audit);
+        env.storage().persistent().extend_ttl(&audit_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        env.events().publish((symbol_short!("attestor"), symbol_short!("added"), attestor), ());
+        env.events().publish(
+            (symbol_short!("audit"), symbol_short!("logged"), log_id),
+            AuditLogEvent {
+                log_id,
+                session_id,
+                operation_index: op_index,
+                operation_type: String::from_str(&env, "register"),
+                status: String::from_str(&env, "success"),
+            },
+        );
+    }
+
+    pub fn revoke_attestor_with_session(env: Env, session_id: u64, attestor: Address) {
+        Self::check_session_expiry(&env, session_id);
+        Self::require_admin(&env);
+        let key = StorageKey::Attestor(attestor.clone());
+        if !env.storage().persistent().has(&key) {
+            panic_with_error!(&env, ErrorCode::AttestorNotRegistered);
+        }
+        env.storage().persistent().remove(&key);
+        // Mark the attestor as revoked so historical attestations surface issuer_revoked=true.
+        let revoked_key = StorageKey::AttestorRevoked(attestor.clone());
+        env.storage().persistent().set(&revoked_key, &true);
+        env.storage().persistent().extend_ttl(&revoked_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        Self::bump_instance_counter(&env, &key_attestor_count(&env), false);
+        Self::bump_instance_counter(&env, &key_revoked_attestor_count(&env), true);
+
+        let sopcnt_key = StorageKey::SessionOpCount(session_id);
+        let op_index: u64 = env.storage().persistent().get(&sopcnt_key).unwrap_or(0u64);
+        env.storage().persistent().set(&sopcnt_key, &(op_index + 1));
+        env.storage().persistent().extend_ttl(&sopcnt_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        let inst = env.storage().instance();
+        let acnt_key = key_audit_counter(&env);
+        let log_id: u64 = inst.get(&acnt_key).unwrap_or(0u64);
+        inst.set(&acnt_key, &(log_id + 1));
+        inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        Self::maybe_prune_audit_log(&env, log_id);
+
+        let admin: Address = inst
+            .get::<_, Address>(&key_admin(&env))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::NotInitialized));
+        let now = env.ledger().timestamp();
+        let audit = AuditLog {
+            log_id,
+            session_id,
+            actor: admin,
+            operation: OperationContext {
+                session_id,
+                operation_index: op_index,
+                operation_type: String::from_str(&env, "revoke"),
+                timestamp: now,
+                status: String::from_str(&env, "success"),
+                result_summary: String::from_str(&env, "attestor_revoked"),
+            },
+        };
+        let audit_key = StorageKey::AuditLog(log_id);
+        env.storage().persistent().set(&audit_key, &audit);
+        env.storage().persistent().extend_ttl(&audit_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        env.events().publish((symbol_short!("attestor"), symbol_short!("removed"), attestor), ());
+        env.events().publish(
+            (symbol_short!("audit"), symbol_short!("logged"), log_id),
+            AuditLogEvent {
+                log_id,
+                session_id,
+                operation_index: op_index,
+                operation_type: String::from_str(&env, "revoke"),
+                status: String::from_str(&env, "success"),
+            },
+        );
+    }
+
+    pub fn get_session(env: Env, session_id: u64) -> Session {
+        env.storage()
+            .persistent()
+            .get::<_, Session>(&StorageKey::Session(session_id))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound))
+    }
+
+    pub fn get_audit_log(env: Env, log_id: u64) -> AuditLog {
+        env.storage()
+            .persistent()
+            .get::<_, AuditLog>(&StorageKey::AuditLog(log_id))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::AttestationNotFound))
+    }
+
+    /// Return audit log entries in [from_id, to_id], capped at 100 entries.
+    /// IDs that have no stored entry are silently skipped.
+    pub fn get_audit_log_range(env: Env, from_id: u64, to_id: u64) -> Vec<AuditLog> {
+        let mut result = Vec::new(&env);
+        if from_id > to_id {
+            return result;
+        }
+        let cap: u64 = 100;
+        let end = if to_id - from_id + 1 > cap { from_id + cap - 1 } else { to_id };
+        let mut id = from_id;
+        while id <= end {
+            if let Some(log) = env.storage().persistent().get::<_, AuditLog>(&StorageKey::AuditLog(id)) {
+                result.push_back(log);
+            }
+            id += 1;
+        }
+        result
+    }
+
+    pub fn get_session_operation_count(env: Env, session_id: u64) -> Option<u64> {
+        let sess_key = StorageKey::Session(session_id);
+        if !env.storage().persistent().has(&sess_key) {
+            return None;
+        }
+        Self::check_session_expiry(&env, session_id);
+        Some(
+            env.storage()
+                .persistent()
+                .get::<_, u64>(&StorageKey::SessionOpCount(session_id))
+                .unwrap_or(0),
+        )
+    }
+
+    /// Returns the current audit log pruning offset — the ID of the first live
+    /// audit log entry.  Any log ID below this value has been pruned and is no
+    /// longer available in storage.
+    ///
+    /// Callers can use this to detect gaps in a session's audit trail:
+    /// if `log_id < get_audit_log_offset()` the entry was intentionally pruned,
+    /// not lost.  Returns `0` when no pruning has occurred yet.
+    pub fn get_audit_log_offset(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&key_audit_log_offset(&env))
+            .unwrap_or(0u64)
+    }
+
+    // -----------------------------------------------------------------------
+    // Metadata cache
+    // -----------------------------------------------------------------------
+
+    pub fn cache_metadata(env: Env, anchor: Address, metadata: AnchorMetadata, ttl_seconds: u64) {
+        Self::require_admin(&env);
+        // Issue #259: skip write if metadata is unchanged.
+        // ttl_seconds=0 entries live in persistent storage (never network-evicted);
+        // all other entries live in temporary storage.
+        if metadata.reputation_score > 10000 || metadata.uptime_percentage > 10000 {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        let key = StorageKey::MetadataCache(anchor.clone());
+        let existing: Option<MetadataCache> = env.storage().persistent().get(&key)
+            .or_else(|| env.storage().temporary().get(&key));
+        if let Some(existing) = existing {
+            let m = &existing.metadata;
+            if m.anchor == metadata.anchor
+                && m.reputation_score == metadata.reputation_score
+                && m.liquidity_score == metadata.liquidity_score
+                && m.uptime_percentage == metadata.uptime_percentage
+                && m.total_volume == metadata.total_volume
+                && m.average_settlement_time == metadata.average_settlement_time
+                && m.is_active == metadata.is_active
+            {
+                return;
+            }
+        }
+        let now = env.ledger().timestamp();
+        let entry = MetadataCache { metadata, cached_at: now, ttl_seconds };
+        if ttl_seconds == 0 {
+            // Persistent storage is not subject to network-level eviction within
+            // its TTL window, so ttl_seconds=0 ("never expire at app level") is
+            // backed by storage that won't disappear under the contract's feet.
+            env.storage().persistent().set(&key, &entry);
+            env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        } else {
+            let ledger_ttl = if ttl_seconds as u32 > MIN_TEMP_TTL { ttl_seconds as u32 } else { MIN_TEMP_TTL };
+            env.storage().temporary().set(&key, &entry);
+            env.storage().temporary().extend_ttl(&key, ledger_ttl, ledger_ttl);
+        }
+
+        Self::add_to_cached_anchors(&env, &anchor);
+    }
+
+    pub fn get_cached_metadata(env: Env, anchor: Address) -> AnchorMetadata {
+        let key = StorageKey::MetadataCache(anchor);
+        // ttl_seconds=0 entries are stored persistently; all others are temporary.
+        let entry: MetadataCache = env.storage().persistent().get(&key)
+            .or_else(|| env.storage().temporary().get(&key))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::CacheNotFound));
+        let now = env.ledger().timestamp();
+        // ttl_seconds = 0 means "never expire at app level" — skip the expiry check
+        if entry.ttl_seconds != 0 && entry.cached_at + entry.ttl_seconds <= now {
+            panic_with_error!(&env, ErrorCode::CacheExpired);
+        }
+        entry.metadata
+    }
+
+    /// Issue #260: returns seconds elapsed since the metadata cache entry was written,
+    /// or `Err(CacheNotFound)` if no cache entry exists for the anchor.
+    /// `Ok(0)` means the entry was just cached at the current ledger timestamp.
+    pub fn get_cache_age_seconds(env: Env, anchor: Address) -> Result<u64, ErrorCode> {
+        let key = StorageKey::MetadataCache(anchor);
+        let entry: MetadataCache = env.storage().persistent().get(&key)
+            .or_else(|| env.storage().temporary().get(&key))?;
+        let now = env.ledger().timestamp();
+        Ok(now.saturating_sub(entry.cached_at))
+    }
+
+    // #272: return the cached data so callers avoid a second storage read.
+    pub fn refresh_metadata_cache(env: Env, anchor: Address) -> AnchorMetadata {
+        Self::require_admin(&env);
+        let key = StorageKey::MetadataCache(anchor.clone());
+        let entry: MetadataCache = env.storage().persistent().get(&key)
+            .or_else(|| env.storage().temporary().get(&key))
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::CacheNotFound));
+        let metadata = entry.metadata.clone();
+        // Remove from whichever storage tier holds the entry.
+        env.storage().persistent().remove(&key);
+        env.storage().temporary().remove(&key);
+
+        // Issue #276: remove from CACHED_ANCHORS set
+        let list_key = key_anchor_list(&env);
+        if let Some(list) = env.storage().persistent().get::<_, Vec<Address>>(&list_key) {
+            let mut new_list = Vec::new(&env);
+            for a in list.iter() {
+                if a != anchor {
+                    new_list.push_back(a);
+                }
+            }
+            env.storage().persistent().set(&list_key, &new_list);
+            env.storage().persistent().extend_ttl(&list_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        }
+        metadata
+    }
+    /// Computes a health score (0-100) for an anchor based on cached metadata.
+    ///
+    /// # Formula
+    ///
+    /// The health score is a weighted combination of three metrics:
+    /// - **Uptime (40%)**: `uptime_percentage / 100` (0-10000 scale → 0-100)
+    /// - **Reputation (35%)**: `reputation_score / 100` (0-10000 scale → 0-100)
+    /// - **Settlement Speed (25%)**: Inverse of `average_settlement_time`, normalized
+    ///
+    /// Settlement speed scoring:
+    /// - 0-300s: 100 points (excellent)
+    /// - 301-600s: 80 points (good)
+    /// - 601-1800s: 60 points (acceptable)
+    /// - 1801-3600s: 40 points (slow)
+    /// - >3600s: 20 points (very slow)
+    ///
+    /// Final score = (uptime_weight × uptime_score) + (reputation_weight × reputation_score) + (speed_weight × speed_score)
+    ///
+    /// # Errors
+    ///
+    /// - `CacheNotFound` (49): No metadata cached for this anchor
+    /// - `CacheExpired` (48): Metadata cache has expired
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let score = contract.get_anchor_health_score(&env, &anchor_addr);
+    /// // score is 0-100, where 100 is perfect health
+    /// ```
+    pub fn get_anchor_health_score(env: Env, anchor: Address) -> u32 {
+        // Retrieve cached metadata (will panic with CacheNotFound or CacheExpired if unavailable)
+        let metadata = Self::get_cached_metadata(env.clone(), anchor);
+
+        // Weight constants (must sum to 100)
+        const UPTIME_WEIGHT: u64 = 40;
+        const REPUTATION_WEIGHT: u64 = 35;
+        const SPEED_WEIGHT: u64 = 25;
+        const SCALE_FACTOR: u64 = 100; // Fixed-point scaling to preserve precision
+
+        // 1. Uptime score: scale from 0-10000 to 0-100 using fixed-point arithmetic
+        // Multiply by SCALE_FACTOR before dividing to preserve decimal precision
+        let uptime_score = (metadata.uptime_percentage as u64 * SCALE_FACTOR) / 100;
+
+        // 2. Reputation score: scale from 0-10000 to 0-100 using fixed-point arithmetic
+        let reputation_score = (metadata.reputation_score as u64 * SCALE_FACTOR) / 100;
+
+        // 3. Settlement speed score: tiered scoring based on settlement time
+        let speed_score = if metadata.average_settlement_time <= 300 {
+            100 * SCALE_FACTOR // Excellent: ≤5 minutes
+        } else if metadata.average_settlement_time <= 600 {
+            80 * SCALE_FACTOR // Good: 5-10 minutes
+        } else if metadata.average_settlement_time <= 1800 {
+            60 * SCALE_FACTOR // Acceptable: 10-30 minutes
+        } else if metadata.average_settlement_time <= 3600 {
+            40 * SCALE_FACTOR // Slow: 30-60 minutes
+        } else {
+            20 * SCALE_FACTOR // Very slow: >1 hour
+        };
+
+        // Calculate weighted health score using fixed-point arithmetic
+        // All intermediate values are scaled by SCALE_FACTOR, so divide once at the end
+        let weighted_sum = UPTIME_WEIGHT * uptime_score
+            + REPUTATION_WEIGHT * reputation_score
+            + SPEED_WEIGHT * speed_score;
+        
+        // Divide by (100 * SCALE_FACTOR) to get final score: 100 for weights, SCALE_FACTOR for precision
+        let health_score = weighted_sum / (100 * SCALE_FACTOR);
+
+        // Ensure score is capped at 100
+        let final_score = if health_score > 100 { 100 } else { health_score as u32 };
+
+        // Issue #464: enforce configurable minimum acceptable health score.
+        // When key_health_threshold is set (> 0), reject anchors whose computed
+        // score falls below it so callers cannot route to unhealthy anchors.
+        let threshold: u32 = env
+            .storage()
+            .instance()
+            .get(&key_health_threshold(&env))
+            .unwrap_or(0u32);
+        if threshold > 0 && final_score < threshold {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+
+        final_score
+    }
+
+
+    /// Issue #276: list all anchors that currently have active metadata cache entries.
+    ///
+    /// Filters out any anchor whose temporary cache keys have all been evicted, and
+    /// writes the pruned list back to persistent storage so CANCHORS does not grow
+    /// unboundedly after natural TTL eviction. The write-back is skipped once the
+    /// contract is read-only.
+    pub fn list_cached_anchors(env: Env) -> Vec<Address> {
+        let list_key = soroban_sdk::vec![&env, symbol_short!("CANCHORS")];
+        let list: Vec<Address> = env.storage().persistent()
+            .get::<_, Vec<Address>>(&list_key)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let mut active = Vec::new(&env);
+        let mut pruned = false;
+        for anchor in list.iter() {
+            let meta_key = StorageKey::MetadataCache(anchor.clone());
+            let caps_key = StorageKey::CapabilitiesCache(anchor.clone());
+            let toml_key = StorageKey::TomlCache(anchor.clone());
+            if env.storage().temporary().has(&meta_key)
+                || env.storage().temporary().has(&caps_key)
+                || env.storage().temporary().has(&toml_key)
+            {
+                active.push_back(anchor);
+            } else {
+                // All temp entries for this anchor have been evicted; mark for pruning.
+                pruned = true;
+            }
+        }
+
+        // Write back the pruned list so stale entries don't accumulate in persistent storage.
+        if pruned && Self::is_writable(&env) {
+            env.storage().persistent().set(&list_key, &active);
+            env.storage().persistent().extend_ttl(&list_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        }
+
+        active
+    }
+
+    // -----------------------------------------------------------------------
+    // Capabilities cache
+    // -----------------------------------------------------------------------
+
+    pub fn cache_capabilities(env: Env, anchor: Address, toml_url: String, capabilities: Vec<u32>, ttl_seconds: u64) {
+        Self::require_admin(&env);
+
+        // Issue #280: Validate toml_url before caching
+        let len = toml_url.len() as usize;
+        let mut buf = [0u8; 256];
+        if len > 256 {
+            panic_with_error!(&env, ErrorCode::InvalidEndpointFormat);
+        }
+        toml_url.copy_into_slice(&mut buf[..len]);
+        let url_str = core::str::from_utf8(&buf[..len]).unwrap_or("");
+        if crate::domain_validator::validate_anchor_domain(url_str).is_err() {
+            panic_with_error!(&env, ErrorCode::InvalidEndpointFormat);
+        }
+
+        let now = env.ledger().timestamp();
+        let entry = CapabilitiesCache { toml_url, capabilities, cached_at: now, ttl_seconds };
+        let key = StorageKey::CapabilitiesCache(anchor.clone());
+        let ledger_ttl = if ttl_seconds as u32 > MIN_TEMP_TTL { ttl_seconds as u32 } else { MIN_TEMP_TTL };
+        env.storage().temporary().set(&key, &entry);
+        env.storage().temporary().extend_ttl(&key, ledger_ttl, ledger_ttl);
+
+        Self::add_to_cached_anchors(&env, &anchor);
+    }
+
+    pub fn get_cached_capabilities(env: Env, anchor: Address) -> CapabilitiesCache {
+        let key = StorageKey::CapabilitiesCache(anchor);
+        let entry: CapabilitiesCache = env.storage().temporary().get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::CacheNotFound));
+        let now = env.ledger().timestamp();
+        // ttl_seconds = 0 means "never expire" — skip the expiry check to prevent refresh loops
+        if entry.ttl_seconds != 0 && entry.cached_at + entry.ttl_seconds <= now {
+            panic_with_error!(&env, ErrorCode::CacheExpired);
+        }
+        entry
+    }
+
+    pub fn refresh_capabilities_cache(env: Env, anchor: Address) {
+        Self::require_admin(&env);
+        let key = StorageKey::CapabilitiesCache(anchor.clone());
+        env.storage().temporary().remove(&key);
+
+        // If no other cache entries remain for this anchor, remove it from CANCHORS.
+        let meta_key = StorageKey::MetadataCache(anchor.clone());
+        let toml_key = StorageKey::TomlCache(anchor.clone());
+        if !env.storage().temporary().has(&meta_key) && !env.storage().temporary().has(&toml_key) {
+            let list_key = soroban_sdk::vec![&env, symbol_short!("CANCHORS")];
+            if let Some(list) = env.storage().persistent().get::<_, Vec<Address>>(&list_key) {
+                let mut new_list = Vec::new(&env);
+                for a in list.iter() {
+                    if a != anchor {
+                        new_list.push_back(a);
+                    }
+                }
+                env.storage().persistent().set(&list_key, &new_list);
+                env.storage().persistent().extend_ttl(&list_key, PERSISTENT_TTL, PERSISTENT_TTL);
+            }
+        }
+    }
+
+    /// Issue #258/#463: admin-only emergency flush of all MetadataCache,
+    /// CapabilitiesCache, and TomlCache entries for every tracked anchor.
+    /// Emits a `CacheInvalidated` event with the count of cleared entries.
+    pub fn invalidate_all_caches(env: Env) {
+        Self::require_admin(&env);
+        let list_key = key_anchor_list(&env);
+        let anchors: Vec<Address> = env.storage().persistent()
+            .get::<_, Vec<Address>>(&list_key)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let mut count: u32 = 0;
+        for anchor in anchors.iter() {
+            let meta_key = StorageKey::MetadataCache(anchor.clone());
+            if env.storage().temporary().has(&meta_key) {
+                env.storage().temporary().remove(&meta_key);
+                count += 1;
+            }
+            let caps_key = StorageKey::CapabilitiesCache(anchor.clone());
+            if env.storage().temporary().has(&caps_key) {
+                env.storage().temporary().remove(&caps_key);
+                count += 1;
+            }
+            // Issue #463: also flush cached stellar.toml entries
+            let toml_key = StorageKey::TomlCache(anchor.clone());
+            if env.storage().temporary().has(&toml_key) {
+                env.storage().temporary().remove(&toml_key);
+                count += 1;
+            }
+        }
+
+        // Clear the anchor list
+        let empty: Vec<Address> = Vec::new(&env);
+        env.storage().persistent().set(&list_key, &empty);
+        env.storage().persistent().extend_ttl(&list_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        env.events().publish(
+            (symbol_short!("cache"), symbol_short!("invall")),
+            count,
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Health monitoring
+    // -----------------------------------------------------------------------
+
+    pub fn set_health_failure_threshold(env: Env, threshold: u32) {
+        Self::require_admin(&env);
+        env.storage().instance().set(&key_health_threshold(&env), &threshold);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    }
+
+    pub fn update_health_status(
+        env: Env,
+        anchor: Address,
+        latency_ms: u64,
+        failure_count: u32,
+        availability_percent: u32,
+    ) {
+        Self::require_admin(&env);
+        let status = HealthStatus {
+            anchor: anchor.clone(),
+            latency_ms,
+            failure_count,
+            availability_percent,
+        };
+        let key = StorageKey::Health(anchor.clone());
+        env.storage().persistent().set(&key, &status);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        let threshold: u32 = env
+            .storage()
+            .instance()
+            .get(&key_health_threshold(&env))
+            .unwrap_or(0u32);
+
+        if threshold > 0 && failure_count >= threshold {
+            let meta_key = StorageKey::AnchorMeta(anchor.clone());
+            if let Some(mut meta) = env
+                .storage()
+                .persistent()
+                .get::<_, AnchorMetadata>(&meta_key)
+            {
+                if meta.is_active {
+                    meta.is_active = false;
+                    env.storage().persistent().set(&meta_key, &meta);
+                    env.storage().persistent().extend_ttl(&meta_key, PERSISTENT_TTL, PERSISTENT_TTL);
+                    env.events().publish(
+                        (symbol_short!("anchor"), symbol_short!("deactiv")),
+                        AnchorDeactivated { anchor, failure_count, threshold },
+                    );
+                }
+            }
+        }
+    }
+
+    pub fn get_health_status(env: Env, anchor: Address) -> Option<HealthStatus> {
+        env.storage()
+            .persistent()
+            .get::<_, HealthStatus>(&StorageKey::Health(anchor))
+    }
+
+    // -----------------------------------------------------------------------
+    // Routing
+    // -----------------------------------------------------------------------
+
+    pub fn get_quote(env: Env, anchor: Address, quote_id: u64) -> Option<Quote> {
+        env.storage().persistent().get::<_, Quote>(&StorageKey::Quote(anchor, quote_id))
+    }
+
+    pub fn set_anchor_metadata(
+        env: Env,
+        anchor: Address,
+        reputation_score: u32,
+        average_settlement_time: u64,
+        liquidity_score: u32,
+        uptime_percentage: u32,
+        total_volume: u64,
+    ) {
+        Self::require_admin(&env);
+        let meta = AnchorMetadata {
+            anchor: anchor.clone(),
+            reputation_score,
+            average_settlement_time,
+            liquidity_score,
+            uptime_percentage,
+            total_volume,
+            is_active: true,
+        };
+        let meta_key = StorageKey::AnchorMeta(anchor.clone());
+        env.storage().persistent().set(&meta_key, &meta);
+        env.storage().persistent().extend_ttl(&meta_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        // Maintain ANCHLIST
+        let list_key = key_anchor_list(&env);
+        let mut list: Vec<Address> = env.storage().persistent()
+            .get::<_, Vec<Address>>(&list_key)
+            .unwrap_or_else(|| Vec::new(&env));
+        if !list.contains(&anchor) {
+            list.push_back(anchor);
+            env.storage().persistent().set(&list_key, &list);
+            env.storage().persistent().extend_ttl(&list_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        }
+    }
+
+    pub fn get_routing_anchors(env: Env) -> Vec<Address> {
+        let list_key = key_anchor_list(&env);
+        env.storage().persistent()
+            .get::<_, Vec<Address>>(&list_key)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Select the best anchor for a transaction and return its `Quote`.
+    ///
+    /// Candidates are filtered to those that are active, meet `min_reputation`,
+    /// have a non-expired quote, and whose quote range covers `request.amount`.
+    ///
+    /// The winner is then chosen by `options.strategy[0]`:
+    ///
+    /// - `"LowestFee"` — lowest `fee_percentage`
+    /// - `"FastestSettlement"` — lowest `average_settlement_time`
+    /// - `"HighestReputation"` — highest `reputation_score`
+    ///
+    /// An empty `strategy` vec panics with `NoQuotesAvailable`.
+    /// An unrecognised symbol returns the first candidate in iteration order.
+    pub fn route_transaction(env: Env, options: RoutingOptions) -> Quote {
+        let now = env.ledger().timestamp();
+        let list_key = key_anchor_list(&env);
+        let anchors: Vec<Address> = env.storage().persistent()
+            .get::<_, Vec<Address>>(&list_key)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let mut candidates: Vec<Quote> = Vec::new(&env);
+        for anchor in anchors.iter() {
+            // Check reputation filter
+            let meta_key = StorageKey::AnchorMeta(anchor.clone());
+            let meta: AnchorMetadata = match env.storage().persistent().get(&meta_key) {
+                Some(m) => m,
+                None => continue,
+            };
+            if !meta.is_active { continue; }
+            if meta.reputation_score < options.min_reputation { continue; }
+
+            // Check KYC requirement filter
+            if options.require_kyc {
+                let services_key = StorageKey::Services(anchor.clone());
+                let services_record: AnchorServices = match env.storage().persistent().get(&services_key) {
+                    Some(sr) => sr,
+                    None => continue,
+                };
+                if !services_record.services.contains(SERVICE_KYC) {
+                    continue;
+                }
+            }
+
+            // Get latest quote for this anchor
+            let lq_key = StorageKey::LatestQuote(anchor.clone());
+            let quote_id: u64 = match env.storage().persistent().get(&lq_key) {
+                Some(id) => id,
+                None => continue,
+            };
+            let q_key = StorageKey::Quote(anchor.clone(), quote_id);
+            let quote: Quote = match env.storage().persistent().get(&q_key) {
+                Some(q) => q,
+                None => continue,
+            };
+
+            if quote.valid_until <= now {
+                if !Self::is_writable(&env) {
+                    continue;
+                }
+                env.events().publish(
+                    (symbol_short!("quote"),),
+                    crate::events::QuoteExpiredEvent {
+                        anchor: anchor.clone(),
+                        quote_id,
+                        valid_until: quote.valid_until,
+                    },
+                );
+                continue;
+            }
+            if options.request.amount < quote.minimum_amount || options.request.amount > quote.maximum_amount {
+                continue;
+            }
+
+            candidates.push_back(quote);
+
+            // Stop adding candidates if we've reached max_anchors limit
+            if options.max_anchors > 0 && candidates.len() >= options.max_anchors as usize {
+                break;
+            }
+        }
+
+        if candidates.is_empty() {
+            panic_with_error!(&env, ErrorCode::NoQuotesAvailable);
+        }
+
+        let strategy_sym = options.strategy.get(0)
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::NoQuotesAvailable));
+
+        let lowest_fee_sym = Symbol::new(&env, "LowestFee");
+        let fastest_sym = Symbol::new(&env, "FastestSettlement");
+        let reputation_sym = Symbol::new(&env, "HighestReputation");
+        let balanced_sym = Symbol::new(&env, "Balanced");
+
+        // Validate that the strategy symbol is recognized
+        if strategy_sym != lowest_fee_sym 
+            && strategy_sym != fastest_sym 
+            && strategy_sym != reputation_sym 
+            && strategy_sym != balanced_sym {
+            panic_with_error!(&env, ErrorCode::InvalidStrategy);
+        }
+
+        let mut best: Quote = candidates.get(0).unwrap();
+
+        if strategy_sym == lowest_fee_sym {
+            for q in candidates.iter() {
+                if q.fee_percentage < best.fee_percentage {
+                    best = q;
+                }
+            }
+        } else if strategy_sym == fastest_sym {
+            // Need settlement time from metadata
+            let meta_key = StorageKey::AnchorMeta(best.anchor.clone());
+            let mut best_time: u64 = env.storage().persistent()
+                .get::<_, AnchorMetadata>(&meta_key)
+                .map(|m| m.average_settlement_time)
+                .unwrap_or(u64::MAX);
+            for q in candidates.iter() {
+                let mk = StorageKey::AnchorMeta(q.anchor.clone());
+                let t = env.storage().persistent()
+                    .get::<_, AnchorMetadata>(&mk)
+                    .map(|m| m.average_settlement_time)
+                    .unwrap_or(u64::MAX);
+                if t < best_time {
+                    best_time = t;
+                    best = q;
+                }
+            }
+        } else if strategy_sym == reputation_sym {
+            let meta_key = StorageKey::AnchorMeta(best.anchor.clone());
+            let mut best_rep: u32 = env.storage().persistent()
+                .get::<_, AnchorMetadata>(&meta_key)
+                .map(|m| m.reputation_score)
+                .unwrap_or(0);
+            for q in candidates.iter() {
+                let mk = StorageKey::AnchorMeta(q.anchor.clone());
+                let rep = env.storage().persistent()
+                    .get::<_, AnchorMetadata>(&mk)
+                    .map(|m| m.reputation_score)
+                    .unwrap_or(0);
+                if rep > best_rep {
+                    best_rep = rep;
+                    best = q;
+                }
+            }
+        } else if strategy_sym == balanced_sym {
+            // score = (40_000 / fee_percentage) + (30_000 / settlement_time) + (reputation * 3_000 / 10_000)
+            // All terms are dimensionless integers; higher score is better.
+            // fee_percentage = 0 or settlement_time = 0 contribute 0 to avoid division by zero.
+            let balanced_score = |env: &Env, q: &Quote| -> u64 {
+                let mk = (symbol_short!("ANCHMETA"), q.anchor.clone());
+                let meta: AnchorMetadata = env.storage().persistent()
+                    .get(&mk)
+                    .unwrap_or(AnchorMetadata {
+                        anchor: q.anchor.clone(),
+                        reputation_score: 0,
+                        average_settlement_time: 0,
+                        liquidity_score: 0,
+                        uptime_percentage: 0,
+                        total_volume: 0,
+                        is_active: false,
+                    });
+                let fee_term = if q.fee_percentage > 0 { 40_000 / q.fee_percentage as u64 } else { 0 };
+                let time_term = 30_000u64.checked_div(meta.average_settlement_time).unwrap_or(0);
+                // Scale reputation (0–10_000) to a 0–3_000 range to match the weight of other terms.
+                let rep_term = meta.reputation_score as u64 * 3_000 / 10_000;
+                fee_term + time_term + rep_term
+            };
+            let mut best_score = balanced_score(&env, &best);
+            for q in candidates.iter() {
+                let score = balanced_score(&env, &q);
+                if score > best_score {
+                    best_score = score;
+                    best = q;
+                }
+            }
+        }
+
+        let strategy_str = if strategy_sym == lowest_fee_sym {
+            String::from_str(&env, "LowestFee")
+        } else if strategy_sym == fastest_sym {
+            String::from_str(&env, "FastestSettlement")
+        } else if strategy_sym == reputation_sym {
+            String::from_str(&env, "HighestReputation")
+        } else {
+            String::from_str(&env, "Balanced")
+        };
+
+        env.events().publish(
+            (symbol_short!("routing"),),
+            crate::events::RoutingDecisionEvent {
+                anchor: best.anchor.clone(),
+                strategy: strategy_str,
+                quote_id: best.quote_id,
+                ledger_sequence: env.ledger().sequence(),
+            },
+        );
+
+        best
+    }
+
+    // -----------------------------------------------------------------------
+    // Anchor Info Discovery
+    // -----------------------------------------------------------------------
+
+    pub fn fetch_anchor_info(env: Env, anchor: Address, toml_data: StellarToml, network_passphrase: String, ttl_override: Option<u64>) {
+        Self::require_writable(&env);
+        anchor.require_auth();
+
+        // Validate network_passphrase matches a known network before caching.
+        // Prevents caching a TOML from the wrong network, which would cause
+        // SEP-10 verification to succeed against the wrong signing key.
+        let np_len = network_passphrase.len() as usize;
+        if np_len > 256 {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        let mut np_buf = [0u8; 256];
+        network_passphrase.copy_into_slice(&mut np_buf[..np_len]);
+        let np_str = core::str::from_utf8(&np_buf[..np_len]).unwrap_or("");
+
+        const MAINNET: &str = "Public Global Stellar Network ; September 2015";
+        const TESTNET: &str = "Test SDF Network ; September 2015";
+        if np_str != MAINNET && np_str != TESTNET {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+
+        // Validate that the TOML's own network_passphrase matches the supplied one.
+        let toml_np_len = toml_data.network_passphrase.len() as usize;
+        if toml_np_len > 256 {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+        let mut toml_np_buf = [0u8; 256];
+        toml_data.network_passphrase.copy_into_slice(&mut toml_np_buf[..toml_np_len]);
+        let toml_np_str = core::str::from_utf8(&toml_np_buf[..toml_np_len]).unwrap_or("");
+        if toml_np_str != np_str {
+            panic_with_error!(&env, ErrorCode::ValidationError);
+        }
+
+        // Reject non-HTTPS endpoints to prevent MITM exposure of anchor metadata.
+        let ts_len = toml_data.transfer_server.len() as usize;
+        if ts_len > 2048 {
+            panic_with_error!(&env, ErrorCode::InvalidEndpointFormat);
+        }
+        let mut ts_buf = [0u8; 2048];
+        toml_data.transfer_server.copy_into_slice(&mut ts_buf[..ts_len]);
+        let transfer_server_str = core::str::from_utf8(&ts_buf[..ts_len]).unwrap_or("");
+        if crate::validate_anchor_domain(transfer_server_str).is_err() {
+            panic_with_error!(&env, ErrorCode::InvalidEndpointFormat);
+        }
+
+        // Validate decimals for each currency: must be in range 0..=18.
+        for asset in toml_data.currencies.iter() {
+            if asset.decimals > 18 {
+                panic_with_error!(&env, ErrorCode::ValidationError);
+            }
+        }
+
+        let now = env.ledger().timestamp();
+        let ttl_seconds = ttl_override.unwrap_or(3600);
+        let cached = CachedToml {
+            toml: toml_data,
+            cached_at: now,
+            ttl_seconds,
+        };
+        let key = StorageKey::TomlCache(anchor.clone());
+        let ledger_ttl = if ttl_seconds as u32 > MIN_TEMP_TTL { ttl_seconds as u32 } else { MIN_TEMP_TTL };
+        env.storage().temporary().set(&key, &cached);
+        env.storage().temporary().extend_ttl(&key, ledger_ttl, ledger_ttl);
+
+        Self::add_to_cached_anchors(&env, &anchor);
+    }
+
+    pub fn get_anchor_toml(env: Env, anchor: Address) -> StellarToml {
+        let key = StorageKey::TomlCache(anchor);
+        let cached: CachedToml = env.storage().temporary().get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::CacheNotFound));
+        let now = env.ledger().timestamp();
+        if cached.cached_at + cached.ttl_seconds <= now {
+            panic_with_error!(&env, ErrorCode::CacheExpired);
+        }
+        cached.toml
+    }
+
+    pub fn refresh_anchor_info(env: Env, anchor: Address, force: bool) {
+        Self::require_writable(&env);
+        anchor.require_auth();
+        let key = StorageKey::TomlCache(anchor);
+        
+        if force {
+            env.storage().temporary().remove(&key);
+        } else if let Some(cached) = env.storage().temporary().get::<_, CachedToml>(&key) {
+            let now = env.ledger().timestamp();
+            if cached.cached_at + cached.ttl_seconds <= now {
+                env.storage().temporary().remove(&key);
+            }
+        }
+    }
+
+    pub fn get_anchor_assets(env: Env, anchor: Address) -> Result<Vec<String>, ErrorCode> {
+        let key = StorageKey::TomlCache(anchor.clone());
+        if !env.storage().temporary().has(&key) {
+            return Err(ErrorCode::CacheNotFound);
+        }
+        let toml = Self::get_anchor_toml(env.clone(), anchor);
+        let mut assets = Vec::new(&env);
+        for asset in toml.currencies.iter() {
+            assets.push_back(asset.code.clone());
+        }
+        Ok(assets)
+    }
+
+    /// Return the fiat currencies supported by `anchor` from its cached stellar.toml.
+    /// Returns `Err(ErrorCode::CacheNotFound)` when no TOML has been cached for this anchor.
+    pub fn get_anchor_currencies(
+        env: Env,
+        anchor: Address,
+    ) -> Result<Vec<FiatCurrency>, ErrorCode> {
+        let key = StorageKey::TomlCache(anchor.clone());
+        if !env.storage().temporary().has(&key) {
+            return Err(ErrorCode::CacheNotFound);
+        }
+        let toml = Self::get_anchor_toml(env.clone(), anchor);
+        Ok(toml.fiat_currencies)
+    }
+
+    pub fn get_anchor_asset_info(env: Env, anchor: Address, asset_code: String) -> AssetInfo {
+        let toml = Self::get_anchor_toml(env.clone(), anchor);
+        for asset in toml.currencies.iter() {
+            if asset.code == asset_code {
+                return asset;
+            }
+        }
+        panic_with_error!(&env, ErrorCode::ValidationError);
+    }
+
+    pub fn get_anchor_deposit_limits(env: Env, anchor: Address, asset_code: String) -> Result<(u64, u64), ErrorCode> {
+        let key = StorageKey::TomlCache(anchor.clone());
+        if !env.storage().temporary().has(&key) {
+            return Err(ErrorCode::CacheNotFound);
+        }
+        let asset = Self::get_anchor_asset_info(env, anchor, asset_code);
+        Ok((asset.deposit_min_amount, asset.deposit_max_amount))
+    }
+
+    pub fn get_anchor_withdrawal_limits(env: Env, anchor: Address, asset_code: String) -> Result<(u64, u64), ErrorCode> {
+        let key = StorageKey::TomlCache(anchor.clone());
+        if !env.storage().temporary().has(&key) {
+            return Err(ErrorCode::CacheNotFound);
+        }
+        let asset = Self::get_anchor_asset_info(env, anchor, asset_code);
+        Ok((asset.withdrawal_min_amount, asset.withdrawal_max_amount))
+    }
+
+    pub fn get_anchor_deposit_fees(env: Env, anchor: Address, asset_code: String) -> (u64, u32) {
+        let asset = Self::get_anchor_asset_info(env, anchor, asset_code);
+        (asset.deposit_fee_fixed, asset.deposit_fee_percent)
+    }
+
+    pub fn get_anchor_withdrawal_fees(env: Env, anchor: Address, asset_code: String) -> (u64, u32) {
+        let asset = Self::get_anchor_asset_info(env, anchor, asset_code);
+        (asset.withdrawal_fee_fixed, asset.withdrawal_fee_percent)
+    }
+
+    pub fn anchor_supports_deposits(
+        env: Env,
+        anchor: Address,
+        asset_code: String,
+    ) -> bool {
+        Self::get_anchor_asset_info(env, anchor, asset_code).deposit_enabled
+    }
+
+    pub fn anchor_supports_withdrawals(
+        env: Env,
+        anchor: Address,
+        asset_code: String,
+    ) -> bool {
+        Self::get_anchor_asset_info(env, anchor, asset_code).withdrawal_enabled
+    }
+
+    // -----------------------------------------------------------------------
+    // Internal helpers
+    // -----------------------------------------------------------------------
+
+    /// Rewrite storage laid out for version `_from` into the next layout.
+    /// Version 1 is the first versioned layout, so no step exists yet and
+    /// every call fails with `NoMigrationPath`; add a `match` arm per
+    /// version whenever `CONTRACT_VERSION` is bumped.
+    fn migrate_storage(env: &Env, _from: u32) {
+        panic_with_error!(env, ErrorCodeExt::NoMigrationPath);
+    }
+
+    /// Move `attestation` to `to`, persisting it and publishing
+    /// `StatusChanged`. Fails with `InvalidStatusTransition` if the lifecycle
+    /// does not allow the move. Leaving the valid states releases the
+    /// subject's value bucket.
+    fn transition_status(env: &Env, attestation: &mut Attestation, to: Status) {
+        let from = attestation.status;
+        if !from.can_transition_to(to) {
+            panic_with_error!(env, ErrorCodeExt::InvalidStatusTransition);
+        }
+        attestation.status = to;
+        env.storage().persistent().set(&StorageKey::Attest(attestation.id), &*attestation);
+        env.events().publish(
+            (symbol_short!("attest"), symbol_short!("status"), attestation.id),
+            StatusChanged { from, to },
+        );
+        if !matches!(to, Status::Active | Status::Disputed) {
+            Self::sync_subject_value(env, attestation, None);
+        }
+    }
+
+    /// Undo a revocation after a successful appeal. This is the only move out
+    /// of `Revoked`, so it bypasses `Status::can_transition_to`.
+    fn reinstate(env: &Env, attestation: &mut Attestation) {
+        attestation.revoked_at = None;
+        attestation.status = Status::Active;
+        env.storage().persistent().set(&StorageKey::Attest(attestation.id), &*attestation);
+        env.events().publish(
+            (symbol_short!("attest"), symbol_short!("status"), attestation.id),
+            StatusChanged { from: Status::Revoked, to: Status::Active },
+        );
+        Self::sync_subject_value(env, attestation, attestation.value);
+    }
+
+    /// Stored attestation `id` regardless of visibility, with `issuer_revoked`
+    /// reflecting the issuer's current registration.
+    fn load_attestation(env: &Env, id: u64) -> Option<Attestation> {
+        let mut attestation = env.storage()
+            .persistent()
+            .get::<_, Attestation>(&StorageKey::Attest(id))?;
+        // Reflect current revocation status without rewriting every stored attestation.
+        if env.storage().persistent().has(&StorageKey::AttestorRevoked(attestation.issuer.clone())) {
+            attestation.issuer_revoked = true;
+        }
+        Some(attestation)
+    }
+
+    fn save_proposal(env: &Env, proposal: &AttestationProposal) {
+        let key = ProposalKey::Proposal(proposal.id);
+        env.storage().temporary().set(&key, proposal);
+        env.storage().temporary().extend_ttl(&key, PROPOSAL_LEDGER_TTL, PROPOSAL_LEDGER_TTL);
+    }
+
+    /// Take `bond` from `challenger` under the bond configuration and store
+    /// a new open challenge against attestation `id`.
+    fn open_challenge(
+        env: &Env,
+        id: u64,
+        challenger: Address,
+        evidence_hash: BytesN<32>,
+        bond: i128,
+        appeal: bool,
+    ) -> Challenge {
+        let bond_token = match Self::get_challenge_bond(env.clone()) {
+            Some(config) => {
+                if bond < config.min_bond {
+                    panic_with_error!(env, ErrorCode::ValidationError);
+                }
+                if bond > 0 {
+                    token::Client::new(env, &config.token).transfer(&challenger, &env.current_contract_address(), &bond);
+                }
+                (bond > 0).then_some(config.token)
+            }
+            None if bond != 0 => panic_with_error!(env, ErrorCode::ValidationError),
+            None => None,
+        };
+
+        let challenge_id: u64 = env.storage().persistent().get(&DisputeKey::ChallengeCount).unwrap_or(0);
+        env.storage().persistent().set(&DisputeKey::ChallengeCount, &(challenge_id + 1));
+        env.storage().persistent().extend_ttl(&DisputeKey::ChallengeCount, PERSISTENT_TTL, PERSISTENT_TTL);
+        let challenge = Challenge {
+            id: challenge_id,
+            attestation_id: id,
+            challenger,
+            evidence_hash,
+            bond,
+            bond_token,
+            opened_at: env.ledger().timestamp(),
+            status: ChallengeStatus::Open,
+            resolved_at: 0,
+            appeal,
+        };
+        Self::save_challenge(env, &challenge);
+        let latest_key = DisputeKey::Latest(id);
+        env.storage().persistent().set(&latest_key, &challenge_id);
+        env.storage().persistent().extend_ttl(&latest_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        challenge
+    }
+
+    /// Record `court` and its ruling deadline on escalated `challenge` and
+    /// open the case there.
+    fn send_to_court(env: &Env, court: &Address, challenge: &Challenge) {
+        let court_key = DisputeKey::Court(challenge.id);
+        env.storage().persistent().set(&court_key, court);
+        env.storage().persistent().extend_ttl(&court_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        let deadline_key = DisputeKey::RulingDeadline(challenge.id);
+        let deadline = env.ledger().timestamp().saturating_add(Self::get_ruling_window(env.clone()));
+        env.storage().persistent().set(&deadline_key, &deadline);
+        env.storage().persistent().extend_ttl(&deadline_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        ArbitrationCourtClient::new(env, court).open_case(
+            &env.current_contract_address(),
+            &challenge.id,
+            &challenge.evidence_hash,
+        );
+    }
+
+    fn load_challenge(env: &Env, challenge_id: u64) -> Challenge {
+        Self::get_challenge(env.clone(), challenge_id)
+            .unwrap_or_else(|| panic_with_error!(env, ErrorCodeExt::ChallengeNotFound))
+    }
+
+    /// Close `challenge` with `outcome`: settle the attestation's status and
+    /// pay the bond to the prevailing side.
+    fn settle_challenge(env: &Env, mut challenge: Challenge, outcome: ChallengeStatus, ruled_by: Address) {
+        let mut attestation: Attestation = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::Attest(challenge.attestation_id))
+            .unwrap_or_else(|| panic_with_error!(env, ErrorCode::AttestationNotFound));
+        let payee = match (outcome, challenge.appeal) {
+            (ChallengeStatus::Open | ChallengeStatus::Escalated, _) => {
+                panic_with_error!(env, ErrorCode::ValidationError)
+            }
+            (ChallengeStatus::Upheld, true) => {
+                Self::reinstate(env, &mut attestation);
+                challenge.challenger.clone()
+            }
+            (ChallengeStatus::Invalidated, true) => env
+                .storage()
+                .instance()
+                .get(&key_admin(env))
+                .unwrap_or_else(|| panic_with_error!(env, ErrorCode::NotInitialized)),
+            (ChallengeStatus::Invalidated, false) => {
+                if attestation.status != Status::Revoked {
+                    Self::mark_revoked(env, attestation.id, attestation.clone(), ruled_by, true);
+                }
+                Self::forfeit_escrowed_fee(env, attestation.id);
+                challenge.challenger.clone()
+            }
+            (ChallengeStatus::Upheld, false) => {
+                if attestation.status == Status::Disputed {
+                    Self::transition_status(env, &mut attestation, Status::Active);
+                }
+                attestation.issuer.clone()
+            }
+        };
+        if let Some(bond_token) = &challenge.bond_token {
+            token::Client::new(env, bond_token).transfer(&env.current_contract_address(), &payee, &challenge.bond);
+        }
+        challenge.status = outcome;
+        challenge.resolved_at = env.ledger().timestamp();
+        Self::save_challenge(env, &challenge);
+        env.events().publish(
+            (symbol_short!("dispute"), symbol_short!("resolved"), challenge.attestation_id),
+            challenge,
+        );
+    }
+
+    /// Send attestation `id`'s escrowed fee, if any, to its challenger pool.
+    fn forfeit_escrowed_fee(env: &Env, id: u64) {
+        let key = DisputeKey::EscrowedFee(id);
+        let Some(held) = env.storage().persistent().get::<_, EscrowedFee>(&key) else {
+            return;
+        };
+        env.storage().persistent().remove(&key);
+        token::Client::new(env, &held.token).transfer(&env.current_contract_address(), &held.challenger_pool, &held.amount);
+        env.events().publish((symbol_short!("fee"), symbol_short!("forfeited"), id), held);
+    }
+
+    fn save_challenge(env: &Env, challenge: &Challenge) {
+        let key = DisputeKey::Challenge(challenge.id);
+        env.storage().persistent().set(&key, challenge);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+    }
+
+    /// Admin authorization for state-changing calls. Also enforces sunset
+    /// mode, since every caller of this helper writes state.
+    fn require_admin(env: &Env) {
+        Self::require_writable(env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&key_admin(env))
+            .unwrap_or_else(|| panic_with_error!(env, ErrorCode::NotInitialized));
+        admin.require_auth();
+    }
+
+    /// Require `caller`'s authorization and that it may act as `role`.
+    fn require_role(env: &Env, caller: &Address, role: Symbol) {
+        Self::require_writable(env);
+        Self::check_role(env, caller, role);
+    }
+
+    /// `require_role` without the sunset check, for calls that only settle
+    /// obligations taken on before sunset.
+    fn check_role(env: &Env, caller: &Address, role: Symbol) {
+        caller.require_auth();
+        if !roles::has_role(env, &role, caller) {
+            panic_with_error!(env, ErrorCodeExt::MissingRole);
+        }
+    }
+
+    fn check_attestor(env: &Env, attestor: &Address) {
+        Self::require_writable(env);
+        if !env
+            .storage()
+            .persistent()
+            .has(&StorageKey::Attestor(attestor.clone()))
+        {
+            panic_with_error!(env, ErrorCode::AttestorNotRegistered);
+        }
+    }
+
+    /// `attestor` was deactivated after `timestamp`.
+    fn deactivated_after(env: &Env, attestor: &Address, timestamp: u64) -> bool {
+        Self::get_attestor_deactivated_at(env.clone(), attestor.clone()).is_some_and(|at| timestamp < at)
+    }
+
+    /// Deactivated attestors can never register again.
+    fn check_not_deactivated(env: &Env, attestor: &Address) {
+        if env.storage().persistent().has(&AttestorKey::DeactivatedAt(attestor.clone())) {
+            panic_with_error!(env, ErrorCodeExt::AttestorDeactivated);
+        }
+    }
+
+    fn check_tier_not_suspended(env: &Env, attestor: &Address) {
+        if let Some(suspended) = Self::get_suspended_tier(env.clone()) {
+            if Self::get_attestor_tier(env.clone(), attestor.clone()) <= suspended {
+                panic_with_error!(env, ErrorCode::AttestorSuspended);
+            }
+        }
+    }
+
+    fn put_paused_subsystems(env: &Env, paused: u32) {
+        env.storage().instance().set(&key_paused_subsystems(env), &paused);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        env.events().publish((symbol_short!("pause"), symbol_short!("subsys")), paused);
+    }
+
+    /// Panic with `ContractPaused` during an emergency stop.
+    fn require_not_stopped(env: &Env) {
+        if Self::is_paused(env.clone()) {
+            panic_with_error!(env, ErrorCodeExt::ContractPaused);
+        }
+    }
+
+    fn put_paused(env: &Env, paused: bool) {
+        env.storage().instance().set(&key_paused(env), &paused);
+        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    }
+
+    /// Panic with `ContractPaused` during an emergency stop, or with
+    /// `SubsystemPaused` while `subsystem` is paused.
+    fn require_subsystem_active(env: &Env, subsystem: u32) {
+        Self::require_not_stopped(env);
+        if Self::get_paused_subsystems(env.clone()) & subsystem != 0 {
+            panic_with_error!(env, ErrorCodeExt::SubsystemPaused);
+        }
+    }
+
+    /// Whether neither a successor nor sunset mode has been set; views use
+    /// this to skip housekeeping writes and events on a read-only contract.
+    fn is_writable(env: &Env) -> bool {
+        !env.storage().instance().has(&key_successor(env))
+            && !env.storage().instance().get(&key_sunset(env)).unwrap_or(false)
+    }
+
+    /// Panic with `MovedToSuccessor` once a successor contract is set, with
+    /// `ReadOnlyMode` once sunset mode has been enabled, or with
+    /// `MigrationPending` while an initialized contract's storage is at a
+    /// layout other than the one this code expects.
+    fn require_writable(env: &Env) {
+        Self::require_not_retired(env);
+        if Self::is_initialized(env.clone()) && Self::storage_version(env.clone()) != CONTRACT_VERSION {
+            panic_with_error!(env, ErrorCodeExt::MigrationPending);
+        }
+    }
+
+    fn require_not_retired(env: &Env) {
+        if env.storage().instance().has(&key_successor(env)) {
+            panic_with_error!(env, ErrorCodeExt::MovedToSuccessor);
+        }
+        if env.storage().instance().get(&key_sunset(env)).unwrap_or(false) {
+            panic_with_error!(env, ErrorCode::ReadOnlyMode);
+        }
+    }
+
+    /// `require_admin` without the layout check, for `upgrade` and
+    /// `migrate`, which bring the layout and the code back in step.
+    fn require_layout_admin(env: &Env) {
+        Self::require_not_retired(env);
+        Self::get_admin(env.clone()).require_auth();
+    }
+
+    fn check_timestamp(env: &Env, timestamp: u64) {
+        let (lower, upper) = Self::timestamp_window(env);
+        if timestamp == 0 || timestamp < lower || timestamp > upper {
+            Self::reject_timestamp(env, "timestamp", timestamp, lower.max(1), upper, ErrorCode::InvalidTimestamp);
+        }
+    }
+
+    /// Inclusive range of submission timestamps the drift limits accept now.
+    fn timestamp_window(env: &Env) -> (u64, u64) {
+        let now = env.ledger().timestamp();
+        let drift = Self::get_timestamp_drift(env.clone());
+        (now.saturating_sub(drift.max_past_drift), now.saturating_add(drift.max_future_drift))
+    }
+
+    /// Publish a `reject/timestamp` event for `field`, then fail with `error`.
+    fn reject_timestamp(
+        env: &Env,
+        field: &str,
+        value: u64,
+        earliest: u64,
+        latest: u64,
+        error: impl Into<soroban_sdk::Error>,
+    ) -> ! {
+        env.events().publish(
+            (symbol_short!("reject"), symbol_short!("timestamp")),
+            TimestampRejected { field: Symbol::new(env, field), value, earliest, latest },
+        );
+        panic_with_error!(env, error)
+    }
+
+    /// Publish a `reject/replay` event for the reused hash, then fail with
+    /// `ReplayAttack`, or `HashAlreadyUsedByIssuer` under a per-issuer scope.
+    fn reject_replay(env: &Env, issuer: &Address, subject: &Address, payload_hash: &Bytes) -> ! {
+        env.events().publish(
+            (symbol_short!("reject"), symbol_short!("replay")),
+            ReplayRejected { issuer: issuer.clone(), subject: subject.clone(), payload_hash: payload_hash.clone() },
+        );
+        panic_with_error!(env, Self::replay_error(env))
+    }
+
+    /// Error a reused payload hash fails with under the current replay scope.
+    fn replay_error(env: &Env) -> soroban_sdk::Error {
+        match Self::get_replay_scope(env.clone()) {
+            ReplayScope::Global => ErrorCode::ReplayAttack.into(),
+            ReplayScope::PerIssuer | ReplayScope::PerIssuerSubject => ErrorCodeExt::HashAlreadyUsedByIssuer.into(),
+        }
+    }
+
+    /// Publish a `reject/quota` event for `scope`, then fail with `error`.
+    fn reject_quota(env: &Env, scope: Symbol, id: u64, limit: u64, used: u64, error: ErrorCode) -> ! {
+        env.events().publish(
+            (symbol_short!("reject"), symbol_short!("quota")),
+            QuotaExceeded { scope, id, limit, used },
+        );
+        panic_with_error!(env, error)
+    }
+
+    /// Publish the `attest/recorded` event at the configured verbosity.
+    #[cfg_attr(feature = "legacy-events", allow(unused_variables))]
+    fn emit_attest_recorded(env: &Env, id: u64, issuer: &Address, input: &AttestationInput) {
+        Self::emit_subject_topic(env, symbol_short!("recorded"), input.subject.clone(), id);
+        #[cfg(not(feature = "legacy-events"))]
+        let topics = (symbol_short!("attest"), symbol_short!("recorded"), issuer.clone(), input.subject.clone());
+        #[cfg(feature = "legacy-events")]
+        let topics = (symbol_short!("attest"), symbol_short!("recorded"), id, input.subject.clone());
+        match Self::get_event_verbosity(env.clone()) {
+            #[cfg(not(feature = "legacy-events"))]
+            EventVerbosity::Minimal => env.events().publish(topics, id),
+            #[cfg(feature = "legacy-events")]
+            EventVerbosity::Minimal => env.events().publish(topics, ()),
+            EventVerbosity::Standard => {
+                #[cfg(not(feature = "legacy-events"))]
+                let body = AttestEvent {
+                    version: EVENT_VERSION,
+                    id,
+                    payload_hash: input.payload_hash.clone(),
+                    timestamp: input.timestamp,
+                    not_before: input.not_before,
+                    data: Self::attestation_data(input),
+                    uri: input.uri.clone(),
+                };
+                #[cfg(feature = "legacy-events")]
+                let body = AttestEventV1 { payload_hash: input.payload_hash.clone(), timestamp: input.timestamp };
+                env.events().publish(topics, body)
+            }
+            EventVerbosity::Verbose => {
+                let attestation: Attestation = env
+                    .storage()
+                    .persistent()
+                    .get(&StorageKey::Attest(id))
+                    .unwrap_or_else(|| panic_with_error!(env, ErrorCode::AttestationNotFound));
+                env.events().publish(topics, attestation)
+            }
+        }
+    }
+
+    /// Mirror an event about `subject` under its registered topic tag, if any.
+    fn emit_subject_topic(env: &Env, kind: Symbol, subject: Address, attestation_id: u64) {
+        if let Some(topic) = Self::get_subject_topic(env.clone(), subject.clone()) {
+            env.events().publish(
+                (symbol_short!("subject"), topic),
+                SubjectTopicEvent { kind, subject, attestation_id },
+            );
+        }
+    }
+
+    /// Extend the TTL of attestation `id` and its companion entries; `false`
+    /// when the attestation is not stored.
+    fn extend_attestation_entries(env: &Env, id: u64, extend_to: u32) -> Option<Attestation> {
+        let key = StorageKey::Attest(id);
+        let attestation = env.storage().persistent().get::<_, Attestation>(&key)?;
+        let extend_to = extend_to.min(env.storage().max_ttl());
+        env.storage().persistent().extend_ttl(&key, extend_to, extend_to);
+        let link_key = StorageKey::ChainLink(id);
+        if env.storage().persistent().has(&link_key) {
+            env.storage().persistent().extend_ttl(&link_key, extend_to, extend_to);
+        }
+        let used_key = Self::replay_key(env, &attestation.issuer, &attestation.subject, &attestation.payload_hash);
+        if env.storage().persistent().has(&used_key) {
+            env.storage().persistent().extend_ttl(&used_key, extend_to, extend_to);
+        }
+        Some(attestation)
+    }
 
-        // Issue #276: remove from CACHED_ANCHORS set
-        let list_key = key_anchor_list(&env);
-        if let Some(list) = env.storage().persistent().get::<_, Vec<Address>>(&list_key) {
-            let mut new_list = Vec::new(&env);
-            for a in list.iter() {
-                if a != anchor {
-                    new_list.push_back(a);
-                }
+    /// Take `reward` from the pool of `attestation`'s schema, or failing
+    /// that its subject, if one holds enough. Returns whether it was paid.
+    fn draw_rent_reward(env: &Env, attestation: &Attestation, reward: i128) -> bool {
+        let schema = attestation.schema_id.map(RentTarget::Schema);
+        let subject = Some(RentTarget::Subject(attestation.subject.clone()));
+        for target in [schema, subject].into_iter().flatten() {
+            let key = RentKey::Pool(target);
+            let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+            if balance >= reward {
+                env.storage().persistent().set(&key, &(balance - reward));
+                env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+                return true;
             }
-            env.storage().persistent().set(&list_key, &new_list);
-            env.storage().persistent().extend_ttl(&list_key, PERSISTENT_TTL, PERSISTENT_TTL);
         }
-        metadata
+        false
     }
-    /// Computes a health score (0-100) for an anchor based on cached metadata.
-    ///
-    /// # Formula
-    ///
-    /// The health score is a weighted combination of three metrics:
-    /// - **Uptime (40%)**: `uptime_percentage / 100` (0-10000 scale → 0-100)
-    /// - **Reputation (35%)**: `reputation_score / 100` (0-10000 scale → 0-100)
-    /// - **Settlement Speed (25%)**: Inverse of `average_settlement_time`, normalized
-    ///
-    /// Settlement speed scoring:
-    /// - 0-300s: 100 points (excellent)
-    /// - 301-600s: 80 points (good)
-    /// - 601-1800s: 60 points (acceptable)
-    /// - 1801-3600s: 40 points (slow)
-    /// - >3600s: 20 points (very slow)
-    ///
-    /// Final score = (uptime_weight × uptime_score) + (reputation_weight × reputation_score) + (speed_weight × speed_score)
-    ///
-    /// # Errors
-    ///
-    /// - `CacheNotFound` (49): No metadata cached for this anchor
-    /// - `CacheExpired` (48): Metadata cache has expired
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// let score = contract.get_anchor_health_score(&env, &anchor_addr);
-    /// // score is 0-100, where 100 is perfect health
-    /// ```
-    pub fn get_anchor_health_score(env: Env, anchor: Address) -> u32 {
-        // Retrieve cached metadata (will panic with CacheNotFound or CacheExpired if unavailable)
-        let metadata = Self::get_cached_metadata(env.clone(), anchor);
 
-        // Weight constants (must sum to 100)
-        const UPTIME_WEIGHT: u64 = 40;
-        const REPUTATION_WEIGHT: u64 = 35;
-        const SPEED_WEIGHT: u64 = 25;
-        const SCALE_FACTOR: u64 = 100; // Fixed-point scaling to preserve precision
+    /// Replay-protection marker for `payload_hash` under the configured scope.
+    fn replay_key(env: &Env, issuer: &Address, subject: &Address, payload_hash: &Bytes) -> StorageKey {
+        match Self::get_replay_scope(env.clone()) {
+            ReplayScope::Global => StorageKey::Used(payload_hash.clone()),
+            ReplayScope::PerIssuer => StorageKey::UsedByIssuer(issuer.clone(), payload_hash.clone()),
+            ReplayScope::PerIssuerSubject => {
+                StorageKey::UsedByIssuerSubject(issuer.clone(), subject.clone(), payload_hash.clone())
+            }
+        }
+    }
 
-        // 1. Uptime score: scale from 0-10000 to 0-100 using fixed-point arithmetic
-        // Multiply by SCALE_FACTOR before dividing to preserve decimal precision
-        let uptime_score = (metadata.uptime_percentage as u64 * SCALE_FACTOR) / 100;
+    /// Allocate the next attestation ID, drawing from the issuer's org range
+    /// when it belongs to an organization.
+    fn next_attestation_id(env: &Env, issuer: &Address) -> u64 {
+        let org_id: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::AttestorOrg(issuer.clone()));
+        if let Some(org_id) = org_id {
+            let key = StorageKey::OrgIdSeq(org_id);
+            let seq: u64 = env.storage().persistent().get(&key).unwrap_or(0u64);
+            if seq >= 1u64 << ORG_ID_BITS {
+                panic_with_error!(env, ErrorCodeExt::AttestationLimitReached);
+            }
+            env.storage().persistent().set(&key, &(seq + 1));
+            env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+            return Self::get_org_id_range(env.clone(), org_id).0 + seq;
+        }
 
-        // 2. Reputation score: scale from 0-10000 to 0-100 using fixed-point arithmetic
-        let reputation_score = (metadata.reputation_score as u64 * SCALE_FACTOR) / 100;
+        let inst = env.storage().instance();
+        let ck = key_counter(env);
+        let id: u64 = inst.get(&ck).unwrap_or(0u64);
+        let next = id.saturating_add(1);
+        if next >= 1u64 << ORG_ID_BITS {
+            panic_with_error!(env, ErrorCodeExt::AttestationLimitReached);
+        }
+        inst.set(&ck, &next);
+        inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+        id
+    }
 
-        // 3. Settlement speed score: tiered scoring based on settlement time
-        let speed_score = if metadata.average_settlement_time <= 300 {
-            100 * SCALE_FACTOR // Excellent: ≤5 minutes
-        } else if metadata.average_settlement_time <= 600 {
-            80 * SCALE_FACTOR // Good: 5-10 minutes
-        } else if metadata.average_settlement_time <= 1800 {
-            60 * SCALE_FACTOR // Acceptable: 10-30 minutes
-        } else if metadata.average_settlement_time <= 3600 {
-            40 * SCALE_FACTOR // Slow: 30-60 minutes
-        } else {
-            20 * SCALE_FACTOR // Very slow: >1 hour
+    fn check_session_expiry(env: &Env, session_id: u64) {
+        let sess_key = StorageKey::Session(session_id);
+        let session: Session = env
+            .storage()
+            .persistent()
+            .get::<_, Session>(&sess_key)
+            .unwrap_or_else(|| panic_with_error!(env, ErrorCode::SessionNotFound));
+        let now = env.ledger().timestamp();
+        if now >= session.expires_at {
+            if Self::is_writable(env) {
+                env.events().publish(
+                    (symbol_short!("session"), symbol_short!("expired"), session_id),
+                    SessionExpired { session_id, expired_at: now },
+                );
+            }
+            panic_with_error!(env, ErrorCode::SessionExpired);
+        }
+    }
+
+    /// Persist a new attestation, update indexes and counters, and advance the
+    /// running chain hash. Returns the new chain head.
+    fn store_attestation(env: &Env, id: u64, issuer: Address, input: &AttestationInput) -> BytesN<32> {
+        Self::require_subsystem_active(env, SUBSYSTEM_ISSUANCE);
+        Self::check_schema_reference(env, input.schema_id);
+        Self::check_attestor_schema(env, &issuer, input.schema_id);
+        Self::check_attestation_ref(env, &issuer, &input.subject, input.ref_id);
+        let subject = input.subject.clone();
+        let org_id: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::AttestorOrg(issuer.clone()));
+        if let Some(org_id) = org_id {
+            Self::charge_org_quota(env, org_id);
+        }
+        let schema_epoch = match input.schema_id {
+            Some(schema_id) => {
+                Self::check_schema_tier(env, schema_id, &issuer);
+                Self::charge_schema_cap(env, schema_id);
+                let latest_key = SchemaKey::SubjectLatest(subject.clone(), schema_id);
+                env.storage().persistent().set(&latest_key, &id);
+                env.storage().persistent().extend_ttl(&latest_key, PERSISTENT_TTL, PERSISTENT_TTL);
+                if let Some(schema) = Self::get_schema(env.clone(), schema_id) {
+                    if schema.value_bucket_width != 0 {
+                        Self::set_subject_value(env, schema_id, schema.value_bucket_width, &subject, input.value);
+                    }
+                }
+                Self::get_schema_revocation_epoch(env.clone(), issuer.clone(), schema_id)
+            }
+            None => 0,
         };
+        Self::record_activity(env, &issuer, true);
+        let attestation = Self::new_attestation(env, id, issuer, input, org_id, schema_epoch);
+        let ttl = Self::attestation_ttl(env, input.schema_id);
+        let key = StorageKey::Attest(id);
+        env.storage().persistent().set(&key, &attestation);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
 
-        // Calculate weighted health score using fixed-point arithmetic
-        // All intermediate values are scaled by SCALE_FACTOR, so divide once at the end
-        let weighted_sum = UPTIME_WEIGHT * uptime_score
-            + REPUTATION_WEIGHT * reputation_score
-            + SPEED_WEIGHT * speed_score;
-        
-        // Divide by (100 * SCALE_FACTOR) to get final score: 100 for weights, SCALE_FACTOR for precision
-        let health_score = weighted_sum / (100 * SCALE_FACTOR);
+        // Subject-specific index for pagination support (#215)
+        // Store only the ID to save storage space (O(1) extra space)
+        let count_key = StorageKey::SubjectCount(subject.clone());
+        let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
 
-        // Ensure score is capped at 100
-        let final_score = if health_score > 100 { 100 } else { health_score as u32 };
+        let subj_att_key = StorageKey::SubjectAttestation(subject.clone(), count);
+        env.storage().persistent().set(&subj_att_key, &id);
+        env.storage().persistent().extend_ttl(&subj_att_key, ttl, ttl);
 
-        // Issue #464: enforce configurable minimum acceptable health score.
-        // When key_health_threshold is set (> 0), reject anchors whose computed
-        // score falls below it so callers cannot route to unhealthy anchors.
-        let threshold: u32 = env
-            .storage()
-            .instance()
-            .get(&key_health_threshold(&env))
-            .unwrap_or(0u32);
-        if threshold > 0 && final_score < threshold {
-            panic_with_error!(&env, ErrorCode::ValidationError);
+        env.storage().persistent().set(&count_key, &(count + 1));
+        Self::push_inbox(env, &subject, id);
+
+        let issuer_count_key = AttestorKey::AttestationCount(attestation.issuer.clone());
+        let issuer_count: u64 = env.storage().persistent().get(&issuer_count_key).unwrap_or(0);
+        let issuer_entry_key = AttestorKey::Attestation(attestation.issuer.clone(), issuer_count);
+        env.storage().persistent().set(&issuer_entry_key, &id);
+        env.storage().persistent().extend_ttl(&issuer_entry_key, ttl, ttl);
+        env.storage().persistent().set(&issuer_count_key, &(issuer_count + 1));
+        env.storage().persistent().extend_ttl(&issuer_count_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        let issuer_latest_key = AttestorKey::SubjectLatest(attestation.issuer.clone(), subject.clone());
+        env.storage().persistent().set(&issuer_latest_key, &id);
+        env.storage().persistent().extend_ttl(&issuer_latest_key, ttl, ttl);
+        let total_key = symbol_short!("TOTALCNT");
+        let total: u64 = env.storage().instance().get(&total_key).unwrap_or(0);
+        env.storage().instance().set(&total_key, &(total + 1));
+        let day_key = StatsKey::DailyAttestations(env.ledger().timestamp() / SECS_PER_DAY);
+        let day_count: u64 = env.storage().persistent().get(&day_key).unwrap_or(0);
+        env.storage().persistent().set(&day_key, &(day_count + 1));
+        env.storage().persistent().extend_ttl(&day_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.storage()
+            .persistent()
+            .extend_ttl(&count_key, PERSISTENT_TTL, PERSISTENT_TTL);
+
+        if let Some(jurisdiction) = &input.jurisdiction {
+            let count_key = StorageKey::JurisdictionCount(jurisdiction.clone());
+            let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
+            let entry_key = StorageKey::JurisdictionAttestation(jurisdiction.clone(), count);
+            env.storage().persistent().set(&entry_key, &id);
+            env.storage().persistent().extend_ttl(&entry_key, PERSISTENT_TTL, PERSISTENT_TTL);
+            env.storage().persistent().set(&count_key, &(count + 1));
+            env.storage().persistent().extend_ttl(&count_key, PERSISTENT_TTL, PERSISTENT_TTL);
         }
 
-        final_score
+        if input.subject_kind == SubjectKind::Asset {
+            Self::index_asset_attestation(env, &subject, id);
+        }
+
+        Self::maybe_emit_metrics_snapshot(env, total + 1);
+        // The chain also holds one link per amendment.
+        let amended: u64 = env.storage().instance().get(&symbol_short!("AMENDCNT")).unwrap_or(0);
+        Self::advance_chain(env, id, total + 1 + amended, &input.payload_hash)
     }
 
+    /// TTL target for an attestation's entries: its schema's retention
+    /// class, or `PERSISTENT_TTL` without a schema.
+    fn attestation_ttl(env: &Env, schema_id: Option<u64>) -> u32 {
+        schema_id
+            .and_then(|schema_id| Self::get_schema(env.clone(), schema_id))
+            .map(|schema| retention_ttl(env, schema.retention))
+            .unwrap_or(PERSISTENT_TTL)
+    }
 
-    /// Issue #276: list all anchors that currently have active metadata cache entries.
-    ///
-    /// Filters out any anchor whose temporary cache keys have all been evicted, and
-    /// writes the pruned list back to persistent storage so CANCHORS does not grow
-    /// unboundedly after natural TTL eviction.
-    pub fn list_cached_anchors(env: Env) -> Vec<Address> {
-        let list_key = soroban_sdk::vec![&env, symbol_short!("CANCHORS")];
-        let list: Vec<Address> = env.storage().persistent()
-            .get::<_, Vec<Address>>(&list_key)
-            .unwrap_or_else(|| Vec::new(&env));
+    /// Append attestation `id` to the hash chain and record its link.
+    fn advance_chain(env: &Env, id: u64, sequence: u64, payload_hash: &Bytes) -> BytesN<32> {
+        let prev_hash = Self::get_chain_head(env.clone());
+        let mut input = Bytes::from(prev_hash.clone());
+        input.extend_from_array(&id.to_be_bytes());
+        input.append(payload_hash);
+        let head: BytesN<32> = env.crypto().sha256(&input).into();
+        env.storage().instance().set(&key_chain_head(env), &head);
+        let link_key = StorageKey::ChainLink(id);
+        let link = ChainLink { sequence, prev_hash, hash: head.clone() };
+        env.storage().persistent().set(&link_key, &link);
+        env.storage().persistent().extend_ttl(&link_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        head
+    }
 
-        let mut active = Vec::new(&env);
-        let mut pruned = false;
-        for anchor in list.iter() {
-            let meta_key = StorageKey::MetadataCache(anchor.clone());
-            let caps_key = StorageKey::CapabilitiesCache(anchor.clone());
-            let toml_key = StorageKey::TomlCache(anchor.clone());
-            if env.storage().temporary().has(&meta_key)
-                || env.storage().temporary().has(&caps_key)
-                || env.storage().temporary().has(&toml_key)
-            {
-                active.push_back(anchor);
-            } else {
-                // All temp entries for this anchor have been evicted; mark for pruning.
-                pruned = true;
+    /// Whether the latest attestation `issuer` recorded about `subject` is
+    /// currently valid.
+    fn holds_valid_from_issuer(env: &Env, subject: &Address, issuer: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .get::<_, u64>(&AttestorKey::SubjectLatest(issuer.clone(), subject.clone()))
+            .and_then(|id| Self::load_attestation(env, id))
+            .is_some_and(|att| Self::evaluate_validity(env, &att).is_valid)
+    }
+
+    fn eval_policy(env: &Env, policy_id: u64, subject: &Address) -> bool {
+        let policy: Policy = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::Policy(policy_id))
+            .unwrap_or_else(|| panic_with_error!(env, ErrorCode::PolicyNotFound));
+        let mut met: u32 = 0;
+        for req in policy.requirements.iter() {
+            let ok = match req {
+                PolicyRequirement::Issuer(addr) => Self::holds_valid_from_issuer(env, subject, &addr),
+                PolicyRequirement::Schema(schema_id) => {
+                    Self::verify_for_subject(env.clone(), subject.clone(), schema_id)
+                }
+                PolicyRequirement::Policy(inner) => Self::eval_policy(env, inner, subject),
+            };
+            if ok {
+                met += 1;
             }
         }
-
-        // Write back the pruned list so stale entries don't accumulate in persistent storage.
-        if pruned {
-            env.storage().persistent().set(&list_key, &active);
-            env.storage().persistent().extend_ttl(&list_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        match policy.op {
+            PolicyOp::And => met == policy.requirements.len(),
+            PolicyOp::Or => met > 0,
+            PolicyOp::Threshold(k) => met >= k,
         }
+    }
 
-        active
+    /// Compute the read-time validity of `attestation`.
+    fn evaluate_validity(env: &Env, attestation: &Attestation) -> AttestationValidity {
+        Self::validity_within(env, attestation, MAX_SUPERSESSION_DEPTH)
     }
 
-    // -----------------------------------------------------------------------
-    // Capabilities cache
-    // -----------------------------------------------------------------------
+    /// `evaluate_validity`, following at most `depth` schema-successor hops
+    /// when deciding whether the attestation is superseded.
+    fn validity_within(env: &Env, attestation: &Attestation, depth: u32) -> AttestationValidity {
+        let lifecycle = Self::status_within(env, attestation, depth);
+        let status = if lifecycle == Status::Revoked {
+            // Revoked without `revoked_at` means struck down by a challenge.
+            if attestation.revoked_at.is_some() {
+                AttestationStatus::Revoked
+            } else {
+                AttestationStatus::Invalidated
+            }
+        } else if attestation.issuer_revoked {
+            AttestationStatus::IssuerRevoked
+        } else if Self::is_schema_revoked(env, attestation) {
+            AttestationStatus::SchemaRevoked
+        } else if lifecycle == Status::Superseded {
+            AttestationStatus::Superseded
+        } else if lifecycle == Status::Disputed {
+            AttestationStatus::Disputed
+        } else if env.ledger().timestamp() < attestation.not_before {
+            AttestationStatus::Pending
+        } else {
+            AttestationStatus::Active
+        };
+        let max_age = Self::get_attestation_max_age(env.clone());
+        let expired = lifecycle == Status::Expired || Self::is_past_expiry(env, attestation, max_age);
+        let invalidated = Self::is_invalidated(env, &attestation.issuer, attestation.timestamp);
+        let issuer_registered = env
+            .storage()
+            .persistent()
+            .has(&StorageKey::Attestor(attestation.issuer.clone()))
+            || Self::deactivated_after(env, &attestation.issuer, attestation.timestamp);
+        let issuer_below_min_tier = Self::is_below_schema_tier(env, attestation.schema_id, &attestation.issuer);
+        let is_valid = matches!(lifecycle, Status::Active | Status::Disputed)
+            && matches!(status, AttestationStatus::Active | AttestationStatus::Disputed)
+            && !invalidated
+            && issuer_registered
+            && !issuer_below_min_tier;
+        let valid_until_ledger = if is_valid {
+            Self::valid_until_ledger(env, attestation, max_age)
+        } else {
+            0
+        };
+        AttestationValidity {
+            status,
+            expired,
+            invalidated,
+            issuer_registered,
+            issuer_below_min_tier,
+            is_valid,
+            weight_bps: attestation.weight_bps,
+            valid_until_ledger,
+        }
+    }
 
-    pub fn cache_capabilities(env: Env, anchor: Address, toml_url: String, capabilities: Vec<u32>, ttl_seconds: u64) {
-        Self::require_admin(&env);
+    /// `attestation`'s stored lifecycle status with the time- and
+    /// schema-driven transitions that `refresh_status` would apply now.
+    /// Reads use this so a record is not reported `Active` past its expiry
+    /// just because nobody has refreshed it yet.
+    fn current_status(env: &Env, attestation: &Attestation) -> Status {
+        Self::status_within(env, attestation, MAX_SUPERSESSION_DEPTH)
+    }
 
-        // Issue #280: Validate toml_url before caching
-        let len = toml_url.len() as usize;
-        let mut buf = [0u8; 256];
-        if len > 256 {
-            panic_with_error!(&env, ErrorCode::InvalidEndpointFormat);
-        }
-        toml_url.copy_into_slice(&mut buf[..len]);
-        let url_str = core::str::from_utf8(&buf[..len]).unwrap_or("");
-        if crate::domain_validator::validate_anchor_domain(url_str).is_err() {
-            panic_with_error!(&env, ErrorCode::InvalidEndpointFormat);
+    fn status_within(env: &Env, attestation: &Attestation, depth: u32) -> Status {
+        let stored = match attestation.status {
+            Status::Superseded => Status::Active,
+            status => status,
+        };
+        let max_age = Self::get_attestation_max_age(env.clone());
+        if stored.can_transition_to(Status::Expired) && Self::is_past_expiry(env, attestation, max_age) {
+            Status::Expired
+        } else if stored.can_transition_to(Status::Superseded) && Self::is_superseded(env, attestation, depth) {
+            Status::Superseded
+        } else {
+            stored
         }
+    }
 
+    /// Past the attestation's own `expires_at` or the contract's `max_age`.
+    fn is_past_expiry(env: &Env, attestation: &Attestation, max_age: u64) -> bool {
         let now = env.ledger().timestamp();
-        let entry = CapabilitiesCache { toml_url, capabilities, cached_at: now, ttl_seconds };
-        let key = StorageKey::CapabilitiesCache(anchor.clone());
-        let ledger_ttl = if ttl_seconds as u32 > MIN_TEMP_TTL { ttl_seconds as u32 } else { MIN_TEMP_TTL };
-        env.storage().temporary().set(&key, &entry);
-        env.storage().temporary().extend_ttl(&key, ledger_ttl, ledger_ttl);
-
-        Self::add_to_cached_anchors(&env, &anchor);
+        (max_age != 0 && now > attestation.timestamp.saturating_add(max_age))
+            || (attestation.expires_at != 0 && now >= attestation.expires_at)
     }
 
-    pub fn get_cached_capabilities(env: Env, anchor: Address) -> CapabilitiesCache {
-        let key = StorageKey::CapabilitiesCache(anchor);
-        let entry: CapabilitiesCache = env.storage().temporary().get(&key)
-            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::CacheNotFound));
+    /// Caching horizon for a currently valid attestation; see
+    /// `AttestationValidity::valid_until_ledger`.
+    fn valid_until_ledger(env: &Env, attestation: &Attestation, max_age: u64) -> u32 {
         let now = env.ledger().timestamp();
-        // ttl_seconds = 0 means "never expire" — skip the expiry check to prevent refresh loops
-        if entry.ttl_seconds != 0 && entry.cached_at + entry.ttl_seconds <= now {
-            panic_with_error!(&env, ErrorCode::CacheExpired);
+        let sequence = env.ledger().sequence();
+        let to_ledger = |deadline: u64| -> u32 {
+            let ledgers = deadline.saturating_sub(now) / LEDGER_PERIOD_SECS;
+            sequence.saturating_add(u32::try_from(ledgers).unwrap_or(u32::MAX))
+        };
+
+        let mut until = attestation.ledger.saturating_add(PERSISTENT_TTL);
+        if attestation.expires_at != 0 {
+            until = until.min(to_ledger(attestation.expires_at).saturating_sub(1));
         }
-        entry
+        if max_age != 0 {
+            until = until.min(to_ledger(attestation.timestamp.saturating_add(max_age)));
+        }
+        if let Some(schema_id) = attestation.schema_id {
+            if let Some(expires_at) =
+                Self::get_schema_grant_expiry(env.clone(), schema_id, attestation.issuer.clone())
+            {
+                // The grant lapses at `expires_at`, so stop one ledger short.
+                until = until.min(to_ledger(expires_at).saturating_sub(1));
+            }
+        }
+        until
     }
 
-    pub fn refresh_capabilities_cache(env: Env, anchor: Address) {
-        Self::require_admin(&env);
-        let key = StorageKey::CapabilitiesCache(anchor.clone());
-        env.storage().temporary().remove(&key);
-
-        // If no other cache entries remain for this anchor, remove it from CANCHORS.
-        let meta_key = StorageKey::MetadataCache(anchor.clone());
-        let toml_key = StorageKey::TomlCache(anchor.clone());
-        if !env.storage().temporary().has(&meta_key) && !env.storage().temporary().has(&toml_key) {
-            let list_key = soroban_sdk::vec![&env, symbol_short!("CANCHORS")];
-            if let Some(list) = env.storage().persistent().get::<_, Vec<Address>>(&list_key) {
-                let mut new_list = Vec::new(&env);
-                for a in list.iter() {
-                    if a != anchor {
-                        new_list.push_back(a);
-                    }
-                }
-                env.storage().persistent().set(&list_key, &new_list);
-                env.storage().persistent().extend_ttl(&list_key, PERSISTENT_TTL, PERSISTENT_TTL);
+    /// `true` when the attestation's schema has a declared successor and the
+    /// subject's latest attestation under it is valid. Successor schemas may
+    /// form a cycle, so the newer attestation's own supersession is checked
+    /// to `depth - 1` further hops; at depth zero nothing is superseded.
+    fn is_superseded(env: &Env, attestation: &Attestation, depth: u32) -> bool {
+        if depth == 0 {
+            return false;
+        }
+        let Some(schema_id) = attestation.schema_id else {
+            return false;
+        };
+        let Some(successor) = Self::get_schema_successor(env.clone(), schema_id) else {
+            return false;
+        };
+        let Some(latest) = env
+            .storage()
+            .persistent()
+            .get::<_, u64>(&SchemaKey::SubjectLatest(attestation.subject.clone(), successor))
+        else {
+            return false;
+        };
+        let Some(newer) = Self::load_attestation(env, latest) else {
+            return false;
+        };
+        Self::validity_within(env, &newer, depth - 1).is_valid
+    }
+
+    /// `true` when the issuer has advanced its schema revocation epoch past
+    /// the one recorded on `attestation`.
+    fn is_schema_revoked(env: &Env, attestation: &Attestation) -> bool {
+        match attestation.schema_id {
+            Some(schema_id) => {
+                attestation.schema_epoch
+                    != Self::get_schema_revocation_epoch(env.clone(), attestation.issuer.clone(), schema_id)
             }
+            None => false,
         }
     }
 
-    /// Issue #258/#463: admin-only emergency flush of all MetadataCache,
-    /// CapabilitiesCache, and TomlCache entries for every tracked anchor.
-    /// Emits a `CacheInvalidated` event with the count of cleared entries.
-    pub fn invalidate_all_caches(env: Env) {
-        Self::require_admin(&env);
-        let list_key = key_anchor_list(&env);
-        let anchors: Vec<Address> = env.storage().persistent()
-            .get::<_, Vec<Address>>(&list_key)
-            .unwrap_or_else(|| Vec::new(&env));
+    /// `true` when `timestamp` predates the global or the issuer's
+    /// invalidation epoch.
+    fn is_invalidated(env: &Env, issuer: &Address, timestamp: u64) -> bool {
+        let global = Self::get_global_invalidation_epoch(env.clone());
+        let per_issuer = Self::get_issuer_invalidation_epoch(env.clone(), issuer.clone());
+        timestamp < global || timestamp < per_issuer
+    }
 
-        let mut count: u32 = 0;
-        for anchor in anchors.iter() {
-            let meta_key = StorageKey::MetadataCache(anchor.clone());
-            if env.storage().temporary().has(&meta_key) {
-                env.storage().temporary().remove(&meta_key);
-                count += 1;
-            }
-            let caps_key = StorageKey::CapabilitiesCache(anchor.clone());
-            if env.storage().temporary().has(&caps_key) {
-                env.storage().temporary().remove(&caps_key);
-                count += 1;
-            }
-            // Issue #463: also flush cached stellar.toml entries
-            let toml_key = StorageKey::TomlCache(anchor.clone());
-            if env.storage().temporary().has(&toml_key) {
-                env.storage().temporary().remove(&toml_key);
-                count += 1;
-            }
+    /// Shared body of every submission path that records a single
+    /// attestation. The caller is responsible for `require_auth` on the
+    /// issuer, and on the subject when `subject_authorized` is set.
+    fn record_submission(
+        env: &Env,
+        issuer: Address,
+        input: &AttestationInput,
+        subject_authorized: bool,
+    ) -> AttestationReceipt {
+        Self::check_new_input(env, &issuer, input, subject_authorized);
+        Self::record_checked(env, issuer, input)
+    }
+
+    /// Input, issuer, subject and schema grant checks on a new attestation.
+    fn check_new_input(env: &Env, issuer: &Address, input: &AttestationInput, subject_authorized: bool) {
+        Self::check_input(env, input);
+        Self::check_issuer(env, issuer);
+        Self::check_subject(env, &input.subject, issuer, subject_authorized);
+        if let Some(schema_id) = input.schema_id {
+            Self::check_schema_grant(env, schema_id, issuer);
         }
+    }
 
-        // Clear the anchor list
-        let empty: Vec<Address> = Vec::new(&env);
-        env.storage().persistent().set(&list_key, &empty);
-        env.storage().persistent().extend_ttl(&list_key, PERSISTENT_TTL, PERSISTENT_TTL);
+    /// Code of the first per-entry check of `batch_attest_partial` that
+    /// `input` fails, if any. Runs the checks `record_submission` makes on a
+    /// single input through the same non-panicking helpers.
+    fn screen_batch_entry(env: &Env, issuer: &Address, input: &AttestationInput) -> Option<u32> {
+        let (lower, upper) = Self::timestamp_window(env);
+        let error = Self::input_error(env, input)
+            .or_else(|| {
+                (input.timestamp < lower || input.timestamp > upper).then(|| ErrorCode::InvalidTimestamp.into())
+            })
+            .or_else(|| Self::signature_error(env, issuer, &Self::signed_message(env, input), &input.signature))
+            .or_else(|| {
+                let used = Self::replay_key(env, issuer, &input.subject, &input.payload_hash);
+                env.storage().persistent().has(&used).then(|| Self::replay_error(env))
+            })
+            .or_else(|| Self::subject_error(env, &input.subject, issuer, false))
+            .or_else(|| {
+                (!Self::attestor_schema_allowed(env, issuer, input.schema_id))
+                    .then(|| ErrorCodeExt::SchemaNotAllowed.into())
+            })
+            .or_else(|| input.schema_id.and_then(|schema_id| Self::schema_issuance_error(env, schema_id, issuer)));
+        error.map(|e| e.get_code())
+    }
 
-        env.events().publish(
-            (symbol_short!("cache"), symbol_short!("invall")),
-            count,
-        );
+    /// Grant, tier and cap checks an issuance under `schema_id` must pass.
+    fn schema_issuance_error(env: &Env, schema_id: u64, issuer: &Address) -> Option<soroban_sdk::Error> {
+        if let Some(e) = Self::schema_grant_error(env, schema_id, issuer) {
+            return Some(e.into());
+        }
+        if Self::is_tier_too_low(env, schema_id, issuer) {
+            return Some(ErrorCodeExt::IssuerTierTooLow.into());
+        }
+        let (count, cap) = Self::schema_cap_usage(env, schema_id);
+        (cap != 0 && count >= cap).then(|| ErrorCode::SchemaCapReached.into())
     }
 
-    // -----------------------------------------------------------------------
-    // Health monitoring
-    // -----------------------------------------------------------------------
+    /// Consent and gate checks on `subject`. A subject that authorized the
+    /// call itself needs no standing consent.
+    fn check_subject(env: &Env, subject: &Address, issuer: &Address, subject_authorized: bool) {
+        if let Some(e) = Self::subject_error(env, subject, issuer, subject_authorized) {
+            panic_with_error!(env, e);
+        }
+    }
 
-    pub fn set_health_failure_threshold(env: Env, threshold: u32) {
-        Self::require_admin(&env);
-        env.storage().instance().set(&key_health_threshold(&env), &threshold);
-        env.storage().instance().extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    fn subject_error(
+        env: &Env,
+        subject: &Address,
+        issuer: &Address,
+        subject_authorized: bool,
+    ) -> Option<soroban_sdk::Error> {
+        if !subject_authorized && Self::lacks_consent(env, subject, issuer) {
+            Some(ErrorCodeExt::SubjectConsentRequired.into())
+        } else if !Self::passes_subject_gate(env, subject) {
+            Some(ErrorCodeExt::SubjectNotEligible.into())
+        } else {
+            None
+        }
     }
 
-    pub fn update_health_status(
-        env: Env,
-        anchor: Address,
-        latency_ms: u64,
-        failure_count: u32,
-        availability_percent: u32,
-    ) {
-        Self::require_admin(&env);
-        let status = HealthStatus {
-            anchor: anchor.clone(),
-            latency_ms,
-            failure_count,
-            availability_percent,
+    /// Checks on an input that do not depend on the issuer.
+    fn check_input(env: &Env, input: &AttestationInput) {
+        let Some(e) = Self::input_error(env, input) else {
+            return;
         };
-        let key = StorageKey::Health(anchor.clone());
-        env.storage().persistent().set(&key, &status);
-        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        if e == soroban_sdk::Error::from(ErrorCode::InvalidTimestamp) {
+            if input.timestamp == 0 {
+                Self::reject_timestamp(env, "timestamp", 0, 1, u64::MAX, e);
+            }
+            Self::reject_timestamp(env, "expires_at", input.expires_at, input.timestamp.saturating_add(1), u64::MAX, e);
+        }
+        if e == soroban_sdk::Error::from(ErrorCodeExt::AttestationExpired) {
+            let now = env.ledger().timestamp();
+            Self::reject_timestamp(env, "expires_at", input.expires_at, now.saturating_add(1), u64::MAX, e);
+        }
+        panic_with_error!(env, e);
+    }
 
-        let threshold: u32 = env
-            .storage()
-            .instance()
-            .get(&key_health_threshold(&env))
-            .unwrap_or(0u32);
+    /// Error the issuer-independent input checks fail with, if any.
+    fn input_error(env: &Env, input: &AttestationInput) -> Option<soroban_sdk::Error> {
+        if let Err(e) = input.validate() {
+            return Some(e.into());
+        }
+        if input.expires_at != 0 && input.expires_at <= env.ledger().timestamp() {
+            return Some(ErrorCodeExt::AttestationExpired.into());
+        }
+        if let Err(e) = validation::check_submission(env, &input.payload_hash, &input.signature) {
+            return Some(e.into());
+        }
+        if let Some(Err(e)) = input.inline_data.as_ref().map(|data| validation::check_inline_data(env, &input.payload_hash, data)) {
+            return Some(e.into());
+        }
+        if let Some(Err(e)) = input.uri.as_ref().map(|uri| validation::check_uri(env, uri)) {
+            return Some(e.into());
+        }
+        None
+    }
 
-        if threshold > 0 && failure_count >= threshold {
-            let meta_key = StorageKey::AnchorMeta(anchor.clone());
-            if let Some(mut meta) = env
-                .storage()
-                .persistent()
-                .get::<_, AnchorMetadata>(&meta_key)
-            {
-                if meta.is_active {
-                    meta.is_active = false;
-                    env.storage().persistent().set(&meta_key, &meta);
-                    env.storage().persistent().extend_ttl(&meta_key, PERSISTENT_TTL, PERSISTENT_TTL);
-                    env.events().publish(
-                        (symbol_short!("anchor"), symbol_short!("deactiv")),
-                        AnchorDeactivated { anchor, failure_count, threshold },
-                    );
-                }
-            }
+    /// Attestation record for a freshly accepted `input`.
+    fn new_attestation(
+        env: &Env,
+        id: u64,
+        issuer: Address,
+        input: &AttestationInput,
+        org_id: Option<u64>,
+        schema_epoch: u32,
+    ) -> Attestation {
+        Attestation {
+            id,
+            issuer,
+            subject: input.subject.clone(),
+            timestamp: input.timestamp,
+            payload_hash: input.payload_hash.clone(),
+            signature: input.signature.clone(),
+            issuer_revoked: false,
+            org_id,
+            schema_id: input.schema_id,
+            ledger: env.ledger().sequence(),
+            schema_epoch,
+            transferable: input.transferable,
+            not_before: input.not_before,
+            jurisdiction: input.jurisdiction.clone(),
+            weight_bps: input.weight_bps,
+            revoked_at: None,
+            value: input.value,
+            expires_at: input.expires_at,
+            subject_kind: input.subject_kind,
+            ref_id: input.ref_id,
+            data: Self::attestation_data(input),
+            status: Status::Active,
+            version: 1,
+            uri: input.uri.clone(),
         }
     }
 
-    pub fn get_health_status(env: Env, anchor: Address) -> Option<HealthStatus> {
+    /// On-chain form of the input's claim: the inline content when supplied,
+    /// otherwise `HashOnly`, leaving `payload_hash` as the only record of it.
+    fn attestation_data(input: &AttestationInput) -> AttestationData {
+        match &input.inline_data {
+            Some(data) => AttestationData::Inline(data.clone()),
+            None => AttestationData::HashOnly,
+        }
+    }
+
+    /// `payload_hash` itself when it is 32 bytes, otherwise its SHA-256.
+    fn payload_digest(env: &Env, payload_hash: &Bytes) -> BytesN<32> {
+        match BytesN::<32>::try_from(payload_hash.clone()) {
+            Ok(hash) => hash,
+            Err(_) => env.crypto().sha256(payload_hash).into(),
+        }
+    }
+
+    /// In consent-required mode, whether `subject` has not granted `issuer`
+    /// standing consent.
+    fn lacks_consent(env: &Env, subject: &Address, issuer: &Address) -> bool {
+        Self::is_consent_required(env.clone()) && !Self::has_consent(env.clone(), subject.clone(), issuer.clone())
+    }
+
+    /// With a subject gate configured, `subject` must hold at least its
+    /// minimum balance of the gate token. A token that fails to report a
+    /// balance counts as none held.
+    fn passes_subject_gate(env: &Env, subject: &Address) -> bool {
+        let Some(gate) = Self::get_subject_gate(env.clone()) else {
+            return true;
+        };
+        let balance = match token::Client::new(env, &gate.token).try_balance(subject) {
+            Ok(Ok(balance)) => balance,
+            _ => 0,
+        };
+        balance >= gate.min_balance
+    }
+
+    /// Token `attestor`'s locked stake is held in.
+    fn stake_token(env: &Env, attestor: &Address) -> Address {
         env.storage()
             .persistent()
-            .get::<_, HealthStatus>(&StorageKey::Health(anchor))
+            .get(&AttestorKey::StakeToken(attestor.clone()))
+            .unwrap_or_else(|| panic_with_error!(env, ErrorCode::StorageCorrupted))
     }
 
-    // -----------------------------------------------------------------------
-    // Routing
-    // -----------------------------------------------------------------------
+    /// Issuer must be a registered attestor outside any suspended tier.
+    fn check_issuer(env: &Env, issuer: &Address) {
+        Self::check_attestor(env, issuer);
+        Self::check_tier_not_suspended(env, issuer);
+    }
 
-    pub fn get_quote(env: Env, anchor: Address, quote_id: u64) -> Option<Quote> {
-        env.storage().persistent().get::<_, Quote>(&StorageKey::Quote(anchor, quote_id))
+    /// Rate-limit, timestamp, signature and replay checks, then store and
+    /// announce the attestation. Input, issuer and schema grant checks must
+    /// already have passed.
+    fn record_checked(env: &Env, issuer: Address, input: &AttestationInput) -> AttestationReceipt {
+        Self::check_signed(env, &issuer, input);
+        Self::commit_record(env, issuer.clone(), &issuer, input)
     }
 
-    pub fn set_anchor_metadata(
-        env: Env,
-        anchor: Address,
-        reputation_score: u32,
-        average_settlement_time: u64,
-        liquidity_score: u32,
-        uptime_percentage: u32,
-        total_volume: u64,
-    ) {
-        Self::require_admin(&env);
-        let meta = AnchorMetadata {
-            anchor: anchor.clone(),
-            reputation_score,
-            average_settlement_time,
-            liquidity_score,
-            uptime_percentage,
-            total_volume,
-            is_active: true,
-        };
-        let meta_key = StorageKey::AnchorMeta(anchor.clone());
-        env.storage().persistent().set(&meta_key, &meta);
-        env.storage().persistent().extend_ttl(&meta_key, PERSISTENT_TTL, PERSISTENT_TTL);
+    /// Rate-limit, timestamp and signature checks on `issuer`'s input.
+    fn check_signed(env: &Env, issuer: &Address, input: &AttestationInput) {
+        Self::charge_rate_limit(env, issuer);
+        Self::check_timestamp(env, input.timestamp);
+        Self::verify_attestation_signature(env, issuer, &Self::signed_message(env, input), &input.signature);
+    }
 
-        // Maintain ANCHLIST
-        let list_key = key_anchor_list(&env);
-        let mut list: Vec<Address> = env.storage().persistent()
-            .get::<_, Vec<Address>>(&list_key)
-            .unwrap_or_else(|| Vec::new(&env));
-        if !list.contains(&anchor) {
-            list.push_back(anchor);
-            env.storage().persistent().set(&list_key, &list);
-            env.storage().persistent().extend_ttl(&list_key, PERSISTENT_TTL, PERSISTENT_TTL);
+    /// What the input's signature covers: the payload hash, bound to the
+    /// referenced attestation when `ref_id` is set.
+    fn signed_message(env: &Env, input: &AttestationInput) -> Bytes {
+        match input.ref_id {
+            Some(ref_id) => Bytes::from(compute_ref_binding_hash(env, &input.payload_hash, ref_id)),
+            None => input.payload_hash.clone(),
         }
     }
 
-    pub fn get_routing_anchors(env: Env) -> Vec<Address> {
-        let list_key = key_anchor_list(&env);
-        env.storage().persistent()
-            .get::<_, Vec<Address>>(&list_key)
-            .unwrap_or_else(|| Vec::new(&env))
+    fn charge_rate_limit(env: &Env, issuer: &Address) {
+        if let Err(e) = crate::rate_limiter::RateLimiter::check_and_increment(env, issuer) {
+            panic_with_error!(env, e);
+        }
     }
 
-    /// Select the best anchor for a transaction and return its `Quote`.
-    ///
-    /// Candidates are filtered to those that are active, meet `min_reputation`,
-    /// have a non-expired quote, and whose quote range covers `request.amount`.
-    ///
-    /// The winner is then chosen by `options.strategy[0]`:
-    ///
-    /// - `"LowestFee"` — lowest `fee_percentage`
-    /// - `"FastestSettlement"` — lowest `average_settlement_time`
-    /// - `"HighestReputation"` — highest `reputation_score`
-    ///
-    /// An empty `strategy` vec panics with `NoQuotesAvailable`.
-    /// An unrecognised symbol returns the first candidate in iteration order.
-    pub fn route_transaction(env: Env, options: RoutingOptions) -> Quote {
-        let now = env.ledger().timestamp();
-        let list_key = key_anchor_list(&env);
-        let anchors: Vec<Address> = env.storage().persistent()
-            .get::<_, Vec<Address>>(&list_key)
-            .unwrap_or_else(|| Vec::new(&env));
+    /// Replay check, then charge `payer` the fee, store and announce the
+    /// attestation.
+    fn commit_record(env: &Env, issuer: Address, payer: &Address, input: &AttestationInput) -> AttestationReceipt {
+        let used_key = Self::replay_key(env, &issuer, &input.subject, &input.payload_hash);
+        if env.storage().persistent().has(&used_key) {
+            Self::reject_replay(env, &issuer, &input.subject, &input.payload_hash);
+        }
 
-        let mut candidates: Vec<Quote> = Vec::new(&env);
-        for anchor in anchors.iter() {
-            // Check reputation filter
-            let meta_key = StorageKey::AnchorMeta(anchor.clone());
-            let meta: AnchorMetadata = match env.storage().persistent().get(&meta_key) {
-                Some(m) => m,
-                None => continue,
-            };
-            if !meta.is_active { continue; }
-            if meta.reputation_score < options.min_reputation { continue; }
+        let id = Self::next_attestation_id(env, &issuer);
+        let fee_paid = Self::collect_fee(env, Some(id), payer, &issuer, input.schema_id);
+        let chain_hash = Self::store_attestation(env, id, issuer.clone(), input);
 
-            // Check KYC requirement filter
-            if options.require_kyc {
-                let services_key = StorageKey::Services(anchor.clone());
-                let services_record: AnchorServices = match env.storage().persistent().get(&services_key) {
-                    Some(sr) => sr,
-                    None => continue,
-                };
-                if !services_record.services.contains(SERVICE_KYC) {
-                    continue;
-                }
-            }
+        env.storage().persistent().set(&used_key, &id);
+        env.storage().persistent().extend_ttl(&used_key, PERSISTENT_TTL, PERSISTENT_TTL);
 
-            // Get latest quote for this anchor
-            let lq_key = StorageKey::LatestQuote(anchor.clone());
-            let quote_id: u64 = match env.storage().persistent().get(&lq_key) {
-                Some(id) => id,
-                None => continue,
-            };
-            let q_key = StorageKey::Quote(anchor.clone(), quote_id);
-            let quote: Quote = match env.storage().persistent().get(&q_key) {
-                Some(q) => q,
-                None => continue,
-            };
+        Self::emit_attest_recorded(env, id, &issuer, input);
 
-            if quote.valid_until <= now {
-                env.events().publish(
-                    (symbol_short!("quote"),),
-                    crate::events::QuoteExpiredEvent {
-                        anchor: anchor.clone(),
-                        quote_id,
-                        valid_until: quote.valid_until,
-                    },
-                );
-                continue;
-            }
-            if options.request.amount < quote.minimum_amount || options.request.amount > quote.maximum_amount {
-                continue;
-            }
+        AttestationReceipt { id, ledger: env.ledger().sequence(), chain_hash, fee_paid }
+    }
+
+    /// Transfer `issuer`'s effective fee for attestation `id` from `payer`
+    /// to the fee recipient, or into escrow while fee escrow is on, failing
+    /// with `FeePaymentFailed` if the token transfer does not go through.
+    /// Bundle claims and ephemeral attestations (`id` of `None`) are never
+    /// escrowed. Nothing is
+    /// collected until `set_fee` has configured a token. Returns the fee
+    /// paid.
+    fn collect_fee(env: &Env, id: Option<u64>, payer: &Address, issuer: &Address, schema_id: Option<u64>) -> i128 {
+        let Some(config) = Self::get_fee(env.clone()) else {
+            return 0;
+        };
+        let fee = Self::get_effective_fee(env.clone(), issuer.clone(), schema_id);
+        if fee <= 0 {
+            return 0;
+        }
+        let escrow = id.zip(Self::get_fee_escrow(env.clone()));
+        let payee = match escrow {
+            Some(_) => env.current_contract_address(),
+            None => config.recipient.clone(),
+        };
+        let client = token::Client::new(env, &config.token);
+        if !matches!(client.try_transfer(payer, &payee, &fee), Ok(Ok(()))) {
+            panic_with_error!(env, ErrorCodeExt::FeePaymentFailed);
+        }
+        if let Some((id, escrow)) = escrow {
+            let key = DisputeKey::EscrowedFee(id);
+            let held = EscrowedFee {
+                token: config.token,
+                amount: fee,
+                recipient: config.recipient,
+                challenger_pool: escrow.challenger_pool,
+                release_at: env.ledger().timestamp().saturating_add(escrow.window),
+            };
+            env.storage().persistent().set(&key, &held);
+            env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        }
+        fee
+    }
 
-            candidates.push_back(quote);
+    /// Apply `change` now when no schema change delay is set; otherwise queue
+    /// it and announce it with a `schema/pending` event. Due changes are
+    /// applied first; a queue already holding `MAX_PENDING_SCHEMA_CHANGES`
+    /// entries fails with `ValidationError`.
+    fn schedule_schema_change(env: &Env, schema_id: u64, change: SchemaChange) {
+        Self::apply_due_schema_changes(env, schema_id);
+        let delay = Self::get_schema_change_delay(env.clone());
+        if delay == 0 {
+            Self::apply_schema_change(env, schema_id, change);
+            return;
+        }
+        let effective_at = env.ledger().timestamp().saturating_add(delay);
+        let key = SchemaKey::PendingChanges(schema_id);
+        let mut pending = Self::get_pending_schema_changes(env.clone(), schema_id);
+        if pending.len() >= MAX_PENDING_SCHEMA_CHANGES {
+            panic_with_error!(env, ErrorCode::ValidationError);
+        }
+        pending.push_back(PendingSchemaChange { change: change.clone(), effective_at });
+        env.storage().persistent().set(&key, &pending);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish(
+            (symbol_short!("schema"), symbol_short!("pending"), schema_id),
+            SchemaChangePending { schema_id, change, effective_at },
+        );
+    }
 
-            // Stop adding candidates if we've reached max_anchors limit
-            if options.max_anchors > 0 && candidates.len() >= options.max_anchors as usize {
+    /// Queued changes on `schema_id` that are due, in the order
+    /// `apply_due_schema_changes` would apply them.
+    fn due_schema_changes(env: &Env, schema_id: u64) -> Vec<SchemaChange> {
+        let now = env.ledger().timestamp();
+        let mut due = Vec::new(env);
+        for entry in Self::get_pending_schema_changes(env.clone(), schema_id).iter() {
+            if entry.effective_at > now {
                 break;
             }
+            due.push_back(entry.change);
         }
+        due
+    }
 
-        if candidates.is_empty() {
-            panic_with_error!(&env, ErrorCode::NoQuotesAvailable);
+    /// Apply the queued changes on `schema_id` that are due, in queue order.
+    fn apply_due_schema_changes(env: &Env, schema_id: u64) -> u32 {
+        let key = SchemaKey::PendingChanges(schema_id);
+        let Some(pending) = env.storage().persistent().get::<_, Vec<PendingSchemaChange>>(&key) else {
+            return 0;
+        };
+        let now = env.ledger().timestamp();
+        let mut remaining = Vec::new(env);
+        let mut applied = 0u32;
+        for entry in pending.iter() {
+            // Keep queue order: nothing jumps ahead of an entry still waiting.
+            if entry.effective_at > now || !remaining.is_empty() {
+                remaining.push_back(entry);
+                continue;
+            }
+            Self::apply_schema_change(env, schema_id, entry.change);
+            applied += 1;
         }
-
-        let strategy_sym = options.strategy.get(0)
-            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::NoQuotesAvailable));
-
-        let lowest_fee_sym = Symbol::new(&env, "LowestFee");
-        let fastest_sym = Symbol::new(&env, "FastestSettlement");
-        let reputation_sym = Symbol::new(&env, "HighestReputation");
-        let balanced_sym = Symbol::new(&env, "Balanced");
-
-        // Validate that the strategy symbol is recognized
-        if strategy_sym != lowest_fee_sym 
-            && strategy_sym != fastest_sym 
-            && strategy_sym != reputation_sym 
-            && strategy_sym != balanced_sym {
-            panic_with_error!(&env, ErrorCode::InvalidStrategy);
+        if remaining.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else if applied > 0 {
+            env.storage().persistent().set(&key, &remaining);
+            env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
         }
+        applied
+    }
 
-        let mut best: Quote = candidates.get(0).unwrap();
-
-        if strategy_sym == lowest_fee_sym {
-            for q in candidates.iter() {
-                if q.fee_percentage < best.fee_percentage {
-                    best = q;
-                }
-            }
-        } else if strategy_sym == fastest_sym {
-            // Need settlement time from metadata
-            let meta_key = StorageKey::AnchorMeta(best.anchor.clone());
-            let mut best_time: u64 = env.storage().persistent()
-                .get::<_, AnchorMetadata>(&meta_key)
-                .map(|m| m.average_settlement_time)
-                .unwrap_or(u64::MAX);
-            for q in candidates.iter() {
-                let mk = StorageKey::AnchorMeta(q.anchor.clone());
-                let t = env.storage().persistent()
-                    .get::<_, AnchorMetadata>(&mk)
-                    .map(|m| m.average_settlement_time)
-                    .unwrap_or(u64::MAX);
-                if t < best_time {
-                    best_time = t;
-                    best = q;
-                }
+    fn apply_schema_change(env: &Env, schema_id: u64, change: SchemaChange) {
+        match change {
+            SchemaChange::Fee(fee) => {
+                let mut schema = Self::load_schema(env, schema_id);
+                schema.fee = fee;
+                Self::save_schema(env, &schema);
             }
-        } else if strategy_sym == reputation_sym {
-            let meta_key = StorageKey::AnchorMeta(best.anchor.clone());
-            let mut best_rep: u32 = env.storage().persistent()
-                .get::<_, AnchorMetadata>(&meta_key)
-                .map(|m| m.reputation_score)
-                .unwrap_or(0);
-            for q in candidates.iter() {
-                let mk = StorageKey::AnchorMeta(q.anchor.clone());
-                let rep = env.storage().persistent()
-                    .get::<_, AnchorMetadata>(&mk)
-                    .map(|m| m.reputation_score)
-                    .unwrap_or(0);
-                if rep > best_rep {
-                    best_rep = rep;
-                    best = q;
-                }
+            SchemaChange::Resolver(resolver) => {
+                let mut schema = Self::load_schema(env, schema_id);
+                schema.resolver = resolver;
+                Self::save_schema(env, &schema);
             }
-        } else if strategy_sym == balanced_sym {
-            // score = (40_000 / fee_percentage) + (30_000 / settlement_time) + (reputation * 3_000 / 10_000)
-            // All terms are dimensionless integers; higher score is better.
-            // fee_percentage = 0 or settlement_time = 0 contribute 0 to avoid division by zero.
-            let balanced_score = |env: &Env, q: &Quote| -> u64 {
-                let mk = (symbol_short!("ANCHMETA"), q.anchor.clone());
-                let meta: AnchorMetadata = env.storage().persistent()
-                    .get(&mk)
-                    .unwrap_or(AnchorMetadata {
-                        anchor: q.anchor.clone(),
-                        reputation_score: 0,
-                        average_settlement_time: 0,
-                        liquidity_score: 0,
-                        uptime_percentage: 0,
-                        total_volume: 0,
-                        is_active: false,
-                    });
-                let fee_term = if q.fee_percentage > 0 { 40_000 / q.fee_percentage as u64 } else { 0 };
-                let time_term = 30_000u64.checked_div(meta.average_settlement_time).unwrap_or(0);
-                // Scale reputation (0–10_000) to a 0–3_000 range to match the weight of other terms.
-                let rep_term = meta.reputation_score as u64 * 3_000 / 10_000;
-                fee_term + time_term + rep_term
-            };
-            let mut best_score = balanced_score(&env, &best);
-            for q in candidates.iter() {
-                let score = balanced_score(&env, &q);
-                if score > best_score {
-                    best_score = score;
-                    best = q;
-                }
+            SchemaChange::AuthorizeIssuer(attestor, expires_at) => {
+                Self::put_schema_issuer(env, schema_id, attestor, expires_at);
             }
+            SchemaChange::RemoveIssuer(attestor) => Self::drop_schema_issuer(env, schema_id, attestor),
         }
+    }
 
-        let strategy_str = if strategy_sym == lowest_fee_sym {
-            String::from_str(&env, "LowestFee")
-        } else if strategy_sym == fastest_sym {
-            String::from_str(&env, "FastestSettlement")
-        } else if strategy_sym == reputation_sym {
-            String::from_str(&env, "HighestReputation")
-        } else {
-            String::from_str(&env, "Balanced")
+    /// Remove `attestor` from the schema allowlist and clear its grant expiry.
+    /// A no-op when the attestor is no longer listed.
+    fn drop_schema_issuer(env: &Env, schema_id: u64, attestor: Address) {
+        let key = SchemaKey::Issuers(schema_id);
+        let mut issuers: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(env));
+        let Some(idx) = issuers.first_index_of(&attestor) else {
+            return;
         };
+        issuers.remove(idx);
+        env.storage().persistent().set(&key, &issuers);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.storage()
+            .persistent()
+            .remove(&SchemaKey::GrantExpiry(schema_id, attestor.clone()));
+        env.events().publish(
+            (symbol_short!("schema"), symbol_short!("iss_rm"), schema_id),
+            SchemaIssuerUpdated { schema_id, attestor, authorized: false, expires_at: 0 },
+        );
+    }
 
+    /// Add `attestor` to the schema allowlist (if absent) and set its grant
+    /// expiry; `expires_at == 0` makes the grant permanent.
+    fn put_schema_issuer(env: &Env, schema_id: u64, attestor: Address, expires_at: u64) {
+        Self::check_attestor(env, &attestor);
+        let key = SchemaKey::Issuers(schema_id);
+        let mut issuers: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(env));
+        if !issuers.contains(&attestor) {
+            issuers.push_back(attestor.clone());
+            env.storage().persistent().set(&key, &issuers);
+            env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        }
+        let expiry_key = SchemaKey::GrantExpiry(schema_id, attestor.clone());
+        if expires_at == 0 {
+            env.storage().persistent().remove(&expiry_key);
+        } else {
+            env.storage().persistent().set(&expiry_key, &expires_at);
+            env.storage().persistent().extend_ttl(&expiry_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        }
         env.events().publish(
-            (symbol_short!("routing"),),
-            crate::events::RoutingDecisionEvent {
-                anchor: best.anchor.clone(),
-                strategy: strategy_str,
-                quote_id: best.quote_id,
-                ledger_sequence: env.ledger().sequence(),
-            },
+            (symbol_short!("schema"), symbol_short!("iss_add"), schema_id),
+            SchemaIssuerUpdated { schema_id, attestor, authorized: true, expires_at },
         );
+    }
 
-        best
+    fn is_schema_grant_expired(env: &Env, schema_id: u64, attestor: &Address) -> bool {
+        match Self::get_schema_grant_expiry(env.clone(), schema_id, attestor.clone()) {
+            Some(expires_at) => env.ledger().timestamp() >= expires_at,
+            None => false,
+        }
     }
 
-    // -----------------------------------------------------------------------
-    // Anchor Info Discovery
-    // -----------------------------------------------------------------------
+    /// Record-time enforcement of the schema allowlist. Schemas with no
+    /// authorized issuers stay open to any registered attestor.
+    fn check_schema_grant(env: &Env, schema_id: u64, issuer: &Address) {
+        if let Some(e) = Self::schema_grant_error(env, schema_id, issuer) {
+            panic_with_error!(env, e);
+        }
+    }
 
-    pub fn fetch_anchor_info(env: Env, anchor: Address, toml_data: StellarToml, network_passphrase: String, ttl_override: Option<u64>) {
-        anchor.require_auth();
+    fn schema_grant_error(env: &Env, schema_id: u64, issuer: &Address) -> Option<ErrorCode> {
+        Self::apply_due_schema_changes(env, schema_id);
+        let issuers: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&SchemaKey::Issuers(schema_id))
+            .unwrap_or_else(|| Vec::new(env));
+        if issuers.is_empty() {
+            None
+        } else if !issuers.contains(issuer) {
+            Some(ErrorCode::IssuerNotAuthorizedForSchema)
+        } else if Self::is_schema_grant_expired(env, schema_id, issuer) {
+            Some(ErrorCode::SchemaGrantExpired)
+        } else {
+            None
+        }
+    }
 
-        // Validate network_passphrase matches a known network before caching.
-        // Prevents caching a TOML from the wrong network, which would cause
-        // SEP-10 verification to succeed against the wrong signing key.
-        let np_len = network_passphrase.len() as usize;
-        if np_len > 256 {
-            panic_with_error!(&env, ErrorCode::ValidationError);
+    /// Store `public_key` as `attestor`'s ed25519 signing key, replacing any
+    /// secp256k1 key, and emit `key_set`.
+    fn put_attestor_key(env: &Env, attestor: &Address, public_key: &BytesN<32>) {
+        env.storage().persistent().remove(&AttestorKey::Secp256k1Key(attestor.clone()));
+        let key = AttestorKey::SigningKey(attestor.clone());
+        env.storage().persistent().set(&key, public_key);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish(
+            (symbol_short!("attestor"), symbol_short!("key_set"), attestor.clone()),
+            public_key.clone(),
+        );
+    }
+
+    /// Validate `public_key` for `key_type` (`ValidationError` on a wrong
+    /// length or a compressed secp256k1 key) and store it as the attestor's
+    /// only signing key.
+    fn put_typed_key(env: &Env, attestor: &Address, key_type: KeyType, public_key: Bytes) {
+        match key_type {
+            KeyType::Ed25519 => {
+                let public_key: BytesN<32> = public_key
+                    .try_into()
+                    .unwrap_or_else(|_| panic_with_error!(env, ErrorCode::ValidationError));
+                Self::put_attestor_key(env, attestor, &public_key);
+            }
+            KeyType::Secp256k1 => {
+                let public_key: BytesN<65> = public_key
+                    .try_into()
+                    .unwrap_or_else(|_| panic_with_error!(env, ErrorCode::ValidationError));
+                if public_key.get(0) != Some(0x04) {
+                    panic_with_error!(env, ErrorCode::ValidationError);
+                }
+                Self::put_secp256k1_key(env, attestor, &public_key);
+            }
         }
-        let mut np_buf = [0u8; 256];
-        network_passphrase.copy_into_slice(&mut np_buf[..np_len]);
-        let np_str = core::str::from_utf8(&np_buf[..np_len]).unwrap_or("");
+    }
 
-        const MAINNET: &str = "Public Global Stellar Network ; September 2015";
-        const TESTNET: &str = "Test SDF Network ; September 2015";
-        if np_str != MAINNET && np_str != TESTNET {
-            panic_with_error!(&env, ErrorCode::ValidationError);
+    /// `attestor`'s secp256k1 key as plain bytes, empty when it has none.
+    fn secp256k1_key_bytes(env: &Env, attestor: Address) -> Bytes {
+        Self::get_attestor_secp256k1_key(env.clone(), attestor).map_or_else(|| Bytes::new(env), Bytes::from)
+    }
+
+    fn put_secp256k1_key(env: &Env, attestor: &Address, public_key: &BytesN<65>) {
+        env.storage().persistent().remove(&AttestorKey::SigningKey(attestor.clone()));
+        let key = AttestorKey::Secp256k1Key(attestor.clone());
+        env.storage().persistent().set(&key, public_key);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish(
+            (symbol_short!("attestor"), symbol_short!("key_set"), attestor.clone()),
+            public_key.clone(),
+        );
+    }
+
+    /// Persist a new attestor registration, update metrics counters, and emit
+    /// the registration event.
+    fn add_attestor_record(env: &Env, attestor: &Address) {
+        Self::require_writable(env);
+        let key = StorageKey::Attestor(attestor.clone());
+        if env.storage().persistent().has(&key) {
+            panic_with_error!(env, ErrorCode::AttestorAlreadyRegistered);
         }
+        Self::check_not_deactivated(env, attestor);
+        env.storage().persistent().set(&key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        Self::bump_instance_counter(env, &key_attestor_count(env), true);
+        // A wind-down from an earlier removal must not release stake locked now.
+        env.storage().persistent().remove(&StorageKey::WindDown(attestor.clone()));
+        let registered_key = AttestorKey::RegisteredAt(attestor.clone());
+        env.storage().persistent().set(&registered_key, &env.ledger().timestamp());
+        env.storage().persistent().extend_ttl(&registered_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        Self::index_attestor(env, attestor);
+        env.events().publish(
+            (symbol_short!("attestor"), symbol_short!("reg")),
+            AttestorRegistered(attestor.clone()),
+        );
+    }
 
-        // Validate that the TOML's own network_passphrase matches the supplied one.
-        let toml_np_len = toml_data.network_passphrase.len() as usize;
-        if toml_np_len > 256 {
-            panic_with_error!(&env, ErrorCode::ValidationError);
+    /// Shared body of `add_attestors` and `onboard_attestors`.
+    fn add_attestor_batch(env: &Env, entries: &Vec<(Address, String, Address)>, strict: bool) -> u32 {
+        let mut attestors = Vec::new(env);
+        for (attestor, _, _) in entries.iter() {
+            attestors.push_back(attestor);
         }
-        let mut toml_np_buf = [0u8; 256];
-        toml_data.network_passphrase.copy_into_slice(&mut toml_np_buf[..toml_np_len]);
-        let toml_np_str = core::str::from_utf8(&toml_np_buf[..toml_np_len]).unwrap_or("");
-        if toml_np_str != np_str {
-            panic_with_error!(&env, ErrorCode::ValidationError);
+        Self::check_attestor_batch(env, &attestors);
+        let mut added = 0;
+        for (attestor, sep10_token, sep10_issuer) in entries.iter() {
+            let registered = env.storage().persistent().has(&StorageKey::Attestor(attestor.clone()));
+            let deactivated = env.storage().persistent().has(&AttestorKey::DeactivatedAt(attestor.clone()));
+            if (registered || deactivated) && !strict {
+                continue;
+            }
+            Self::verify_sep10_token_matches_attestor(env, &sep10_token, &sep10_issuer, &attestor);
+            Self::add_attestor_record(env, &attestor);
+            added += 1;
         }
+        added
+    }
 
-        // Reject non-HTTPS endpoints to prevent MITM exposure of anchor metadata.
-        let ts_len = toml_data.transfer_server.len() as usize;
-        if ts_len > 2048 {
-            panic_with_error!(&env, ErrorCode::InvalidEndpointFormat);
+    /// Bulk attestor lists hold 1 to `MAX_BATCH_SIZE` entries and never the
+    /// contract itself.
+    fn check_attestor_batch(env: &Env, attestors: &Vec<Address>) {
+        if attestors.is_empty() || attestors.len() > MAX_BATCH_SIZE {
+            panic_with_error!(env, ErrorCode::ValidationError);
         }
-        let mut ts_buf = [0u8; 2048];
-        toml_data.transfer_server.copy_into_slice(&mut ts_buf[..ts_len]);
-        let transfer_server_str = core::str::from_utf8(&ts_buf[..ts_len]).unwrap_or("");
-        if crate::validate_anchor_domain(transfer_server_str).is_err() {
-            panic_with_error!(&env, ErrorCode::InvalidEndpointFormat);
+        if attestors.contains(env.current_contract_address()) {
+            panic_with_error!(env, ErrorCode::ValidationError);
         }
+    }
 
-        // Validate decimals for each currency: must be in range 0..=18.
-        for asset in toml_data.currencies.iter() {
-            if asset.decimals > 18 {
-                panic_with_error!(&env, ErrorCode::ValidationError);
-            }
+    /// On an address's first registration, create its profile and append it
+    /// to the attestor index. Re-registration after revocation keeps both.
+    fn index_attestor(env: &Env, attestor: &Address) {
+        let info_key = AttestorKey::Info(attestor.clone());
+        if env.storage().persistent().has(&info_key) {
+            return;
         }
-
-        let now = env.ledger().timestamp();
-        let ttl_seconds = ttl_override.unwrap_or(3600);
-        let cached = CachedToml {
-            toml: toml_data,
-            cached_at: now,
-            ttl_seconds,
+        let info = AttestorInfo {
+            name: Bytes::new(env),
+            public_key: BytesN::from_array(env, &[0u8; 32]),
+            secp256k1_key: Bytes::new(env),
+            registered_at: env.ledger().timestamp(),
+            uri: Bytes::new(env),
         };
-        let key = StorageKey::TomlCache(anchor.clone());
-        let ledger_ttl = if ttl_seconds as u32 > MIN_TEMP_TTL { ttl_seconds as u32 } else { MIN_TEMP_TTL };
-        env.storage().temporary().set(&key, &cached);
-        env.storage().temporary().extend_ttl(&key, ledger_ttl, ledger_ttl);
+        env.storage().persistent().set(&info_key, &info);
+        env.storage().persistent().extend_ttl(&info_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        let count: u64 = env.storage().persistent().get(&AttestorKey::ListCount).unwrap_or(0);
+        let entry_key = AttestorKey::ListEntry(count);
+        env.storage().persistent().set(&entry_key, attestor);
+        env.storage().persistent().extend_ttl(&entry_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.storage().persistent().set(&AttestorKey::ListCount, &(count + 1));
+        env.storage().persistent().extend_ttl(&AttestorKey::ListCount, PERSISTENT_TTL, PERSISTENT_TTL);
+    }
 
-        Self::add_to_cached_anchors(&env, &anchor);
+    /// Remove an attestor registration and mark it revoked so historical
+    /// attestations surface `issuer_revoked = true`. Starts the stake
+    /// wind-down, which is returned.
+    fn revoke_attestor_record(env: &Env, attestor: &Address) -> WindDown {
+        Self::require_writable(env);
+        let key = StorageKey::Attestor(attestor.clone());
+        if !env.storage().persistent().has(&key) {
+            panic_with_error!(env, ErrorCode::AttestorNotRegistered);
+        }
+        env.storage().persistent().remove(&key);
+        let revoked_key = StorageKey::AttestorRevoked(attestor.clone());
+        env.storage().persistent().set(&revoked_key, &true);
+        env.storage().persistent().extend_ttl(&revoked_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        Self::bump_instance_counter(env, &key_attestor_count(env), false);
+        Self::bump_instance_counter(env, &key_revoked_attestor_count(env), true);
+        Self::leave_org(env, attestor);
+        env.events().publish(
+            (symbol_short!("attestor"), symbol_short!("revoked")),
+            AttestorRevoked(attestor.clone()),
+        );
+        Self::start_wind_down(env, attestor)
     }
 
-    pub fn get_anchor_toml(env: Env, anchor: Address) -> StellarToml {
-        let key = StorageKey::TomlCache(anchor);
-        let cached: CachedToml = env.storage().temporary().get(&key)
-            .unwrap_or_else(|| panic_with_error!(&env, ErrorCode::CacheNotFound));
+    /// Store a `WindDown` for `attestor` releasing at the end of the exit
+    /// challenge window, replacing any earlier one.
+    fn start_wind_down(env: &Env, attestor: &Address) -> WindDown {
         let now = env.ledger().timestamp();
-        if cached.cached_at + cached.ttl_seconds <= now {
-            panic_with_error!(&env, ErrorCode::CacheExpired);
-        }
-        cached.toml
+        let wind_down = WindDown {
+            attestor: attestor.clone(),
+            started_at: now,
+            release_at: now.saturating_add(Self::get_exit_challenge_window(env.clone())),
+        };
+        let key = StorageKey::WindDown(attestor.clone());
+        env.storage().persistent().set(&key, &wind_down);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.events().publish(
+            (symbol_short!("attestor"), symbol_short!("winddown"), attestor.clone()),
+            wind_down.clone(),
+        );
+        wind_down
     }
 
-    pub fn refresh_anchor_info(env: Env, anchor: Address, force: bool) {
-        anchor.require_auth();
-        let key = StorageKey::TomlCache(anchor);
-        
-        if force {
-            env.storage().temporary().remove(&key);
-        } else if let Some(cached) = env.storage().temporary().get::<_, CachedToml>(&key) {
-            let now = env.ledger().timestamp();
-            if cached.cached_at + cached.ttl_seconds <= now {
-                env.storage().temporary().remove(&key);
-            }
+    /// Drop `attestor` from its organization, if any.
+    fn leave_org(env: &Env, attestor: &Address) {
+        let org_key = StorageKey::AttestorOrg(attestor.clone());
+        if let Some(org_id) = env.storage().persistent().get::<_, u64>(&org_key) {
+            let mut org = Self::load_org(env, org_id);
+            org.attestor_count = org.attestor_count.saturating_sub(1);
+            Self::save_org(env, &org);
+            env.storage().persistent().remove(&org_key);
         }
     }
 
-    pub fn get_anchor_assets(env: Env, anchor: Address) -> Result<Vec<String>, ErrorCode> {
-        let key = StorageKey::TomlCache(anchor.clone());
-        if !env.storage().temporary().has(&key) {
-            return Err(ErrorCode::CacheNotFound);
-        }
-        let toml = Self::get_anchor_toml(env.clone(), anchor);
-        let mut assets = Vec::new(&env);
-        for asset in toml.currencies.iter() {
-            assets.push_back(asset.code.clone());
-        }
-        Ok(assets)
+    fn load_org(env: &Env, org_id: u64) -> Organization {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::Org(org_id))
+            .unwrap_or_else(|| panic_with_error!(env, ErrorCode::OrgNotFound))
     }
 
-    /// Return the fiat currencies supported by `anchor` from its cached stellar.toml.
-    /// Returns `Err(ErrorCode::CacheNotFound)` when no TOML has been cached for this anchor.
-    pub fn get_anchor_currencies(
-        env: Env,
-        anchor: Address,
-    ) -> Result<Vec<FiatCurrency>, ErrorCode> {
-        let key = StorageKey::TomlCache(anchor.clone());
-        if !env.storage().temporary().has(&key) {
-            return Err(ErrorCode::CacheNotFound);
+    /// Return the deposit `relier` locked to pin attestation `id`, if any.
+    fn refund_pin_deposit(env: &Env, id: u64, relier: &Address) {
+        let key = PinKey::Deposit(id, relier.clone());
+        if let Some(deposit) = env.storage().persistent().get::<_, PinDeposit>(&key) {
+            env.storage().persistent().remove(&key);
+            token::Client::new(env, &deposit.token).transfer(&env.current_contract_address(), relier, &deposit.amount);
         }
-        let toml = Self::get_anchor_toml(env.clone(), anchor);
-        Ok(toml.fiat_currencies)
     }
 
-    pub fn get_anchor_asset_info(env: Env, anchor: Address, asset_code: String) -> AssetInfo {
-        let toml = Self::get_anchor_toml(env.clone(), anchor);
-        for asset in toml.currencies.iter() {
-            if asset.code == asset_code {
-                return asset;
-            }
+    fn save_reliers(env: &Env, id: u64, reliers: &Vec<Address>) {
+        let key = StorageKey::AttestationReliers(id);
+        if reliers.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, reliers);
+            env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
         }
-        panic_with_error!(&env, ErrorCode::ValidationError);
     }
 
-    pub fn get_anchor_deposit_limits(env: Env, anchor: Address, asset_code: String) -> Result<(u64, u64), ErrorCode> {
-        let key = StorageKey::TomlCache(anchor.clone());
-        if !env.storage().temporary().has(&key) {
-            return Err(ErrorCode::CacheNotFound);
+    /// Fail with `SchemaNotFound` unless `schema_id` names a registered
+    /// schema. Without `is_schema_required`, a missing ID or an unregistered
+    /// one outside the registry's range (`REGISTERED_SCHEMA_BIT`) also passes.
+    fn check_schema_reference(env: &Env, schema_id: Option<u64>) {
+        let required = Self::is_schema_required(env.clone());
+        match schema_id {
+            Some(schema_id) if env.storage().persistent().has(&SchemaKey::Record(schema_id)) => {}
+            Some(schema_id) if !required && schema_id & REGISTERED_SCHEMA_BIT == 0 => {}
+            None if !required => {}
+            _ => panic_with_error!(env, ErrorCode::SchemaNotFound),
         }
-        let asset = Self::get_anchor_asset_info(env, anchor, asset_code);
-        Ok((asset.deposit_min_amount, asset.deposit_max_amount))
     }
 
-    pub fn get_anchor_withdrawal_limits(env: Env, anchor: Address, asset_code: String) -> Result<(u64, u64), ErrorCode> {
-        let key = StorageKey::TomlCache(anchor.clone());
-        if !env.storage().temporary().has(&key) {
-            return Err(ErrorCode::CacheNotFound);
+    /// A referenced attestation must exist, come from the same issuer and be
+    /// about the same subject.
+    fn check_attestation_ref(env: &Env, issuer: &Address, subject: &Address, ref_id: Option<u64>) {
+        let Some(ref_id) = ref_id else {
+            return;
+        };
+        let referenced: Attestation = env
+            .storage()
+            .persistent()
+            .get(&StorageKey::Attest(ref_id))
+            .unwrap_or_else(|| panic_with_error!(env, ErrorCode::AttestationNotFound));
+        if referenced.issuer != *issuer || referenced.subject != *subject {
+            panic_with_error!(env, ErrorCode::ValidationError);
         }
-        let asset = Self::get_anchor_asset_info(env, anchor, asset_code);
-        Ok((asset.withdrawal_min_amount, asset.withdrawal_max_amount))
     }
 
-    pub fn get_anchor_deposit_fees(env: Env, anchor: Address, asset_code: String) -> (u64, u32) {
-        let asset = Self::get_anchor_asset_info(env, anchor, asset_code);
-        (asset.deposit_fee_fixed, asset.deposit_fee_percent)
+    fn load_schema(env: &Env, schema_id: u64) -> Schema {
+        env.storage()
+            .persistent()
+            .get(&SchemaKey::Record(schema_id))
+            .unwrap_or_else(|| panic_with_error!(env, ErrorCode::SchemaNotFound))
     }
 
-    pub fn get_anchor_withdrawal_fees(env: Env, anchor: Address, asset_code: String) -> (u64, u32) {
-        let asset = Self::get_anchor_asset_info(env, anchor, asset_code);
-        (asset.withdrawal_fee_fixed, asset.withdrawal_fee_percent)
+    /// Whether `schema_id` has a record or any state that would carry over
+    /// to a schema registered under it.
+    fn schema_state_exists(env: &Env, schema_id: u64) -> bool {
+        let storage = env.storage().persistent();
+        storage.has(&SchemaKey::Record(schema_id))
+            || storage.has(&SchemaKey::Issuers(schema_id))
+            || storage.has(&SchemaKey::AttestationCount(schema_id))
+            || storage.has(&SchemaKey::SupersededBy(schema_id))
+            || storage.has(&SchemaKey::PendingChanges(schema_id))
     }
 
-    pub fn anchor_supports_deposits(
-        env: Env,
-        anchor: Address,
-        asset_code: String,
-    ) -> bool {
-        Self::get_anchor_asset_info(env, anchor, asset_code).deposit_enabled
+    fn save_schema(env: &Env, schema: &Schema) {
+        let key = SchemaKey::Record(schema.id);
+        env.storage().persistent().set(&key, schema);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
     }
 
-    pub fn anchor_supports_withdrawals(
-        env: Env,
-        anchor: Address,
-        asset_code: String,
-    ) -> bool {
-        Self::get_anchor_asset_info(env, anchor, asset_code).withdrawal_enabled
+    /// Require `caller` to be the global admin, the schema owner, or a
+    /// delegate holding `right` on `schema_id`. Unregistered schema IDs are
+    /// governed by the global admin alone.
+    fn require_schema_right(env: &Env, caller: &Address, schema_id: u64, right: u32) {
+        Self::require_writable(env);
+        caller.require_auth();
+        let admin: Option<Address> = env.storage().instance().get(&key_admin(env));
+        if admin.as_ref() == Some(caller) {
+            return;
+        }
+        let Some(schema) = Self::get_schema(env.clone(), schema_id) else {
+            panic_with_error!(env, ErrorCode::NotSchemaAdmin);
+        };
+        if &schema.owner == caller {
+            return;
+        }
+        let rights = Self::get_schema_delegate_rights(env.clone(), schema_id, caller.clone());
+        if rights & right == 0 {
+            panic_with_error!(env, ErrorCode::NotSchemaAdmin);
+        }
     }
 
-    // -----------------------------------------------------------------------
-    // Internal helpers
-    // -----------------------------------------------------------------------
+    fn load_bundle(env: &Env, bundle_id: u64) -> AttestationBundle {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::Bundle(bundle_id))
+            .unwrap_or_else(|| panic_with_error!(env, ErrorCode::BundleNotFound))
+    }
 
-    fn require_admin(env: &Env) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get::<_, Address>(&key_admin(env))
-            .unwrap_or_else(|| panic_with_error!(env, ErrorCode::NotInitialized));
-        admin.require_auth();
+    fn save_bundle(env: &Env, bundle: &AttestationBundle) {
+        let key = StorageKey::Bundle(bundle.id);
+        env.storage().persistent().set(&key, bundle);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
     }
 
-    fn check_attestor(env: &Env, attestor: &Address) {
-        if !env
+    fn load_org_stats(env: &Env, org_id: u64) -> OrgStats {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::OrgStats(org_id))
+            .unwrap_or(OrgStats {
+                total_attestations: 0,
+                window_count: 0,
+                window_start_ledger: env.ledger().sequence(),
+            })
+    }
+
+    /// Bump today's issuance (`issued == true`) or revocation counter for `attestor`.
+    fn record_activity(env: &Env, attestor: &Address, issued: bool) {
+        let day = env.ledger().timestamp() / SECS_PER_DAY;
+        let key = AttestorKey::Activity(attestor.clone(), day);
+        let mut activity: AttestorActivity = env
             .storage()
             .persistent()
-            .has(&StorageKey::Attestor(attestor.clone()))
-        {
-            panic_with_error!(env, ErrorCode::AttestorNotRegistered);
+            .get(&key)
+            .unwrap_or(AttestorActivity { day, issued: 0, revoked: 0 });
+        if issued {
+            activity.issued += 1;
+        } else {
+            activity.revoked += 1;
         }
+        env.storage().persistent().set(&key, &activity);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
     }
 
-    fn check_timestamp(env: &Env, timestamp: u64) {
-        if timestamp == 0 {
-            panic_with_error!(env, ErrorCode::InvalidTimestamp);
+    /// Append `id` to `asset`'s index, adding the asset to the attested-asset
+    /// list on its first attestation.
+    fn index_asset_attestation(env: &Env, asset: &Address, id: u64) {
+        let count_key = AssetKey::AttestationCount(asset.clone());
+        let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        if count == 0 {
+            let assets: u64 = env.storage().persistent().get(&AssetKey::AssetCount).unwrap_or(0);
+            let asset_key = AssetKey::Asset(assets);
+            env.storage().persistent().set(&asset_key, asset);
+            env.storage().persistent().extend_ttl(&asset_key, PERSISTENT_TTL, PERSISTENT_TTL);
+            env.storage().persistent().set(&AssetKey::AssetCount, &(assets + 1));
+            env.storage().persistent().extend_ttl(&AssetKey::AssetCount, PERSISTENT_TTL, PERSISTENT_TTL);
         }
-        let now = env.ledger().timestamp();
-        // Read the configured replay window (default 300 s if not set).
-        let window: u64 = env
-            .storage()
-            .instance()
-            .get(&key_replay_window(env))
-            .unwrap_or(300u64);
-        let lower = now.saturating_sub(window);
-        if timestamp < lower || timestamp > now {
-            panic_with_error!(env, ErrorCode::InvalidTimestamp);
+        let entry_key = AssetKey::Attestation(asset.clone(), count);
+        env.storage().persistent().set(&entry_key, &id);
+        env.storage().persistent().extend_ttl(&entry_key, PERSISTENT_TTL, PERSISTENT_TTL);
+        env.storage().persistent().set(&count_key, &(count + 1));
+        env.storage().persistent().extend_ttl(&count_key, PERSISTENT_TTL, PERSISTENT_TTL);
+    }
+
+    /// Append `id` to `subject`'s inbox, dropping the oldest entry when full.
+    /// Queue a revocation notice for `relier`, dropping the oldest once the
+    /// queue holds `INBOX_SIZE` IDs.
+    fn push_relier_notice(env: &Env, relier: &Address, id: u64) {
+        let key = PinKey::Notices(relier.clone());
+        let mut notices: Vec<u64> = env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env));
+        if notices.len() >= INBOX_SIZE {
+            notices.pop_front();
         }
+        notices.push_back(id);
+        env.storage().persistent().set(&key, &notices);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
     }
 
-    fn next_attestation_id(env: &Env) -> u64 {
-        let inst = env.storage().instance();
-        let ck = key_counter(env);
-        let id: u64 = inst.get(&ck).unwrap_or(0u64);
-        let next = id.saturating_add(1);
-        if next == u64::MAX {
-            panic_with_error!(env, ErrorCode::AttestationLimitReached);
+    fn push_inbox(env: &Env, subject: &Address, id: u64) {
+        let key = StorageKey::SubjectInbox(subject.clone());
+        let mut inbox: Vec<u64> = env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env));
+        if inbox.len() >= INBOX_SIZE {
+            inbox.pop_front();
         }
-        inst.set(&ck, &next);
-        inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
-        id
+        inbox.push_back(id);
+        env.storage().persistent().set(&key, &inbox);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
     }
 
-    fn check_session_expiry(env: &Env, session_id: u64) {
-        let sess_key = StorageKey::Session(session_id);
-        let session: Session = env
-            .storage()
+    fn value_bucket_count(env: &Env, schema_id: u64, bucket: u32) -> u64 {
+        env.storage()
             .persistent()
-            .get::<_, Session>(&sess_key)
-            .unwrap_or_else(|| panic_with_error!(env, ErrorCode::SessionNotFound));
-        let now = env.ledger().timestamp();
-        if now >= session.expires_at {
-            env.events().publish(
-                (symbol_short!("session"), symbol_short!("expired"), session_id),
-                SessionExpired { session_id, expired_at: now },
-            );
-            panic_with_error!(env, ErrorCode::SessionExpired);
+            .get(&SchemaKey::ValueBucket(schema_id, bucket))
+            .unwrap_or(0)
+    }
+
+    /// Move `subject` out of its current value bucket for `schema_id` and,
+    /// if `value` is set, into the bucket for `value`.
+    fn set_subject_value(env: &Env, schema_id: u64, width: u32, subject: &Address, value: Option<u32>) {
+        let bucket_of = |value: u32| (value / width).min(VALUE_BUCKETS - 1);
+        let bump = |bucket: u32, increment: bool| {
+            let key = SchemaKey::ValueBucket(schema_id, bucket);
+            let count = Self::value_bucket_count(env, schema_id, bucket);
+            let next = if increment { count + 1 } else { count.saturating_sub(1) };
+            env.storage().persistent().set(&key, &next);
+            env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+        };
+        let key = SchemaKey::SubjectValue(subject.clone(), schema_id);
+        if let Some(previous) = env.storage().persistent().get::<_, u32>(&key) {
+            bump(bucket_of(previous), false);
+        }
+        match value {
+            Some(value) => {
+                bump(bucket_of(value), true);
+                env.storage().persistent().set(&key, &value);
+                env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+            }
+            None => env.storage().persistent().remove(&key),
         }
     }
 
-    fn store_attestation(
-        env: &Env,
-        id: u64,
-        issuer: Address,
-        subject: Address,
-        timestamp: u64,
-        payload_hash: Bytes,
-        signature: Bytes,
-    ) {
-        let attestation = Attestation {
-            id,
-            issuer,
-            subject: subject.clone(),
-            timestamp,
-            payload_hash,
-            signature,
-            issuer_revoked: false,
+    /// Set the subject's bucketed value for the attestation's schema to
+    /// `value` if the attestation is still the subject's latest under it.
+    /// `None` drops a revoked or erased attestation's value; a reinstated
+    /// one puts its own value back.
+    fn sync_subject_value(env: &Env, attestation: &Attestation, value: Option<u32>) {
+        let schema = match attestation.schema_id.and_then(|id| Self::get_schema(env.clone(), id)) {
+            Some(schema) if schema.value_bucket_width != 0 => schema,
+            _ => return,
         };
-        let key = StorageKey::Attest(id);
-        env.storage().persistent().set(&key, &attestation);
+        let latest: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&SchemaKey::SubjectLatest(attestation.subject.clone(), schema.id));
+        if latest == Some(attestation.id) {
+            Self::set_subject_value(env, schema.id, schema.value_bucket_width, &attestation.subject, value);
+        }
+    }
+
+    /// Reject `relayer` with `RelayerNotAllowed` while the relayer allowlist
+    /// is enforced and does not include it.
+    fn check_relayer(env: &Env, relayer: &Address) {
+        if Self::is_relayer_allowlist_enforced(env.clone()) && !Self::is_relayer_allowed(env.clone(), relayer.clone()) {
+            panic_with_error!(env, ErrorCodeExt::RelayerNotAllowed);
+        }
+    }
+
+    /// An issuer with a schema allow-list may only issue under those schemas.
+    fn check_attestor_schema(env: &Env, issuer: &Address, schema_id: Option<u64>) {
+        if !Self::attestor_schema_allowed(env, issuer, schema_id) {
+            panic_with_error!(env, ErrorCodeExt::SchemaNotAllowed);
+        }
+    }
+
+    fn attestor_schema_allowed(env: &Env, issuer: &Address, schema_id: Option<u64>) -> bool {
+        let allowed = Self::get_attestor_schemas(env.clone(), issuer.clone());
+        allowed.is_empty() || schema_id.is_some_and(|schema_id| allowed.contains(schema_id))
+    }
+
+    /// Issuer must hold at least the schema's minimum trust tier.
+    fn check_schema_tier(env: &Env, schema_id: u64, issuer: &Address) {
+        if Self::is_tier_too_low(env, schema_id, issuer) {
+            panic_with_error!(env, ErrorCodeExt::IssuerTierTooLow);
+        }
+    }
+
+    fn is_tier_too_low(env: &Env, schema_id: u64, issuer: &Address) -> bool {
+        Self::get_schema(env.clone(), schema_id)
+            .is_some_and(|schema| Self::get_attestor_tier(env.clone(), issuer.clone()) < schema.min_issuer_tier)
+    }
+
+    /// Whether the schema enforces its tier minimum at verification and
+    /// `issuer` currently falls below it.
+    fn is_below_schema_tier(env: &Env, schema_id: Option<u64>, issuer: &Address) -> bool {
+        schema_id
+            .and_then(|schema_id| Self::get_schema(env.clone(), schema_id))
+            .is_some_and(|schema| {
+                schema.min_tier_on_verify && Self::get_attestor_tier(env.clone(), issuer.clone()) < schema.min_issuer_tier
+            })
+    }
+
+    /// Count one attestation against the schema, failing with
+    /// `SchemaCapReached` once its cap is exhausted.
+    fn charge_schema_cap(env: &Env, schema_id: u64) {
+        let (count, cap) = Self::schema_cap_usage(env, schema_id);
+        if cap != 0 && count >= cap {
+            Self::reject_quota(env, symbol_short!("schema"), schema_id, cap, count, ErrorCode::SchemaCapReached);
+        }
+        let key = SchemaKey::AttestationCount(schema_id);
+        env.storage().persistent().set(&key, &(count + 1));
         env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+    }
 
-        // Subject-specific index for pagination support (#215)
-        // Store only the ID to save storage space (O(1) extra space)
-        let count_key = StorageKey::SubjectCount(subject.clone());
-        let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    /// Attestations issued under the schema so far and its cap (`0` when
+    /// uncapped or unregistered).
+    fn schema_cap_usage(env: &Env, schema_id: u64) -> (u64, u64) {
+        let count = env.storage().persistent().get(&SchemaKey::AttestationCount(schema_id)).unwrap_or(0);
+        let cap = Self::get_schema(env.clone(), schema_id).map_or(0, |schema| schema.max_attestations);
+        (count, cap)
+    }
 
-        let subj_att_key = StorageKey::SubjectAttestation(subject.clone(), count);
-        env.storage().persistent().set(&subj_att_key, &id);
-        env.storage().persistent().extend_ttl(&subj_att_key, PERSISTENT_TTL, PERSISTENT_TTL);
+    /// Count one issuance against the org, enforcing its lifetime and
+    /// per-window caps.
+    fn charge_org_quota(env: &Env, org_id: u64) {
+        let mut stats = Self::load_org_stats(env, org_id);
+        let current_ledger = env.ledger().sequence();
+        let limits: Option<OrgLimits> = env.storage().persistent().get(&StorageKey::OrgLimits(org_id));
+        if let Some(limits) = limits {
+            if limits.window_length > 0
+                && current_ledger.saturating_sub(stats.window_start_ledger) >= limits.window_length
+            {
+                stats.window_count = 0;
+                stats.window_start_ledger = current_ledger;
+            }
+            if limits.max_attestations > 0 && stats.total_attestations >= limits.max_attestations {
+                Self::reject_quota(
+                    env,
+                    symbol_short!("org"),
+                    org_id,
+                    limits.max_attestations,
+                    stats.total_attestations,
+                    ErrorCode::OrgQuotaExceeded,
+                );
+            }
+            if limits.max_per_window > 0 && stats.window_count >= limits.max_per_window {
+                Self::reject_quota(
+                    env,
+                    Symbol::new(env, "org_window"),
+                    org_id,
+                    limits.max_per_window as u64,
+                    stats.window_count as u64,
+                    ErrorCode::OrgQuotaExceeded,
+                );
+            }
+        }
+        stats.total_attestations += 1;
+        stats.window_count += 1;
+        let key = StorageKey::OrgStats(org_id);
+        env.storage().persistent().set(&key, &stats);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+    }
 
-        env.storage().persistent().set(&count_key, &(count + 1));
-        let total_key = symbol_short!("TOTALCNT");
-        let total: u64 = env.storage().instance().get(&total_key).unwrap_or(0);
-        env.storage().instance().set(&total_key, &(total + 1));
-        env.storage()
-            .persistent()
-            .extend_ttl(&count_key, PERSISTENT_TTL, PERSISTENT_TTL);
+    fn save_org(env: &Env, org: &Organization) {
+        let key = StorageKey::Org(org.id);
+        env.storage().persistent().set(&key, org);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_TTL, PERSISTENT_TTL);
+    }
+
+    /// Increment (or saturating-decrement) a `u64` counter in instance storage.
+    fn bump_instance_counter(env: &Env, key: &Vec<Symbol>, increment: bool) {
+        let inst = env.storage().instance();
+        let current: u64 = inst.get(key).unwrap_or(0u64);
+        let next = if increment { current + 1 } else { current.saturating_sub(1) };
+        inst.set(key, &next);
+        inst.extend_ttl(INSTANCE_TTL, INSTANCE_TTL);
+    }
+
+    /// Publish a `MetricsSnapshot` when `total` lands on a multiple of the
+    /// configured metrics interval. An interval of `0` (the default) disables
+    /// snapshots entirely.
+    fn maybe_emit_metrics_snapshot(env: &Env, total: u64) {
+        let inst = env.storage().instance();
+        let interval: u64 = inst.get(&key_metrics_interval(env)).unwrap_or(0u64);
+        if interval == 0 || !total.is_multiple_of(interval) {
+            return;
+        }
+        let active: u64 = inst.get(&key_attestor_count(env)).unwrap_or(0u64);
+        let revoked: u64 = inst.get(&key_revoked_attestor_count(env)).unwrap_or(0u64);
+        let ever_registered = active + revoked;
+        let revocation_rate_bps = (revoked * 10_000).checked_div(ever_registered).unwrap_or(0) as u32;
+        env.events().publish(
+            (symbol_short!("metrics"), symbol_short!("snapshot")),
+            MetricsSnapshot {
+                total_attestations: total,
+                active_attestors: active,
+                revoked_attestors: revoked,
+                revocation_rate_bps,
+                ledger_sequence: env.ledger().sequence(),
+            },
+        );
     }
 
     fn store_span(env: &Env, request_id: &RequestId, operation: String, actor: Address, now: u64, status: String) {
@@ -2115,34 +7619,69 @@ impl AnchorKitContract {
     }
 
     /// Verifies that the attestation signature is valid for the given payload hash
-    /// using any of the public keys registered for the issuer.
+    /// using the issuer's registered attestor key (ed25519 or secp256k1), or else
+    /// any of the SEP-10 keys registered under the issuer's address.
     ///
     /// # Panics
     ///
-    /// Panics with `ErrorCode::UnauthorizedAttestor` if no valid signature is found.
-    // Verifies that the attestation signature is valid for the given payload hash
-// using any of the public keys registered for the issuer.
-//
-// # Panics
-//
-// Panics with `ErrorCode::UnauthorizedAttestor` if no valid signature is found.
+    /// Panics with `ErrorCodeExt::InvalidSignature` if the signature is malformed
+    /// or no key verifies it, and with `ErrorCode::UnauthorizedAttestor` if the
+    /// issuer has no key at all.
 fn verify_attestation_signature(
     env: &Env,
     issuer: &Address,
     payload_hash: &Bytes,
     signature: &Bytes,
 ) {
-    // Retrieve the list of registered public keys for the issuer.
-    let keys: Vec<Bytes> = env
+    if let Some(e) = Self::signature_error(env, issuer, payload_hash, signature) {
+        panic_with_error!(env, e);
+    }
+}
+
+/// Error `verify_attestation_signature` would fail with, if any.
+fn signature_error(
+    env: &Env,
+    issuer: &Address,
+    payload_hash: &Bytes,
+    signature: &Bytes,
+) -> Option<soroban_sdk::Error> {
+    let invalid = Some(ErrorCodeExt::InvalidSignature.into());
+    if let Some(public_key) = env
         .storage()
         .persistent()
-        .get(&StorageKey::Sep10Key(issuer.clone()))
-        .unwrap_or_else(|| panic_with_error!(env, ErrorCode::UnauthorizedAttestor));
+        .get::<_, BytesN<65>>(&AttestorKey::Secp256k1Key(issuer.clone()))
+    {
+        return if Self::secp256k1_signature_matches(env, &public_key, payload_hash, signature) {
+            None
+        } else {
+            invalid
+        };
+    }
 
     // Convert signature to the fixed-size BytesN<64> expected by env.crypto().ed25519_verify.
-    let sig_n: BytesN<64> = signature.clone().try_into().unwrap_or_else(|_| {
-        panic_with_error!(env, ErrorCode::UnauthorizedAttestor)
-    });
+    let sig_n: Option<BytesN<64>> = signature.clone().try_into().ok();
+    if let Some(public_key) = env
+        .storage()
+        .persistent()
+        .get::<_, BytesN<32>>(&AttestorKey::SigningKey(issuer.clone()))
+    {
+        return match sig_n {
+            Some(sig_n) if env.crypto().ed25519_verify(&public_key, payload_hash, &sig_n) => None,
+            _ => invalid,
+        };
+    }
+
+    // Retrieve the list of registered public keys for the issuer.
+    let Some(keys) = env
+        .storage()
+        .persistent()
+        .get::<_, Vec<Bytes>>(&StorageKey::Sep10Key(issuer.clone()))
+    else {
+        return Some(ErrorCode::UnauthorizedAttestor.into());
+    };
+    let Some(sig_n) = sig_n else {
+        return invalid;
+    };
 
     // Attempt verification with each stored public key.
     for key in keys.iter() {
@@ -2153,13 +7692,33 @@ fn verify_attestation_signature(
         let pk_n: BytesN<32> = key.clone().try_into().unwrap();
         // Use the host environment's crypto verification.
         if env.crypto().ed25519_verify(&pk_n, payload_hash, &sig_n) {
-            // Successful verification; exit the function.
-            return;
+            // Successful verification.
+            return None;
         }
     }
 
     // If we reach this point, no key verified the signature.
-    panic_with_error!(env, ErrorCode::UnauthorizedAttestor);
+    invalid
+}
+
+/// Checks a 65-byte `r || s || v` secp256k1 signature over
+/// `keccak256(payload_hash)` by recovering the signer's key and comparing it
+/// with `public_key`. `false` if the signature is malformed or recovers to a
+/// different key.
+fn secp256k1_signature_matches(env: &Env, public_key: &BytesN<65>, payload_hash: &Bytes, signature: &Bytes) -> bool {
+    if signature.len() != 65 {
+        return false;
+    }
+    let recovery_id = match signature.get(64).unwrap_or(u8::MAX) {
+        v @ (0 | 1) => v,
+        v @ (27 | 28) => v - 27,
+        _ => return false,
+    };
+    let Ok(rs) = BytesN::<64>::try_from(signature.slice(0..64)) else {
+        return false;
+    };
+    let digest = env.crypto().keccak256(payload_hash);
+    &env.crypto().secp256k1_recover(&digest, &rs, recovery_id as u32) == public_key
 }
 }
 
@@ -2178,3 +7737,13 @@ pub fn get_admin(env: Env) -> Address {
 pub fn get_attestation_count(env: Env) -> u64 {
     AnchorKitContract::get_attestation_count(env)
 }
+
+#[cfg(feature = "test-vectors")]
+#[contractimpl]
+impl AnchorKitContract {
+    /// Canonical preimages and expected hashes derived from `seed`, for
+    /// cross-language SDK conformance tests. Debug builds only.
+    pub fn generate_test_vectors(env: Env, seed: BytesN<32>, subject: Address) -> crate::test_vectors::TestVector {
+        crate::test_vectors::generate(&env, seed, subject)
+    }
+}
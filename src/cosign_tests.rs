@@ -0,0 +1,114 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Bytes, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::errors::{ErrorCode, ErrorCodeExt};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+
+struct Setup<'a> {
+    client: AnchorKitContractClient<'a>,
+    a: Address,
+    b: Address,
+    c: Address,
+    input: AttestationInput,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestors = [(); 3].map(|_| Address::generate(env));
+    let keys = [(); 3].map(|_| SigningKey::generate(&mut OsRng));
+    for (attestor, sk) in attestors.iter().zip(keys.iter()) {
+        register_attestor_with_sep10(env, &client, attestor, attestor, sk);
+    }
+    let payload = Bytes::from_array(env, &[6u8; 32]);
+    let sig = sign_payload(env, &keys[0], &payload);
+    let input = AttestationInput::new(Address::generate(env), NOW, payload, sig);
+    let [a, b, c] = attestors;
+    Setup { client, a, b, c, input }
+}
+
+fn propose(env: &Env, s: &Setup, threshold: u32) -> u64 {
+    let signers = vec![env, s.a.clone(), s.b.clone(), s.c.clone()];
+    s.client.propose_attestation(&s.a, &s.input, &signers, &threshold)
+}
+
+#[test]
+fn test_finalizes_at_threshold() {
+    let env = Env::default();
+    let s = setup(&env);
+    let pid = propose(&env, &s, 3);
+    assert_eq!(s.client.get_attestation_count(), 0);
+
+    assert_eq!(s.client.cosign(&s.b, &pid), None);
+    assert_eq!(s.client.get_proposal(&pid).unwrap().approvals.len(), 2);
+    let receipt = s.client.cosign(&s.c, &pid).unwrap();
+
+    let attestation = s.client.get_attestation(&receipt.id).unwrap();
+    assert_eq!(attestation.issuer, s.a);
+    assert_eq!(attestation.subject, s.input.subject);
+    assert_eq!(s.client.get_proposal(&pid), None);
+    assert_eq!(s.client.get_cosigners(&receipt.id), vec![&env, s.a.clone(), s.b.clone(), s.c.clone()]);
+}
+
+#[test]
+fn test_finalization_rechecks_input() {
+    let env = Env::default();
+    let mut s = setup(&env);
+    s.input = s.input.clone().with_expiry(NOW + 100);
+    let pid = propose(&env, &s, 2);
+    env.ledger().with_mut(|li| li.timestamp = NOW + 200);
+    let err = s.client.try_cosign(&s.b, &pid);
+    assert_eq!(err.err(), Some(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::AttestationExpired as u32))));
+}
+
+#[test]
+fn test_double_approval_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let pid = propose(&env, &s, 3);
+    let err = s.client.try_cosign(&s.a, &pid);
+    assert_eq!(err.err(), Some(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::AlreadyCosigned as u32))));
+}
+
+#[test]
+fn test_outsider_cannot_cosign() {
+    let env = Env::default();
+    let s = setup(&env);
+    let pid = propose(&env, &s, 2);
+    let err = s.client.try_cosign(&Address::generate(&env), &pid);
+    assert_eq!(err.err(), Some(Ok(soroban_sdk::Error::from_contract_error(ErrorCode::UnauthorizedAttestor as u32))));
+}
+
+#[test]
+fn test_invalid_threshold_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let signers = vec![&env, s.a.clone(), s.b.clone()];
+    assert!(s.client.try_propose_attestation(&s.a, &s.input, &signers, &1).is_err());
+    assert!(s.client.try_propose_attestation(&s.a, &s.input, &signers, &3).is_err());
+    let without_proposer = vec![&env, s.b.clone(), s.c.clone()];
+    assert!(s.client.try_propose_attestation(&s.a, &s.input, &without_proposer, &2).is_err());
+}
+
+#[test]
+fn test_lapsed_proposal_cannot_finalize() {
+    let env = Env::default();
+    let s = setup(&env);
+    let pid = propose(&env, &s, 2);
+    env.ledger().with_mut(|li| li.timestamp = NOW + 86_401);
+    let err = s.client.try_cosign(&s.b, &pid);
+    assert_eq!(err.err(), Some(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::ProposalNotFound as u32))));
+}
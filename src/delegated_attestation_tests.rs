@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+
+use crate::contract::AnchorKitContractClient;
+use crate::errors::ErrorCodeExt;
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+
+fn input(env: &Env, sk: &SigningKey, seed: u8) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(Address::generate(env), NOW, payload, sig)
+}
+
+fn authorize(
+    env: &Env,
+    client: &AnchorKitContractClient,
+    sk: &SigningKey,
+    issuer: &Address,
+    input: &AttestationInput,
+    deadline: u64,
+    nonce: u64,
+) -> Bytes {
+    let message = client.delegation_message_public(issuer, input, &deadline, &nonce);
+    sign_payload(env, sk, &message)
+}
+
+#[test]
+fn test_relayer_submits_on_issuers_behalf() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let relayer = Address::generate(&env);
+    let first = input(&env, &sk, 1);
+    let auth = authorize(&env, &client, &sk, &attestor, &first, NOW + 60, 0);
+
+    let id = client.attest_delegated(&relayer, &attestor, &first, &(NOW + 60), &0, &auth).id;
+    // Only the relayer authorized the transaction.
+    assert_eq!(env.auths().len(), 1);
+    assert_eq!(env.auths()[0].0, relayer);
+    let attestation = client.get_attestation(&id).unwrap();
+    assert_eq!(attestation.issuer, attestor);
+    assert_eq!(attestation.subject, first.subject);
+    assert_eq!(client.get_delegation_nonce(&attestor), 1);
+
+    let second = input(&env, &sk, 2);
+    let auth = authorize(&env, &client, &sk, &attestor, &second, NOW + 60, 1);
+    client.attest_delegated(&relayer, &attestor, &second, &(NOW + 60), &1, &auth);
+    assert_eq!(client.get_delegation_nonce(&attestor), 2);
+}
+
+#[test]
+fn test_delegation_cannot_be_replayed_or_altered() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let relayer = Address::generate(&env);
+    let signed = input(&env, &sk, 1);
+    let auth = authorize(&env, &client, &sk, &attestor, &signed, NOW + 60, 0);
+
+    // The authorization covers the exact input.
+    let altered = signed.clone().with_weight(5_000);
+    assert!(client.try_attest_delegated(&relayer, &attestor, &altered, &(NOW + 60), &0, &auth).is_err());
+    // ...and the deadline.
+    assert!(client.try_attest_delegated(&relayer, &attestor, &signed, &(NOW + 120), &0, &auth).is_err());
+
+    client.attest_delegated(&relayer, &attestor, &signed, &(NOW + 60), &0, &auth);
+    assert!(client.try_attest_delegated(&relayer, &attestor, &signed, &(NOW + 60), &0, &auth).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn test_expired_delegation_rejected() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let signed = input(&env, &sk, 1);
+    let auth = authorize(&env, &client, &sk, &attestor, &signed, NOW + 60, 0);
+    env.ledger().with_mut(|li| li.timestamp = NOW + 61);
+    client.attest_delegated(&Address::generate(&env), &attestor, &signed, &(NOW + 60), &0, &auth);
+}
+
+#[test]
+fn test_unlisted_relayer_rejected_while_enforced() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let relayer = Address::generate(&env);
+    let first = input(&env, &sk, 1);
+    let auth = authorize(&env, &client, &sk, &attestor, &first, NOW + 60, 0);
+    client.set_relayer_allowlist_enforced(&true);
+
+    let err = client.try_attest_delegated(&relayer, &attestor, &first, &(NOW + 60), &0, &auth);
+    assert_eq!(err.err().unwrap().unwrap(), soroban_sdk::Error::from_contract_error(ErrorCodeExt::RelayerNotAllowed as u32));
+    client.add_relayer(&relayer);
+    client.attest_delegated(&relayer, &attestor, &first, &(NOW + 60), &0, &auth);
+    assert_eq!(client.get_delegation_nonce(&attestor), 1);
+}
@@ -1,4 +1,6 @@
-use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+use crate::types::{Attestation, AttestationInput};
 
 /// Compute a canonical SHA-256 hash over attestation payload fields.
 ///
@@ -18,6 +20,11 @@ pub fn compute_payload_hash(
     timestamp: u64,
     data: &Bytes,
 ) -> BytesN<32> {
+    env.crypto().sha256(&payload_preimage(env, subject, timestamp, data)).into()
+}
+
+/// Exact bytes hashed by [`compute_payload_hash`].
+pub fn payload_preimage(env: &Env, subject: &Address, timestamp: u64, data: &Bytes) -> Bytes {
     let mut input = Bytes::new(env);
 
     // 1. subject — serialised as its raw XDR bytes via to_xdr
@@ -32,7 +39,154 @@ pub fn compute_payload_hash(
     // 3. data payload
     input.append(data);
 
-    env.crypto().sha256(&input).into()
+    input
+}
+
+/// Compute the hash an issuer signs when anchoring an attestation bundle.
+///
+/// Field ordering is fixed: subject bytes || timestamp (8-byte BE) ||
+/// for each claim, schema_id (8-byte BE) || payload_hash length (4-byte BE)
+/// || payload_hash. The length prefix keeps claim boundaries unambiguous,
+/// since payload hashes vary in length.
+pub fn compute_bundle_hash(
+    env: &Env,
+    subject: &Address,
+    timestamp: u64,
+    claims: &Vec<(u64, Bytes)>,
+) -> BytesN<32> {
+    env.crypto().sha256(&bundle_preimage(env, subject, timestamp, claims)).into()
+}
+
+/// Exact bytes hashed by [`compute_bundle_hash`].
+pub fn bundle_preimage(env: &Env, subject: &Address, timestamp: u64, claims: &Vec<(u64, Bytes)>) -> Bytes {
+    let mut input = Bytes::new(env);
+    input.append(&subject.clone().to_xdr(env));
+    input.extend_from_array(&timestamp.to_be_bytes());
+    for (schema_id, payload_hash) in claims.iter() {
+        input.extend_from_array(&schema_id.to_be_bytes());
+        input.extend_from_array(&payload_hash.len().to_be_bytes());
+        input.append(&payload_hash);
+    }
+    input
+}
+
+/// Compute the message an issuer signs, in place of the bare payload hash,
+/// for an attestation that references attestation `ref_id`.
+///
+/// Field ordering is fixed: the ASCII tag `ref` || payload_hash ||
+/// referenced attestation ID (8-byte BE). Binding the ID keeps a signed
+/// payload from being re-linked to another attestation.
+pub fn compute_ref_binding_hash(env: &Env, payload_hash: &Bytes, ref_id: u64) -> BytesN<32> {
+    env.crypto().sha256(&ref_binding_preimage(env, payload_hash, ref_id)).into()
+}
+
+/// Exact bytes hashed by [`compute_ref_binding_hash`].
+pub fn ref_binding_preimage(env: &Env, payload_hash: &Bytes, ref_id: u64) -> Bytes {
+    let mut input = Bytes::from_slice(env, b"ref");
+    input.append(payload_hash);
+    input.extend_from_array(&ref_id.to_be_bytes());
+    input
+}
+
+/// Prefix shared by every domain-separated signing message. Attestation
+/// payload hashes may not start with it, so an attestation signature can
+/// never double as a revocation or delegation authorization.
+pub const SIGNING_DOMAIN_PREFIX: &[u8] = b"anchorkit-";
+
+/// Domain an issuer's relayed-revocation signature is made under.
+pub const REVOCATION_DOMAIN: &[u8] = b"anchorkit-revoke-v1";
+
+/// Domain an issuer's delegation signature is made under.
+pub const DELEGATION_DOMAIN: &[u8] = b"anchorkit-delegate-v1";
+
+/// Bytes actually signed for `hash` under `domain`: `domain` || `hash`.
+pub fn domain_message(env: &Env, domain: &[u8], hash: &BytesN<32>) -> Bytes {
+    let mut message = Bytes::from_slice(env, domain);
+    message.extend_from_array(&hash.to_array());
+    message
+}
+
+/// Compute the hash behind a relayed revocation; the issuer signs it under
+/// [`REVOCATION_DOMAIN`] (see [`domain_message`]).
+///
+/// Field ordering is fixed: the ASCII tag `revoke` || contract address bytes ||
+/// attestation ID (8-byte BE) || nonce (8-byte BE). Binding the contract
+/// address keeps a signed revocation from being replayed on another deployment.
+pub fn compute_revocation_hash(env: &Env, contract: &Address, id: u64, nonce: u64) -> BytesN<32> {
+    env.crypto().sha256(&revocation_preimage(env, contract, id, nonce)).into()
+}
+
+/// Exact bytes hashed by [`compute_revocation_hash`].
+pub fn revocation_preimage(env: &Env, contract: &Address, id: u64, nonce: u64) -> Bytes {
+    let mut input = Bytes::from_slice(env, b"revoke");
+    input.append(&contract.clone().to_xdr(env));
+    input.extend_from_array(&id.to_be_bytes());
+    input.extend_from_array(&nonce.to_be_bytes());
+    input
+}
+
+/// Compute the hash behind a delegation letting a relayer submit `input`;
+/// the issuer signs it under [`DELEGATION_DOMAIN`] (see [`domain_message`]).
+///
+/// Field ordering is fixed: the ASCII tag `delegate` || contract address
+/// bytes || issuer address bytes || `input` XDR || deadline (8-byte BE) ||
+/// nonce (8-byte BE).
+pub fn compute_delegation_hash(
+    env: &Env,
+    contract: &Address,
+    issuer: &Address,
+    input: &AttestationInput,
+    deadline: u64,
+    nonce: u64,
+) -> BytesN<32> {
+    env.crypto()
+        .sha256(&delegation_preimage(env, contract, issuer, input, deadline, nonce))
+        .into()
+}
+
+/// Exact bytes hashed by [`compute_delegation_hash`].
+pub fn delegation_preimage(
+    env: &Env,
+    contract: &Address,
+    issuer: &Address,
+    input: &AttestationInput,
+    deadline: u64,
+    nonce: u64,
+) -> Bytes {
+    let mut preimage = Bytes::from_slice(env, b"delegate");
+    preimage.append(&contract.clone().to_xdr(env));
+    preimage.append(&issuer.clone().to_xdr(env));
+    preimage.append(&input.clone().to_xdr(env));
+    preimage.extend_from_array(&deadline.to_be_bytes());
+    preimage.extend_from_array(&nonce.to_be_bytes());
+    preimage
+}
+
+/// High bit set on every registered schema ID.
+///
+/// Attestations could name arbitrary schema IDs before the registry
+/// existed; those stay below this bit, so a registered schema never inherits
+/// their history.
+pub const REGISTERED_SCHEMA_BIT: u64 = 1 << 63;
+
+/// Schema ID derived from a schema's definition hash: its first eight bytes
+/// read as a big-endian `u64`, with [`REGISTERED_SCHEMA_BIT`] set.
+///
+/// The same definition therefore gets the same ID on every deployment.
+pub fn schema_id_from_hash(schema_hash: &BytesN<32>) -> u64 {
+    let bytes = schema_hash.to_array();
+    let mut id = [0u8; 8];
+    id.copy_from_slice(&bytes[..8]);
+    u64::from_be_bytes(id) | REGISTERED_SCHEMA_BIT
+}
+
+/// Canonical byte form of an attestation record: its `ScVal` XDR encoding.
+///
+/// Contract-type structs encode as an `ScMap` whose keys are the field names
+/// in sorted order, so the bytes are stable regardless of how the record was
+/// built and can be reproduced off-chain with any XDR library.
+pub fn canonical_attestation_bytes(env: &Env, attestation: &Attestation) -> Bytes {
+    attestation.clone().to_xdr(env)
 }
 
 /// Verify that the stored attestation's payload hash matches the expected hash.
@@ -103,4 +257,23 @@ mod deterministic_hash_tests {
         let h2 = compute_payload_hash(&env, &subject, ts + 1, &data);
         assert!(!verify_payload_hash(&h1, &h2));
     }
+
+    #[test]
+    fn test_bundle_claim_boundaries_are_bound() {
+        let env = Env::default();
+        let subject = Address::generate(&env);
+        let ts: u64 = 1_700_000_000;
+
+        // Without length prefixes both claim lists hash the same bytes.
+        let mut merged = Bytes::from_array(&env, &[1u8; 32]);
+        merged.extend_from_array(&2u64.to_be_bytes());
+        merged.append(&Bytes::from_array(&env, &[2u8; 32]));
+        let one = soroban_sdk::vec![&env, (1u64, merged)];
+        let two = soroban_sdk::vec![
+            &env,
+            (1u64, Bytes::from_array(&env, &[1u8; 32])),
+            (2u64, Bytes::from_array(&env, &[2u8; 32])),
+        ];
+        assert_ne!(compute_bundle_hash(&env, &subject, ts, &one), compute_bundle_hash(&env, &subject, ts, &two));
+    }
 }
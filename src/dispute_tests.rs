@@ -0,0 +1,320 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Ledger},
+    token, Address, Bytes, BytesN, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::errors::{ErrorCode, ErrorCodeExt};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::{AttestationStatus, ChallengeStatus};
+
+const NOW: u64 = 1_700_000_000;
+
+/// Court that files every case it is sent and rules when told to.
+#[contract]
+struct TestCourt;
+
+#[contractimpl]
+impl TestCourt {
+    pub fn open_case(env: Env, registry: Address, challenge_id: u64, _evidence_hash: BytesN<32>) {
+        env.storage().instance().set(&symbol_short!("case"), &(registry, challenge_id));
+    }
+
+    pub fn case(env: Env) -> Option<(Address, u64)> {
+        env.storage().instance().get(&symbol_short!("case"))
+    }
+
+    pub fn rule(env: Env, registry: Address, challenge_id: u64, upheld: bool) {
+        AnchorKitContractClient::new(&env, &registry).rule_on_challenge(&challenge_id, &upheld);
+    }
+}
+
+struct Setup<'a> {
+    client: AnchorKitContractClient<'a>,
+    attestor: Address,
+    challenger: Address,
+    token: Address,
+    id: u64,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    let payload = Bytes::from_array(env, &[7; 32]);
+    let sig = sign_payload(env, &sk, &payload);
+    let id = client.submit_attestation(&attestor, &Address::generate(env), &NOW, &payload, &sig);
+
+    let token = env.register_stellar_asset_contract_v2(Address::generate(env)).address();
+    client.set_challenge_bond(&token, &100);
+    let challenger = Address::generate(env);
+    token::StellarAssetClient::new(env, &token).mint(&challenger, &1_000);
+    Setup { client, attestor, challenger, token, id }
+}
+
+fn evidence(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[9; 32])
+}
+
+fn status(s: &Setup) -> AttestationStatus {
+    s.client.get_attestation_v2(&s.id).unwrap().validity.status
+}
+
+#[test]
+fn test_challenge_marks_attestation_disputed() {
+    let env = Env::default();
+    let s = setup(&env);
+    let cid = s.client.challenge_attestation(&s.challenger, &s.id, &evidence(&env), &150);
+
+    let challenge = s.client.get_challenge(&cid).unwrap();
+    assert_eq!(challenge.status, ChallengeStatus::Open);
+    assert_eq!(challenge.bond, 150);
+    assert_eq!(s.client.get_attestation_challenge(&s.id), Some(challenge));
+    assert_eq!(token::Client::new(&env, &s.token).balance(&s.client.address), 150);
+
+    let view = s.client.get_attestation_v2(&s.id).unwrap();
+    assert_eq!(view.validity.status, AttestationStatus::Disputed);
+    // An open challenge alone does not take the credential offline.
+    assert!(view.validity.is_valid);
+}
+
+#[test]
+fn test_bond_below_minimum_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let err = s.client.try_challenge_attestation(&s.challenger, &s.id, &evidence(&env), &99);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCode::ValidationError as u32))));
+}
+
+#[test]
+fn test_second_open_challenge_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.challenge_attestation(&s.challenger, &s.id, &evidence(&env), &100);
+    let err = s.client.try_challenge_attestation(&s.challenger, &s.id, &evidence(&env), &100);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::AttestationDisputed as u32))));
+}
+
+#[test]
+fn test_invalidated_refunds_challenger() {
+    let env = Env::default();
+    let s = setup(&env);
+    let cid = s.client.challenge_attestation(&s.challenger, &s.id, &evidence(&env), &100);
+    let admin = s.client.get_admin();
+    s.client.resolve_challenge(&admin, &cid, &ChallengeStatus::Invalidated);
+
+    assert_eq!(token::Client::new(&env, &s.token).balance(&s.challenger), 1_000);
+    let view = s.client.get_attestation_v2(&s.id).unwrap();
+    assert_eq!(view.validity.status, AttestationStatus::Invalidated);
+    assert!(!view.validity.is_valid);
+    let day = NOW / 86_400;
+    assert_eq!(s.client.get_attestor_activity(&s.attestor, &day, &day).get(0).unwrap().revoked, 1);
+    let err = s.client.try_challenge_attestation(&s.challenger, &s.id, &evidence(&env), &100);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::AttestationDisputed as u32))));
+}
+
+#[test]
+fn test_upheld_pays_issuer_and_allows_rechallenge() {
+    let env = Env::default();
+    let s = setup(&env);
+    let cid = s.client.challenge_attestation(&s.challenger, &s.id, &evidence(&env), &100);
+    let arbiter = Address::generate(&env);
+    s.client.grant_role(&s.client.get_admin(), &crate::roles::ROLE_ARBITER, &arbiter);
+    s.client.resolve_challenge(&arbiter, &cid, &ChallengeStatus::Upheld);
+
+    assert_eq!(token::Client::new(&env, &s.token).balance(&s.attestor), 100);
+    assert_eq!(status(&s), AttestationStatus::Active);
+    assert_eq!(s.client.get_challenge(&cid).unwrap().resolved_at, NOW);
+
+    let err = s.client.try_resolve_challenge(&arbiter, &cid, &ChallengeStatus::Invalidated);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::ChallengeResolved as u32))));
+    let err = s.client.try_challenge_attestation(&s.challenger, &s.id, &evidence(&env), &100);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::ChallengeCooldown as u32))));
+
+    env.ledger().with_mut(|li| li.timestamp = NOW + s.client.get_challenge_cooldown());
+    s.client.challenge_attestation(&s.challenger, &s.id, &evidence(&env), &100);
+    assert_eq!(status(&s), AttestationStatus::Disputed);
+}
+
+#[test]
+fn test_resolve_requires_arbiter() {
+    let env = Env::default();
+    let s = setup(&env);
+    let cid = s.client.challenge_attestation(&s.challenger, &s.id, &evidence(&env), &100);
+    let err = s.client.try_resolve_challenge(&s.challenger, &cid, &ChallengeStatus::Invalidated);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::MissingRole as u32))));
+    let err = s.client.try_resolve_challenge(&s.client.get_admin(), &42, &ChallengeStatus::Upheld);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::ChallengeNotFound as u32))));
+}
+
+#[test]
+fn test_bond_paid_out_in_posted_token() {
+    let env = Env::default();
+    let s = setup(&env);
+    let cid = s.client.challenge_attestation(&s.challenger, &s.id, &evidence(&env), &100);
+    assert_eq!(s.client.get_challenge(&cid).unwrap().bond_token, Some(s.token.clone()));
+    let other = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    s.client.set_challenge_bond(&other, &50);
+
+    s.client.resolve_challenge(&s.client.get_admin(), &cid, &ChallengeStatus::Invalidated);
+    assert_eq!(token::Client::new(&env, &s.token).balance(&s.challenger), 1_000);
+    assert_eq!(token::Client::new(&env, &other).balance(&s.challenger), 0);
+}
+
+#[test]
+fn test_escalated_challenge_follows_court_ruling() {
+    let env = Env::default();
+    let s = setup(&env);
+    let cid = s.client.challenge_attestation(&s.challenger, &s.id, &evidence(&env), &100);
+    let court = env.register_contract(None, TestCourt);
+    let court_client = TestCourtClient::new(&env, &court);
+    s.client.set_arbitration_court(&Some(court.clone()));
+
+    s.client.escalate_challenge(&s.attestor, &cid);
+    assert_eq!(s.client.get_challenge(&cid).unwrap().status, ChallengeStatus::Escalated);
+    assert_eq!(court_client.case(), Some((s.client.address.clone(), cid)));
+    let err = s.client.try_resolve_challenge(&s.client.get_admin(), &cid, &ChallengeStatus::Upheld);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::ChallengeEscalated as u32))));
+
+    court_client.rule(&s.client.address, &cid, &false);
+    assert_eq!(s.client.get_challenge(&cid).unwrap().status, ChallengeStatus::Invalidated);
+    assert_eq!(status(&s), AttestationStatus::Invalidated);
+    assert_eq!(token::Client::new(&env, &s.token).balance(&s.challenger), 1_000);
+    assert!(court_client.try_rule(&s.client.address, &cid, &true).is_err());
+}
+
+#[test]
+fn test_arbiter_may_close_escalation_after_ruling_deadline() {
+    let env = Env::default();
+    let s = setup(&env);
+    let cid = s.client.challenge_attestation(&s.challenger, &s.id, &evidence(&env), &100);
+    s.client.set_arbitration_court(&Some(env.register_contract(None, TestCourt)));
+    s.client.set_ruling_window(&3_600);
+    s.client.escalate_challenge(&s.challenger, &cid);
+    assert_eq!(s.client.get_ruling_deadline(&cid), Some(NOW + 3_600));
+
+    let admin = s.client.get_admin();
+    let err = s.client.try_resolve_challenge(&admin, &cid, &ChallengeStatus::Upheld);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::ChallengeEscalated as u32))));
+
+    env.ledger().with_mut(|li| li.timestamp = NOW + 3_601);
+    s.client.enable_sunset();
+    s.client.resolve_challenge(&admin, &cid, &ChallengeStatus::Upheld);
+    assert_eq!(s.client.get_challenge(&cid).unwrap().status, ChallengeStatus::Upheld);
+    assert_eq!(token::Client::new(&env, &s.token).balance(&s.attestor), 100);
+}
+
+#[test]
+fn test_escalation_needs_court_and_party() {
+    let env = Env::default();
+    let s = setup(&env);
+    let cid = s.client.challenge_attestation(&s.challenger, &s.id, &evidence(&env), &100);
+    let err = s.client.try_escalate_challenge(&s.challenger, &cid);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::ArbitrationUnavailable as u32))));
+
+    s.client.set_arbitration_court(&Some(env.register_contract(None, TestCourt)));
+    let err = s.client.try_escalate_challenge(&Address::generate(&env), &cid);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCode::UnauthorizedAttestor as u32))));
+    // Rulings apply only to escalated challenges.
+    let err = s.client.try_rule_on_challenge(&cid, &true);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::ChallengeResolved as u32))));
+}
+
+#[test]
+fn test_appeal_upheld_reinstates_revoked_attestation() {
+    let env = Env::default();
+    let s = setup(&env);
+    let court = env.register_contract(None, TestCourt);
+    s.client.set_arbitration_court(&Some(court.clone()));
+    s.client.set_appeal_window(&3_600);
+    token::StellarAssetClient::new(&env, &s.token).mint(&s.attestor, &1_000);
+
+    s.client.revoke_attestation(&s.client.get_admin(), &s.id);
+    assert_eq!(s.client.get_appeal_deadline(&s.id), Some(NOW + 3_600));
+    let cid = s.client.appeal_revocation(&s.attestor, &s.id, &evidence(&env), &100);
+    assert!(s.client.get_challenge(&cid).unwrap().appeal);
+    assert_eq!(s.client.get_appeal_deadline(&s.id), None);
+
+    TestCourtClient::new(&env, &court).rule(&s.client.address, &cid, &true);
+    assert_eq!(status(&s), AttestationStatus::Active);
+    assert!(s.client.get_attestation(&s.id).unwrap().revoked_at.is_none());
+    assert_eq!(token::Client::new(&env, &s.token).balance(&s.attestor), 1_000);
+}
+
+#[test]
+fn test_appeal_rejected_makes_revocation_final() {
+    let env = Env::default();
+    let s = setup(&env);
+    let court = env.register_contract(None, TestCourt);
+    s.client.set_arbitration_court(&Some(court.clone()));
+    s.client.set_appeal_window(&3_600);
+    token::StellarAssetClient::new(&env, &s.token).mint(&s.attestor, &1_000);
+
+    s.client.revoke_attestation(&s.client.get_admin(), &s.id);
+    let cid = s.client.appeal_revocation(&s.attestor, &s.id, &evidence(&env), &100);
+    TestCourtClient::new(&env, &court).rule(&s.client.address, &cid, &false);
+    assert_eq!(status(&s), AttestationStatus::Revoked);
+    assert_eq!(token::Client::new(&env, &s.token).balance(&s.client.get_admin()), 100);
+    let err = s.client.try_appeal_revocation(&s.attestor, &s.id, &evidence(&env), &100);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::AppealClosed as u32))));
+}
+
+#[test]
+fn test_appeal_only_within_window_of_admin_revocation() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_arbitration_court(&Some(env.register_contract(None, TestCourt)));
+    s.client.set_appeal_window(&3_600);
+
+    s.client.revoke_attestation(&s.client.get_admin(), &s.id);
+    env.ledger().with_mut(|li| li.timestamp = NOW + 3_601);
+    let err = s.client.try_appeal_revocation(&s.attestor, &s.id, &evidence(&env), &100);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::AppealClosed as u32))));
+
+}
+
+#[test]
+fn test_erasure_waits_for_disputes_to_settle() {
+    let env = Env::default();
+    let s = setup(&env);
+    let pending = Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::DisputePending as u32)));
+    let cid = s.client.challenge_attestation(&s.challenger, &s.id, &evidence(&env), &100);
+    assert_eq!(s.client.try_erase_attestation(&s.id), pending);
+
+    s.client.resolve_challenge(&s.client.get_admin(), &cid, &ChallengeStatus::Invalidated);
+    assert_eq!(token::Client::new(&env, &s.token).balance(&s.challenger), 1_000);
+    s.client.erase_attestation(&s.id);
+    assert!(s.client.get_attestation(&s.id).is_none());
+}
+
+#[test]
+fn test_erasure_waits_for_appeal_window() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_appeal_window(&3_600);
+    s.client.revoke_attestation(&s.client.get_admin(), &s.id);
+    let err = s.client.try_erase_attestation(&s.id);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::DisputePending as u32))));
+
+    env.ledger().with_mut(|li| li.timestamp = NOW + 3_601);
+    s.client.erase_attestation(&s.id);
+}
+
+#[test]
+fn test_issuer_revocation_has_no_appeal() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_appeal_window(&3_600);
+    s.client.revoke_attestation(&s.attestor, &s.id);
+    assert_eq!(s.client.get_appeal_deadline(&s.id), None);
+}
@@ -0,0 +1,58 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Bytes, Env};
+#[cfg(not(feature = "legacy-events"))]
+use soroban_sdk::{symbol_short, testutils::Events, Symbol, TryFromVal};
+use ed25519_dalek::SigningKey;
+
+use crate::contract::AnchorKitContractClient;
+#[cfg(not(feature = "legacy-events"))]
+use crate::events::AttestEvent;
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::types::{AttestationInput, AttestationStatus};
+
+const NOW: u64 = 1_700_000_000;
+const ACTIVATES: u64 = NOW + 86_400;
+
+fn record_embargoed(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey, subject: &Address) -> u64 {
+    let payload = Bytes::from_array(env, &[8u8; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    let input = AttestationInput::new(subject.clone(), NOW, payload, sig).with_not_before(ACTIVATES);
+    client.record_attestation(attestor, &input).id
+}
+
+#[test]
+fn test_embargoed_attestation_is_pending_and_hidden() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let subject = Address::generate(&env);
+    let id = record_embargoed(&env, &client, &attestor, &sk, &subject);
+
+    let validity = client.get_attestation_v2(&id).unwrap().validity;
+    assert_eq!(validity.status, AttestationStatus::Pending);
+    assert!(!validity.is_valid);
+    assert_eq!(client.list_attestations(&subject, &0, &10).len(), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = ACTIVATES);
+    assert!(client.get_attestation_v2(&id).unwrap().validity.is_valid);
+    assert_eq!(client.list_attestations(&subject, &0, &10).len(), 1);
+}
+
+#[cfg(not(feature = "legacy-events"))]
+#[test]
+fn test_recorded_event_carries_activation_time() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    record_embargoed(&env, &client, &attestor, &sk, &Address::generate(&env));
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            topics.len() == 4
+                && Symbol::try_from_val(&env, &topics.get(1).unwrap()) == Ok(symbol_short!("recorded"))
+        })
+        .map(|(_, _, data)| AttestEvent::try_from_val(&env, &data).unwrap())
+        .unwrap();
+    assert_eq!(event.not_before, ACTIVATES);
+}
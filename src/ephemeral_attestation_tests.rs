@@ -0,0 +1,63 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+
+use crate::errors::ErrorCode;
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+
+fn input(env: &Env, sk: &SigningKey, seed: u8) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(Address::generate(env), NOW, payload, sig)
+}
+
+#[test]
+fn test_ephemeral_ids_separate_from_persistent() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.record_attestation(&attestor, &input(&env, &sk, 1));
+    let id = client.record_ephemeral_attestation(&attestor, &input(&env, &sk, 2), &100);
+    assert_eq!(id, 0);
+    assert_eq!(client.get_ephemeral_attestation(&id).payload_hash, Bytes::from_array(&env, &[2; 32]));
+    assert_eq!(client.get_attestation_count(), 1);
+}
+
+#[test]
+fn test_not_found_after_ttl() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let id = client.record_ephemeral_attestation(&attestor, &input(&env, &sk, 1), &100);
+    env.ledger().with_mut(|li| li.sequence_number += 100);
+    client.get_ephemeral_attestation(&id);
+    env.ledger().with_mut(|li| li.sequence_number += 1);
+    let err = client.try_get_ephemeral_attestation(&id);
+    assert_eq!(err.err(), Some(Ok(ErrorCode::AttestationNotFound)));
+}
+
+#[test]
+fn test_zero_ttl_rejected() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    assert!(client.try_record_ephemeral_attestation(&attestor, &input(&env, &sk, 1), &0).is_err());
+}
+
+#[test]
+fn test_ephemeral_counts_against_schema_cap() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let schema_id = client.register_schema(
+        &Address::generate(&env),
+        &Bytes::from_array(&env, &[9u8; 32]),
+        &soroban_sdk::String::from_str(&env, "ipfs://session"),
+    );
+    client.set_schema_cap(&schema_id, &1);
+    client.record_ephemeral_attestation(&attestor, &input(&env, &sk, 1).with_schema(schema_id), &100);
+    assert_eq!(client.get_schema_attestation_count(&schema_id), 1);
+    assert!(client
+        .try_record_ephemeral_attestation(&attestor, &input(&env, &sk, 2).with_schema(schema_id), &100)
+        .is_err());
+}
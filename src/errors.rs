@@ -17,127 +17,12 @@
 //! The full `String`-based struct is only compiled when `feature = "std"` is
 //! active (the default for host-side / test builds).
 
-use soroban_sdk::contracterror;
-
 #[cfg(feature = "std")]
 extern crate alloc;
 #[cfg(feature = "std")]
 use alloc::string::String;
 
-// ---------------------------------------------------------------------------
-// ErrorCode — the canonical list of all error kinds (replaces the old Error enum)
-// ---------------------------------------------------------------------------
-
-/// Numeric error codes for every AnchorKit error kind.
-///
-/// The `#[contracterror]` attribute keeps Soroban on-chain compatibility.
-///
-/// ## Migration note
-///
-/// Prior to this fix the codes were non-contiguous: values 1-19 were followed
-/// by a gap (20-47) and then 48-54, with `NotInitialized` at 101.
-/// All codes have been renumbered to the contiguous range **1-30**.
-/// Clients that matched on raw numeric values must update their mappings:
-///
-/// | Old code | New code | Name                    |
-/// |----------|----------|-------------------------|
-/// | 1        | 1        | AlreadyInitialized      |
-/// | 2        | 2        | AttestorAlreadyRegistered |
-/// | 3        | 3        | AttestorNotRegistered   |
-/// | 4        | 4        | UnauthorizedAttestor    |
-/// | 5        | 5        | InvalidTimestamp        |
-/// | 6        | 6        | ReplayAttack            |
-/// | 7        | 7        | InvalidQuote            |
-/// | 8        | 8        | InvalidServiceType      |
-/// | 9        | 9        | InvalidTransactionIntent |
-/// | 10       | 10       | StaleQuote              |
-/// | 11       | 11       | ComplianceNotMet        |
-/// | 12       | 12       | InvalidEndpointFormat   |
-/// | 13       | 13       | NoQuotesAvailable       |
-/// | 14       | 14       | ServicesNotConfigured   |
-/// | 15       | 15       | ValidationError         |
-/// | 16       | 16       | RateLimitExceeded       |
-/// | 17       | 17       | AttestationNotFound     |
-/// | 18       | 18       | InvalidSep10Token       |
-/// | 19       | 19       | StorageCorrupted        |
-/// | 48       | 20       | CacheExpired            |
-/// | 49       | 21       | CacheNotFound           |
-/// | 51       | 22       | AuditLogMaxSizeInvalid  |
-/// | 52       | 23       | UnauthorizedProposeAdmin |
-/// | 53       | 24       | NoPendingAdmin          |
-/// | 54       | 25       | NotPendingAdmin         |
-/// | 101      | 26       | NotInitialized          |
-#[contracterror]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
-#[repr(u32)]
-pub enum ErrorCode {
-    AlreadyInitialized = 1,
-    AttestorAlreadyRegistered = 2,
-    AttestorNotRegistered = 3,
-    UnauthorizedAttestor = 4,
-    InvalidTimestamp = 5,
-    ReplayAttack = 6,
-    InvalidQuote = 7,
-    InvalidServiceType = 8,
-    InvalidTransactionIntent = 9,
-    StaleQuote = 10,
-    ComplianceNotMet = 11,
-    InvalidEndpointFormat = 12,
-    NoQuotesAvailable = 13,
-    ServicesNotConfigured = 14,
-    ValidationError = 15,
-    RateLimitExceeded = 16,
-    AttestationNotFound = 17,
-    InvalidSep10Token = 18,
-    StorageCorrupted = 19,
-    CacheExpired = 48,
-    CacheNotFound = 49,
-    AuditLogMaxSizeInvalid = 51,
-    PendingAdminAlreadyExists = 52,
-    NoPendingAdmin = 53,
-    NotPendingAdmin = 54,
-    SessionNotFound = 55,
-    SessionExpired = 56,
-    MissingSigningKey = 57,
-}
-
-impl ErrorCode {
-    /// Returns the canonical human-readable message for this error code.
-    pub fn default_message(&self) -> &'static str {
-        match self {
-            ErrorCode::AlreadyInitialized => "Contract is already initialized",
-            ErrorCode::AttestorAlreadyRegistered => "Attestor is already registered",
-            ErrorCode::AttestorNotRegistered => "Attestor is not registered",
-            ErrorCode::UnauthorizedAttestor => "Attestor is not authorized",
-            ErrorCode::InvalidTimestamp => "Timestamp is invalid",
-            ErrorCode::ReplayAttack => "Replay attack detected",
-            ErrorCode::InvalidQuote => "Quote is invalid",
-            ErrorCode::InvalidServiceType => "Service type is invalid",
-            ErrorCode::InvalidTransactionIntent => "Transaction intent is invalid",
-            ErrorCode::StaleQuote => "Quote has expired",
-            ErrorCode::ComplianceNotMet => "Compliance requirements not met",
-            ErrorCode::InvalidEndpointFormat => "Endpoint format is invalid",
-            ErrorCode::NoQuotesAvailable => "No quotes are available",
-            ErrorCode::ServicesNotConfigured => "Services are not configured",
-            ErrorCode::ValidationError => "Response schema validation failed",
-            ErrorCode::RateLimitExceeded => "Rate limit exceeded",
-            ErrorCode::NotInitialized => "Contract is not initialized",
-            ErrorCode::AttestationNotFound => "Attestation not found",
-            ErrorCode::InvalidSep10Token => "SEP-10 JWT is missing, expired, or invalid",
-            ErrorCode::StorageCorrupted => "On-chain storage entry is corrupted or unreadable",
-            ErrorCode::CacheExpired => "Cache entry has expired",
-            ErrorCode::CacheNotFound => "Cache entry not found",
-            ErrorCode::AuditLogMaxSizeInvalid => "max_audit_log_size must be at least 1",
-            ErrorCode::PendingAdminAlreadyExists => "An admin transfer is already pending",
-            ErrorCode::NoPendingAdmin => "No pending admin transfer found",
-            ErrorCode::NotPendingAdmin => "Caller is not the pending admin",
-            ErrorCode::SessionNotFound => "Session not found",
-            ErrorCode::SessionExpired => "Session has expired",
-            ErrorCode::MissingSigningKey => "Anchor TOML does not publish a signing key",
-        }
-    }
-
-}
+pub use anchorkit_interface::{ErrorCode, ErrorCodeExt};
 
 // ---------------------------------------------------------------------------
 // AnchorKitError — the unified base error type
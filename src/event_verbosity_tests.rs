@@ -0,0 +1,108 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Bytes, Env, Symbol, TryFromVal, Val,
+};
+use ed25519_dalek::SigningKey;
+
+use crate::contract::AnchorKitContractClient;
+#[cfg(not(feature = "legacy-events"))]
+use crate::events::{AttestEvent, EVENT_VERSION};
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::types::{Attestation, EventVerbosity};
+
+const NOW: u64 = 1_700_000_000;
+
+/// Submit one attestation and return the body of its `attest/recorded` event.
+fn submit_and_capture(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey) -> Val {
+    let payload = Bytes::from_array(env, &[3u8; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    client.submit_attestation(attestor, &Address::generate(env), &NOW, &payload, &sig);
+    env.events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            topics.len() == 4
+                && Symbol::try_from_val(env, &topics.get(1).unwrap()) == Ok(symbol_short!("recorded"))
+        })
+        .map(|(_, _, data)| data)
+        .unwrap()
+}
+
+#[cfg(not(feature = "legacy-events"))]
+#[test]
+fn test_default_is_standard() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    assert_eq!(client.get_event_verbosity(), EventVerbosity::Standard);
+    let data = submit_and_capture(&env, &client, &attestor, &sk);
+    assert!(AttestEvent::try_from_val(&env, &data).is_ok());
+}
+
+#[cfg(not(feature = "legacy-events"))]
+#[test]
+fn test_minimal_carries_only_id() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.set_event_verbosity(&EventVerbosity::Minimal);
+    let data = submit_and_capture(&env, &client, &attestor, &sk);
+    assert!(u64::try_from_val(&env, &data).is_ok());
+}
+
+#[cfg(not(feature = "legacy-events"))]
+#[test]
+fn test_topics_carry_issuer_and_subject() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let subject = Address::generate(&env);
+    let payload = Bytes::from_array(&env, &[4u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    let id = client.submit_attestation(&attestor, &subject, &NOW, &payload, &sig);
+    let (_, topics, data) = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics.len() == 4
+                && Symbol::try_from_val(&env, &topics.get(1).unwrap()) == Ok(symbol_short!("recorded"))
+        })
+        .last()
+        .unwrap();
+    assert_eq!(Address::try_from_val(&env, &topics.get(2).unwrap()), Ok(attestor));
+    assert_eq!(Address::try_from_val(&env, &topics.get(3).unwrap()), Ok(subject));
+    let body = AttestEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(body.version, EVENT_VERSION);
+    assert_eq!(body.id, id);
+}
+
+#[test]
+fn test_verbose_carries_full_record() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.set_event_verbosity(&EventVerbosity::Verbose);
+    let data = submit_and_capture(&env, &client, &attestor, &sk);
+    let record = Attestation::try_from_val(&env, &data).unwrap();
+    assert_eq!(record.issuer, attestor);
+}
+
+#[cfg(feature = "legacy-events")]
+#[test]
+fn test_legacy_layout_keys_on_id() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let payload = Bytes::from_array(&env, &[3u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    let id = client.submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &sig);
+    let (topics, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| Symbol::try_from_val(&env, &topics.get(1).unwrap()) == Ok(symbol_short!("recorded")))
+        .map(|(_, topics, data)| (topics, data))
+        .unwrap();
+    assert_eq!(u64::try_from_val(&env, &topics.get(2).unwrap()), Ok(id));
+    let event = crate::events::AttestEventV1::try_from_val(&env, &data).unwrap();
+    assert_eq!((event.payload_hash, event.timestamp), (payload, NOW));
+}
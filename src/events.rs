@@ -1,4 +1,21 @@
-use soroban_sdk::{contracttype, Address, Bytes, String};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, String, Symbol};
+
+use crate::types::{RentTarget, SchemaChange};
+
+#[cfg_attr(feature = "legacy-events", allow(unused_imports))]
+pub use anchorkit_interface::{
+    AttestEvent, AttestationAmended, AttestationRevoked, StatusChanged, SubjectTopicEvent, UriUpdated, EVENT_VERSION,
+};
+
+/// Pre-0.1.0 body of the `("attest", "recorded", id, subject)` event,
+/// published instead of [`AttestEvent`] with the `legacy-events` feature.
+#[cfg(feature = "legacy-events")]
+#[contracttype]
+#[derive(Clone)]
+pub struct AttestEventV1 {
+    pub payload_hash: Bytes,
+    pub timestamp: u64,
+}
 
 #[contracttype]
 #[derive(Clone)]
@@ -37,13 +54,6 @@ pub struct AuditLogEvent {
     pub status: String,
 }
 
-#[contracttype]
-#[derive(Clone)]
-pub struct AttestEvent {
-    pub payload_hash: Bytes,
-    pub timestamp: u64,
-}
-
 #[contracttype]
 #[derive(Clone)]
 pub struct AuditLogPruned {
@@ -97,3 +107,179 @@ pub struct QuoteExpiredEvent {
     pub quote_id: u64,
     pub valid_until: u64,
 }
+
+/// Registry health summary emitted every `metrics_interval` attestations.
+///
+/// `revocation_rate_bps` is the share of all attestors ever registered that
+/// have since been revoked, in basis points (0–10_000).
+#[contracttype]
+#[derive(Clone)]
+pub struct MetricsSnapshot {
+    pub total_attestations: u64,
+    pub active_attestors: u64,
+    pub revoked_attestors: u64,
+    pub revocation_rate_bps: u32,
+    pub ledger_sequence: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SchemaRegistered {
+    pub schema_id: u64,
+    pub creator: Address,
+    pub schema_hash: BytesN<32>,
+    pub metadata_uri: String,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SchemaChangePending {
+    pub schema_id: u64,
+    pub change: SchemaChange,
+    /// Unix seconds from which the change applies.
+    pub effective_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SchemaIssuerUpdated {
+    pub schema_id: u64,
+    pub attestor: Address,
+    pub authorized: bool,
+    /// Grant expiry (Unix seconds); `0` for a permanent grant or a removal.
+    pub expires_at: u64,
+}
+
+/// Body of the `("attest", "visible", id)` event.
+#[contracttype]
+#[derive(Clone)]
+pub struct VisibilityChanged {
+    pub subject: Address,
+    pub hidden: bool,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ConsentGranted {
+    pub subject: Address,
+    pub issuer: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ConsentRevoked {
+    pub subject: Address,
+    pub issuer: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AttestorSlashed {
+    pub attestor: Address,
+    pub amount: i128,
+    /// Hash of the off-chain evidence or ruling behind the slash.
+    pub reason_hash: BytesN<32>,
+    /// Stake left after the slash.
+    pub remaining: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct FeeUpdated {
+    pub token: Address,
+    pub amount: i128,
+    pub recipient: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ContractUpgraded {
+    /// `version()` of the code being replaced.
+    pub from_version: u32,
+    pub new_wasm_hash: BytesN<32>,
+}
+
+// Rejection diagnostics. Published right before a call fails so the
+// offending value shows up next to the bare error code; the failed call's
+// events are rolled back, so they are only visible in diagnostic output.
+
+/// `field` held a time outside `[earliest, latest]`.
+#[contracttype]
+#[derive(Clone)]
+pub struct TimestampRejected {
+    pub field: Symbol,
+    pub value: u64,
+    pub earliest: u64,
+    pub latest: u64,
+}
+
+/// `payload_hash` was already recorded by `issuer` for `subject`.
+#[contracttype]
+#[derive(Clone)]
+pub struct ReplayRejected {
+    pub issuer: Address,
+    pub subject: Address,
+    pub payload_hash: Bytes,
+}
+
+/// The `scope` (`schema`, `org` or `org_window`) identified by `id` has
+/// used up its `limit`.
+#[contracttype]
+#[derive(Clone)]
+pub struct QuotaExceeded {
+    pub scope: Symbol,
+    pub id: u64,
+    pub limit: u64,
+    pub used: u64,
+}
+
+/// `field` was `len` long, over the configured `max`.
+#[contracttype]
+#[derive(Clone)]
+pub struct FieldRejected {
+    pub field: Symbol,
+    pub len: u32,
+    pub max: u32,
+}
+
+/// Body of the `("attest", "tainted", id)` event.
+#[contracttype]
+#[derive(Clone)]
+pub struct LineageTainted {
+    pub id: u64,
+    /// Attestation revoked for cause that tainted `id`.
+    pub source: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct OrgCreated {
+    pub org_id: u64,
+    pub admin: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct OrgAdminChanged {
+    pub org_id: u64,
+    pub old_admin: Address,
+    pub new_admin: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct OrgAttestorUpdated {
+    pub org_id: u64,
+    pub attestor: Address,
+    pub added: bool,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RentSponsored {
+    pub target: RentTarget,
+    pub depositor: Address,
+    pub amount: i128,
+    /// Pool balance after the deposit.
+    pub balance: i128,
+}
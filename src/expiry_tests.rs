@@ -0,0 +1,55 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+
+fn input(env: &Env, sk: &SigningKey, timestamp: u64, expires_at: u64) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[1u8; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(Address::generate(env), timestamp, payload, sig).with_expiry(expires_at)
+}
+
+#[test]
+fn test_attestation_expires_at_deadline() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let id = client.record_attestation(&attestor, &input(&env, &sk, NOW, NOW + 3_600)).id;
+    assert!(client.is_valid(&id));
+    assert_eq!(client.get_attestation(&id).unwrap().expires_at, NOW + 3_600);
+
+    env.ledger().with_mut(|li| li.timestamp = NOW + 3_600);
+    assert!(!client.is_valid(&id));
+    assert!(client.get_attestation_v2(&id).unwrap().validity.expired);
+}
+
+#[test]
+fn test_is_valid_reflects_revocation_and_missing_ids() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let id = client.record_attestation(&attestor, &input(&env, &sk, NOW, 0)).id;
+    assert!(client.is_valid(&id));
+    client.revoke_attestation(&attestor, &id);
+    assert!(!client.is_valid(&id));
+    assert!(!client.is_valid(&(id + 1)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #78)")]
+fn test_already_expired_attestation_rejected() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.record_attestation(&attestor, &input(&env, &sk, NOW - 60, NOW));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn test_expiry_before_timestamp_rejected() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.record_attestation(&attestor, &input(&env, &sk, NOW, NOW - 1));
+}
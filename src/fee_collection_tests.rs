@@ -0,0 +1,163 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Bytes, BytesN, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::deterministic_hash::compute_bundle_hash;
+use crate::errors::ErrorCodeExt;
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::{AttestationInput, ChallengeStatus, FeeConfig, FeeEscrowConfig};
+
+const NOW: u64 = 1_700_000_000;
+
+struct Setup<'a> {
+    client: AnchorKitContractClient<'a>,
+    attestor: Address,
+    sk: SigningKey,
+    token: Address,
+    recipient: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    let token = env.register_stellar_asset_contract_v2(Address::generate(env)).address();
+    Setup { client, attestor, sk, token, recipient: Address::generate(env) }
+}
+
+fn input(env: &Env, sk: &SigningKey, seed: u8) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(Address::generate(env), NOW, payload, sig)
+}
+
+#[test]
+fn test_fee_transferred_to_recipient() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert_eq!(s.client.get_fee(), None);
+    token::StellarAssetClient::new(&env, &s.token).mint(&s.attestor, &1_000);
+    s.client.set_fee(&s.token, &250, &s.recipient);
+    assert_eq!(
+        s.client.get_fee(),
+        Some(FeeConfig { token: s.token.clone(), amount: 250, recipient: s.recipient.clone() })
+    );
+    assert_eq!(s.client.get_effective_fee(&s.attestor, &None), 250);
+
+    let receipt = s.client.record_attestation(&s.attestor, &input(&env, &s.sk, 1));
+    assert_eq!(receipt.fee_paid, 250);
+    let balances = token::Client::new(&env, &s.token);
+    assert_eq!(balances.balance(&s.attestor), 750);
+    assert_eq!(balances.balance(&s.recipient), 250);
+
+    // A waived issuer pays nothing.
+    s.client.set_attestor_fee_override(&s.attestor, &Some(0));
+    assert_eq!(s.client.record_attestation(&s.attestor, &input(&env, &s.sk, 2)).fee_paid, 0);
+    assert_eq!(balances.balance(&s.attestor), 750);
+}
+
+#[test]
+fn test_unpaid_fee_blocks_recording() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_fee(&s.token, &250, &s.recipient);
+    let err = s.client.try_record_attestation(&s.attestor, &input(&env, &s.sk, 1));
+    assert_eq!(
+        err.err().unwrap().unwrap(),
+        soroban_sdk::Error::from_contract_error(ErrorCodeExt::FeePaymentFailed as u32)
+    );
+    assert_eq!(s.client.get_attestation_count(), 0);
+}
+
+#[test]
+fn test_relayer_pays_delegated_fee() {
+    let env = Env::default();
+    let s = setup(&env);
+    let relayer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &s.token).mint(&relayer, &1_000);
+    s.client.set_fee(&s.token, &250, &s.recipient);
+
+    let delegated = input(&env, &s.sk, 1);
+    let message = s.client.delegation_message_public(&s.attestor, &delegated, &(NOW + 60), &0);
+    let auth = sign_payload(&env, &s.sk, &message);
+    let receipt = s.client.attest_delegated(&relayer, &s.attestor, &delegated, &(NOW + 60), &0, &auth);
+    assert_eq!(receipt.fee_paid, 250);
+    let balances = token::Client::new(&env, &s.token);
+    assert_eq!(balances.balance(&relayer), 750);
+    assert_eq!(balances.balance(&s.attestor), 0);
+}
+
+#[test]
+fn test_bundle_pays_fee_per_claim() {
+    let env = Env::default();
+    let s = setup(&env);
+    token::StellarAssetClient::new(&env, &s.token).mint(&s.attestor, &1_000);
+    s.client.set_fee(&s.token, &250, &s.recipient);
+
+    let subject = Address::generate(&env);
+    let claims = vec![&env, (1u64, Bytes::from_array(&env, &[1u8; 32])), (2u64, Bytes::from_array(&env, &[2u8; 32]))];
+    let hash = compute_bundle_hash(&env, &subject, NOW, &claims);
+    let sig = sign_payload(&env, &s.sk, &Bytes::from(hash));
+    s.client.submit_bundle(&s.attestor, &subject, &NOW, &claims, &sig);
+    let balances = token::Client::new(&env, &s.token);
+    assert_eq!(balances.balance(&s.attestor), 500);
+    assert_eq!(balances.balance(&s.recipient), 500);
+}
+
+fn escrow_setup(env: &Env) -> (Setup<'_>, Address) {
+    let s = setup(env);
+    token::StellarAssetClient::new(env, &s.token).mint(&s.attestor, &1_000);
+    s.client.set_fee(&s.token, &250, &s.recipient);
+    let pool = Address::generate(env);
+    s.client.set_fee_escrow(&Some(FeeEscrowConfig { window: 3_600, challenger_pool: pool.clone() }));
+    (s, pool)
+}
+
+#[test]
+fn test_escrowed_fee_released_after_window() {
+    let env = Env::default();
+    let (s, _) = escrow_setup(&env);
+    let id = s.client.record_attestation(&s.attestor, &input(&env, &s.sk, 1)).id;
+    let balances = token::Client::new(&env, &s.token);
+    assert_eq!(balances.balance(&s.client.address), 250);
+    assert_eq!(s.client.get_escrowed_fee(&id).unwrap().release_at, NOW + 3_600);
+
+    let err = s.client.try_release_escrowed_fee(&id);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::FeeEscrowLocked as u32))));
+    env.ledger().with_mut(|li| li.timestamp = NOW + 3_600);
+    s.client.release_escrowed_fee(&id);
+    assert_eq!(balances.balance(&s.recipient), 250);
+    assert_eq!(s.client.get_escrowed_fee(&id), None);
+    let err = s.client.try_release_escrowed_fee(&id);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::FeeNotEscrowed as u32))));
+}
+
+#[test]
+fn test_invalidating_challenge_forfeits_escrowed_fee() {
+    let env = Env::default();
+    let (s, pool) = escrow_setup(&env);
+    let id = s.client.record_attestation(&s.attestor, &input(&env, &s.sk, 1)).id;
+    let cid = s.client.challenge_attestation(&Address::generate(&env), &id, &BytesN::from_array(&env, &[9; 32]), &0);
+
+    // An open challenge holds the fee past the window.
+    env.ledger().with_mut(|li| li.timestamp = NOW + 3_600);
+    let err = s.client.try_release_escrowed_fee(&id);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::FeeEscrowLocked as u32))));
+
+    s.client.resolve_challenge(&s.client.get_admin(), &cid, &ChallengeStatus::Invalidated);
+    let balances = token::Client::new(&env, &s.token);
+    assert_eq!(balances.balance(&pool), 250);
+    assert_eq!(balances.balance(&s.recipient), 0);
+    assert_eq!(s.client.get_escrowed_fee(&id), None);
+}
@@ -0,0 +1,46 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Bytes, Env, String};
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, u64) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let owner = Address::generate(env);
+    let schema_id = client.register_schema(&owner, &Bytes::from_array(env, &[1u8; 32]), &String::from_str(env, "kyc"));
+    client.set_schema_fee(&owner, &schema_id, &500);
+    (client, schema_id)
+}
+
+#[test]
+fn test_fee_precedence() {
+    let env = Env::default();
+    let (client, schema_id) = setup(&env);
+    let attestor = Address::generate(&env);
+
+    assert_eq!(client.get_effective_fee(&attestor, &None), 0);
+    assert_eq!(client.get_effective_fee(&attestor, &Some(schema_id)), 500);
+
+    client.set_attestor_fee_override(&attestor, &Some(120));
+    assert_eq!(client.get_effective_fee(&attestor, &Some(schema_id)), 120);
+    assert_eq!(client.get_effective_fee(&attestor, &None), 120);
+
+    // A zero override waives the schema fee entirely.
+    client.set_attestor_fee_override(&attestor, &Some(0));
+    assert_eq!(client.get_attestor_fee_override(&attestor), Some(0));
+    assert_eq!(client.get_effective_fee(&attestor, &Some(schema_id)), 0);
+
+    client.set_attestor_fee_override(&attestor, &None);
+    assert_eq!(client.get_attestor_fee_override(&attestor), None);
+    assert_eq!(client.get_effective_fee(&attestor, &Some(schema_id)), 500);
+}
+
+#[test]
+fn test_negative_override_rejected() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    assert!(client.try_set_attestor_fee_override(&Address::generate(&env), &Some(-1)).is_err());
+}
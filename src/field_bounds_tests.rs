@@ -0,0 +1,63 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, Env};
+use ed25519_dalek::Signer;
+
+use crate::deterministic_hash::compute_bundle_hash;
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::types::FieldBounds;
+use crate::validation::DEFAULT_FIELD_BOUNDS;
+
+const NOW: u64 = 1_700_000_000;
+
+#[test]
+fn test_default_bounds_accept_normal_submission() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    assert_eq!(client.get_field_bounds(), DEFAULT_FIELD_BOUNDS);
+
+    let payload = Bytes::from_array(&env, &[1u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    client.submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &sig);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #75)")]
+fn test_oversized_payload_hash_rejected() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.set_field_bounds(&FieldBounds { max_payload_hash_len: 16, ..DEFAULT_FIELD_BOUNDS });
+
+    let payload = Bytes::from_array(&env, &[1u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    client.submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &sig);
+}
+
+#[test]
+fn test_zero_bound_disables_check() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.set_field_bounds(&FieldBounds { max_payload_hash_len: 0, max_signature_len: 0, max_bundle_claims: 0 });
+
+    let payload = Bytes::from_slice(&env, &[7u8; 200]);
+    let sig = Bytes::from_slice(&env, &sk.sign(&[7u8; 200]).to_bytes());
+    client.submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &sig);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #75)")]
+fn test_bundle_claim_count_bounded() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.set_field_bounds(&FieldBounds { max_bundle_claims: 1, ..DEFAULT_FIELD_BOUNDS });
+
+    let subject = Address::generate(&env);
+    let claims = vec![
+        &env,
+        (1u64, Bytes::from_array(&env, &[1u8; 32])),
+        (2u64, Bytes::from_array(&env, &[2u8; 32])),
+    ];
+    let hash = compute_bundle_hash(&env, &subject, NOW, &claims);
+    let sig = sign_payload(&env, &sk, &Bytes::from(hash));
+    client.submit_bundle(&attestor, &subject, &NOW, &claims, &sig);
+}
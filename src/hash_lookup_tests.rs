@@ -0,0 +1,46 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+
+use crate::contract::AnchorKitContractClient;
+use crate::errors::ErrorCode;
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::types::ReplayScope;
+
+const NOW: u64 = 1_700_000_000;
+
+fn submit(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey, seed: u8) -> (u64, Bytes) {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    let id = client.submit_attestation(attestor, &Address::generate(env), &NOW, &payload, &sig);
+    (id, payload)
+}
+
+#[test]
+fn test_hash_resolves_to_record() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    submit(&env, &client, &attestor, &sk, 1);
+    let (id, payload) = submit(&env, &client, &attestor, &sk, 2);
+    let attestation = client.get_attestation_by_hash(&payload);
+    assert_eq!(attestation.id, id);
+    assert_eq!(attestation.payload_hash, payload);
+}
+
+#[test]
+fn test_unknown_hash_not_found() {
+    let env = Env::default();
+    let (client, _, _) = setup_attestor(&env, NOW);
+    let err = client.try_get_attestation_by_hash(&Bytes::from_array(&env, &[9u8; 32]));
+    assert_eq!(err.err(), Some(Ok(ErrorCode::AttestationNotFound)));
+}
+
+#[test]
+fn test_per_issuer_scope_does_not_resolve() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.set_replay_scope(&ReplayScope::PerIssuer);
+    let (_, payload) = submit(&env, &client, &attestor, &sk, 3);
+    assert!(client.try_get_attestation_by_hash(&payload).is_err());
+}
@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::Status;
+
+const NOW: u64 = 1_700_000_000;
+
+fn setup(env: &Env, count: u8) -> AnchorKitContractClient<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    for seed in 0..count {
+        let payload = Bytes::from_array(env, &[seed; 32]);
+        let sig = sign_payload(env, &sk, &payload);
+        client.submit_attestation(&attestor, &Address::generate(env), &NOW, &payload, &sig);
+    }
+    client
+}
+
+#[test]
+fn test_lists_sequential_ids() {
+    let env = Env::default();
+    let client = setup(&env, 5);
+    let page = client.list_attestations_by_id(&1, &3);
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(0).unwrap().id, 1);
+    assert_eq!(page.get(2).unwrap().id, 3);
+    assert_eq!(client.list_attestations_by_id(&5, &10).len(), 0);
+}
+
+#[test]
+fn test_skips_erased_ids() {
+    let env = Env::default();
+    let client = setup(&env, 4);
+    client.erase_attestation(&1);
+    let page = client.list_attestations_by_id(&0, &4);
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(1).unwrap().id, 2);
+}
+
+#[test]
+fn test_limit_capped_at_max_page_size() {
+    let env = Env::default();
+    let client = setup(&env, 5);
+    client.set_max_page_size(&2);
+    assert_eq!(client.list_attestations_by_id(&0, &50).len(), 2);
+}
+
+#[test]
+fn test_limit_capped_at_sync_page() {
+    let env = Env::default();
+    let client = setup(&env, 25);
+    assert_eq!(client.list_attestations_by_id(&0, &50).len(), 20);
+}
+
+#[test]
+fn test_lists_current_status() {
+    let env = Env::default();
+    let client = setup(&env, 2);
+    client.set_attestation_max_age(&60);
+    env.ledger().with_mut(|li| li.timestamp = NOW + 61);
+    let page = client.list_attestations_by_id(&0, &2);
+    assert_eq!(page.get(0).unwrap().status, Status::Expired);
+}
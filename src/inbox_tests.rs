@@ -0,0 +1,43 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+
+use crate::contract::AnchorKitContractClient;
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+
+const NOW: u64 = 1_700_000_000;
+
+fn submit(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey, subject: &Address, seed: u8) -> u64 {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    client.submit_attestation(attestor, subject, &NOW, &payload, &sig)
+}
+
+#[test]
+fn test_inbox_keeps_most_recent_ids() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let subject = Address::generate(&env);
+    assert!(client.get_inbox(&subject).is_empty());
+
+    let mut last = 0;
+    for seed in 0..20u8 {
+        last = submit(&env, &client, &attestor, &sk, &subject, seed);
+    }
+    let inbox = client.get_inbox(&subject);
+    assert_eq!(inbox.len(), 16);
+    assert_eq!(inbox.first().unwrap(), last - 15);
+    assert_eq!(inbox.last().unwrap(), last);
+}
+
+#[test]
+fn test_inbox_skips_erased_attestations() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let subject = Address::generate(&env);
+    let first = submit(&env, &client, &attestor, &sk, &subject, 1);
+    let second = submit(&env, &client, &attestor, &sk, &subject, 2);
+    client.erase_attestation(&first);
+    assert_eq!(client.get_inbox(&subject), soroban_sdk::vec![&env, second]);
+}
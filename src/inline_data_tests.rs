@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Bytes, Env};
+#[cfg(not(feature = "legacy-events"))]
+use soroban_sdk::{symbol_short, testutils::Events, Symbol, TryFromVal};
+use ed25519_dalek::SigningKey;
+
+use crate::errors::ErrorCode;
+#[cfg(not(feature = "legacy-events"))]
+use crate::events::AttestEvent;
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+#[cfg(not(feature = "legacy-events"))]
+use crate::types::AttestationData;
+use crate::types::{AttestationInput, MAX_INLINE_DATA_LEN};
+
+const NOW: u64 = 1_700_000_000;
+
+/// Input signing the SHA-256 of `claim` and carrying `claim` inline.
+fn inline_input(env: &Env, sk: &SigningKey, claim: &Bytes) -> AttestationInput {
+    let payload: Bytes = env.crypto().sha256(claim).into();
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(Address::generate(env), NOW, payload, sig).with_inline_data(claim.clone())
+}
+
+#[cfg(not(feature = "legacy-events"))]
+fn recorded_event(env: &Env) -> AttestEvent {
+    env.events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            topics.len() == 4
+                && Symbol::try_from_val(env, &topics.get(1).unwrap()) == Ok(symbol_short!("recorded"))
+        })
+        .map(|(_, _, data)| AttestEvent::try_from_val(env, &data).unwrap())
+        .unwrap()
+}
+
+#[cfg(not(feature = "legacy-events"))]
+#[test]
+fn test_inline_claim_stored_and_announced() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let claim = Bytes::from_slice(&env, b"{\"kyc\":\"tier2\"}");
+    let id = client.record_attestation(&attestor, &inline_input(&env, &sk, &claim)).id;
+    assert_eq!(recorded_event(&env).data, AttestationData::Inline(claim.clone()));
+    assert_eq!(client.get_attestation(&id).unwrap().data, AttestationData::Inline(claim));
+}
+
+#[cfg(not(feature = "legacy-events"))]
+#[test]
+fn test_hash_only_by_default() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let payload = Bytes::from_array(&env, &[5u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    let id = client.submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &sig);
+    assert_eq!(recorded_event(&env).data, AttestationData::HashOnly);
+    assert_eq!(client.get_attestation(&id).unwrap().data, AttestationData::HashOnly);
+}
+
+#[test]
+fn test_oversized_inline_claim_rejected() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let mut claim = Bytes::new(&env);
+    for _ in 0..=MAX_INLINE_DATA_LEN {
+        claim.push_back(1);
+    }
+    let err = client.try_record_attestation(&attestor, &inline_input(&env, &sk, &claim));
+    assert_eq!(err.err(), Some(Ok(soroban_sdk::Error::from_contract_error(ErrorCode::FieldTooLarge as u32))));
+}
+
+#[test]
+fn test_inline_claim_must_match_signed_hash() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let input = inline_input(&env, &sk, &Bytes::from_slice(&env, b"approved"))
+        .with_inline_data(Bytes::from_slice(&env, b"rejected"));
+    let err = client.try_record_attestation(&attestor, &input);
+    assert_eq!(err.err(), Some(Ok(soroban_sdk::Error::from_contract_error(ErrorCode::ValidationError as u32))));
+}
@@ -0,0 +1,23 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, Env};
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+
+#[test]
+fn test_supports_implemented_interfaces() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(&env, &contract_id);
+
+    // Introspection works before initialization.
+    assert!(client.supports(&symbol_short!("core")));
+    assert!(client.supports(&symbol_short!("schemas")));
+    assert!(client.supports(&symbol_short!("fees")));
+    assert!(!client.supports(&symbol_short!("feeds")));
+
+    let interfaces = client.get_interfaces();
+    for iface in interfaces.iter() {
+        assert!(client.supports(&iface));
+    }
+}
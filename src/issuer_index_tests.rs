@@ -0,0 +1,52 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Bytes, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+
+const NOW: u64 = 1_700_000_000;
+
+fn register(env: &Env, client: &AnchorKitContractClient) -> (Address, SigningKey) {
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, client, &attestor, &attestor, &sk);
+    (attestor, sk)
+}
+
+fn submit(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey, seed: u8) -> u64 {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    client.submit_attestation(attestor, &Address::generate(env), &NOW, &payload, &sig)
+}
+
+#[test]
+fn test_issuer_index_pages_own_attestations() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env), &100_u64, &None);
+    let (alice, alice_sk) = register(&env, &client);
+    let (bob, bob_sk) = register(&env, &client);
+
+    let a0 = submit(&env, &client, &alice, &alice_sk, 1);
+    let b0 = submit(&env, &client, &bob, &bob_sk, 2);
+    let a1 = submit(&env, &client, &alice, &alice_sk, 3);
+    let a2 = submit(&env, &client, &alice, &alice_sk, 4);
+    client.revoke_attestation(&alice, &a1);
+    client.erase_attestation(&a0);
+
+    assert_eq!(client.count_by_issuer(&alice), 3);
+    assert_eq!(client.count_by_issuer(&bob), 1);
+    assert_eq!(client.get_attestations_by_issuer(&alice, &0, &10), vec![&env, a1, a2]);
+    assert_eq!(client.get_attestations_by_issuer(&alice, &2, &10), vec![&env, a2]);
+    assert_eq!(client.get_attestations_by_issuer(&bob, &0, &10), vec![&env, b0]);
+    assert!(client.get_attestations_by_issuer(&alice, &3, &10).is_empty());
+}
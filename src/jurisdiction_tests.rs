@@ -0,0 +1,55 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+
+fn input(env: &Env, sk: &SigningKey, seed: u8) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(Address::generate(env), NOW, payload, sig)
+}
+
+#[test]
+fn test_jurisdiction_is_stored_and_indexed() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+
+    let us1 = client.record_attestation(&attestor, &input(&env, &sk, 1).with_jurisdiction(symbol_short!("US"))).id;
+    let eu = client.record_attestation(&attestor, &input(&env, &sk, 2).with_jurisdiction(symbol_short!("EU"))).id;
+    let untagged = client.record_attestation(&attestor, &input(&env, &sk, 3)).id;
+    let us2 = client.record_attestation(&attestor, &input(&env, &sk, 4).with_jurisdiction(symbol_short!("US"))).id;
+
+    assert_eq!(client.get_attestation(&eu).unwrap().jurisdiction, Some(symbol_short!("EU")));
+    assert_eq!(client.get_attestation(&untagged).unwrap().jurisdiction, None);
+
+    assert_eq!(client.get_jurisdiction_count(&symbol_short!("US")), 2);
+    assert_eq!(client.get_jurisdiction_count(&symbol_short!("SG")), 0);
+
+    let us = client.list_by_jurisdiction(&symbol_short!("US"), &0, &10);
+    assert_eq!(us.len(), 2);
+    assert_eq!(us.get(0).unwrap().id, us1);
+    assert_eq!(us.get(1).unwrap().id, us2);
+
+    let page = client.list_by_jurisdiction(&symbol_short!("US"), &1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, us2);
+}
+
+#[test]
+fn test_jurisdiction_listing_skips_erased() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+
+    let first = client.record_attestation(&attestor, &input(&env, &sk, 1).with_jurisdiction(symbol_short!("EU"))).id;
+    let second = client.record_attestation(&attestor, &input(&env, &sk, 2).with_jurisdiction(symbol_short!("EU"))).id;
+    client.erase_attestation(&first);
+
+    let eu = client.list_by_jurisdiction(&symbol_short!("EU"), &0, &10);
+    assert_eq!(eu.len(), 1);
+    assert_eq!(eu.get(0).unwrap().id, second);
+}
@@ -5,9 +5,13 @@ mod deterministic_hash;
 mod domain_validator;
 mod errors;
 mod events;
+mod merkle;
 mod storage;
 mod types;
+mod roles;
 mod validation;
+#[cfg(feature = "test-vectors")]
+mod test_vectors;
 
 #[cfg(test)]
 mod config_tests;
@@ -102,3 +106,234 @@ mod anchor_health_score_tests;
 
 #[cfg(test)]
 mod compute_payload_hash_tests;
+
+#[cfg(test)]
+mod metrics_snapshot_tests;
+
+#[cfg(test)]
+mod schema_issuer_tests;
+
+#[cfg(test)]
+mod org_tests;
+
+#[cfg(test)]
+mod consent_receipt_tests;
+
+#[cfg(test)]
+mod attestation_validity_tests;
+
+#[cfg(test)]
+mod selective_disclosure_tests;
+
+#[cfg(test)]
+mod policy_tests;
+
+#[cfg(test)]
+mod bundle_tests;
+
+#[cfg(test)]
+mod primary_attestation_tests;
+
+#[cfg(test)]
+mod attestation_input_tests;
+
+#[cfg(test)]
+mod replay_scope_tests;
+
+#[cfg(test)]
+mod subject_successor_tests;
+
+#[cfg(test)]
+mod event_verbosity_tests;
+
+#[cfg(test)]
+mod embargo_tests;
+
+#[cfg(test)]
+mod pinning_tests;
+
+#[cfg(test)]
+mod attestor_exit_tests;
+
+#[cfg(test)]
+mod schema_governance_tests;
+
+#[cfg(test)]
+mod sunset_tests;
+
+#[cfg(test)]
+mod jurisdiction_tests;
+
+#[cfg(test)]
+mod weight_tests;
+
+#[cfg(test)]
+mod schema_grant_tests;
+
+#[cfg(test)]
+mod schema_cap_tests;
+
+#[cfg(test)]
+mod supersession_tests;
+
+#[cfg(test)]
+mod introspection_tests;
+
+#[cfg(test)]
+mod fee_override_tests;
+
+#[cfg(test)]
+mod tier_suspension_tests;
+
+#[cfg(test)]
+mod test_vectors_tests;
+
+#[cfg(test)]
+mod subject_topic_tests;
+
+#[cfg(test)]
+mod retention_tests;
+
+#[cfg(test)]
+mod field_bounds_tests;
+
+#[cfg(test)]
+mod attestor_activity_tests;
+
+#[cfg(test)]
+mod attestation_export_tests;
+
+#[cfg(test)]
+mod attestation_revocation_tests;
+
+#[cfg(test)]
+mod value_bucket_tests;
+
+#[cfg(test)]
+mod attestor_key_tests;
+
+#[cfg(test)]
+mod expiry_tests;
+
+#[cfg(test)]
+mod subject_index_tests;
+
+#[cfg(test)]
+mod inbox_tests;
+
+#[cfg(test)]
+mod issuer_index_tests;
+
+#[cfg(test)]
+mod batch_attest_tests;
+
+#[cfg(test)]
+mod asset_attestation_tests;
+
+#[cfg(test)]
+mod root_anchor_tests;
+
+#[cfg(test)]
+mod schema_change_delay_tests;
+
+#[cfg(test)]
+mod subsystem_pause_tests;
+
+#[cfg(test)]
+mod proof_bundle_tests;
+
+#[cfg(test)]
+mod roles_tests;
+
+#[cfg(test)]
+mod attestor_info_tests;
+
+#[cfg(test)]
+mod relayed_revocation_tests;
+
+#[cfg(test)]
+mod secp256k1_key_tests;
+
+#[cfg(test)]
+mod attestation_ref_tests;
+
+#[cfg(test)]
+mod rejection_event_tests;
+
+#[cfg(test)]
+mod schema_tier_tests;
+
+#[cfg(test)]
+mod ttl_extension_tests;
+
+#[cfg(test)]
+mod upgrade_tests;
+
+#[cfg(test)]
+mod delegated_attestation_tests;
+
+#[cfg(test)]
+mod consent_mode_tests;
+
+#[cfg(test)]
+mod stats_getters_tests;
+
+#[cfg(test)]
+mod fee_collection_tests;
+
+#[cfg(test)]
+mod staking_tests;
+
+#[cfg(test)]
+mod dispute_tests;
+
+#[cfg(test)]
+mod id_range_listing_tests;
+
+#[cfg(test)]
+mod inline_data_tests;
+
+#[cfg(test)]
+mod hash_lookup_tests;
+
+#[cfg(test)]
+mod cosign_tests;
+
+#[cfg(test)]
+mod status_lifecycle_tests;
+
+#[cfg(test)]
+mod attestor_schema_tests;
+
+#[cfg(test)]
+mod timestamp_drift_tests;
+
+#[cfg(test)]
+mod visibility_tests;
+
+#[cfg(test)]
+mod bulk_attestor_tests;
+
+#[cfg(test)]
+mod ephemeral_attestation_tests;
+
+#[cfg(test)]
+mod amendment_tests;
+
+#[cfg(test)]
+mod subject_gate_tests;
+
+#[cfg(test)]
+mod verify_view_tests;
+
+#[cfg(test)]
+mod attestor_deactivation_tests;
+
+#[cfg(test)]
+mod attestation_uri_tests;
+
+#[cfg(test)]
+mod lineage_taint_tests;
+
+#[cfg(test)]
+mod renewal_keeper_tests;
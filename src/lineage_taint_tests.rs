@@ -0,0 +1,85 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Bytes, Env, Symbol, TryFromVal,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::events::LineageTainted;
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::{AttestationInput, Status};
+
+const NOW: u64 = 1_700_000_000;
+
+/// Records a three-link renewal chain and returns `(client, attestor, [first, second, third])`.
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, [u64; 3]) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+
+    let subject = Address::generate(env);
+    let mut ids = [0u64; 3];
+    for (i, seed) in [1u8, 2, 3].iter().enumerate() {
+        let payload = Bytes::from_array(env, &[*seed; 32]);
+        let input = if i > 0 {
+            let bound = client.compute_ref_binding_hash_public(&payload, &ids[i - 1]);
+            let sig = sign_payload(env, &sk, &Bytes::from(bound));
+            AttestationInput::new(subject.clone(), NOW, payload, sig).with_ref(ids[i - 1])
+        } else {
+            let sig = sign_payload(env, &sk, &payload);
+            AttestationInput::new(subject.clone(), NOW, payload, sig)
+        };
+        ids[i] = client.record_attestation(&attestor, &input).id;
+    }
+    (client, attestor, ids)
+}
+
+#[test]
+fn test_cause_revocation_taints_whole_lineage() {
+    let env = Env::default();
+    let (client, attestor, [first, second, third]) = setup(&env);
+    client.revoke_attestation_for_cause(&attestor, &second, &true);
+
+    assert_eq!(client.get_attestation(&second).unwrap().status, Status::Revoked);
+    // The older link is flagged, the newer one inherits it through its chain.
+    assert!(client.is_lineage_tainted(&first));
+    assert!(client.is_lineage_tainted(&second));
+    assert!(client.is_lineage_tainted(&third));
+    assert_eq!(client.get_attestation(&third).unwrap().status, Status::Active);
+
+    let sources = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| Symbol::try_from_val(&env, &topics.get(1).unwrap()) == Ok(symbol_short!("tainted")))
+        .map(|(_, _, data)| LineageTainted::try_from_val(&env, &data).unwrap().source)
+        .fold((0, true), |(n, same), source| (n + 1, same && source == second));
+    assert_eq!(sources, (2, true));
+}
+
+#[test]
+fn test_cause_revocation_without_taint() {
+    let env = Env::default();
+    let (client, attestor, [first, second, third]) = setup(&env);
+    client.revoke_attestation_for_cause(&attestor, &second, &false);
+    assert_eq!(client.get_attestation(&second).unwrap().status, Status::Revoked);
+    assert!(!client.is_lineage_tainted(&first));
+    assert!(!client.is_lineage_tainted(&third));
+}
+
+#[test]
+fn test_cause_revocation_needs_issuer_or_admin() {
+    let env = Env::default();
+    let (client, _, [_, second, _]) = setup(&env);
+    assert!(client.try_revoke_attestation_for_cause(&Address::generate(&env), &second, &true).is_err());
+    assert!(!client.is_lineage_tainted(&second));
+}
@@ -0,0 +1,100 @@
+//! Merkle commitments for selective disclosure.
+//!
+//! An attestation's `payload_hash` may be the root of a small Merkle tree whose
+//! leaves are per-field hashes. Leaves and internal nodes are hashed under
+//! different prefixes (`sha256(0x00 || leaf)` and `sha256(0x01 || lo || hi)`),
+//! so an internal node can never be passed off as a leaf with a shorter proof.
+//! Sibling pairs are hashed in sorted order, so a proof is just the list of
+//! sibling hashes from leaf to root and needs no left/right position flags.
+
+use soroban_sdk::{Bytes, BytesN, Env, Vec};
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// Hash a field hash into its tree leaf.
+pub fn hash_leaf(env: &Env, leaf: &BytesN<32>) -> BytesN<32> {
+    let mut input = Bytes::from_array(env, &[LEAF_PREFIX]);
+    input.append(&Bytes::from(leaf.clone()));
+    env.crypto().sha256(&input).into()
+}
+
+/// Hash two sibling nodes into their parent.
+pub fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (lo, hi) = if a.to_array() <= b.to_array() { (a, b) } else { (b, a) };
+    let mut input = Bytes::from_array(env, &[NODE_PREFIX]);
+    input.append(&Bytes::from(lo.clone()));
+    input.append(&Bytes::from(hi.clone()));
+    env.crypto().sha256(&input).into()
+}
+
+/// Fold `leaf` up through `proof` and return the resulting root. The first
+/// proof entry is the sibling's leaf hash (`hash_leaf`), not its field hash.
+pub fn compute_root(env: &Env, leaf: &BytesN<32>, proof: &Vec<BytesN<32>>) -> BytesN<32> {
+    let mut node = hash_leaf(env, leaf);
+    for sibling in proof.iter() {
+        node = hash_pair(env, &node, &sibling);
+    }
+    node
+}
+
+/// Returns `true` when `leaf` together with `proof` hashes to `root`.
+pub fn verify_proof(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: &BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    &compute_root(env, leaf, proof) == root
+}
+
+#[cfg(test)]
+mod merkle_tests {
+    use super::*;
+
+    fn leaf(env: &Env, b: u8) -> BytesN<32> {
+        BytesN::from_array(env, &[b; 32])
+    }
+
+    #[test]
+    fn test_four_leaf_tree_proofs() {
+        let env = Env::default();
+        let (a, b, c, d) = (leaf(&env, 1), leaf(&env, 2), leaf(&env, 3), leaf(&env, 4));
+        let (ha, hb, hc, hd) = (hash_leaf(&env, &a), hash_leaf(&env, &b), hash_leaf(&env, &c), hash_leaf(&env, &d));
+        let ab = hash_pair(&env, &ha, &hb);
+        let cd = hash_pair(&env, &hc, &hd);
+        let root = hash_pair(&env, &ab, &cd);
+
+        let proof_c = soroban_sdk::vec![&env, hd.clone(), ab.clone()];
+        assert!(verify_proof(&env, &root, &c, &proof_c));
+        let proof_b = soroban_sdk::vec![&env, ha.clone(), cd.clone()];
+        assert!(verify_proof(&env, &root, &b, &proof_b));
+    }
+
+    #[test]
+    fn test_wrong_leaf_rejected() {
+        let env = Env::default();
+        let (a, b) = (leaf(&env, 1), leaf(&env, 2));
+        let hb = hash_leaf(&env, &b);
+        let root = hash_pair(&env, &hash_leaf(&env, &a), &hb);
+        let proof = soroban_sdk::vec![&env, hb.clone()];
+        assert!(!verify_proof(&env, &root, &leaf(&env, 9), &proof));
+    }
+
+    #[test]
+    fn test_internal_node_is_not_a_leaf() {
+        let env = Env::default();
+        let (a, b, c, d) = (leaf(&env, 1), leaf(&env, 2), leaf(&env, 3), leaf(&env, 4));
+        let ab = hash_pair(&env, &hash_leaf(&env, &a), &hash_leaf(&env, &b));
+        let cd = hash_pair(&env, &hash_leaf(&env, &c), &hash_leaf(&env, &d));
+        let root = hash_pair(&env, &ab, &cd);
+        assert!(!verify_proof(&env, &root, &ab, &soroban_sdk::vec![&env, cd]));
+    }
+
+    #[test]
+    fn test_pair_hash_is_order_independent() {
+        let env = Env::default();
+        let (a, b) = (leaf(&env, 1), leaf(&env, 2));
+        assert_eq!(hash_pair(&env, &a, &b), hash_pair(&env, &b, &a));
+    }
+}
@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Bytes, Env, Symbol, TryFromVal,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::events::MetricsSnapshot;
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+
+const NOW: u64 = 1_700_000_000;
+
+fn make_env() -> Env {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    env
+}
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, SigningKey) {
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let attestor = Address::generate(env);
+    client.initialize(&admin, &100_u64, &None);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    (client, attestor, sk)
+}
+
+fn submit(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey, byte: u8) {
+    let payload = Bytes::from_array(env, &[byte; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    client.submit_attestation(attestor, &Address::generate(env), &NOW, &payload, &sig);
+}
+
+fn snapshots(env: &Env) -> alloc::vec::Vec<MetricsSnapshot> {
+    let mut out = alloc::vec::Vec::new();
+    for (_, topics, data) in env.events().all().iter() {
+        if topics.len() == 2
+            && Symbol::try_from_val(env, &topics.get(0).unwrap()) == Ok(symbol_short!("metrics"))
+        {
+            out.push(MetricsSnapshot::try_from_val(env, &data).unwrap());
+        }
+    }
+    out
+}
+
+#[test]
+fn test_interval_defaults_to_disabled() {
+    let env = make_env();
+    let (client, attestor, sk) = setup(&env);
+    assert_eq!(client.get_metrics_interval(), 0);
+    submit(&env, &client, &attestor, &sk, 1);
+    assert!(snapshots(&env).is_empty());
+}
+
+#[test]
+fn test_snapshot_emitted_on_interval_boundary() {
+    let env = make_env();
+    let (client, attestor, sk) = setup(&env);
+    client.set_metrics_interval(&2);
+
+    submit(&env, &client, &attestor, &sk, 1);
+    assert!(snapshots(&env).is_empty());
+
+    submit(&env, &client, &attestor, &sk, 2);
+    let snaps = snapshots(&env);
+    assert_eq!(snaps.len(), 1);
+    assert_eq!(snaps[0].total_attestations, 2);
+    assert_eq!(snaps[0].active_attestors, 1);
+    assert_eq!(snaps[0].revoked_attestors, 0);
+    assert_eq!(snaps[0].revocation_rate_bps, 0);
+}
+
+#[test]
+fn test_snapshot_reports_revocation_rate() {
+    let env = make_env();
+    let (client, attestor, sk) = setup(&env);
+
+    let other = Address::generate(&env);
+    let other_sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(&env, &client, &other, &other, &other_sk);
+    client.revoke_attestor(&other);
+
+    client.set_metrics_interval(&1);
+    submit(&env, &client, &attestor, &sk, 3);
+
+    let snaps = snapshots(&env);
+    assert_eq!(snaps.len(), 1);
+    assert_eq!(snaps[0].active_attestors, 1);
+    assert_eq!(snaps[0].revoked_attestors, 1);
+    assert_eq!(snaps[0].revocation_rate_bps, 5_000);
+}
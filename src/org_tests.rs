@@ -0,0 +1,275 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Bytes, Env, Symbol, TryFromVal,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::errors::{ErrorCode, ErrorCodeExt};
+use crate::events::OrgAdminChanged;
+use crate::types::{AttestationInput, OrgLimits, ORG_ID_BITS};
+use crate::sep10_test_util::{sep10_token_for, sign_payload};
+
+const NOW: u64 = 1_700_000_000;
+
+fn make_env() -> Env {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    env
+}
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, u64) {
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin, &100_u64, &None);
+    client.create_org(&Address::generate(env), &1);
+    (client, 1)
+}
+
+fn add_key(env: &Env, client: &AnchorKitContractClient, org_id: u64) -> (Address, SigningKey) {
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    let token = sep10_token_for(env, client, &attestor, &attestor, &sk);
+    client.add_org_attestor(&org_id, &attestor, &token, &attestor);
+    (attestor, sk)
+}
+
+#[test]
+fn test_create_org_uses_caller_id() {
+    let env = make_env();
+    let (client, _) = setup(&env);
+    client.create_org(&Address::generate(&env), &42);
+    assert_eq!(client.get_org(&42).unwrap().id, 42);
+    assert!(client.get_org(&2).is_none());
+
+    let taken = client.try_create_org(&Address::generate(&env), &42);
+    assert_eq!(taken, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::OrgAlreadyExists as u32))));
+    let no_range = client.try_create_org(&Address::generate(&env), &0);
+    assert_eq!(no_range, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCode::ValidationError as u32))));
+
+    let org = client.get_org(&42).unwrap();
+    assert_eq!(org.attestor_count, 0);
+    assert_eq!(org.created_at, NOW);
+}
+
+#[test]
+fn test_org_admin_manages_attestors() {
+    let env = make_env();
+    let (client, org_id) = setup(&env);
+    let (attestor, _) = add_key(&env, &client, org_id);
+
+    assert!(client.is_attestor(&attestor));
+    assert_eq!(client.get_attestor_org(&attestor), Some(org_id));
+    assert_eq!(client.get_org(&org_id).unwrap().attestor_count, 1);
+
+    client.remove_org_attestor(&org_id, &attestor);
+    assert!(!client.is_attestor(&attestor));
+    assert_eq!(client.get_attestor_org(&attestor), None);
+    assert_eq!(client.get_org(&org_id).unwrap().attestor_count, 0);
+}
+
+#[test]
+fn test_attestations_are_tagged_with_org() {
+    let env = make_env();
+    let (client, org_id) = setup(&env);
+    let (attestor, sk) = add_key(&env, &client, org_id);
+
+    let payload = Bytes::from_array(&env, &[7u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    let id = client.submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &sig);
+    assert_eq!(client.get_attestation(&id).unwrap().org_id, Some(org_id));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #59)")]
+fn test_cannot_remove_attestor_of_another_org() {
+    let env = make_env();
+    let (client, org_a) = setup(&env);
+    let org_b = 2;
+    client.create_org(&Address::generate(&env), &org_b);
+    let (attestor, _) = add_key(&env, &client, org_a);
+    client.remove_org_attestor(&org_b, &attestor);
+}
+
+#[test]
+fn test_admin_revocation_leaves_org() {
+    let env = make_env();
+    let (client, org_id) = setup(&env);
+    let (attestor, _) = add_key(&env, &client, org_id);
+    client.revoke_attestor(&attestor);
+    assert_eq!(client.get_attestor_org(&attestor), None);
+    assert_eq!(client.get_org(&org_id).unwrap().attestor_count, 0);
+}
+
+#[test]
+fn test_org_admin_handover_is_announced() {
+    let env = make_env();
+    let (client, org_id) = setup(&env);
+    let old_admin = client.get_org(&org_id).unwrap().admin;
+    let new_admin = Address::generate(&env);
+    client.set_org_admin(&org_id, &new_admin);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(Symbol::try_from_val(&env, &topics.get(1).unwrap()), Ok(symbol_short!("admin")));
+    let event = OrgAdminChanged::try_from_val(&env, &data).unwrap();
+    assert_eq!((event.org_id, event.old_admin, event.new_admin), (org_id, old_admin, new_admin.clone()));
+    assert_eq!(client.get_org(&org_id).unwrap().admin, new_admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #58)")]
+fn test_unknown_org_rejected() {
+    let env = make_env();
+    let (client, _) = setup(&env);
+    client.remove_org_attestor(&99, &Address::generate(&env));
+}
+
+fn submit(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey, byte: u8) -> u64 {
+    let payload = Bytes::from_array(env, &[byte; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    client.submit_attestation(attestor, &Address::generate(env), &NOW, &payload, &sig)
+}
+
+#[test]
+fn test_org_stats_aggregate_across_keys() {
+    let env = make_env();
+    let (client, org_id) = setup(&env);
+    let (a1, sk1) = add_key(&env, &client, org_id);
+    let (a2, sk2) = add_key(&env, &client, org_id);
+    submit(&env, &client, &a1, &sk1, 1);
+    submit(&env, &client, &a2, &sk2, 2);
+    assert_eq!(client.get_org_stats(&org_id).total_attestations, 2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #60)")]
+fn test_org_lifetime_quota_spans_keys() {
+    let env = make_env();
+    let (client, org_id) = setup(&env);
+    client.set_org_limits(&org_id, &OrgLimits { max_attestations: 2, max_per_window: 0, window_length: 0 });
+    let (a1, sk1) = add_key(&env, &client, org_id);
+    let (a2, sk2) = add_key(&env, &client, org_id);
+    submit(&env, &client, &a1, &sk1, 1);
+    submit(&env, &client, &a2, &sk2, 2);
+    submit(&env, &client, &a2, &sk2, 3);
+}
+
+#[test]
+fn test_org_window_quota_resets() {
+    let env = make_env();
+    let (client, org_id) = setup(&env);
+    client.set_org_limits(&org_id, &OrgLimits { max_attestations: 0, max_per_window: 1, window_length: 10 });
+    let (attestor, sk) = add_key(&env, &client, org_id);
+    submit(&env, &client, &attestor, &sk, 1);
+    assert!(client.try_submit_attestation(
+        &attestor,
+        &Address::generate(&env),
+        &NOW,
+        &Bytes::from_array(&env, &[2u8; 32]),
+        &sign_payload(&env, &sk, &Bytes::from_array(&env, &[2u8; 32])),
+    ).is_err());
+
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    submit(&env, &client, &attestor, &sk, 3);
+    assert_eq!(client.get_org_stats(&org_id).total_attestations, 2);
+}
+
+#[test]
+fn test_org_window_quota_needs_window_length() {
+    let env = make_env();
+    let (client, org_id) = setup(&env);
+    let result = client.try_set_org_limits(&org_id, &OrgLimits { max_attestations: 0, max_per_window: 1, window_length: 0 });
+    assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCode::ValidationError as u32))));
+}
+
+#[test]
+fn test_org_attestations_use_reserved_id_range() {
+    let env = make_env();
+    let (client, org_a) = setup(&env);
+    let org_b = 2;
+    client.create_org(&Address::generate(&env), &org_b);
+    let (a1, sk1) = add_key(&env, &client, org_a);
+    let (a2, sk2) = add_key(&env, &client, org_a);
+    let (b1, skb) = add_key(&env, &client, org_b);
+
+    let (a_start, a_end) = client.get_org_id_range(&org_a);
+    let (b_start, _) = client.get_org_id_range(&org_b);
+    assert_eq!(a_start, org_a << ORG_ID_BITS);
+    assert_eq!(a_end, b_start);
+
+    let first = submit(&env, &client, &a1, &sk1, 1);
+    let other = submit(&env, &client, &b1, &skb, 2);
+    let second = submit(&env, &client, &a2, &sk2, 3);
+    assert_eq!(first, a_start);
+    assert_eq!(second, a_start + 1);
+    assert_eq!(other, b_start);
+    assert_eq!(client.get_attestation(&second).unwrap().org_id, Some(org_a));
+
+    // Attestors outside any org keep the shared range.
+    let solo = Address::generate(&env);
+    let solo_sk = SigningKey::generate(&mut OsRng);
+    crate::sep10_test_util::register_attestor_with_sep10(&env, &client, &solo, &solo, &solo_sk);
+    assert!(submit(&env, &client, &solo, &solo_sk, 4) < 1u64 << ORG_ID_BITS);
+}
+
+#[test]
+fn test_full_org_range_rejected() {
+    let env = make_env();
+    let (client, org_id) = setup(&env);
+    let (attestor, sk) = add_key(&env, &client, org_id);
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&crate::storage::StorageKey::OrgIdSeq(org_id), &(1u64 << ORG_ID_BITS));
+    });
+    let payload = Bytes::from_array(&env, &[1u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    let err = client.try_submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &sig);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::AttestationLimitReached as u32))));
+}
+
+#[test]
+fn test_org_scoped_record_and_listing() {
+    let env = make_env();
+    let (client, org_a) = setup(&env);
+    let org_b = 2;
+    client.create_org(&Address::generate(&env), &org_b);
+    let (a1, sk1) = add_key(&env, &client, org_a);
+    let (b1, skb) = add_key(&env, &client, org_b);
+
+    let payload = Bytes::from_array(&env, &[1u8; 32]);
+    let input = AttestationInput::new(Address::generate(&env), NOW, payload.clone(), sign_payload(&env, &sk1, &payload));
+    assert!(client.try_record_org_attestation(&org_b, &a1, &input).is_err());
+    let id = client.record_org_attestation(&org_a, &a1, &input).id;
+    submit(&env, &client, &b1, &skb, 2);
+    submit(&env, &client, &a1, &sk1, 3);
+
+    assert_eq!(client.get_org_attestation_count(&org_a), 2);
+    let page = client.list_org_attestations(&org_a, &0, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().id, id);
+    assert!(page.iter().all(|a| a.org_id == Some(org_a)));
+    assert_eq!(client.list_org_attestations(&org_a, &1, &10).len(), 1);
+    assert_eq!(client.list_org_attestations(&org_a, &u64::MAX, &10).len(), 0);
+}
+
+#[test]
+fn test_org_fee_precedence() {
+    let env = make_env();
+    let (client, org_id) = setup(&env);
+    let (attestor, _) = add_key(&env, &client, org_id);
+    client.set_org_fee(&org_id, &Some(5));
+    assert_eq!(client.get_org_fee(&org_id), Some(5));
+    assert_eq!(client.get_effective_fee(&attestor, &None), 5);
+
+    client.set_attestor_fee_override(&attestor, &Some(0));
+    assert_eq!(client.get_effective_fee(&attestor, &None), 0);
+    client.set_attestor_fee_override(&attestor, &None);
+    client.set_org_fee(&org_id, &None);
+    assert_eq!(client.get_effective_fee(&attestor, &None), 0);
+}
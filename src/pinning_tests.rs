@@ -0,0 +1,124 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Bytes, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::PinDeposit;
+
+const NOW: u64 = 1_700_000_000;
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, u64) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    let payload = Bytes::from_array(env, &[1u8; 32]);
+    let sig = sign_payload(env, &sk, &payload);
+    let id = client.submit_attestation(&attestor, &Address::generate(env), &NOW, &payload, &sig);
+    (client, id)
+}
+
+fn revoke(client: &AnchorKitContractClient, id: u64) {
+    let issuer = client.get_attestation(&id).unwrap().issuer;
+    client.revoke_attestation(&issuer, &id);
+}
+
+#[test]
+fn test_pin_and_unpin_track_reliers() {
+    let env = Env::default();
+    let (client, id) = setup(&env);
+    let (a, b) = (Address::generate(&env), Address::generate(&env));
+    client.pin_attestation(&a, &id);
+    client.pin_attestation(&a, &id);
+    client.pin_attestation(&b, &id);
+    assert_eq!(client.get_reliers(&id).len(), 2);
+
+    client.unpin_attestation(&a, &id);
+    let reliers = client.get_reliers(&id);
+    assert_eq!(reliers.len(), 1);
+    assert_eq!(reliers.get(0).unwrap(), b);
+}
+
+#[test]
+fn test_erase_blocked_while_pinned() {
+    let env = Env::default();
+    let (client, id) = setup(&env);
+    let relier = Address::generate(&env);
+    client.pin_attestation(&relier, &id);
+    assert!(client.try_erase_attestation(&id).is_err());
+
+    client.unpin_attestation(&relier, &id);
+    client.erase_attestation(&id);
+    assert!(client.get_attestation(&id).is_none());
+}
+
+#[test]
+fn test_erase_drops_pins_once_revoked() {
+    let env = Env::default();
+    let (client, id) = setup(&env);
+    let relier = Address::generate(&env);
+    client.pin_attestation(&relier, &id);
+    revoke(&client, id);
+    client.erase_attestation(&id);
+    assert!(client.get_attestation(&id).is_none());
+    assert!(client.get_reliers(&id).is_empty());
+}
+
+#[test]
+fn test_pin_deposit_is_locked_and_refunded() {
+    let env = Env::default();
+    let (client, id) = setup(&env);
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    let relier = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&relier, &100);
+    client.set_pin_deposit(&Some(PinDeposit { token: token.clone(), amount: 40 }));
+
+    let balance = token::Client::new(&env, &token);
+    client.pin_attestation(&relier, &id);
+    assert_eq!(balance.balance(&relier), 60);
+    client.unpin_attestation(&relier, &id);
+    assert_eq!(balance.balance(&relier), 100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #110)")]
+fn test_pin_limit() {
+    let env = Env::default();
+    let (client, id) = setup(&env);
+    for _ in 0..8 {
+        client.pin_attestation(&Address::generate(&env), &id);
+    }
+    client.pin_attestation(&Address::generate(&env), &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+fn test_cannot_pin_missing_attestation() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+    client.pin_attestation(&Address::generate(&env), &999);
+}
+
+#[test]
+fn test_revocation_queues_relier_notices() {
+    let env = Env::default();
+    let (client, id) = setup(&env);
+    let relier = Address::generate(&env);
+    client.pin_attestation(&relier, &id);
+    assert!(client.get_relier_notices(&relier).is_empty());
+    revoke(&client, id);
+    assert_eq!(client.get_relier_notices(&relier), soroban_sdk::vec![&env, id]);
+
+    assert_eq!(client.take_relier_notices(&relier), soroban_sdk::vec![&env, id]);
+    assert!(client.get_relier_notices(&relier).is_empty());
+}
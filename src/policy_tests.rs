@@ -0,0 +1,175 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Bytes, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::{AttestationInput, PolicyOp, PolicyRequirement};
+
+const NOW: u64 = 1_700_000_000;
+const KYC: u64 = 1;
+const RESIDENCY: u64 = 2;
+const ACCREDITED: u64 = 3;
+
+struct Issuer {
+    addr: Address,
+    sk: SigningKey,
+    schema_id: u64,
+}
+
+fn make_env() -> Env {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    env
+}
+
+fn setup(env: &Env) -> AnchorKitContractClient<'_> {
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    client
+}
+
+fn issuer_for(env: &Env, client: &AnchorKitContractClient, schema_id: u64) -> Issuer {
+    let addr = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, client, &addr, &addr, &sk);
+    client.authorize_schema_issuer(&client.get_admin(), &schema_id, &addr);
+    Issuer { addr, sk, schema_id }
+}
+
+fn attest(env: &Env, client: &AnchorKitContractClient, issuer: &Issuer, subject: &Address, byte: u8) -> u64 {
+    let payload = Bytes::from_array(env, &[byte; 32]);
+    let sig = sign_payload(env, &issuer.sk, &payload);
+    let input = AttestationInput::new(subject.clone(), NOW, payload, sig).with_schema(issuer.schema_id);
+    client.record_attestation(&issuer.addr, &input).id
+}
+
+fn composite_policy(env: &Env, client: &AnchorKitContractClient) -> u64 {
+    let creator = Address::generate(env);
+    let both = client.create_policy(
+        &creator,
+        &PolicyOp::And,
+        &vec![env, PolicyRequirement::Schema(KYC), PolicyRequirement::Schema(RESIDENCY)],
+    );
+    client.create_policy(
+        &creator,
+        &PolicyOp::Or,
+        &vec![env, PolicyRequirement::Policy(both), PolicyRequirement::Schema(ACCREDITED)],
+    )
+}
+
+#[test]
+fn test_composite_policy() {
+    let env = make_env();
+    let client = setup(&env);
+    let kyc = issuer_for(&env, &client, KYC);
+    let residency = issuer_for(&env, &client, RESIDENCY);
+    let accredited = issuer_for(&env, &client, ACCREDITED);
+    let policy = composite_policy(&env, &client);
+
+    let alice = Address::generate(&env);
+    attest(&env, &client, &kyc, &alice, 1);
+    assert!(!client.check_policy(&policy, &alice));
+    attest(&env, &client, &residency, &alice, 2);
+    assert!(client.check_policy(&policy, &alice));
+
+    let bob = Address::generate(&env);
+    assert!(!client.check_policy(&policy, &bob));
+    attest(&env, &client, &accredited, &bob, 3);
+    assert!(client.check_policy(&policy, &bob));
+}
+
+#[test]
+fn test_schema_requirement_needs_attestation_under_schema() {
+    let env = make_env();
+    let client = setup(&env);
+    let kyc = issuer_for(&env, &client, KYC);
+    let policy = client.create_policy(&Address::generate(&env), &PolicyOp::And, &vec![&env, PolicyRequirement::Schema(KYC)]);
+
+    // An allowlisted issuer's attestation outside the schema does not count.
+    let subject = Address::generate(&env);
+    let payload = Bytes::from_array(&env, &[9; 32]);
+    let sig = sign_payload(&env, &kyc.sk, &payload);
+    client.submit_attestation(&kyc.addr, &subject, &NOW, &payload, &sig);
+    assert!(!client.check_policy(&policy, &subject));
+
+    attest(&env, &client, &kyc, &subject, 10);
+    assert!(client.check_policy(&policy, &subject));
+}
+
+#[test]
+fn test_threshold_and_revoked_issuer() {
+    let env = make_env();
+    let client = setup(&env);
+    let a = issuer_for(&env, &client, KYC);
+    let b = issuer_for(&env, &client, RESIDENCY);
+    let c = issuer_for(&env, &client, ACCREDITED);
+    let policy = client.create_policy(
+        &Address::generate(&env),
+        &PolicyOp::Threshold(2),
+        &vec![
+            &env,
+            PolicyRequirement::Issuer(a.addr.clone()),
+            PolicyRequirement::Issuer(b.addr.clone()),
+            PolicyRequirement::Issuer(c.addr.clone()),
+        ],
+    );
+
+    let subject = Address::generate(&env);
+    attest(&env, &client, &a, &subject, 1);
+    attest(&env, &client, &c, &subject, 2);
+    assert!(client.check_policy(&policy, &subject));
+
+    client.revoke_attestor(&c.addr);
+    assert!(!client.check_policy(&policy, &subject));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #61)")]
+fn test_threshold_out_of_range_rejected() {
+    let env = make_env();
+    let client = setup(&env);
+    client.create_policy(
+        &Address::generate(&env),
+        &PolicyOp::Threshold(2),
+        &vec![&env, PolicyRequirement::Schema(KYC)],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #62)")]
+fn test_nested_unknown_policy_rejected() {
+    let env = make_env();
+    let client = setup(&env);
+    client.create_policy(
+        &Address::generate(&env),
+        &PolicyOp::Or,
+        &vec![&env, PolicyRequirement::Policy(42)],
+    );
+}
+
+#[test]
+fn test_issuer_requirement_reads_latest_attestation() {
+    let env = make_env();
+    let client = setup(&env);
+    let kyc = issuer_for(&env, &client, KYC);
+    let policy = client.create_policy(
+        &Address::generate(&env),
+        &PolicyOp::And,
+        &vec![&env, PolicyRequirement::Issuer(kyc.addr.clone())],
+    );
+
+    let subject = Address::generate(&env);
+    attest(&env, &client, &kyc, &subject, 1);
+    let latest = attest(&env, &client, &kyc, &subject, 2);
+    assert!(client.check_policy(&policy, &subject));
+    client.revoke_attestation(&kyc.addr, &latest);
+    assert!(!client.check_policy(&policy, &subject));
+}
@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::errors::{ErrorCode, ErrorCodeExt};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+const KYC: u64 = 1;
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, SigningKey) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    client.authorize_schema_issuer(&client.get_admin(), &KYC, &attestor);
+    (client, attestor, sk)
+}
+
+fn attest(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey, subject: &Address, byte: u8) -> u64 {
+    attest_under(env, client, attestor, sk, subject, byte, KYC)
+}
+
+fn attest_under(
+    env: &Env,
+    client: &AnchorKitContractClient,
+    attestor: &Address,
+    sk: &SigningKey,
+    subject: &Address,
+    byte: u8,
+    schema_id: u64,
+) -> u64 {
+    let payload = Bytes::from_array(env, &[byte; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    let input = AttestationInput::new(subject.clone(), NOW, payload, sig).with_schema(schema_id);
+    client.record_attestation(attestor, &input).id
+}
+
+#[test]
+fn test_set_and_replace_primary() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup(&env);
+    let subject = Address::generate(&env);
+    let first = attest(&env, &client, &attestor, &sk, &subject, 1);
+    let second = attest(&env, &client, &attestor, &sk, &subject, 2);
+
+    assert_eq!(client.get_primary(&subject, &KYC), None);
+    client.set_primary(&subject, &KYC, &first);
+    assert_eq!(client.get_primary(&subject, &KYC), Some(first));
+    client.set_primary(&subject, &KYC, &second);
+    assert_eq!(client.get_primary(&subject, &KYC), Some(second));
+
+    client.clear_primary(&subject, &KYC);
+    assert_eq!(client.get_primary(&subject, &KYC), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #65)")]
+fn test_cannot_designate_someone_elses_attestation() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup(&env);
+    let id = attest(&env, &client, &attestor, &sk, &Address::generate(&env), 1);
+    client.set_primary(&Address::generate(&env), &KYC, &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #66)")]
+fn test_issuer_must_be_authorized_for_schema() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup(&env);
+    let subject = Address::generate(&env);
+    let id = attest_under(&env, &client, &attestor, &sk, &subject, 1, 99);
+    client.set_primary(&subject, &99, &id);
+}
+
+#[test]
+fn test_primary_must_be_valid_and_under_schema() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup(&env);
+    let subject = Address::generate(&env);
+    let id = attest(&env, &client, &attestor, &sk, &subject, 1);
+
+    let err = client.try_set_primary(&subject, &2, &id);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCode::ValidationError as u32))));
+
+    client.revoke_attestation(&attestor, &id);
+    let err = client.try_set_primary(&subject, &KYC, &id);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::AttestationNotValid as u32))));
+}
+
+#[test]
+fn test_verify_for_subject_reads_primary_then_latest() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup(&env);
+    let subject = Address::generate(&env);
+    let first = attest(&env, &client, &attestor, &sk, &subject, 1);
+    let second = attest(&env, &client, &attestor, &sk, &subject, 2);
+    client.revoke_attestation(&attestor, &second);
+    // Only the latest record is consulted without a primary designation.
+    assert!(!client.verify_for_subject(&subject, &KYC));
+
+    client.set_primary(&subject, &KYC, &first);
+    assert!(client.verify_for_subject(&subject, &KYC));
+}
@@ -0,0 +1,54 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, Env, String};
+use ed25519_dalek::SigningKey;
+
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+
+fn input(env: &Env, sk: &SigningKey, seed: u8) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(Address::generate(env), NOW, payload, sig)
+}
+
+#[test]
+fn test_proof_bundle_describes_attestation() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let definition = Bytes::from_array(&env, &[4; 32]);
+    let schema_id = client.register_schema(&attestor, &definition, &String::from_str(&env, "ipfs://kyc"));
+    let schema_hash: BytesN<32> = env.crypto().sha256(&definition).into();
+    client.set_attestor_tier(&attestor, &2);
+
+    let first = client.record_attestation(&attestor, &input(&env, &sk, 1));
+    let second = client.record_attestation(&attestor, &input(&env, &sk, 2).with_schema(schema_id));
+
+    let bundle = client.get_proof_bundle(&second.id).unwrap();
+    assert_eq!(bundle.attestation.id, second.id);
+    assert!(bundle.validity.is_valid);
+    assert!(bundle.issuer.registered && !bundle.issuer.revoked);
+    assert_eq!(bundle.issuer.tier, 2);
+    assert_eq!(bundle.schema_hash, schema_hash);
+
+    let chain = bundle.chain;
+    assert_eq!(chain.sequence, 2);
+    assert_eq!(chain.prev_hash, first.chain_hash);
+    assert_eq!(chain.hash, second.chain_hash);
+    assert_eq!(client.get_proof_bundle(&first.id).unwrap().schema_hash, BytesN::from_array(&env, &[0; 32]));
+}
+
+#[test]
+fn test_proof_bundle_reflects_issuer_revocation() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let id = client.record_attestation(&attestor, &input(&env, &sk, 1)).id;
+    client.revoke_attestor(&attestor);
+
+    let bundle = client.get_proof_bundle(&id).unwrap();
+    assert!(bundle.issuer.revoked && !bundle.issuer.registered);
+    assert!(!bundle.validity.is_valid);
+    assert!(client.get_proof_bundle(&(id + 1)).is_none());
+}
@@ -0,0 +1,111 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Bytes, Env, String, Symbol, TryFromVal, Val,
+};
+use ed25519_dalek::SigningKey;
+
+use crate::events::{FieldRejected, QuotaExceeded, ReplayRejected, TimestampRejected};
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::types::{AttestationInput, FieldBounds};
+
+const NOW: u64 = 1_700_000_000;
+
+fn input(env: &Env, sk: &SigningKey, subject: &Address, seed: u8) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(subject.clone(), NOW, payload, sig)
+}
+
+/// Data of the last `reject/<kind>` event, including ones from failed calls.
+fn rejection(env: &Env, kind: Symbol) -> Option<Val> {
+    let mut found = None;
+    for (_, topics, data) in env.events().all().iter() {
+        if topics.len() == 2
+            && Symbol::try_from_val(env, &topics.get(0).unwrap()) == Ok(symbol_short!("reject"))
+            && Symbol::try_from_val(env, &topics.get(1).unwrap()) == Ok(kind.clone())
+        {
+            found = Some(data);
+        }
+    }
+    found
+}
+
+#[test]
+fn test_replay_reports_reused_hash() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let subject = Address::generate(&env);
+    client.record_attestation(&attestor, &input(&env, &sk, &subject, 7));
+    assert!(client.try_record_attestation(&attestor, &input(&env, &sk, &subject, 7)).is_err());
+
+    let event = ReplayRejected::try_from_val(&env, &rejection(&env, symbol_short!("replay")).unwrap()).unwrap();
+    assert_eq!(event.issuer, attestor);
+    assert_eq!(event.subject, subject);
+    assert_eq!(event.payload_hash, Bytes::from_array(&env, &[7u8; 32]));
+}
+
+#[test]
+fn test_stale_timestamp_reports_window() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let mut stale = input(&env, &sk, &Address::generate(&env), 1);
+    stale.timestamp = NOW - 301;
+    assert!(client.try_record_attestation(&attestor, &stale).is_err());
+
+    let event =
+        TimestampRejected::try_from_val(&env, &rejection(&env, symbol_short!("timestamp")).unwrap()).unwrap();
+    assert_eq!(event.field, symbol_short!("timestamp"));
+    assert_eq!(event.value, NOW - 301);
+    assert_eq!((event.earliest, event.latest), (NOW - 300, NOW));
+}
+
+#[test]
+fn test_past_expiry_reports_field() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    env.ledger().with_mut(|li| li.timestamp = NOW + 10);
+    let expired = input(&env, &sk, &Address::generate(&env), 1).with_expiry(NOW + 5);
+    assert!(client.try_record_attestation(&attestor, &expired).is_err());
+
+    let event =
+        TimestampRejected::try_from_val(&env, &rejection(&env, symbol_short!("timestamp")).unwrap()).unwrap();
+    assert_eq!(event.field, Symbol::new(&env, "expires_at"));
+    assert_eq!(event.value, NOW + 5);
+    assert_eq!(event.earliest, NOW + 11);
+}
+
+#[test]
+fn test_schema_cap_reports_quota() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let schema_id = client.register_schema(
+        &Address::generate(&env),
+        &Bytes::from_array(&env, &[1u8; 32]),
+        &String::from_str(&env, "ipfs://capped"),
+    );
+    client.set_schema_cap(&schema_id, &1);
+    client.record_attestation(&attestor, &input(&env, &sk, &Address::generate(&env), 1).with_schema(schema_id));
+    assert!(client
+        .try_record_attestation(&attestor, &input(&env, &sk, &Address::generate(&env), 2).with_schema(schema_id))
+        .is_err());
+
+    let event = QuotaExceeded::try_from_val(&env, &rejection(&env, symbol_short!("quota")).unwrap()).unwrap();
+    assert_eq!(event.scope, symbol_short!("schema"));
+    assert_eq!(event.id, schema_id);
+    assert_eq!((event.limit, event.used), (1, 1));
+}
+
+#[test]
+fn test_oversized_field_reports_length() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.set_field_bounds(&FieldBounds { max_payload_hash_len: 16, max_signature_len: 128, max_bundle_claims: 64 });
+    assert!(client.try_record_attestation(&attestor, &input(&env, &sk, &Address::generate(&env), 1)).is_err());
+
+    let event = FieldRejected::try_from_val(&env, &rejection(&env, symbol_short!("field")).unwrap()).unwrap();
+    assert_eq!(event.field, symbol_short!("payload"));
+    assert_eq!((event.len, event.max), (32, 16));
+}
@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::AnchorKitContractClient;
+use crate::errors::ErrorCodeExt;
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::types::AttestationStatus;
+
+const NOW: u64 = 1_700_000_000;
+
+fn attest(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey, seed: u8) -> u64 {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    client.submit_attestation(attestor, &Address::generate(env), &NOW, &payload, &sig)
+}
+
+fn sign_revocation(env: &Env, client: &AnchorKitContractClient, sk: &SigningKey, id: u64, nonce: u64) -> Bytes {
+    let message = client.revocation_message_public(&id, &nonce);
+    sign_payload(env, sk, &message)
+}
+
+#[test]
+fn test_relayer_submits_signed_revocation() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let first = attest(&env, &client, &attestor, &sk, 1);
+    let second = attest(&env, &client, &attestor, &sk, 2);
+    let relayer = Address::generate(&env);
+
+    client.revoke_attestation_signed(&relayer, &first, &0, &sign_revocation(&env, &client, &sk, first, 0));
+    assert_eq!(client.get_attestation_v2(&first).unwrap().validity.status, AttestationStatus::Revoked);
+    assert_eq!(client.get_revocation_nonce(&attestor), 1);
+
+    client.revoke_attestation_signed(&relayer, &second, &1, &sign_revocation(&env, &client, &sk, second, 1));
+    assert_eq!(client.get_revocation_nonce(&attestor), 2);
+}
+
+#[test]
+fn test_signed_revocation_cannot_be_replayed_or_forged() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let first = attest(&env, &client, &attestor, &sk, 1);
+    let second = attest(&env, &client, &attestor, &sk, 2);
+    let relayer = Address::generate(&env);
+    let signed = sign_revocation(&env, &client, &sk, first, 0);
+    client.revoke_attestation_signed(&relayer, &first, &0, &signed);
+
+    // Stale nonce.
+    assert!(client.try_revoke_attestation_signed(&relayer, &second, &0, &signed).is_err());
+    // Signature over a different attestation.
+    assert!(client.try_revoke_attestation_signed(&relayer, &second, &1, &sign_revocation(&env, &client, &sk, first, 1)).is_err());
+    // Signed by someone other than the issuer.
+    let stranger = SigningKey::generate(&mut OsRng);
+    assert!(client.try_revoke_attestation_signed(&relayer, &second, &1, &sign_revocation(&env, &client, &stranger, second, 1)).is_err());
+    assert_eq!(client.get_revocation_nonce(&attestor), 1);
+}
+
+#[test]
+fn test_enforced_allowlist_limits_relayers() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let id = attest(&env, &client, &attestor, &sk, 1);
+    let relayer = Address::generate(&env);
+    client.set_relayer_allowlist_enforced(&true);
+    let signed = sign_revocation(&env, &client, &sk, id, 0);
+
+    let err = client.try_revoke_attestation_signed(&relayer, &id, &0, &signed);
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::RelayerNotAllowed as u32))));
+    client.add_relayer(&relayer);
+    assert!(client.is_relayer_allowed(&relayer));
+    client.revoke_attestation_signed(&relayer, &id, &0, &signed);
+
+    client.remove_relayer(&relayer);
+    let second = attest(&env, &client, &attestor, &sk, 2);
+    let err = client.try_revoke_attestation_signed(&relayer, &second, &1, &sign_revocation(&env, &client, &sk, second, 1));
+    assert_eq!(err, Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::RelayerNotAllowed as u32))));
+}
+
+#[test]
+fn test_signature_needs_revocation_domain() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let id = attest(&env, &client, &attestor, &sk, 1);
+    // A signature over the bare hash is what an attestation signature looks like.
+    let bare = sign_payload(&env, &sk, &Bytes::from(client.compute_revocation_hash_public(&id, &0)));
+    assert!(client.try_revoke_attestation_signed(&Address::generate(&env), &id, &0, &bare).is_err());
+
+    let reserved = client.revocation_message_public(&id, &0);
+    let sig = sign_payload(&env, &sk, &reserved);
+    assert!(client
+        .try_submit_attestation(&attestor, &Address::generate(&env), &NOW, &reserved, &sig)
+        .is_err());
+}
@@ -0,0 +1,65 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, u64) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    let payload = Bytes::from_array(env, &[1u8; 32]);
+    let sig = sign_payload(env, &sk, &payload);
+    let input = AttestationInput::new(Address::generate(env), NOW, payload, sig).with_expiry(NOW + 100);
+    let id = client.record_attestation(&attestor, &input).id;
+    (client, attestor, id)
+}
+
+#[test]
+fn test_keeper_renews_attestation() {
+    let env = Env::default();
+    let (client, attestor, id) = setup(&env);
+    let keeper = Address::generate(&env);
+    client.set_renewal_keeper(&attestor, &Some(keeper.clone()));
+    assert_eq!(client.get_renewal_keeper(&attestor), Some(keeper.clone()));
+
+    client.renew_attestation(&keeper, &id, &(NOW + 200));
+    env.ledger().with_mut(|li| li.timestamp = NOW + 150);
+    assert!(client.is_valid(&id));
+    assert!(client.try_renew_attestation(&keeper, &id, &(NOW + 200)).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_keeper_cannot_revoke() {
+    let env = Env::default();
+    let (client, attestor, id) = setup(&env);
+    let keeper = Address::generate(&env);
+    client.set_renewal_keeper(&attestor, &Some(keeper.clone()));
+    client.revoke_attestation(&keeper, &id);
+}
+
+#[test]
+fn test_removed_keeper_cannot_renew() {
+    let env = Env::default();
+    let (client, attestor, id) = setup(&env);
+    let keeper = Address::generate(&env);
+    client.set_renewal_keeper(&attestor, &Some(keeper.clone()));
+    client.set_renewal_keeper(&attestor, &None);
+    assert!(client.try_renew_attestation(&keeper, &id, &(NOW + 200)).is_err());
+    client.renew_attestation(&attestor, &id, &(NOW + 200));
+}
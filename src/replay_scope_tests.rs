@@ -0,0 +1,113 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::errors::{ErrorCode, ErrorCodeExt};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::ReplayScope;
+
+const NOW: u64 = 1_700_000_000;
+
+struct Fixture<'a> {
+    client: AnchorKitContractClient<'a>,
+    issuers: alloc::vec::Vec<(Address, SigningKey)>,
+}
+
+fn setup(env: &Env) -> Fixture<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let mut issuers = alloc::vec::Vec::new();
+    for _ in 0..2 {
+        let attestor = Address::generate(env);
+        let sk = SigningKey::generate(&mut OsRng);
+        register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+        issuers.push((attestor, sk));
+    }
+    Fixture { client, issuers }
+}
+
+fn submit(env: &Env, f: &Fixture, issuer: usize, subject: &Address) -> Result<(), soroban_sdk::Error> {
+    let (attestor, sk) = &f.issuers[issuer];
+    let payload = Bytes::from_array(env, &[42u8; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    match f.client.try_submit_attestation(attestor, subject, &NOW, &payload, &sig) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e.unwrap()),
+    }
+}
+
+fn try_submit(env: &Env, f: &Fixture, issuer: usize, subject: &Address) -> bool {
+    submit(env, f, issuer, subject).is_ok()
+}
+
+#[test]
+fn test_default_scope_is_global() {
+    let env = Env::default();
+    let f = setup(&env);
+    assert_eq!(f.client.get_replay_scope(), ReplayScope::Global);
+    let subject = Address::generate(&env);
+    assert!(try_submit(&env, &f, 0, &subject));
+    assert!(!try_submit(&env, &f, 1, &subject));
+}
+
+#[test]
+fn test_per_issuer_scope() {
+    let env = Env::default();
+    let f = setup(&env);
+    f.client.set_replay_scope(&ReplayScope::PerIssuer);
+    let subject = Address::generate(&env);
+    assert!(try_submit(&env, &f, 0, &subject));
+    assert!(try_submit(&env, &f, 1, &subject));
+    assert!(!try_submit(&env, &f, 0, &Address::generate(&env)));
+}
+
+#[test]
+fn test_per_issuer_subject_scope() {
+    let env = Env::default();
+    let f = setup(&env);
+    f.client.set_replay_scope(&ReplayScope::PerIssuerSubject);
+    let subject = Address::generate(&env);
+    assert!(try_submit(&env, &f, 0, &subject));
+    assert!(try_submit(&env, &f, 0, &Address::generate(&env)));
+    assert!(!try_submit(&env, &f, 0, &subject));
+}
+
+#[test]
+fn test_reused_hash_error_depends_on_scope() {
+    let env = Env::default();
+    let f = setup(&env);
+    let subject = Address::generate(&env);
+    assert!(try_submit(&env, &f, 0, &subject));
+    assert_eq!(submit(&env, &f, 1, &subject), Err(ErrorCode::ReplayAttack.into()));
+
+    let env = Env::default();
+    let f = setup(&env);
+    f.client.set_replay_scope(&ReplayScope::PerIssuer);
+    let subject = Address::generate(&env);
+    assert!(try_submit(&env, &f, 0, &subject));
+    assert!(try_submit(&env, &f, 1, &subject));
+    assert_eq!(
+        submit(&env, &f, 1, &subject),
+        Err(soroban_sdk::Error::from_contract_error(ErrorCodeExt::HashAlreadyUsedByIssuer as u32))
+    );
+}
+
+#[test]
+fn test_scope_fixed_after_first_attestation() {
+    let env = Env::default();
+    let f = setup(&env);
+    let subject = Address::generate(&env);
+    assert!(try_submit(&env, &f, 0, &subject));
+    assert!(f.client.try_set_replay_scope(&ReplayScope::PerIssuer).is_err());
+    // The global marker still blocks the hash for every issuer.
+    assert!(!try_submit(&env, &f, 1, &subject));
+}
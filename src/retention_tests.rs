@@ -0,0 +1,74 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Address as _, Ledger},
+    Address, Bytes, Env, String,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::storage::{StorageKey, RETENTION_TTL_SHORT, RETENTION_TTL_STANDARD};
+use crate::types::{AttestationInput, RetentionClass};
+
+const NOW: u64 = 1_700_000_000;
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, SigningKey, u64) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    let schema_id = client.register_schema(
+        &Address::generate(env),
+        &Bytes::from_array(env, &[1u8; 32]),
+        &String::from_str(env, "ipfs://event-ticket"),
+    );
+    (client, attestor, sk, schema_id)
+}
+
+fn record_ttl(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey, schema_id: u64, seed: u8) -> u32 {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    let input = AttestationInput::new(Address::generate(env), NOW, payload, sig).with_schema(schema_id);
+    let id = client.record_attestation(attestor, &input).id;
+    env.as_contract(&client.address, || env.storage().persistent().get_ttl(&StorageKey::Attest(id)))
+}
+
+#[test]
+fn test_retention_class_sets_attestation_ttl() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    assert_eq!(client.get_schema(&schema_id).unwrap().retention, RetentionClass::Standard);
+    assert_eq!(record_ttl(&env, &client, &attestor, &sk, schema_id, 1), RETENTION_TTL_STANDARD);
+
+    client.set_schema_retention(&schema_id, &RetentionClass::Short);
+    assert_eq!(record_ttl(&env, &client, &attestor, &sk, schema_id, 2), RETENTION_TTL_SHORT);
+
+    client.set_schema_retention(&schema_id, &RetentionClass::Permanent);
+    let max_ttl = env.as_contract(&client.address, || env.storage().max_ttl());
+    assert_eq!(record_ttl(&env, &client, &attestor, &sk, schema_id, 3), max_ttl);
+}
+
+#[test]
+fn test_edits_keep_retention_ttl() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    client.set_schema_retention(&schema_id, &RetentionClass::Short);
+    let payload = Bytes::from_array(&env, &[1u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    let input = AttestationInput::new(Address::generate(&env), NOW, payload, sig)
+        .with_schema(schema_id)
+        .with_expiry(NOW + 60);
+    let id = client.record_attestation(&attestor, &input).id;
+    let ttl = || env.as_contract(&client.address, || env.storage().persistent().get_ttl(&StorageKey::Attest(id)));
+
+    client.set_attestation_uri(&id, &Some(Bytes::from_slice(&env, b"ipfs://ticket")));
+    assert_eq!(ttl(), RETENTION_TTL_SHORT);
+    client.renew_attestation(&attestor, &id, &(NOW + 3_600));
+    assert_eq!(ttl(), RETENTION_TTL_SHORT);
+}
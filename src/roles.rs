@@ -0,0 +1,38 @@
+//! Delegable roles layered on top of the single admin key.
+//!
+//! The admin key implicitly holds every role. Holders of [`ROLE_ADMIN`] may
+//! grant and revoke roles and also pass every role check, so organizations
+//! can hand out narrower duties without sharing the admin key itself.
+
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::storage::{key_admin, StorageKey};
+
+/// Grant and revoke roles; passes every role check.
+pub const ROLE_ADMIN: Symbol = symbol_short!("admin");
+/// Onboard and offboard attestors.
+pub const ROLE_ATTESTOR_MANAGER: Symbol = symbol_short!("att_mgr");
+/// Trigger and lift the emergency stop and per-subsystem pauses.
+pub const ROLE_PAUSER: Symbol = symbol_short!("pauser");
+/// Resolve challenges against attestations.
+pub const ROLE_ARBITER: Symbol = symbol_short!("arbiter");
+
+pub fn is_known_role(role: &Symbol) -> bool {
+    *role == ROLE_ADMIN || *role == ROLE_ATTESTOR_MANAGER || *role == ROLE_PAUSER || *role == ROLE_ARBITER
+}
+
+/// Whether `account` holds `role` explicitly (ignores the admin key and
+/// `ROLE_ADMIN` implication).
+pub fn holds_role(env: &Env, role: &Symbol, account: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&StorageKey::Role(role.clone(), account.clone()))
+        .unwrap_or(false)
+}
+
+/// Whether `account` may act as `role`: the admin key, a `ROLE_ADMIN`
+/// holder, or an explicit holder of `role`.
+pub fn has_role(env: &Env, role: &Symbol, account: &Address) -> bool {
+    let admin: Option<Address> = env.storage().instance().get(&key_admin(env));
+    admin.as_ref() == Some(account) || holds_role(env, &ROLE_ADMIN, account) || holds_role(env, role, account)
+}
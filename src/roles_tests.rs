@@ -0,0 +1,78 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::roles::{ROLE_ADMIN, ROLE_ATTESTOR_MANAGER, ROLE_PAUSER};
+use crate::sep10_test_util::sep10_token_for;
+
+const NOW: u64 = 1_700_000_000;
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin, &100_u64, &None);
+    (client, admin)
+}
+
+fn onboard(env: &Env, client: &AnchorKitContractClient, caller: &Address) -> Address {
+    let attestor = Address::generate(env);
+    let token = sep10_token_for(env, client, &attestor, &attestor, &SigningKey::generate(&mut OsRng));
+    client.onboard_attestor(caller, &attestor, &token, &attestor);
+    attestor
+}
+
+#[test]
+fn test_attestor_manager_onboards_and_offboards() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let manager = Address::generate(&env);
+    client.grant_role(&admin, &ROLE_ATTESTOR_MANAGER, &manager);
+    assert!(client.has_role(&ROLE_ATTESTOR_MANAGER, &manager));
+    assert!(!client.has_role(&ROLE_PAUSER, &manager));
+
+    let attestor = onboard(&env, &client, &manager);
+    assert!(client.is_attestor(&attestor));
+    client.offboard_attestor(&manager, &attestor);
+    assert!(!client.is_attestor(&attestor));
+
+    client.revoke_role(&admin, &ROLE_ATTESTOR_MANAGER, &manager);
+    assert!(!client.has_role(&ROLE_ATTESTOR_MANAGER, &manager));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #82)")]
+fn test_pauser_cannot_onboard_attestors() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let pauser = Address::generate(&env);
+    client.grant_role(&admin, &ROLE_PAUSER, &pauser);
+    client.pause(&pauser);
+    client.unpause(&pauser);
+    onboard(&env, &client, &pauser);
+}
+
+#[test]
+fn test_role_admin_delegates_roles() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let ops = Address::generate(&env);
+    let pauser = Address::generate(&env);
+    assert!(client.try_grant_role(&ops, &ROLE_PAUSER, &pauser).is_err());
+
+    client.grant_role(&admin, &ROLE_ADMIN, &ops);
+    client.grant_role(&ops, &ROLE_PAUSER, &pauser);
+    assert!(client.has_role(&ROLE_PAUSER, &pauser));
+    // Admin-role holders pass every role check.
+    assert!(client.has_role(&ROLE_ATTESTOR_MANAGER, &ops));
+    assert!(client.try_grant_role(&ops, &symbol_short!("root"), &pauser).is_err());
+}
@@ -0,0 +1,82 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, BytesN, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::merkle::{hash_leaf, hash_pair};
+use crate::sep10_test_util::register_attestor_with_sep10;
+
+const NOW: u64 = 1_700_000_000;
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    (client, attestor)
+}
+
+fn leaf(env: &Env, b: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[b; 32])
+}
+
+#[test]
+fn test_anchor_and_verify_inclusion() {
+    let env = Env::default();
+    let (client, attestor) = setup(&env);
+    let (a, b, c, d) = (leaf(&env, 1), leaf(&env, 2), leaf(&env, 3), leaf(&env, 4));
+    let hd = hash_leaf(&env, &d);
+    let ab = hash_pair(&env, &hash_leaf(&env, &a), &hash_leaf(&env, &b));
+    let cd = hash_pair(&env, &hash_leaf(&env, &c), &hd);
+    let root = hash_pair(&env, &ab, &cd);
+
+    let id = client.anchor_root(&attestor, &root, &4);
+    let anchored = client.get_anchored_root(&id).unwrap();
+    assert_eq!(anchored.issuer, attestor);
+    assert_eq!(anchored.anchored_at, NOW);
+
+    assert!(client.verify_inclusion(&id, &c, &vec![&env, hd.clone(), ab.clone()]));
+    assert!(!client.verify_inclusion(&id, &leaf(&env, 9), &vec![&env, hd.clone(), ab.clone()]));
+    assert!(!client.verify_inclusion(&(id + 1), &c, &vec![&env, hd, ab]));
+}
+
+#[test]
+fn test_proof_deeper_than_tree_rejected() {
+    let env = Env::default();
+    let (client, attestor) = setup(&env);
+    let (a, b) = (leaf(&env, 1), leaf(&env, 2));
+    let (hb, hc) = (hash_leaf(&env, &b), hash_leaf(&env, &leaf(&env, 3)));
+    let ab = hash_pair(&env, &hash_leaf(&env, &a), &hb);
+    let root = hash_pair(&env, &ab, &hc);
+    // A two-leaf tree is one level deep, so a two-step proof cannot belong to it.
+    let id = client.anchor_root(&attestor, &root, &2);
+    assert!(!client.verify_inclusion(&id, &a, &vec![&env, hb, hc]));
+}
+
+#[test]
+fn test_internal_node_rejected_as_leaf() {
+    let env = Env::default();
+    let (client, attestor) = setup(&env);
+    let (a, b, c, d) = (leaf(&env, 1), leaf(&env, 2), leaf(&env, 3), leaf(&env, 4));
+    let ab = hash_pair(&env, &hash_leaf(&env, &a), &hash_leaf(&env, &b));
+    let cd = hash_pair(&env, &hash_leaf(&env, &c), &hash_leaf(&env, &d));
+    let id = client.anchor_root(&attestor, &hash_pair(&env, &ab, &cd), &4);
+    assert!(!client.verify_inclusion(&id, &ab, &vec![&env, cd]));
+}
+
+#[test]
+fn test_anchor_requires_attestor_and_leaves() {
+    let env = Env::default();
+    let (client, attestor) = setup(&env);
+    assert!(client.try_anchor_root(&Address::generate(&env), &leaf(&env, 1), &1).is_err());
+    assert!(client.try_anchor_root(&attestor, &leaf(&env, 1), &0).is_err());
+}
@@ -0,0 +1,86 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env, String,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, SigningKey, u64) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    let schema_id = client.register_schema(
+        &Address::generate(env),
+        &Bytes::from_array(env, &[1u8; 32]),
+        &String::from_str(env, "ipfs://cohort-2026"),
+    );
+    (client, attestor, sk, schema_id)
+}
+
+fn input(env: &Env, sk: &SigningKey, seed: u8, schema_id: u64) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(Address::generate(env), NOW, payload, sig).with_schema(schema_id)
+}
+
+#[test]
+fn test_uncapped_schema_counts_attestations() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    assert_eq!(client.get_schema(&schema_id).unwrap().max_attestations, 0);
+    for seed in 1..=3u8 {
+        client.record_attestation(&attestor, &input(&env, &sk, seed, schema_id));
+    }
+    assert_eq!(client.get_schema_attestation_count(&schema_id), 3);
+}
+
+#[test]
+fn test_cap_blocks_issuance_once_reached() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    client.set_schema_cap(&schema_id, &2);
+
+    client.record_attestation(&attestor, &input(&env, &sk, 1, schema_id));
+    client.record_attestation(&attestor, &input(&env, &sk, 2, schema_id));
+    assert!(client.try_record_attestation(&attestor, &input(&env, &sk, 3, schema_id)).is_err());
+    assert_eq!(client.get_schema_attestation_count(&schema_id), 2);
+
+    // Attestations outside the schema are unaffected.
+    let payload = Bytes::from_array(&env, &[4u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    client.record_attestation(&attestor, &AttestationInput::new(Address::generate(&env), NOW, payload, sig));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #73)")]
+fn test_cap_reached_error_code() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    client.set_schema_cap(&schema_id, &1);
+    client.record_attestation(&attestor, &input(&env, &sk, 1, schema_id));
+    client.record_attestation(&attestor, &input(&env, &sk, 2, schema_id));
+}
+
+#[test]
+fn test_cap_cannot_drop_below_issued() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    client.record_attestation(&attestor, &input(&env, &sk, 1, schema_id));
+    client.record_attestation(&attestor, &input(&env, &sk, 2, schema_id));
+    assert!(client.try_set_schema_cap(&schema_id, &1).is_err());
+    client.set_schema_cap(&schema_id, &2);
+    client.set_schema_cap(&schema_id, &0);
+}
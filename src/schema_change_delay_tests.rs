@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, Bytes, Env, String};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::sep10_test_util::{register_attestor_with_sep10, setup_attestor, sign_payload};
+use crate::types::{AttestationInput, PendingSchemaChange, SchemaChange};
+
+const NOW: u64 = 1_700_000_000;
+const DELAY: u64 = 86_400;
+const KYC: u64 = 7;
+
+fn input(env: &Env, sk: &SigningKey, seed: u8) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(Address::generate(env), env.ledger().timestamp(), payload, sig).with_schema(KYC)
+}
+
+fn advance(env: &Env, secs: u64) {
+    env.ledger().with_mut(|li| li.timestamp += secs);
+}
+
+#[test]
+fn test_issuer_removal_waits_out_delay() {
+    let env = Env::default();
+    let (client, auditor, sk) = setup_attestor(&env, NOW);
+    let admin = client.get_admin();
+    let other = Address::generate(&env);
+    register_attestor_with_sep10(&env, &client, &other, &other, &SigningKey::generate(&mut OsRng));
+    client.authorize_schema_issuer(&admin, &KYC, &auditor);
+    client.authorize_schema_issuer(&admin, &KYC, &other);
+    client.set_schema_change_delay(&DELAY);
+
+    client.remove_schema_issuer(&admin, &KYC, &auditor);
+    assert_eq!(
+        client.get_pending_schema_changes(&KYC).get(0),
+        Some(PendingSchemaChange { change: SchemaChange::RemoveIssuer(auditor.clone()), effective_at: NOW + DELAY })
+    );
+    // Still authorized during the freeze window.
+    client.record_attestation(&auditor, &input(&env, &sk, 1));
+
+    advance(&env, DELAY);
+    // The next submission under the schema applies the removal first.
+    assert!(client.try_record_attestation(&auditor, &input(&env, &sk, 2)).is_err());
+    client.apply_schema_changes(&KYC);
+    assert_eq!(client.get_issuers_for_schema(&KYC), vec![&env, other]);
+    assert_eq!(client.get_pending_schema_changes(&KYC).len(), 0);
+}
+
+#[test]
+fn test_fee_change_applies_after_delay() {
+    let env = Env::default();
+    let (client, owner, _) = setup_attestor(&env, NOW);
+    let id = client.register_schema(&owner, &Bytes::from_array(&env, &[9; 32]), &String::from_str(&env, "ipfs://kyc"));
+    client.set_schema_change_delay(&DELAY);
+
+    client.set_schema_fee(&owner, &id, &500);
+    assert_eq!(client.get_schema(&id).unwrap().fee, 0);
+    assert_eq!(client.apply_schema_changes(&id), 0);
+
+    advance(&env, DELAY);
+    assert_eq!(client.apply_schema_changes(&id), 1);
+    assert_eq!(client.get_schema(&id).unwrap().fee, 500);
+}
+
+#[test]
+fn test_views_reflect_due_changes_before_apply() {
+    let env = Env::default();
+    let (client, owner, _) = setup_attestor(&env, NOW);
+    let id = client.register_schema(&owner, &Bytes::from_array(&env, &[9; 32]), &String::from_str(&env, "ipfs://kyc"));
+    client.set_schema_change_delay(&DELAY);
+    client.set_schema_fee(&owner, &id, &500);
+    client.authorize_schema_issuer(&owner, &id, &owner);
+    assert_eq!(client.get_effective_fee(&owner, &Some(id)), 0);
+    assert!(client.get_issuers_for_schema(&id).is_empty());
+
+    advance(&env, DELAY);
+    assert_eq!(client.get_schema(&id).unwrap().fee, 500);
+    assert_eq!(client.get_effective_fee(&owner, &Some(id)), 500);
+    assert_eq!(client.get_issuers_for_schema(&id), vec![&env, owner]);
+    assert_eq!(client.get_pending_schema_changes(&id).len(), 2);
+}
+
+#[test]
+fn test_pending_queue_is_bounded() {
+    let env = Env::default();
+    let (client, owner, _) = setup_attestor(&env, NOW);
+    let id = client.register_schema(&owner, &Bytes::from_array(&env, &[9; 32]), &String::from_str(&env, "ipfs://kyc"));
+    client.set_schema_change_delay(&DELAY);
+    for fee in 1..=8 {
+        client.set_schema_fee(&owner, &id, &fee);
+    }
+    assert!(client.try_set_schema_fee(&owner, &id, &9).is_err());
+
+    // Once the queue is due it drains before the next change is queued.
+    advance(&env, DELAY);
+    client.set_schema_fee(&owner, &id, &9);
+    assert_eq!(client.get_pending_schema_changes(&id).len(), 1);
+}
@@ -0,0 +1,148 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, BytesN, Env, String,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::deterministic_hash::{schema_id_from_hash, REGISTERED_SCHEMA_BIT};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::{AttestationInput, SCHEMA_RIGHT_FEE, SCHEMA_RIGHT_ISSUERS};
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, u64) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let creator = Address::generate(env);
+    let id = client.register_schema(
+        &creator,
+        &Bytes::from_array(env, &[1u8; 32]),
+        &String::from_str(env, "ipfs://kyc-v1"),
+    );
+    (client, creator, id)
+}
+
+fn attestor(env: &Env, client: &AnchorKitContractClient) -> Address {
+    let addr = Address::generate(env);
+    register_attestor_with_sep10(env, client, &addr, &addr, &SigningKey::generate(&mut OsRng));
+    addr
+}
+
+#[test]
+fn test_register_and_transfer_schema() {
+    let env = Env::default();
+    let (client, creator, id) = setup(&env);
+    let schema = client.get_schema(&id).unwrap();
+    assert_eq!(schema.creator, creator);
+    assert_eq!(schema.owner, creator);
+
+    let new_owner = Address::generate(&env);
+    client.transfer_schema_ownership(&id, &new_owner);
+    let schema = client.get_schema(&id).unwrap();
+    assert_eq!(schema.owner, new_owner);
+    assert_eq!(schema.creator, creator);
+}
+
+#[test]
+fn test_schema_id_is_derived_from_definition_hash() {
+    let env = Env::default();
+    let (client, _, id) = setup(&env);
+    let schema_hash: BytesN<32> = env.crypto().sha256(&Bytes::from_array(&env, &[1u8; 32])).into();
+    assert_eq!(id, schema_id_from_hash(&schema_hash));
+    assert_ne!(id & REGISTERED_SCHEMA_BIT, 0);
+    assert_eq!(client.get_schema(&id).unwrap().schema_hash, schema_hash);
+    assert_eq!(client.get_schema_id_by_hash(&schema_hash), Some(id));
+
+    // A second deployment assigns the same ID to the same definition.
+    let (other, _, other_id) = setup(&env);
+    assert_eq!(other_id, id);
+
+    let duplicate = client.try_register_schema(
+        &Address::generate(&env),
+        &Bytes::from_array(&env, &[1u8; 32]),
+        &String::from_str(&env, "ipfs://kyc-v1-copy"),
+    );
+    assert!(duplicate.is_err());
+    assert!(other.get_schema(&id).is_some());
+}
+
+#[test]
+fn test_owner_and_delegate_manage_issuers() {
+    let env = Env::default();
+    let (client, owner, id) = setup(&env);
+    let a = attestor(&env, &client);
+    client.authorize_schema_issuer(&owner, &id, &a);
+
+    let delegate = Address::generate(&env);
+    client.set_schema_delegate(&id, &delegate, &SCHEMA_RIGHT_ISSUERS);
+    client.remove_schema_issuer(&delegate, &id, &a);
+    assert_eq!(client.get_issuers_for_schema(&id).len(), 0);
+}
+
+#[test]
+fn test_delegate_rights_are_scoped() {
+    let env = Env::default();
+    let (client, _, id) = setup(&env);
+    let delegate = Address::generate(&env);
+    client.set_schema_delegate(&id, &delegate, &SCHEMA_RIGHT_FEE);
+    client.set_schema_fee(&delegate, &id, &500);
+    assert_eq!(client.get_schema(&id).unwrap().fee, 500);
+
+    let a = attestor(&env, &client);
+    assert!(client.try_authorize_schema_issuer(&delegate, &id, &a).is_err());
+    assert!(client.try_set_schema_resolver(&delegate, &id, &None).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #69)")]
+fn test_stranger_cannot_manage_schema() {
+    let env = Env::default();
+    let (client, _, id) = setup(&env);
+    client.set_schema_fee(&Address::generate(&env), &id, &1);
+}
+
+#[test]
+fn test_required_schema_must_be_registered() {
+    let env = Env::default();
+    let (client, _, id) = setup(&env);
+    env.ledger().with_mut(|li| li.timestamp = 1_700_000_000);
+    let issuer = Address::generate(&env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(&env, &client, &issuer, &issuer, &sk);
+    let input = |seed: u8| {
+        let payload = Bytes::from_array(&env, &[seed; 32]);
+        let sig = sign_payload(&env, &sk, &payload);
+        AttestationInput::new(Address::generate(&env), 1_700_000_000, payload, sig)
+    };
+    client.set_schema_required(&true);
+
+    assert!(client.try_record_attestation(&issuer, &input(1)).is_err());
+    assert!(client.try_record_attestation(&issuer, &input(2).with_schema(id + 1)).is_err());
+    client.record_attestation(&issuer, &input(3).with_schema(id));
+
+    client.set_schema_required(&false);
+    client.record_attestation(&issuer, &input(4));
+}
+
+#[test]
+fn test_registry_range_is_reserved() {
+    let env = Env::default();
+    let (client, _, id) = setup(&env);
+    env.ledger().with_mut(|li| li.timestamp = 1_700_000_000);
+    let issuer = Address::generate(&env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(&env, &client, &issuer, &issuer, &sk);
+    let input = |seed: u8| {
+        let payload = Bytes::from_array(&env, &[seed; 32]);
+        let sig = sign_payload(&env, &sk, &payload);
+        AttestationInput::new(Address::generate(&env), 1_700_000_000, payload, sig)
+    };
+
+    // Ad hoc IDs stay usable below the registry range, but not inside it.
+    client.record_attestation(&issuer, &input(1).with_schema(7));
+    assert!(client.try_record_attestation(&issuer, &input(2).with_schema(id + 1)).is_err());
+}
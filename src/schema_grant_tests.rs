@@ -0,0 +1,91 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::sep10_test_util::{register_attestor_with_sep10, setup_attestor, sign_payload};
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+const QUARTER: u64 = 90 * 86_400;
+const KYC: u64 = 7;
+
+fn input(env: &Env, sk: &SigningKey, seed: u8) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(Address::generate(env), env.ledger().timestamp(), payload, sig).with_schema(KYC)
+}
+
+fn advance(env: &Env, secs: u64) {
+    env.ledger().with_mut(|li| li.timestamp += secs);
+}
+
+#[test]
+fn test_grant_expires_and_blocks_issuance() {
+    let env = Env::default();
+    let (client, auditor, sk) = setup_attestor(&env, NOW);
+    client.grant_schema_issuer(&client.get_admin(), &KYC, &auditor, &(NOW + QUARTER));
+
+    assert_eq!(client.get_schema_grant_expiry(&KYC, &auditor), Some(NOW + QUARTER));
+    client.record_attestation(&auditor, &input(&env, &sk, 1));
+
+    advance(&env, QUARTER);
+    assert_eq!(client.get_issuers_for_schema(&KYC).len(), 0);
+    assert!(client.try_record_attestation(&auditor, &input(&env, &sk, 2)).is_err());
+}
+
+#[test]
+fn test_grant_renewal_extends_mandate() {
+    let env = Env::default();
+    let (client, auditor, sk) = setup_attestor(&env, NOW);
+    client.grant_schema_issuer(&client.get_admin(), &KYC, &auditor, &(NOW + QUARTER));
+
+    advance(&env, QUARTER - 1);
+    client.grant_schema_issuer(&client.get_admin(), &KYC, &auditor, &(NOW + 2 * QUARTER));
+    advance(&env, 10);
+    client.record_attestation(&auditor, &input(&env, &sk, 1));
+    assert_eq!(client.get_issuers_for_schema(&KYC).len(), 1);
+}
+
+#[test]
+fn test_authorize_makes_grant_permanent() {
+    let env = Env::default();
+    let (client, auditor, sk) = setup_attestor(&env, NOW);
+    client.grant_schema_issuer(&client.get_admin(), &KYC, &auditor, &(NOW + QUARTER));
+    client.authorize_schema_issuer(&client.get_admin(), &KYC, &auditor);
+    assert_eq!(client.get_schema_grant_expiry(&KYC, &auditor), None);
+
+    advance(&env, 2 * QUARTER);
+    client.record_attestation(&auditor, &input(&env, &sk, 1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #72)")]
+fn test_expired_grant_error_code() {
+    let env = Env::default();
+    let (client, auditor, sk) = setup_attestor(&env, NOW);
+    client.grant_schema_issuer(&client.get_admin(), &KYC, &auditor, &(NOW + QUARTER));
+    advance(&env, QUARTER);
+    client.record_attestation(&auditor, &input(&env, &sk, 1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #66)")]
+fn test_unlisted_issuer_rejected_once_schema_has_allowlist() {
+    let env = Env::default();
+    let (client, auditor, _) = setup_attestor(&env, NOW);
+    let other = Address::generate(&env);
+    let other_sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(&env, &client, &other, &other, &other_sk);
+    client.authorize_schema_issuer(&client.get_admin(), &KYC, &auditor);
+    client.record_attestation(&other, &input(&env, &other_sk, 1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn test_grant_expiry_must_be_in_future() {
+    let env = Env::default();
+    let (client, auditor, _) = setup_attestor(&env, NOW);
+    client.grant_schema_issuer(&client.get_admin(), &KYC, &auditor, &NOW);
+}
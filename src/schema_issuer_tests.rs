@@ -0,0 +1,56 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::register_attestor_with_sep10;
+
+fn make_env() -> Env {
+    let env = Env::default();
+    env.mock_all_auths();
+    env
+}
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address) {
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let attestor = Address::generate(env);
+    client.initialize(&admin, &100_u64, &None);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    (client, attestor)
+}
+
+#[test]
+fn test_empty_allowlist_for_unknown_schema() {
+    let env = make_env();
+    let (client, _) = setup(&env);
+    assert_eq!(client.get_issuers_for_schema(&7).len(), 0);
+}
+
+#[test]
+fn test_authorize_and_remove_issuer() {
+    let env = make_env();
+    let (client, attestor) = setup(&env);
+
+    client.authorize_schema_issuer(&client.get_admin(), &7, &attestor);
+    client.authorize_schema_issuer(&client.get_admin(), &7, &attestor);
+    let issuers = client.get_issuers_for_schema(&7);
+    assert_eq!(issuers.len(), 1);
+    assert_eq!(issuers.get(0).unwrap(), attestor);
+    assert_eq!(client.get_issuers_for_schema(&8).len(), 0);
+
+    client.remove_schema_issuer(&client.get_admin(), &7, &attestor);
+    assert_eq!(client.get_issuers_for_schema(&7).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_authorize_unregistered_attestor_fails() {
+    let env = make_env();
+    let (client, _) = setup(&env);
+    client.authorize_schema_issuer(&client.get_admin(), &7, &Address::generate(&env));
+}
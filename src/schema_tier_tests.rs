@@ -0,0 +1,87 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env, String,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::errors::ErrorCodeExt;
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, SigningKey, u64) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    let schema_id = client.register_schema(
+        &Address::generate(env),
+        &Bytes::from_array(env, &[1u8; 32]),
+        &String::from_str(env, "ipfs://sensitive"),
+    );
+    (client, attestor, sk, schema_id)
+}
+
+fn input(env: &Env, sk: &SigningKey, seed: u8, schema_id: u64) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(Address::generate(env), NOW, payload, sig).with_schema(schema_id)
+}
+
+#[test]
+fn test_provisional_issuer_excluded_from_tiered_schema() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    client.set_schema_min_tier(&schema_id, &2, &false);
+    let schema = client.get_schema(&schema_id).unwrap();
+    assert_eq!((schema.min_issuer_tier, schema.min_tier_on_verify), (2, false));
+
+    let err = client.try_record_attestation(&attestor, &input(&env, &sk, 1, schema_id));
+    assert_eq!(
+        err.err().unwrap().unwrap(),
+        soroban_sdk::Error::from_contract_error(ErrorCodeExt::IssuerTierTooLow as u32)
+    );
+
+    client.set_attestor_tier(&attestor, &2);
+    let id = client.record_attestation(&attestor, &input(&env, &sk, 2, schema_id)).id;
+    assert!(client.is_valid(&id));
+
+    // Without verification-time enforcement, a later demotion keeps the record valid.
+    client.set_attestor_tier(&attestor, &1);
+    assert!(client.is_valid(&id));
+}
+
+#[test]
+fn test_verification_time_tier_check() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    client.set_attestor_tier(&attestor, &3);
+    client.set_schema_min_tier(&schema_id, &3, &true);
+    let id = client.record_attestation(&attestor, &input(&env, &sk, 1, schema_id)).id;
+    assert!(client.is_valid(&id));
+
+    client.set_attestor_tier(&attestor, &1);
+    let validity = client.get_attestation_v2(&id).unwrap().validity;
+    assert!(validity.issuer_below_min_tier);
+    assert!(!validity.is_valid);
+
+    client.set_schema_min_tier(&schema_id, &0, &true);
+    assert!(client.is_valid(&id));
+}
+
+#[test]
+fn test_untiered_schema_admits_provisional_issuers() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    let id = client.record_attestation(&attestor, &input(&env, &sk, 1, schema_id)).id;
+    assert!(!client.get_attestation_v2(&id).unwrap().validity.issuer_below_min_tier);
+}
@@ -0,0 +1,102 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env,
+};
+use ed25519_dalek::SigningKey;
+use k256::ecdsa::SigningKey as Secp256k1SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::errors::ErrorCodeExt;
+use crate::sep10_test_util::sep10_token_for;
+use crate::types::{AttestationInput, KeyType};
+
+const NOW: u64 = 1_700_000_000;
+
+fn encoded(env: &Env, sk: &Secp256k1SigningKey) -> Bytes {
+    Bytes::from_slice(env, sk.verifying_key().to_encoded_point(false).as_bytes())
+}
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, Secp256k1SigningKey) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let token = sep10_token_for(env, &client, &attestor, &attestor, &SigningKey::generate(&mut OsRng));
+    let sk = Secp256k1SigningKey::random(&mut OsRng);
+    client.register_attestor_with_key_type(&attestor, &token, &attestor, &KeyType::Secp256k1, &encoded(env, &sk));
+    (client, attestor, sk)
+}
+
+/// `r || s || v` over keccak256(payload_hash), with an EVM-style `v`.
+fn sign(env: &Env, sk: &Secp256k1SigningKey, payload_hash: &Bytes) -> Bytes {
+    let mut hash = [0u8; 32];
+    payload_hash.copy_into_slice(&mut hash);
+    let digest = env.crypto().keccak256(&Bytes::from_array(env, &hash)).to_array();
+    let (sig, recovery_id) = sk.sign_prehash_recoverable(&digest).unwrap();
+    let mut out = Bytes::from_slice(env, &sig.to_bytes());
+    out.push_back(27 + recovery_id.to_byte());
+    out
+}
+
+#[test]
+fn test_secp256k1_attestor_issues() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup(&env);
+    assert_eq!(client.get_attestor_key_type(&attestor), KeyType::Secp256k1);
+
+    let payload = Bytes::from_array(&env, &[1; 32]);
+    let input = AttestationInput::new(Address::generate(&env), NOW, payload.clone(), sign(&env, &sk, &payload));
+    let id = client.record_attestation(&attestor, &input).id;
+    assert!(client.is_valid(&id));
+}
+
+#[test]
+fn test_secp256k1_mismatch_is_invalid_signature() {
+    let env = Env::default();
+    let (client, attestor, _) = setup(&env);
+    let other = Secp256k1SigningKey::random(&mut OsRng);
+
+    let payload = Bytes::from_array(&env, &[1; 32]);
+    let input = AttestationInput::new(Address::generate(&env), NOW, payload.clone(), sign(&env, &other, &payload));
+    let err = client.try_record_attestation(&attestor, &input).unwrap_err().unwrap();
+    assert_eq!(err, soroban_sdk::Error::from_contract_error(ErrorCodeExt::InvalidSignature as u32));
+}
+
+#[test]
+fn test_secp256k1_key_reported_in_views() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup(&env);
+    let key = encoded(&env, &sk);
+    assert_eq!(client.get_attestor_secp256k1_key(&attestor), Some(key.clone().try_into().unwrap()));
+    assert_eq!(client.get_attestor_info(&attestor).unwrap().secp256k1_key, key);
+
+    let payload = Bytes::from_array(&env, &[1; 32]);
+    let input = AttestationInput::new(Address::generate(&env), NOW, payload.clone(), sign(&env, &sk, &payload));
+    let id = client.record_attestation(&attestor, &input).id;
+    assert_eq!(client.get_proof_bundle(&id).unwrap().issuer.secp256k1_key, key);
+}
+
+#[test]
+fn test_secp256k1_key_rotates() {
+    let env = Env::default();
+    let (client, attestor, old) = setup(&env);
+    let new = Secp256k1SigningKey::random(&mut OsRng);
+    client.set_attestor_key_with_type(&attestor, &KeyType::Secp256k1, &encoded(&env, &new));
+
+    let payload = Bytes::from_array(&env, &[1; 32]);
+    let stale = AttestationInput::new(Address::generate(&env), NOW, payload.clone(), sign(&env, &old, &payload));
+    assert!(client.try_record_attestation(&attestor, &stale).is_err());
+    let input = AttestationInput::new(Address::generate(&env), NOW, payload.clone(), sign(&env, &new, &payload));
+    client.record_attestation(&attestor, &input);
+
+    let ed25519 = SigningKey::generate(&mut OsRng);
+    let public_key = Bytes::from_array(&env, &ed25519.verifying_key().to_bytes());
+    client.set_attestor_key_with_type(&attestor, &KeyType::Ed25519, &public_key);
+    assert_eq!(client.get_attestor_key_type(&attestor), KeyType::Ed25519);
+    assert!(client.get_attestor_info(&attestor).unwrap().secp256k1_key.is_empty());
+}
@@ -0,0 +1,42 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, BytesN, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::merkle::{hash_leaf, hash_pair};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+
+const NOW: u64 = 1_700_000_000;
+
+#[test]
+fn test_verify_field_against_committed_root() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(&env, &contract_id);
+    let attestor = Address::generate(&env);
+    client.initialize(&Address::generate(&env), &100_u64, &None);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(&env, &client, &attestor, &attestor, &sk);
+
+    let name = BytesN::from_array(&env, &[1u8; 32]);
+    let nationality = BytesN::from_array(&env, &[2u8; 32]);
+    let dob = BytesN::from_array(&env, &[3u8; 32]);
+    let (h_name, h_dob) = (hash_leaf(&env, &name), hash_leaf(&env, &dob));
+    let root = hash_pair(&env, &hash_pair(&env, &h_name, &hash_leaf(&env, &nationality)), &h_dob);
+
+    let payload = Bytes::from(root);
+    let sig = sign_payload(&env, &sk, &payload);
+    let id = client.submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &sig);
+
+    let proof = soroban_sdk::vec![&env, h_name.clone(), h_dob.clone()];
+    assert!(client.verify_field(&id, &nationality, &proof));
+    let bad = BytesN::from_array(&env, &[4u8; 32]);
+    assert!(!client.verify_field(&id, &bad, &proof));
+}
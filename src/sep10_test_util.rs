@@ -4,9 +4,13 @@ use alloc::format;
 
 use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
 use ed25519_dalek::{Signer, SigningKey};
-use soroban_sdk::{Address, Bytes, Env, String};
+use rand::rngs::OsRng;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env, String,
+};
 
-use crate::contract::AnchorKitContractClient;
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
 
 pub fn build_sep10_jwt(signing_key: &SigningKey, sub: &str, exp: u64) -> alloc::string::String {
     let header = r#"{"alg":"EdDSA","typ":"JWT"}"#;
@@ -38,20 +42,23 @@ pub fn build_sep10_jwt_with_scope(
     format!("{}.{}", signing_input, sig_b64)
 }
 
-/// Sign a payload hash with the given signing key, returning a 64-byte signature as Bytes.
+/// Sign a payload hash (or any signing message) with the given signing key,
+/// returning a 64-byte signature as Bytes.
 pub fn sign_payload(env: &Env, signing_key: &SigningKey, payload_hash: &Bytes) -> Bytes {
-    let mut hash_arr = [0u8; 32];
-    payload_hash.copy_into_slice(&mut hash_arr);
-    let sig = signing_key.sign(&hash_arr);
+    let mut message = alloc::vec![0u8; payload_hash.len() as usize];
+    payload_hash.copy_into_slice(&mut message);
+    let sig = signing_key.sign(&message);
     Bytes::from_slice(env, &sig.to_bytes())
 }
-pub fn register_attestor_with_sep10(
+/// Publish `signing_key` as the SEP-10 key of `sep10_issuer` and return a
+/// valid SEP-10 JWT whose `sub` is `attestor`.
+pub fn sep10_token_for(
     env: &Env,
     client: &AnchorKitContractClient,
     attestor: &Address,
     sep10_issuer: &Address,
     signing_key: &SigningKey,
-) {
+) -> String {
     let pk = Bytes::from_slice(env, signing_key.verifying_key().as_bytes());
     client.set_sep10_jwt_verifying_key(sep10_issuer, &pk);
 
@@ -61,9 +68,33 @@ pub fn register_attestor_with_sep10(
     let final_len = if len > 128 { 128 } else { len };
     sub.copy_into_slice(&mut buf[..final_len]);
     let sub_str = core::str::from_utf8(&buf[..final_len]).unwrap_or("");
-    
+
     let exp = env.ledger().timestamp().saturating_add(86_400);
     let jwt = build_sep10_jwt(signing_key, sub_str, exp);
-    let token = String::from_str(env, jwt.as_str());
+    String::from_str(env, jwt.as_str())
+}
+
+pub fn register_attestor_with_sep10(
+    env: &Env,
+    client: &AnchorKitContractClient,
+    attestor: &Address,
+    sep10_issuer: &Address,
+    signing_key: &SigningKey,
+) {
+    let token = sep10_token_for(env, client, attestor, sep10_issuer, signing_key);
     client.register_attestor(attestor, &token, sep10_issuer);
 }
+
+/// Register and initialize a fresh contract with the ledger clock at `now`,
+/// and return it with one SEP-10 registered attestor and its signing key.
+pub fn setup_attestor(env: &Env, now: u64) -> (AnchorKitContractClient<'_>, Address, SigningKey) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = now);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    (client, attestor, sk)
+}
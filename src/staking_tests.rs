@@ -0,0 +1,120 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::errors::ErrorCodeExt;
+use crate::sep10_test_util::sep10_token_for;
+
+const NOW: u64 = 1_700_000_000;
+const WINDOW: u64 = 3_600;
+
+struct Setup<'a> {
+    client: AnchorKitContractClient<'a>,
+    attestor: Address,
+    token: Address,
+    treasury: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let token = env.register_stellar_asset_contract_v2(Address::generate(env)).address();
+    let treasury = Address::generate(env);
+    client.set_stake_config(&token, &500, &treasury);
+    client.set_exit_challenge_window(&WINDOW);
+
+    let attestor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token).mint(&attestor, &1_000);
+    Setup { client, attestor, token, treasury }
+}
+
+fn register(env: &Env, s: &Setup, amount: i128) {
+    let sk = SigningKey::generate(&mut OsRng);
+    let sep10_token = sep10_token_for(env, &s.client, &s.attestor, &s.attestor, &sk);
+    s.client.register_attestor_with_stake(&s.attestor, &sep10_token, &s.attestor, &amount);
+}
+
+#[test]
+fn test_stake_locked_on_registration() {
+    let env = Env::default();
+    let s = setup(&env);
+    register(&env, &s, 600);
+    assert!(s.client.is_attestor(&s.attestor));
+    assert_eq!(s.client.get_stake(&s.attestor), 600);
+    let balances = token::Client::new(&env, &s.token);
+    assert_eq!(balances.balance(&s.attestor), 400);
+    assert_eq!(balances.balance(&s.client.address), 600);
+}
+
+#[test]
+fn test_stake_below_minimum_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let sk = SigningKey::generate(&mut OsRng);
+    let sep10_token = sep10_token_for(&env, &s.client, &s.attestor, &s.attestor, &sk);
+    let err = s.client.try_register_attestor_with_stake(&s.attestor, &sep10_token, &s.attestor, &499);
+    assert_eq!(
+        err.err().unwrap().unwrap(),
+        soroban_sdk::Error::from_contract_error(ErrorCodeExt::InsufficientStake as u32)
+    );
+    assert!(!s.client.is_attestor(&s.attestor));
+}
+
+#[test]
+fn test_slash_sends_stake_to_treasury() {
+    let env = Env::default();
+    let s = setup(&env);
+    register(&env, &s, 600);
+    let reason = BytesN::from_array(&env, &[9u8; 32]);
+    s.client.slash(&s.attestor, &200, &reason);
+    assert_eq!(s.client.get_stake(&s.attestor), 400);
+    assert_eq!(token::Client::new(&env, &s.token).balance(&s.treasury), 200);
+    assert!(s.client.try_slash(&s.attestor, &401, &reason).is_err());
+}
+
+#[test]
+fn test_stake_returned_after_wind_down() {
+    let env = Env::default();
+    let s = setup(&env);
+    register(&env, &s, 600);
+    assert!(s.client.try_withdraw_stake(&s.attestor).is_err());
+
+    s.client.deregister_self(&s.attestor);
+    let err = s.client.try_withdraw_stake(&s.attestor);
+    assert_eq!(
+        err.err().unwrap().unwrap(),
+        soroban_sdk::Error::from_contract_error(ErrorCodeExt::StakeLocked as u32)
+    );
+    // Still slashable during the challenge window.
+    s.client.slash(&s.attestor, &100, &BytesN::from_array(&env, &[1u8; 32]));
+
+    env.ledger().with_mut(|li| li.timestamp = NOW + WINDOW);
+    assert_eq!(s.client.withdraw_stake(&s.attestor), 500);
+    assert_eq!(s.client.get_stake(&s.attestor), 0);
+    assert_eq!(token::Client::new(&env, &s.token).balance(&s.attestor), 900);
+}
+
+#[test]
+fn test_locked_stake_keeps_its_token() {
+    let env = Env::default();
+    let s = setup(&env);
+    register(&env, &s, 600);
+    let other = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    s.client.set_stake_config(&other, &500, &s.treasury);
+
+    s.client.slash(&s.attestor, &100, &BytesN::from_array(&env, &[1u8; 32]));
+    assert_eq!(token::Client::new(&env, &s.token).balance(&s.treasury), 100);
+    s.client.deregister_self(&s.attestor);
+    env.ledger().with_mut(|li| li.timestamp = NOW + WINDOW);
+    assert_eq!(s.client.withdraw_stake(&s.attestor), 500);
+    assert_eq!(token::Client::new(&env, &s.token).balance(&s.attestor), 900);
+}
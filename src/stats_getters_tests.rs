@@ -0,0 +1,53 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+
+use crate::contract::AnchorKitContractClient;
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+
+const DAY: u64 = 86_400;
+const NOW: u64 = 19_000 * DAY + 3_600;
+
+fn attest(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey, subject: &Address, seed: u8) {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    let now = env.ledger().timestamp();
+    client.submit_attestation(attestor, subject, &now, &payload, &sig);
+}
+
+#[test]
+fn test_counters_track_issuance() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    assert_eq!(client.attestor_count(), 1);
+    let alice = Address::generate(&env);
+    attest(&env, &client, &attestor, &sk, &alice, 1);
+    attest(&env, &client, &attestor, &sk, &alice, 2);
+    attest(&env, &client, &attestor, &sk, &Address::generate(&env), 3);
+
+    assert_eq!(client.total_attestations(), 3);
+    assert_eq!(client.count_by_subject(&alice), 2);
+    assert_eq!(client.count_by_subject(&Address::generate(&env)), 0);
+}
+
+#[test]
+fn test_attestations_since_sums_day_buckets() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let subject = Address::generate(&env);
+    attest(&env, &client, &attestor, &sk, &subject, 1);
+    env.ledger().with_mut(|li| li.timestamp = NOW + 2 * DAY);
+    attest(&env, &client, &attestor, &sk, &subject, 2);
+    attest(&env, &client, &attestor, &sk, &subject, 3);
+
+    assert_eq!(client.attestations_since(&NOW), 3);
+    assert_eq!(client.attestations_since(&(NOW + DAY)), 2);
+    // Buckets are whole days: an hour into today still counts all of today.
+    assert_eq!(client.attestations_since(&(NOW + 2 * DAY + 60)), 2);
+    assert_eq!(client.attestations_since(&(NOW + 3 * DAY)), 0);
+    // The range spans at most 31 days, today included.
+    assert_eq!(client.attestations_since(&(NOW + 2 * DAY - 30 * DAY)), 3);
+    assert!(client.try_attestations_since(&(NOW + 2 * DAY - 31 * DAY)).is_err());
+    assert!(client.try_attestations_since(&(NOW - 400 * DAY)).is_err());
+}
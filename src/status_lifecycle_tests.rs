@@ -0,0 +1,116 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Bytes, BytesN, Env, Symbol, TryFromVal,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::events::StatusChanged;
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::{AttestationInput, ChallengeStatus, Status};
+
+const NOW: u64 = 1_700_000_000;
+
+fn setup(env: &Env, expires_at: u64) -> (AnchorKitContractClient<'_>, Address, u64) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    let payload = Bytes::from_array(env, &[1u8; 32]);
+    let sig = sign_payload(env, &sk, &payload);
+    let input = AttestationInput::new(Address::generate(env), NOW, payload, sig).with_expiry(expires_at);
+    let id = client.record_attestation(&attestor, &input).id;
+    (client, attestor, id)
+}
+
+/// Body of the most recent `attest/status` event.
+fn status_event(env: &Env) -> StatusChanged {
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics.len() == 3 && Symbol::try_from_val(env, &topics.get(1).unwrap()) == Ok(symbol_short!("status"))
+        })
+        .last()
+        .map(|(_, _, data)| StatusChanged::try_from_val(env, &data).unwrap())
+        .unwrap()
+}
+
+#[test]
+fn test_revocation_moves_to_revoked() {
+    let env = Env::default();
+    let (client, attestor, id) = setup(&env, 0);
+    assert_eq!(client.get_attestation(&id).unwrap().status, Status::Active);
+    client.revoke_attestation(&attestor, &id);
+    let event = status_event(&env);
+    assert_eq!((event.from, event.to), (Status::Active, Status::Revoked));
+    assert_eq!(client.get_attestation(&id).unwrap().status, Status::Revoked);
+}
+
+#[test]
+fn test_refresh_applies_expiry() {
+    let env = Env::default();
+    let (client, attestor, id) = setup(&env, NOW + 100);
+    assert_eq!(client.refresh_status(&id), Status::Active);
+    env.ledger().with_mut(|li| li.timestamp = NOW + 100);
+    assert_eq!(client.refresh_status(&id), Status::Expired);
+    assert_eq!(client.get_attestation(&id).unwrap().status, Status::Expired);
+    // Expired records can still be revoked.
+    client.revoke_attestation(&attestor, &id);
+    assert_eq!(client.get_attestation(&id).unwrap().status, Status::Revoked);
+}
+
+#[test]
+fn test_challenge_outcomes_drive_status() {
+    let env = Env::default();
+    let (client, _, id) = setup(&env, 0);
+    let challenger = Address::generate(&env);
+    let evidence = BytesN::from_array(&env, &[2u8; 32]);
+    let admin = client.get_admin();
+    client.set_challenge_cooldown(&0);
+
+    let first = client.challenge_attestation(&challenger, &id, &evidence, &0);
+    assert_eq!(client.get_attestation(&id).unwrap().status, Status::Disputed);
+    client.resolve_challenge(&admin, &first, &ChallengeStatus::Upheld);
+    assert_eq!(client.get_attestation(&id).unwrap().status, Status::Active);
+
+    let second = client.challenge_attestation(&challenger, &id, &evidence, &0);
+    client.resolve_challenge(&admin, &second, &ChallengeStatus::Invalidated);
+    let event = status_event(&env);
+    assert_eq!((event.from, event.to), (Status::Disputed, Status::Revoked));
+}
+
+#[test]
+fn test_revoked_is_terminal() {
+    for to in [Status::Active, Status::Expired, Status::Disputed, Status::Superseded] {
+        assert!(!Status::Revoked.can_transition_to(to));
+    }
+    assert!(!Status::Expired.can_transition_to(Status::Active));
+    assert!(Status::Superseded.can_transition_to(Status::Expired));
+}
+
+#[test]
+fn test_validity_follows_lifecycle_status() {
+    let env = Env::default();
+    let (client, _, id) = setup(&env, 0);
+    client.set_attestation_max_age(&100);
+    env.ledger().with_mut(|li| li.timestamp = NOW + 101);
+    // Not refreshed yet: the stored status lags, validity does not.
+    assert_eq!(client.get_attestation(&id).unwrap().status, Status::Active);
+    let validity = client.get_attestation_v2(&id).unwrap().validity;
+    assert!(validity.expired && !validity.is_valid);
+
+    // Once stored, `Expired` holds even if the max age is lifted again.
+    assert_eq!(client.refresh_status(&id), Status::Expired);
+    client.set_attestation_max_age(&0);
+    let validity = client.get_attestation_v2(&id).unwrap().validity;
+    assert!(validity.expired && !validity.is_valid);
+}
@@ -1,4 +1,6 @@
-use soroban_sdk::{contracttype, Address, Bytes};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN};
+
+use crate::types::RentTarget;
 
 /// Typed storage keys for all contract state.
 ///
@@ -23,14 +25,23 @@ pub enum StorageKey {
     Endpoint(Address),
     /// Supported services record for an anchor (persistent).
     Services(Address),
-    /// Replay-protection flag for a payload hash (persistent).
+    /// Replay-protection marker for a payload hash, holding the ID of the
+    /// attestation that used it; bundle markers hold `true` (persistent).
     Used(Bytes),
+    /// Replay-protection marker under `ReplayScope::PerIssuer` (persistent).
+    UsedByIssuer(Address, Bytes),
+    /// Replay-protection marker under `ReplayScope::PerIssuerSubject` (persistent).
+    UsedByIssuerSubject(Address, Address, Bytes),
     /// Attestation record by ID (persistent).
     Attest(u64),
     /// Per-subject attestation count (persistent).
     SubjectCount(Address),
     /// Per-subject attestation index entry (persistent).
     SubjectAttestation(Address, u64),
+    /// Per-jurisdiction attestation count (persistent).
+    JurisdictionCount(Symbol),
+    /// Per-jurisdiction attestation index entry (persistent).
+    JurisdictionAttestation(Symbol, u64),
     /// Tracing span keyed by request-ID bytes (temporary).
     Span(Bytes),
     /// Session record by session ID (persistent).
@@ -61,15 +72,295 @@ pub enum StorageKey {
     RateLimitState(Address),
     /// Per-attestor rate-limit configuration override (persistent).
     RateLimitOverride(Address),
+    /// Organization record keyed by org ID (persistent).
+    Org(u64),
+    /// Organization an attestor key belongs to (persistent).
+    AttestorOrg(Address),
+    /// Next sequence number within an organization's reserved ID range (persistent).
+    OrgIdSeq(u64),
+    /// Org-level issuance caps (persistent).
+    OrgLimits(u64),
+    /// Org-level issuance counters (persistent).
+    OrgStats(u64),
+    /// Consent receipt for an attestation ID (persistent).
+    ConsentReceipt(u64),
+    /// Number of consent receipts held by a subject (persistent).
+    SubjectConsentCount(Address),
+    /// Attestation ID at an index of a subject's consent receipts (persistent).
+    SubjectConsent(Address, u64),
+    /// Registered policy keyed by policy ID (persistent).
+    Policy(u64),
+    /// Attestation bundle keyed by bundle ID (persistent).
+    Bundle(u64),
+    /// Primary attestation ID a subject designated for a schema (persistent).
+    PrimaryAttestation(Address, u64),
+    /// Per-issuer invalidation cutoff timestamp (persistent).
+    IssuerInvalidationEpoch(Address),
+    /// Successor address a subject migrated to (persistent).
+    SubjectSuccessor(Address),
+    /// Contracts that pinned an attestation ID (persistent).
+    AttestationReliers(u64),
+    /// Wind-down record of a self-deregistered attestor (persistent).
+    WindDown(Address),
+    /// Admin-set fee override for an attestor (persistent).
+    AttestorFeeOverride(Address),
+    /// Trust tier assigned to an attestor; absent means tier 0 (persistent).
+    AttestorTier(Address),
+    /// Event topic tag a subject registered for events about them (persistent).
+    SubjectTopic(Address),
+    /// Most recent attestation IDs issued to a subject, oldest first, capped
+    /// at `INBOX_SIZE` (persistent).
+    SubjectInbox(Address),
+    /// Hash-chain linkage recorded for an attestation ID (persistent).
+    ChainLink(u64),
+    /// Explicit grant of a `roles::ROLE_*` role to an address (persistent).
+    Role(Symbol, Address),
     // --- Instance-storage counters (stored as Vec<Symbol> keys) ---
     // These are kept as plain symbol_short! vecs because instance storage
     // requires a Vec<Symbol> key; they are defined as named constants below.
 }
 
+/// Storage keys for the schema registry and schema-scoped state.
+///
+/// Kept apart from `StorageKey` because a `contracttype` enum is limited to
+/// 50 variants.
+#[contracttype]
+#[derive(Clone)]
+pub enum SchemaKey {
+    /// Registered schema keyed by schema ID (persistent).
+    Record(u64),
+    /// Rights bitmask (`SCHEMA_RIGHT_*`) delegated on a schema (persistent).
+    Delegate(u64, Address),
+    /// Attestors authorized to issue under a schema ID (persistent).
+    Issuers(u64),
+    /// Expiry (Unix seconds) of a time-boxed schema issuer grant (persistent).
+    GrantExpiry(u64, Address),
+    /// Number of attestations ever issued under a schema (persistent).
+    AttestationCount(u64),
+    /// Schema whose attestations supersede those of the keyed schema (persistent).
+    SupersededBy(u64),
+    /// Most recent attestation ID a subject holds under a schema (persistent).
+    SubjectLatest(Address, u64),
+    /// Per-(issuer, schema) revocation epoch (persistent).
+    RevocationEpoch(Address, u64),
+    /// Number of subjects whose current value falls in a schema's bucket (persistent).
+    ValueBucket(u64, u32),
+    /// Value a subject currently counts with in a schema's buckets (persistent).
+    SubjectValue(Address, u64),
+    /// Governance changes queued behind the schema change delay (persistent).
+    PendingChanges(u64),
+    /// Schema ID registered for a full definition hash (persistent).
+    ByHash(BytesN<32>),
+}
+
+/// Storage keys for per-attestor state and the issuer-side attestation index.
+///
+/// Kept apart from `StorageKey` for the same 50-variant limit as `SchemaKey`.
+#[contracttype]
+#[derive(Clone)]
+pub enum AttestorKey {
+    /// ed25519 key an attestor signs attestation payloads with (persistent).
+    SigningKey(Address),
+    /// secp256k1 key an attestor signs attestation payloads with, in place
+    /// of an ed25519 `SigningKey` (persistent).
+    Secp256k1Key(Address),
+    /// Day-bucketed issuance/revocation counts of an attestor, keyed by
+    /// UTC day number (persistent).
+    Activity(Address, u64),
+    /// Number of attestations an issuer has recorded (persistent).
+    AttestationCount(Address),
+    /// Attestation ID at a position in an issuer's index (persistent).
+    Attestation(Address, u64),
+    /// Profile published by an attestor (persistent).
+    Info(Address),
+    /// Number of addresses ever registered as attestors (persistent).
+    ListCount,
+    /// Attestor address at a position in the registration index (persistent).
+    ListEntry(u64),
+    /// Nonce the issuer's next signed revocation must carry (persistent).
+    RevocationNonce(Address),
+    /// Nonce the issuer's next delegated attestation must carry (persistent).
+    DelegationNonce(Address),
+    /// Standing consent from a subject (second) to attestations by the
+    /// issuer (first) (persistent).
+    Consent(Address, Address),
+    /// Stake an attestor has locked in the contract (persistent).
+    Stake(Address),
+    /// Token an attestor's stake was locked in (persistent).
+    StakeToken(Address),
+    /// Schema IDs an attestor is restricted to issuing under (persistent).
+    AllowedSchemas(Address),
+    /// Time (Unix seconds) an attestor was deactivated (persistent).
+    DeactivatedAt(Address),
+    /// Time (Unix seconds) of an attestor's latest registration
+    /// (persistent).
+    RegisteredAt(Address),
+    /// Keeper allowed to renew an attestor's attestations (persistent).
+    RenewalKeeper(Address),
+    /// ID of the latest attestation an issuer (first) recorded about a
+    /// subject (second) (persistent).
+    SubjectLatest(Address, Address),
+}
+
+/// Storage keys for the asset-level attestation index.
+#[contracttype]
+#[derive(Clone)]
+pub enum AssetKey {
+    /// Number of attestations about an asset (persistent).
+    AttestationCount(Address),
+    /// Attestation ID at a position in an asset's index (persistent).
+    Attestation(Address, u64),
+    /// Number of distinct assets with at least one attestation (persistent).
+    AssetCount,
+    /// Asset address at a position in the attested-asset list (persistent).
+    Asset(u64),
+}
+
+/// Storage keys for anchored Merkle roots.
+#[contracttype]
+#[derive(Clone)]
+pub enum RootKey {
+    /// Number of roots ever anchored (persistent).
+    Count,
+    /// Anchored root by ID (persistent).
+    Root(u64),
+}
+
+/// Storage keys for subject-controlled attestation visibility.
+#[contracttype]
+#[derive(Clone)]
+pub enum VisibilityKey {
+    /// Present while the subject keeps the attestation hidden (persistent).
+    Hidden(u64),
+}
+
+/// Storage keys for revocation-for-cause bookkeeping.
+#[contracttype]
+#[derive(Clone)]
+pub enum LineageKey {
+    /// ID of the attestation whose revocation for cause tainted this one
+    /// (persistent).
+    Tainted(u64),
+}
+
+/// Storage keys for the relayer allowlist.
+#[contracttype]
+#[derive(Clone)]
+pub enum RelayerKey {
+    /// Present when a relayer is on the allowlist (persistent).
+    Allowed(Address),
+}
+
+/// Storage keys for attestation pins.
+#[contracttype]
+#[derive(Clone)]
+pub enum PinKey {
+    /// Deposit a relier locked to pin an attestation, by (ID, relier)
+    /// (persistent).
+    Deposit(u64, Address),
+    /// IDs of pinned attestations revoked since a relier last took its
+    /// notices, capped at `INBOX_SIZE` (persistent).
+    Notices(Address),
+}
+
+/// Storage keys for rent sponsorship pools.
+#[contracttype]
+#[derive(Clone)]
+pub enum RentKey {
+    /// Unspent deposits earmarked for a schema or subject (persistent).
+    Pool(RentTarget),
+    /// Ledger up to which keepers have been paid to keep an attestation
+    /// alive (persistent).
+    PaidUntil(u64),
+}
+
+/// Storage keys for short-lived attestations.
+#[contracttype]
+#[derive(Clone)]
+pub enum EphemeralKey {
+    /// Ephemeral attestation by ID, in its own ID space (temporary).
+    Attestation(u64),
+}
+
+/// Storage keys for per-organization configuration.
+#[contracttype]
+#[derive(Clone)]
+pub enum OrgKey {
+    /// Fee charged per attestation by the org's attestors (persistent).
+    Fee(u64),
+}
+
+/// Storage keys for amended attestations.
+#[contracttype]
+#[derive(Clone)]
+pub enum AmendmentKey {
+    /// Attestation as it stood at an earlier version, by (ID, version)
+    /// (persistent).
+    Version(u64, u32),
+}
+
+/// Storage keys for co-signed attestation proposals.
+#[contracttype]
+#[derive(Clone)]
+pub enum ProposalKey {
+    /// Pending proposal by ID (temporary; lapses with the proposal).
+    Proposal(u64),
+    /// Signers who approved the co-signed attestation with this ID
+    /// (persistent).
+    Approvals(u64),
+}
+
+/// Storage keys for attestation challenges.
+#[contracttype]
+#[derive(Clone)]
+pub enum DisputeKey {
+    /// Number of challenges ever opened (persistent).
+    ChallengeCount,
+    /// Challenge record by ID (persistent).
+    Challenge(u64),
+    /// ID of the most recent challenge against an attestation (persistent).
+    Latest(u64),
+    /// Court an escalated challenge was handed to (persistent).
+    Court(u64),
+    /// Time (Unix seconds) after which an arbiter may close an escalated
+    /// challenge the court has not ruled on (persistent).
+    RulingDeadline(u64),
+    /// Deadline (Unix seconds) for the issuer to appeal the admin's
+    /// revocation of an attestation, while no appeal was filed (persistent).
+    AppealDeadline(u64),
+    /// Issuance fee of an attestation held in escrow (persistent).
+    EscrowedFee(u64),
+}
+
+/// Storage keys for contract-wide analytics counters.
+#[contracttype]
+#[derive(Clone)]
+pub enum StatsKey {
+    /// Attestations recorded on a UTC day, keyed by day number (persistent).
+    DailyAttestations(u64),
+}
+
 // Instance-storage counter keys (Vec<Symbol>).
 // Defined as functions returning the canonical key to avoid repetition.
 use soroban_sdk::{symbol_short, Env, Symbol, Vec};
 
+/// Persistent TTL target (ledgers) for `RetentionClass::Standard`; equal to
+/// the contract-wide `PERSISTENT_TTL` (~90 days at 5 s/ledger).
+pub const RETENTION_TTL_STANDARD: u32 = 1_555_200;
+/// Persistent TTL target (ledgers) for `RetentionClass::Short`: the network
+/// minimum persistent TTL, ~7 days at 5 s/ledger.
+pub const RETENTION_TTL_SHORT: u32 = 120_960;
+
+/// Persistent TTL bump target for a retention class. `Permanent` tracks the
+/// network's current maximum entry TTL.
+pub fn retention_ttl(env: &Env, class: crate::types::RetentionClass) -> u32 {
+    match class {
+        crate::types::RetentionClass::Permanent => env.storage().max_ttl(),
+        crate::types::RetentionClass::Standard => RETENTION_TTL_STANDARD,
+        crate::types::RetentionClass::Short => RETENTION_TTL_SHORT,
+    }
+}
+
 pub fn key_admin(env: &Env) -> Vec<Symbol> {
     soroban_sdk::vec![env, symbol_short!("ADMIN")]
 }
@@ -97,3 +388,111 @@ pub fn key_health_threshold(env: &Env) -> Vec<Symbol> {
 pub fn key_replay_window(env: &Env) -> Vec<Symbol> {
     soroban_sdk::vec![env, symbol_short!("RPWINDOW")]
 }
+pub fn key_metrics_interval(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("MINTERVAL")]
+}
+pub fn key_attestor_count(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("ATTCNT")]
+}
+pub fn key_revoked_attestor_count(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("REVCNT")]
+}
+pub fn key_attestation_max_age(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("ATTMAXAGE")]
+}
+pub fn key_policy_counter(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("POLCNT")]
+}
+pub fn key_bundle_counter(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("BNDLCNT")]
+}
+pub fn key_invalidation_epoch(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("INVEPOCH")]
+}
+pub fn key_replay_scope(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("RPSCOPE")]
+}
+pub fn key_chain_head(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("CHAINHEAD")]
+}
+pub fn key_event_verbosity(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("EVTLEVEL")]
+}
+pub fn key_exit_challenge_window(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("EXITWIN")]
+}
+pub fn key_challenge_cooldown(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("CHCOOL")]
+}
+pub fn key_sunset(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("SUNSET")]
+}
+pub fn key_suspended_tier(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("SUSPTIER")]
+}
+pub fn key_field_bounds(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("FLDBOUNDS")]
+}
+pub fn key_schema_change_delay(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("SCHDELAY")]
+}
+pub fn key_paused_subsystems(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("PAUSEDSYS")]
+}
+pub fn key_paused(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("PAUSED")]
+}
+pub fn key_schema_required(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("REQSCHEMA")]
+}
+pub fn key_successor(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("SUCCESSOR")]
+}
+pub fn key_ephemeral_count(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("EPHCNT")]
+}
+pub fn key_proposal_count(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("PROPCNT")]
+}
+pub fn key_challenge_bond(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("CHALBOND")]
+}
+pub fn key_arbitration_court(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("COURT")]
+}
+pub fn key_pin_deposit(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("PINDEP")]
+}
+pub fn key_ruling_window(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("RULINGWIN")]
+}
+pub fn key_appeal_window(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("APPEALWIN")]
+}
+pub fn key_stake_config(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("STAKECFG")]
+}
+pub fn key_timestamp_drift(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("TSDRIFT")]
+}
+pub fn key_fee_config(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("FEECFG")]
+}
+pub fn key_fee_escrow(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("FEEESCROW")]
+}
+pub fn key_rent_sponsor(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("RENTSPON")]
+}
+pub fn key_relayer_allowlist(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("RELAYLIST")]
+}
+pub fn key_consent_required(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("REQCONSNT")]
+}
+pub fn key_storage_version(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("STORVER")]
+}
+pub fn key_subject_gate(env: &Env) -> Vec<Symbol> {
+    soroban_sdk::vec![env, symbol_short!("SUBJGATE")]
+}
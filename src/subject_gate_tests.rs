@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Bytes, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::errors::ErrorCodeExt;
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::SubjectGate;
+
+const NOW: u64 = 1_700_000_000;
+
+struct Setup<'a> {
+    client: AnchorKitContractClient<'a>,
+    attestor: Address,
+    sk: SigningKey,
+    token: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    let token = env.register_stellar_asset_contract_v2(Address::generate(env)).address();
+    Setup { client, attestor, sk, token }
+}
+
+fn try_submit(env: &Env, s: &Setup, subject: &Address, seed: u8) -> Result<u64, soroban_sdk::Error> {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, &s.sk, &payload);
+    match s.client.try_submit_attestation(&s.attestor, subject, &NOW, &payload, &sig) {
+        Ok(id) => Ok(id.unwrap()),
+        Err(e) => Err(e.unwrap()),
+    }
+}
+
+#[test]
+fn test_gate_requires_min_balance() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_subject_gate(&s.token, &100);
+    assert_eq!(s.client.get_subject_gate(), Some(SubjectGate { token: s.token.clone(), min_balance: 100 }));
+
+    let subject = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &s.token).mint(&subject, &99);
+    let not_eligible = soroban_sdk::Error::from_contract_error(ErrorCodeExt::SubjectNotEligible as u32);
+    assert_eq!(try_submit(&env, &s, &subject, 1), Err(not_eligible));
+
+    token::StellarAssetClient::new(&env, &s.token).mint(&subject, &1);
+    assert!(try_submit(&env, &s, &subject, 1).is_ok());
+}
+
+#[test]
+fn test_cleared_gate_admits_any_subject() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_subject_gate(&s.token, &1);
+    assert!(try_submit(&env, &s, &Address::generate(&env), 1).is_err());
+    s.client.clear_subject_gate();
+    assert!(s.client.get_subject_gate().is_none());
+    assert!(try_submit(&env, &s, &Address::generate(&env), 1).is_ok());
+}
+
+#[test]
+fn test_non_positive_min_balance_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert!(s.client.try_set_subject_gate(&s.token, &0).is_err());
+}
+
+#[test]
+fn test_subject_authorization_does_not_bypass_gate() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_subject_gate(&s.token, &1);
+    let subject = Address::generate(&env);
+    let payload = Bytes::from_array(&env, &[1; 32]);
+    let sig = sign_payload(&env, &s.sk, &payload);
+    let not_eligible = soroban_sdk::Error::from_contract_error(ErrorCodeExt::SubjectNotEligible as u32);
+    assert_eq!(
+        s.client.try_submit_attestation_with_consent(&s.attestor, &subject, &NOW, &payload, &sig),
+        Err(Ok(not_eligible))
+    );
+
+    token::StellarAssetClient::new(&env, &s.token).mint(&subject, &1);
+    assert!(s.client.try_submit_attestation_with_consent(&s.attestor, &subject, &NOW, &payload, &sig).is_ok());
+}
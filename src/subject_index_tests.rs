@@ -0,0 +1,48 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env, Vec,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+
+#[test]
+fn test_get_attestations_by_subject_pages_ids() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(&env, &contract_id);
+    client.initialize(&Address::generate(&env), &100_u64, &None);
+    let attestor = Address::generate(&env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(&env, &client, &attestor, &attestor, &sk);
+
+    let subject = Address::generate(&env);
+    let mut ids = Vec::new(&env);
+    for i in 0..5u8 {
+        let payload = Bytes::from_array(&env, &[i; 32]);
+        let sig = sign_payload(&env, &sk, &payload);
+        let mut input = AttestationInput::new(subject.clone(), NOW, payload, sig);
+        if i == 4 {
+            input = input.with_not_before(NOW + 60);
+        }
+        ids.push_back(client.record_attestation(&attestor, &input).id);
+    }
+    client.erase_attestation(&ids.get(1).unwrap());
+    client.revoke_attestation(&attestor, &ids.get(2).unwrap());
+
+    assert_eq!(client.get_subject_attestation_count(&subject), 5);
+    let page1 = client.get_attestations_by_subject(&subject, &0, &2);
+    assert_eq!(page1, Vec::from_array(&env, [ids.get(0).unwrap()]));
+    let page2 = client.get_attestations_by_subject(&subject, &2, &3);
+    assert_eq!(page2, Vec::from_array(&env, [ids.get(2).unwrap(), ids.get(3).unwrap()]));
+    assert!(client.get_attestations_by_subject(&Address::generate(&env), &0, &10).is_empty());
+}
@@ -0,0 +1,47 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+
+use crate::contract::AnchorKitContractClient;
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+
+fn record(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey, subject: &Address, byte: u8, transferable: bool) -> u64 {
+    let payload = Bytes::from_array(env, &[byte; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    let input = AttestationInput::new(subject.clone(), NOW, payload, sig).with_transferable(transferable);
+    client.record_attestation(attestor, &input).id
+}
+
+#[test]
+fn test_transferable_claim_follows_successor_chain() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let (old, mid, new) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    let id = record(&env, &client, &attestor, &sk, &old, 1, true);
+
+    assert!(client.verify_for_holder(&id, &old));
+    assert!(!client.verify_for_holder(&id, &new));
+
+    client.link_subject_successor(&old, &mid);
+    client.link_subject_successor(&mid, &new);
+    assert_eq!(client.get_subject_successor(&old), Some(mid.clone()));
+    assert!(client.verify_for_holder(&id, &mid));
+    assert!(client.verify_for_holder(&id, &new));
+}
+
+#[test]
+fn test_subject_bound_claim_does_not_transfer() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let (old, new) = (Address::generate(&env), Address::generate(&env));
+    let id = record(&env, &client, &attestor, &sk, &old, 1, false);
+    assert!(!client.get_attestation(&id).unwrap().transferable);
+
+    client.link_subject_successor(&old, &new);
+    assert!(client.verify_for_holder(&id, &old));
+    assert!(!client.verify_for_holder(&id, &new));
+}
@@ -0,0 +1,64 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Bytes, Env, Symbol, TryFromVal,
+};
+
+use crate::events::SubjectTopicEvent;
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+
+const NOW: u64 = 1_700_000_000;
+
+/// Bodies of `("subject", topic)` events published so far.
+fn topic_events(env: &Env, topic: Symbol) -> alloc::vec::Vec<SubjectTopicEvent> {
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics.len() == 2
+                && Symbol::try_from_val(env, &topics.get(0).unwrap()) == Ok(symbol_short!("subject"))
+                && Symbol::try_from_val(env, &topics.get(1).unwrap()) == Ok(topic.clone())
+        })
+        .map(|(_, _, data)| SubjectTopicEvent::try_from_val(env, &data).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_events_mirrored_under_subject_topic() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let subject = Address::generate(&env);
+    let tag = symbol_short!("usr_42");
+    client.set_subject_topic(&subject, &Some(tag.clone()));
+    assert_eq!(client.get_subject_topic(&subject), Some(tag.clone()));
+
+    let payload = Bytes::from_array(&env, &[1u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    let id = client.submit_attestation(&attestor, &subject, &NOW, &payload, &sig);
+    let events = topic_events(&env, tag.clone());
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, symbol_short!("recorded"));
+    assert_eq!(events[0].subject, subject);
+    assert_eq!(events[0].attestation_id, id);
+
+    client.erase_attestation(&id);
+    assert_eq!(topic_events(&env, tag).last().unwrap().kind, symbol_short!("erased"));
+}
+
+#[test]
+fn test_untagged_and_cleared_subjects_emit_nothing_extra() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let subject = Address::generate(&env);
+    let tag = symbol_short!("usr_7");
+    client.set_subject_topic(&subject, &Some(tag.clone()));
+    client.set_subject_topic(&subject, &None);
+    assert_eq!(client.get_subject_topic(&subject), None);
+
+    let payload = Bytes::from_array(&env, &[1u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    client.submit_attestation(&attestor, &subject, &NOW, &payload, &sig);
+    assert!(topic_events(&env, tag).is_empty());
+}
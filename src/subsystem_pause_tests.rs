@@ -0,0 +1,84 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::types::{AttestationInput, SUBSYSTEM_FEES, SUBSYSTEM_ISSUANCE, SUBSYSTEM_REVOCATION};
+
+const NOW: u64 = 1_700_000_000;
+
+fn input(env: &Env, sk: &SigningKey, seed: u8) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(Address::generate(env), NOW, payload, sig)
+}
+
+#[test]
+fn test_fee_pause_leaves_issuance_running() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.pause_subsystems(&client.get_admin(), &SUBSYSTEM_FEES);
+
+    assert!(client.try_set_attestor_fee_override(&attestor, &Some(0)).is_err());
+    client.record_attestation(&attestor, &input(&env, &sk, 1));
+
+    client.resume_subsystems(&client.get_admin(), &SUBSYSTEM_FEES);
+    client.set_attestor_fee_override(&attestor, &Some(0));
+    assert_eq!(client.get_paused_subsystems(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #80)")]
+fn test_issuance_pause_blocks_record() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.pause_subsystems(&client.get_admin(), &SUBSYSTEM_ISSUANCE);
+    client.record_attestation(&attestor, &input(&env, &sk, 1));
+}
+
+#[test]
+fn test_revocation_pause_is_independent() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let first = client.record_attestation(&attestor, &input(&env, &sk, 1)).id;
+    let second = client.record_attestation(&attestor, &input(&env, &sk, 2)).id;
+    let admin = client.get_admin();
+    client.pause_subsystems(&admin, &(SUBSYSTEM_ISSUANCE | SUBSYSTEM_REVOCATION));
+    client.resume_subsystems(&admin, &SUBSYSTEM_ISSUANCE);
+
+    assert_eq!(client.get_paused_subsystems(), SUBSYSTEM_REVOCATION);
+    assert!(client.try_revoke_attestation(&attestor, &first).is_err());
+    client.record_attestation(&attestor, &input(&env, &sk, 3));
+
+    client.resume_subsystems(&admin, &SUBSYSTEM_REVOCATION);
+    client.revoke_attestation(&attestor, &second);
+}
+
+#[test]
+fn test_emergency_stop_halts_attestation_paths() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let id = client.record_attestation(&attestor, &input(&env, &sk, 1)).id;
+    client.pause(&client.get_admin());
+
+    assert!(client.is_paused());
+    assert!(client.try_record_attestation(&attestor, &input(&env, &sk, 2)).is_err());
+    assert!(client.try_batch_attest(&attestor, &vec![&env, input(&env, &sk, 3)]).is_err());
+    assert!(client.try_revoke_attestation(&attestor, &id).is_err());
+    // Reads keep working while paused.
+    assert!(client.get_attestation(&id).is_some());
+
+    client.unpause(&client.get_admin());
+    client.record_attestation(&attestor, &input(&env, &sk, 2));
+    client.revoke_attestation(&attestor, &id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #81)")]
+fn test_paused_record_fails_with_contract_paused() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.pause(&client.get_admin());
+    client.record_attestation(&attestor, &input(&env, &sk, 1));
+}
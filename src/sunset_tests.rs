@@ -0,0 +1,106 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env, String,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::errors::ErrorCodeExt;
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+
+const NOW: u64 = 1_700_000_000;
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, SigningKey, u64) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    let payload = Bytes::from_array(env, &[1u8; 32]);
+    let sig = sign_payload(env, &sk, &payload);
+    let id = client.submit_attestation(&attestor, &Address::generate(env), &NOW, &payload, &sig);
+    (client, attestor, sk, id)
+}
+
+#[test]
+fn test_views_keep_working_in_sunset() {
+    let env = Env::default();
+    let (client, attestor, _, id) = setup(&env);
+    assert!(!client.is_sunset());
+    client.enable_sunset();
+    assert!(client.is_sunset());
+
+    assert!(client.get_attestation(&id).is_some());
+    assert!(client.get_attestation_v2(&id).unwrap().validity.is_valid);
+    assert!(client.is_attestor(&attestor));
+    assert_eq!(client.get_attestation_count(), 1);
+}
+
+#[test]
+fn test_writes_rejected_in_sunset() {
+    let env = Env::default();
+    let (client, attestor, sk, id) = setup(&env);
+    client.enable_sunset();
+
+    let payload = Bytes::from_array(&env, &[2u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    assert!(client.try_submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &sig).is_err());
+    assert!(client.try_revoke_attestor(&attestor).is_err());
+    assert!(client.try_pin_attestation(&Address::generate(&env), &id).is_err());
+    assert!(client
+        .try_register_schema(
+            &Address::generate(&env),
+            &Bytes::from_array(&env, &[0u8; 32]),
+            &String::from_str(&env, "uri"),
+        )
+        .is_err());
+    assert!(client.try_create_org(&Address::generate(&env), &1).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #70)")]
+fn test_sunset_cannot_be_reenabled_or_undone() {
+    let env = Env::default();
+    let (client, _, _, _) = setup(&env);
+    client.enable_sunset();
+    client.enable_sunset();
+}
+
+#[test]
+fn test_stake_withdrawal_settles_in_sunset() {
+    let env = Env::default();
+    let (client, attestor, _, _) = setup(&env);
+    let wind_down = client.deregister_self(&attestor);
+    client.enable_sunset();
+    client.set_successor(&Some(Address::generate(&env)));
+    env.ledger().with_mut(|li| li.timestamp = wind_down.release_at);
+    assert_eq!(client.withdraw_stake(&attestor), 0);
+}
+
+#[test]
+fn test_successor_redirects_writes() {
+    let env = Env::default();
+    let (client, attestor, sk, id) = setup(&env);
+    assert_eq!(client.successor(), None);
+    let next = Address::generate(&env);
+    client.set_successor(&Some(next.clone()));
+    assert_eq!(client.successor(), Some(next));
+
+    let payload = Bytes::from_array(&env, &[2u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    let err = client
+        .try_submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &sig)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, soroban_sdk::Error::from_contract_error(ErrorCodeExt::MovedToSuccessor as u32));
+    assert!(client.get_attestation(&id).is_some());
+
+    client.set_successor(&None);
+    client.submit_attestation(&attestor, &Address::generate(&env), &NOW, &payload, &sig);
+}
@@ -0,0 +1,148 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env, String,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::{AttestationInput, AttestationStatus, Status};
+
+const NOW: u64 = 1_700_000_000;
+
+struct Fixture<'a> {
+    client: AnchorKitContractClient<'a>,
+    attestor: Address,
+    sk: SigningKey,
+    kyc_v1: u64,
+    kyc_v2: u64,
+}
+
+fn setup(env: &Env) -> Fixture<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    let owner = Address::generate(env);
+    let kyc_v1 = client.register_schema(&owner, &Bytes::from_array(env, &[1u8; 32]), &String::from_str(env, "kyc-v1"));
+    let kyc_v2 = client.register_schema(&owner, &Bytes::from_array(env, &[2u8; 32]), &String::from_str(env, "kyc-v2"));
+    Fixture { client, attestor, sk, kyc_v1, kyc_v2 }
+}
+
+fn record(env: &Env, f: &Fixture, subject: &Address, schema_id: u64, seed: u8) -> u64 {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, &f.sk, &payload);
+    let input = AttestationInput::new(subject.clone(), NOW, payload, sig).with_schema(schema_id);
+    f.client.record_attestation(&f.attestor, &input).id
+}
+
+#[test]
+fn test_successor_attestation_supersedes_old_schema() {
+    let env = Env::default();
+    let f = setup(&env);
+    let migrated = Address::generate(&env);
+    let pending = Address::generate(&env);
+    let old = record(&env, &f, &migrated, f.kyc_v1, 1);
+    let untouched = record(&env, &f, &pending, f.kyc_v1, 2);
+
+    f.client.set_schema_successor(&f.kyc_v1, &Some(f.kyc_v2));
+    assert_eq!(f.client.get_schema_successor(&f.kyc_v1), Some(f.kyc_v2));
+    // Declaring the rule alone does not invalidate anything.
+    assert!(f.client.get_attestation_v2(&old).unwrap().validity.is_valid);
+
+    let new = record(&env, &f, &migrated, f.kyc_v2, 3);
+    let view = f.client.get_attestation_v2(&old).unwrap();
+    assert_eq!(view.validity.status, AttestationStatus::Superseded);
+    assert!(!view.validity.is_valid);
+    assert!(f.client.get_attestation_v2(&new).unwrap().validity.is_valid);
+    assert!(f.client.get_attestation_v2(&untouched).unwrap().validity.is_valid);
+}
+
+#[test]
+fn test_clearing_successor_restores_old_attestations() {
+    let env = Env::default();
+    let f = setup(&env);
+    let subject = Address::generate(&env);
+    let old = record(&env, &f, &subject, f.kyc_v1, 1);
+    f.client.set_schema_successor(&f.kyc_v1, &Some(f.kyc_v2));
+    record(&env, &f, &subject, f.kyc_v2, 2);
+    assert!(!f.client.get_attestation_v2(&old).unwrap().validity.is_valid);
+
+    f.client.set_schema_successor(&f.kyc_v1, &None);
+    assert_eq!(f.client.get_attestation_v2(&old).unwrap().validity.status, AttestationStatus::Active);
+}
+
+#[test]
+fn test_revoked_successor_does_not_supersede() {
+    let env = Env::default();
+    let f = setup(&env);
+    let subject = Address::generate(&env);
+    let old = record(&env, &f, &subject, f.kyc_v1, 1);
+    f.client.set_schema_successor(&f.kyc_v1, &Some(f.kyc_v2));
+    record(&env, &f, &subject, f.kyc_v2, 2);
+    f.client.revoke_all_by_schema(&f.attestor, &f.kyc_v2);
+    assert_eq!(f.client.get_attestation_v2(&old).unwrap().validity.status, AttestationStatus::Active);
+}
+
+#[test]
+fn test_expired_successor_does_not_supersede() {
+    let env = Env::default();
+    let f = setup(&env);
+    let subject = Address::generate(&env);
+    let old = record(&env, &f, &subject, f.kyc_v1, 1);
+    f.client.set_schema_successor(&f.kyc_v1, &Some(f.kyc_v2));
+    let payload = Bytes::from_array(&env, &[2u8; 32]);
+    let sig = sign_payload(&env, &f.sk, &payload);
+    let input = AttestationInput::new(subject.clone(), NOW, payload, sig)
+        .with_schema(f.kyc_v2)
+        .with_expiry(NOW + 60);
+    f.client.record_attestation(&f.attestor, &input);
+    assert_eq!(f.client.get_attestation_v2(&old).unwrap().validity.status, AttestationStatus::Superseded);
+
+    env.ledger().with_mut(|li| li.timestamp = NOW + 60);
+    assert_eq!(f.client.get_attestation_v2(&old).unwrap().validity.status, AttestationStatus::Active);
+}
+
+#[test]
+fn test_refresh_does_not_store_supersession() {
+    let env = Env::default();
+    let f = setup(&env);
+    let subject = Address::generate(&env);
+    let old = record(&env, &f, &subject, f.kyc_v1, 1);
+    f.client.set_schema_successor(&f.kyc_v1, &Some(f.kyc_v2));
+    let new = record(&env, &f, &subject, f.kyc_v2, 2);
+    assert_eq!(f.client.refresh_status(&old), Status::Superseded);
+    assert_eq!(f.client.get_attestation(&old).unwrap().status, Status::Active);
+
+    f.client.revoke_attestation(&f.attestor, &new);
+    assert_eq!(f.client.get_attestation_v2(&old).unwrap().validity.status, AttestationStatus::Active);
+}
+
+#[test]
+fn test_successor_cycle_is_bounded() {
+    let env = Env::default();
+    let f = setup(&env);
+    let subject = Address::generate(&env);
+    let v1 = record(&env, &f, &subject, f.kyc_v1, 1);
+    let v2 = record(&env, &f, &subject, f.kyc_v2, 2);
+    f.client.set_schema_successor(&f.kyc_v1, &Some(f.kyc_v2));
+    f.client.set_schema_successor(&f.kyc_v2, &Some(f.kyc_v1));
+    // The mutual successor links are followed a bounded number of hops.
+    assert!(f.client.get_attestation_v2(&v1).is_some());
+    assert!(f.client.get_attestation_v2(&v2).is_some());
+}
+
+#[test]
+fn test_schema_cannot_supersede_itself() {
+    let env = Env::default();
+    let f = setup(&env);
+    assert!(f.client.try_set_schema_successor(&f.kyc_v1, &Some(f.kyc_v1)).is_err());
+    assert!(f.client.try_set_schema_successor(&f.kyc_v1, &Some(99)).is_err());
+}
@@ -0,0 +1,67 @@
+//! Deterministic signing test vectors for SDK authors (`test-vectors` feature).
+//!
+//! Every value is derived from a caller-chosen 32-byte `seed` and subject, so
+//! an SDK can call `generate_test_vectors` on a debug deployment (or in the
+//! soroban test host) and compare byte-for-byte with its own encoder.
+
+use soroban_sdk::{contracttype, vec, Address, Bytes, BytesN, Env, Vec};
+
+use crate::deterministic_hash::{bundle_preimage, compute_bundle_hash, compute_payload_hash, payload_preimage};
+
+/// Canonical inputs, preimages and expected hashes for one seed.
+///
+/// Derivation from `seed`:
+/// * `timestamp` - first 8 bytes of `seed`, big-endian
+/// * `data` - `sha256(seed)`
+/// * `claims` - `[(1, sha256(seed || 0x01)), (2, sha256(seed || 0x02))]`
+/// * `chain_hash` - the chain head after appending attestation ID `0` with
+///   `payload_hash` to an empty chain: `sha256(0x00 * 32 || 0u64 BE || payload_hash)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TestVector {
+    pub seed: BytesN<32>,
+    pub subject: Address,
+    pub timestamp: u64,
+    pub data: Bytes,
+    pub payload_preimage: Bytes,
+    pub payload_hash: BytesN<32>,
+    pub claims: Vec<(u64, Bytes)>,
+    pub bundle_preimage: Bytes,
+    pub bundle_hash: BytesN<32>,
+    pub chain_hash: BytesN<32>,
+}
+
+/// Build the test vector for `seed` and `subject`. Pure; touches no storage.
+pub fn generate(env: &Env, seed: BytesN<32>, subject: Address) -> TestVector {
+    let seed_bytes = seed.to_array();
+    let mut ts = [0u8; 8];
+    ts.copy_from_slice(&seed_bytes[..8]);
+    let timestamp = u64::from_be_bytes(ts);
+
+    let seed_raw = Bytes::from_array(env, &seed_bytes);
+    let data = Bytes::from(env.crypto().sha256(&seed_raw).to_bytes());
+    let claim_hash = |tag: u8| {
+        let mut input = seed_raw.clone();
+        input.push_back(tag);
+        Bytes::from(env.crypto().sha256(&input).to_bytes())
+    };
+    let claims = vec![env, (1u64, claim_hash(1)), (2u64, claim_hash(2))];
+
+    let payload_hash = compute_payload_hash(env, &subject, timestamp, &data);
+    let mut chain_input = Bytes::from_array(env, &[0u8; 32]);
+    chain_input.extend_from_array(&0u64.to_be_bytes());
+    chain_input.append(&Bytes::from(payload_hash.clone()));
+
+    TestVector {
+        payload_preimage: payload_preimage(env, &subject, timestamp, &data),
+        bundle_preimage: bundle_preimage(env, &subject, timestamp, &claims),
+        bundle_hash: compute_bundle_hash(env, &subject, timestamp, &claims),
+        chain_hash: env.crypto().sha256(&chain_input).into(),
+        payload_hash,
+        seed,
+        subject,
+        timestamp,
+        data,
+        claims,
+    }
+}
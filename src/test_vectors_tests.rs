@@ -0,0 +1,33 @@
+#![cfg(all(test, feature = "test-vectors"))]
+
+use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, Env};
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+
+#[test]
+fn test_vectors_are_deterministic_and_match_contract_hashes() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(&env, &contract_id);
+    let subject = Address::generate(&env);
+    let mut raw = [7u8; 32];
+    raw[..8].copy_from_slice(&1_700_000_000u64.to_be_bytes());
+    let seed = BytesN::from_array(&env, &raw);
+
+    let tv = client.generate_test_vectors(&seed, &subject);
+    assert_eq!(tv, client.generate_test_vectors(&seed, &subject));
+    assert_eq!(tv.timestamp, 1_700_000_000);
+    assert_eq!(tv.payload_hash, client.compute_payload_hash(&subject, &tv.timestamp, &tv.data));
+    assert_eq!(tv.payload_hash, env.crypto().sha256(&tv.payload_preimage).to_bytes());
+    assert_eq!(tv.bundle_hash, env.crypto().sha256(&tv.bundle_preimage).to_bytes());
+
+    // The chain hash matches what the contract produces for its first record.
+    let mut chain_input = Bytes::from_array(&env, &[0u8; 32]);
+    chain_input.extend_from_array(&0u64.to_be_bytes());
+    chain_input.append(&Bytes::from(tv.payload_hash.clone()));
+    assert_eq!(tv.chain_hash, env.crypto().sha256(&chain_input).to_bytes());
+
+    let other = client.generate_test_vectors(&BytesN::from_array(&env, &[8u8; 32]), &subject);
+    assert_ne!(other.payload_hash, tv.payload_hash);
+    assert_eq!(tv.claims.len(), 2);
+}
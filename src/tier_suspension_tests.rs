@@ -0,0 +1,72 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+
+const NOW: u64 = 1_700_000_000;
+
+fn setup(env: &Env) -> AnchorKitContractClient<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    client
+}
+
+fn attestor(env: &Env, client: &AnchorKitContractClient, tier: u32) -> (Address, SigningKey) {
+    let addr = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, client, &addr, &addr, &sk);
+    if tier > 0 {
+        client.set_attestor_tier(&addr, &tier);
+    }
+    (addr, sk)
+}
+
+fn submit(env: &Env, client: &AnchorKitContractClient, who: &Address, sk: &SigningKey, seed: u8) -> bool {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    client
+        .try_submit_attestation(who, &Address::generate(env), &NOW, &payload, &sig)
+        .is_ok()
+}
+
+#[test]
+fn test_suspend_tier_freezes_lower_tiers_only() {
+    let env = Env::default();
+    let client = setup(&env);
+    let (provisional, sk0) = attestor(&env, &client, 0);
+    let (standard, sk1) = attestor(&env, &client, 1);
+    let (trusted, sk2) = attestor(&env, &client, 2);
+    assert_eq!(client.get_attestor_tier(&standard), 1);
+
+    client.suspend_tier(&1);
+    assert_eq!(client.get_suspended_tier(), Some(1));
+    assert!(!submit(&env, &client, &provisional, &sk0, 1));
+    assert!(!submit(&env, &client, &standard, &sk1, 2));
+    assert!(submit(&env, &client, &trusted, &sk2, 3));
+
+    client.lift_tier_suspension();
+    assert_eq!(client.get_suspended_tier(), None);
+    assert!(submit(&env, &client, &provisional, &sk0, 4));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #74)")]
+fn test_suspended_attestor_error_code() {
+    let env = Env::default();
+    let client = setup(&env);
+    let (provisional, sk) = attestor(&env, &client, 0);
+    client.suspend_tier(&0);
+    let payload = Bytes::from_array(&env, &[1u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    client.submit_attestation(&provisional, &Address::generate(&env), &NOW, &payload, &sig);
+}
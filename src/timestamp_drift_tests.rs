@@ -0,0 +1,52 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+
+use crate::contract::AnchorKitContractClient;
+use crate::errors::ErrorCode;
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use crate::types::TimestampDrift;
+
+const NOW: u64 = 1_700_000_000;
+
+/// Whether an attestation dated `timestamp` is accepted; any rejection
+/// must be `InvalidTimestamp`.
+fn accepted_at(
+    env: &Env,
+    client: &AnchorKitContractClient,
+    attestor: &Address,
+    sk: &SigningKey,
+    timestamp: u64,
+) -> bool {
+    let payload = Bytes::from_array(env, &[(timestamp % 251) as u8; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    match client.try_submit_attestation(attestor, &Address::generate(env), &timestamp, &payload, &sig) {
+        Ok(_) => true,
+        Err(err) => {
+            assert_eq!(err, Ok(soroban_sdk::Error::from_contract_error(ErrorCode::InvalidTimestamp as u32)));
+            false
+        }
+    }
+}
+
+#[test]
+fn test_defaults_follow_replay_window() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    assert_eq!(client.get_timestamp_drift(), TimestampDrift { max_past_drift: 300, max_future_drift: 0 });
+    assert!(accepted_at(&env, &client, &attestor, &sk, NOW - 300));
+    assert!(!accepted_at(&env, &client, &attestor, &sk, NOW - 301));
+    assert!(!accepted_at(&env, &client, &attestor, &sk, NOW + 1));
+}
+
+#[test]
+fn test_configured_window_enforced() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.set_timestamp_drift(&10, &30);
+    assert!(accepted_at(&env, &client, &attestor, &sk, NOW + 30));
+    assert!(accepted_at(&env, &client, &attestor, &sk, NOW - 10));
+    assert!(!accepted_at(&env, &client, &attestor, &sk, NOW + 31));
+    assert!(!accepted_at(&env, &client, &attestor, &sk, NOW - 11));
+}
@@ -0,0 +1,140 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Address as _, Ledger},
+    token, vec, Address, Bytes, Env, String,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::storage::{StorageKey, RETENTION_TTL_SHORT};
+use crate::types::{AttestationInput, RentSponsorConfig, RentTarget, RetentionClass};
+
+const NOW: u64 = 1_700_000_000;
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, SigningKey, u64) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    let schema_id = client.register_schema(
+        &Address::generate(env),
+        &Bytes::from_array(env, &[1u8; 32]),
+        &String::from_str(env, "ipfs://short-lived"),
+    );
+    client.set_schema_retention(&schema_id, &RetentionClass::Short);
+    (client, attestor, sk, schema_id)
+}
+
+fn record(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey, schema_id: u64, seed: u8) -> u64 {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    let input = AttestationInput::new(Address::generate(env), NOW, payload, sig).with_schema(schema_id);
+    client.record_attestation(attestor, &input).id
+}
+
+fn ttl(env: &Env, client: &AnchorKitContractClient, key: &StorageKey) -> u32 {
+    env.as_contract(&client.address, || env.storage().persistent().get_ttl(key))
+}
+
+#[test]
+fn test_extend_attestation_ttl() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    let id = record(&env, &client, &attestor, &sk, schema_id, 1);
+    assert_eq!(ttl(&env, &client, &StorageKey::Attest(id)), RETENTION_TTL_SHORT);
+
+    client.extend_attestation_ttl(&id, &(RETENTION_TTL_SHORT * 16));
+    assert_eq!(ttl(&env, &client, &StorageKey::Attest(id)), RETENTION_TTL_SHORT * 16);
+    assert_eq!(ttl(&env, &client, &StorageKey::ChainLink(id)), RETENTION_TTL_SHORT * 16);
+
+    // Requests past the network maximum are capped rather than rejected.
+    client.extend_attestation_ttl(&id, &u32::MAX);
+    let max_ttl = env.as_contract(&client.address, || env.storage().max_ttl());
+    assert_eq!(ttl(&env, &client, &StorageKey::Attest(id)), max_ttl);
+    let used = StorageKey::Used(Bytes::from_array(&env, &[1u8; 32]));
+    assert_eq!(ttl(&env, &client, &used), max_ttl);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+fn test_extend_unknown_attestation_fails() {
+    let env = Env::default();
+    let (client, _, _, _) = setup(&env);
+    client.extend_attestation_ttl(&42, &RETENTION_TTL_SHORT);
+}
+
+#[test]
+fn test_bulk_extension_skips_missing_ids() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    let a = record(&env, &client, &attestor, &sk, schema_id, 1);
+    let b = record(&env, &client, &attestor, &sk, schema_id, 2);
+    let extend_to = RETENTION_TTL_SHORT * 2;
+    assert_eq!(client.extend_attestation_ttls(&vec![&env, a, 999, b], &extend_to), 2);
+    assert_eq!(ttl(&env, &client, &StorageKey::Attest(a)), extend_to);
+    assert_eq!(ttl(&env, &client, &StorageKey::Attest(b)), extend_to);
+}
+
+#[test]
+fn test_extend_hash_ttl() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    record(&env, &client, &attestor, &sk, schema_id, 1);
+    let max_ttl = env.as_contract(&client.address, || env.storage().max_ttl());
+    assert!(client.extend_hash_ttl(&Bytes::from_array(&env, &[1u8; 32]), &max_ttl));
+    let used = StorageKey::Used(Bytes::from_array(&env, &[1u8; 32]));
+    assert_eq!(ttl(&env, &client, &used), max_ttl);
+    assert!(!client.extend_hash_ttl(&Bytes::from_array(&env, &[9u8; 32]), &max_ttl));
+}
+
+#[test]
+fn test_sponsored_extension_pays_keeper_from_pool() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    let id = record(&env, &client, &attestor, &sk, schema_id, 1);
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    let sponsor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&sponsor, &1_000);
+    client.set_rent_sponsorship(&RentSponsorConfig { token: token.clone(), reward: 40, interval: RETENTION_TTL_SHORT });
+    client.sponsor_rent(&sponsor, &RentTarget::Schema(schema_id), &100);
+
+    let keeper = Address::generate(&env);
+    let extend_to = RETENTION_TTL_SHORT;
+    assert_eq!(client.extend_sponsored_ttls(&keeper, &vec![&env, id], &extend_to), 40);
+    assert_eq!(ttl(&env, &client, &StorageKey::Attest(id)), extend_to);
+    assert_eq!(token::Client::new(&env, &token).balance(&keeper), 40);
+    assert_eq!(client.get_rent_pool(&RentTarget::Schema(schema_id)), 60);
+
+    // No second reward for lifetime already paid for.
+    assert_eq!(client.extend_sponsored_ttls(&keeper, &vec![&env, id], &extend_to), 0);
+    env.ledger().with_mut(|li| li.sequence_number += RETENTION_TTL_SHORT);
+    assert_eq!(client.extend_sponsored_ttls(&keeper, &vec![&env, id], &extend_to), 40);
+    // The pool cannot cover a third reward.
+    env.ledger().with_mut(|li| li.sequence_number += RETENTION_TTL_SHORT);
+    assert_eq!(client.extend_sponsored_ttls(&keeper, &vec![&env, id], &extend_to), 0);
+    assert_eq!(client.get_rent_pool(&RentTarget::Schema(schema_id)), 20);
+}
+
+#[test]
+fn test_revoked_attestation_earns_no_reward() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    let id = record(&env, &client, &attestor, &sk, schema_id, 1);
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    let sponsor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&sponsor, &1_000);
+    client.set_rent_sponsorship(&RentSponsorConfig { token, reward: 40, interval: RETENTION_TTL_SHORT });
+    client.sponsor_rent(&sponsor, &RentTarget::Schema(schema_id), &100);
+    client.revoke_attestation(&attestor, &id);
+
+    let keeper = Address::generate(&env);
+    assert_eq!(client.extend_sponsored_ttls(&keeper, &vec![&env, id], &(RETENTION_TTL_SHORT * 2)), 0);
+    assert_eq!(client.get_rent_pool(&RentTarget::Schema(schema_id)), 100);
+}
@@ -1,4 +1,12 @@
-use soroban_sdk::{contracttype, Address, Bytes, String, Vec};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, String, Vec};
+
+// Attestation records, inputs and validity views live in the
+// `anchorkit-interface` crate so consumer contracts share one definition.
+pub use anchorkit_interface::{
+    Attestation, AttestationData, AttestationInput, AttestationReceipt, AttestationStatus,
+    AttestationValidity, AttestationView, ChainLink, IssuerSnapshot, ProofBundle, Status,
+    SubjectKind, MAX_INLINE_DATA_LEN, MAX_URI_LEN,
+};
 extern crate alloc;
 use alloc::string::String as AllocString;
 
@@ -197,20 +205,493 @@ pub struct RequestId {
     pub created_at: u64,
 }
 
+/// How much data record events carry.
 #[contracttype]
-#[derive(Clone)]
-pub struct Attestation {
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum EventVerbosity {
+    /// Topics plus the bare attestation ID (an empty body with the
+    /// `legacy-events` feature).
+    Minimal,
+    /// Topics plus the compact event body (default).
+    Standard,
+    /// Topics plus the full stored record.
+    Verbose,
+}
+
+/// Boundary within which a payload hash may only be used once.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReplayScope {
+    /// A payload hash is unique across the whole contract (default).
+    Global,
+    /// Each issuer may use a payload hash once.
+    PerIssuer,
+    /// Each (issuer, subject) pair may use a payload hash once.
+    PerIssuerSubject,
+}
+
+/// Outcome of one entry of `batch_attest_partial`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BatchItemResult {
+    Recorded(AttestationReceipt),
+    /// Contract error code the entry was rejected with.
+    Rejected(u32),
+}
+
+/// One claim inside an attestation bundle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BundleClaim {
+    pub schema_id: u64,
+    pub payload_hash: Bytes,
+    pub revoked: bool,
+    /// Issuer's revocation epoch for `schema_id` when the claim was anchored.
+    /// The claim is void once `revoke_all_by_schema` advances that epoch.
+    pub schema_epoch: u32,
+}
+
+/// Several claims about one subject anchored by a single issuer signature.
+///
+/// Each claim is addressed by `(bundle id, index)` and can be revoked on its own.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationBundle {
     pub id: u64,
     pub issuer: Address,
     pub subject: Address,
     pub timestamp: u64,
-    pub payload_hash: Bytes,
+    pub bundle_hash: BytesN<32>,
     pub signature: Bytes,
-    /// Set to `true` when the issuer attestor has been revoked after this
-    /// attestation was submitted. Historical attestations are preserved for
-    /// audit purposes; callers should treat `issuer_revoked = true` as a
-    /// signal that the issuer's authority has been withdrawn.
-    pub issuer_revoked: bool,
+    pub claims: Vec<BundleClaim>,
+}
+
+/// How a policy combines its requirements.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PolicyOp {
+    And,
+    Or,
+    /// Satisfied when at least this many requirements hold.
+    Threshold(u32),
+}
+
+/// A single condition a subject must meet under a policy.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PolicyRequirement {
+    /// The latest attestation this attestor recorded about the subject is
+    /// valid.
+    Issuer(Address),
+    /// Subject holds a valid attestation under this schema, checked as by
+    /// `verify_for_subject`.
+    Schema(u64),
+    /// Another, previously registered policy holds.
+    Policy(u64),
+}
+
+/// A composite credential predicate evaluated by `check_policy`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Policy {
+    pub id: u64,
+    pub creator: Address,
+    pub op: PolicyOp,
+    pub requirements: Vec<PolicyRequirement>,
+}
+
+/// Proof that a subject consented to a specific attestation.
+///
+/// `scope_hash` is the payload hash the subject authorized, so the receipt
+/// pins exactly what they agreed to be attested about.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsentReceipt {
+    pub subject: Address,
+    pub attestation_id: u64,
+    pub consent_ledger: u32,
+    pub scope_hash: Bytes,
+}
+
+/// Delegable per-schema admin right: set the schema fee.
+pub const SCHEMA_RIGHT_FEE: u32 = 1;
+/// Delegable per-schema admin right: set the schema resolver contract.
+pub const SCHEMA_RIGHT_RESOLVER: u32 = 2;
+/// Delegable per-schema admin right: manage the schema's issuer allowlist.
+pub const SCHEMA_RIGHT_ISSUERS: u32 = 4;
+
+/// Pausable subsystem: every attestation issuance path.
+pub const SUBSYSTEM_ISSUANCE: u32 = 1;
+/// Pausable subsystem: attestation and bundle-claim revocation.
+pub const SUBSYSTEM_REVOCATION: u32 = 2;
+/// Pausable subsystem: schema fee and attestor fee-override configuration.
+pub const SUBSYSTEM_FEES: u32 = 4;
+/// Pausable subsystem: anchor quote (rate) feeds.
+pub const SUBSYSTEM_FEEDS: u32 = 8;
+/// Pausable subsystem: opening and resolving attestation challenges.
+pub const SUBSYSTEM_DISPUTES: u32 = 16;
+
+/// A governance change to a schema's fee, resolver, or issuer allowlist.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SchemaChange {
+    Fee(i128),
+    Resolver(Option<Address>),
+    /// Authorize an issuer until the given expiry; `0` makes the grant permanent.
+    AuthorizeIssuer(Address, u64),
+    RemoveIssuer(Address),
+}
+
+/// A schema change waiting out the schema change delay.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingSchemaChange {
+    pub change: SchemaChange,
+    pub effective_at: u64,
+}
+
+/// How long attestations under a schema are kept alive in persistent storage.
+/// See `storage::retention_ttl` for the ledger counts.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RetentionClass {
+    /// Bumped to the network's maximum entry TTL.
+    Permanent,
+    /// The contract's default persistent TTL (~90 days).
+    Standard,
+    /// The network's minimum persistent TTL (~7 days), for short-lived credentials.
+    Short,
+}
+
+/// A registered credential schema and its governance settings.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Schema {
+    pub id: u64,
+    pub creator: Address,
+    pub owner: Address,
+    pub schema_hash: BytesN<32>,
+    pub metadata_uri: String,
+    pub created_at: u64,
+    /// Per-attestation fee in stroops requested by the schema owner.
+    pub fee: i128,
+    /// Optional contract consulted for schema-specific checks.
+    pub resolver: Option<Address>,
+    /// Lifetime cap on attestations issued under the schema; `0` = unlimited.
+    pub max_attestations: u64,
+    /// TTL target for attestations issued under the schema.
+    pub retention: RetentionClass,
+    /// Width of the value buckets backing threshold queries; `0` disables them.
+    pub value_bucket_width: u32,
+    /// Lowest attestor trust tier allowed to issue under the schema; `0`
+    /// admits provisional issuers.
+    pub min_issuer_tier: u32,
+    /// Also require the issuer's current tier to meet `min_issuer_tier`
+    /// when validity is evaluated, so demoted issuers' attestations lapse.
+    pub min_tier_on_verify: bool,
+}
+
+/// Merkle root committing to an off-chain batch of attestations, anchored
+/// with `anchor_root`. Leaves are checked with `verify_inclusion`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnchoredRoot {
+    pub id: u64,
+    pub issuer: Address,
+    pub root: BytesN<32>,
+    pub leaf_count: u32,
+    pub anchored_at: u64,
+}
+
+/// An attestation held in temporary storage; see
+/// `record_ephemeral_attestation`.
+#[contracttype]
+#[derive(Clone)]
+pub struct EphemeralAttestation {
+    pub attestation: Attestation,
+    /// Last ledger on which the attestation can be read.
+    pub expires_ledger: u32,
+}
+
+/// What a hidden attestation discloses to viewers other than its subject
+/// and issuer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RedactedAttestation {
+    pub id: u64,
+    pub issuer: Address,
+    pub status: Status,
+}
+
+/// Result of `get_attestation_as`.
+#[contracttype]
+#[derive(Clone)]
+#[allow(clippy::large_enum_variant)] // contract types cannot box their fields
+pub enum AttestationDisclosure {
+    Full(Attestation),
+    Redacted(RedactedAttestation),
+}
+
+/// An attestation awaiting co-signatures; see `propose_attestation`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationProposal {
+    pub id: u64,
+    /// Issuer of the attestation once finalized.
+    pub proposer: Address,
+    pub input: AttestationInput,
+    /// Attestors whose approval counts, including the proposer.
+    pub signers: Vec<Address>,
+    /// Approvals needed before the attestation is recorded.
+    pub threshold: u32,
+    pub approvals: Vec<Address>,
+    pub expires_at: u64,
+}
+
+/// Token and minimum of the bond posted with `challenge_attestation`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChallengeBondConfig {
+    pub token: Address,
+    pub min_bond: i128,
+}
+
+/// Lifecycle of a challenge against an attestation.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChallengeStatus {
+    Open,
+    /// Handed to the arbitration court; only its ruling closes it.
+    Escalated,
+    /// The attestation stands; the bond went to its issuer. An upheld appeal
+    /// reinstates the attestation and refunds the issuer's deposit.
+    Upheld,
+    /// The attestation was false; the bond went back to the challenger. An
+    /// invalidated appeal makes the revocation final and the deposit goes to
+    /// the admin.
+    Invalidated,
+}
+
+/// A dispute raised against an attestation via `challenge_attestation`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Challenge {
+    pub id: u64,
+    pub attestation_id: u64,
+    pub challenger: Address,
+    /// Hash of the off-chain evidence backing the challenge.
+    pub evidence_hash: BytesN<32>,
+    pub bond: i128,
+    /// Token `bond` was posted in; `None` when no bond was posted.
+    pub bond_token: Option<Address>,
+    pub opened_at: u64,
+    pub status: ChallengeStatus,
+    /// Resolution time (Unix seconds); `0` while open.
+    pub resolved_at: u64,
+    /// The issuer's appeal against an admin revocation (see
+    /// `appeal_revocation`) rather than a challenge to the attestation.
+    pub appeal: bool,
+}
+
+/// Token and minimum of the stake locked by `register_attestor_with_stake`,
+/// and where slashed stake goes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeConfig {
+    pub token: Address,
+    pub min_stake: i128,
+    pub treasury: Address,
+}
+
+/// Record of an attestor that left the registry via `deregister_self`.
+///
+/// Any bonded funds become releasable once `release_at` passes without a
+/// successful challenge.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WindDown {
+    pub attestor: Address,
+    pub started_at: u64,
+    pub release_at: u64,
+}
+
+/// An organization owning one or more attestor keys.
+///
+/// The org admin manages the org's attestors directly, without involving the
+/// global contract admin.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Organization {
+    pub id: u64,
+    pub admin: Address,
+    pub created_at: u64,
+    pub attestor_count: u32,
+}
+
+/// Issuance caps applied to an organization across all of its attestor keys.
+///
+/// A value of `0` disables the corresponding cap.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrgLimits {
+    /// Lifetime cap on attestations issued by the org.
+    pub max_attestations: u64,
+    /// Maximum attestations per rate-limit window.
+    pub max_per_window: u32,
+    /// Window length in ledgers; must be non-zero when `max_per_window` is set.
+    pub window_length: u32,
+}
+
+/// Width of the per-organization sequence in attestation IDs.
+///
+/// Attestors that belong to an organization get IDs of the form
+/// `(org_id << ORG_ID_BITS) | seq`, so each org owns the contiguous range
+/// `[org_id << ORG_ID_BITS, (org_id + 1) << ORG_ID_BITS)`. Attestations from
+/// attestors outside any org use the shared range below `1 << ORG_ID_BITS`.
+pub const ORG_ID_BITS: u32 = 40;
+
+/// How far (in seconds) an attestation's `timestamp` may sit before or
+/// after the ledger time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimestampDrift {
+    pub max_past_drift: u64,
+    pub max_future_drift: u64,
+}
+
+/// Token, amount and recipient of the global attestation fee.
+///
+/// The amount is the lowest-precedence fee in `get_effective_fee`; whatever
+/// fee applies is always paid in `token` to `recipient`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    pub token: Address,
+    pub amount: i128,
+    pub recipient: Address,
+}
+
+/// Schema or subject whose attestations a rent sponsorship pool keeps alive.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RentTarget {
+    Schema(u64),
+    Subject(Address),
+}
+
+/// Token and keeper reward of the rent sponsorship pools; see
+/// `set_rent_sponsorship`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RentSponsorConfig {
+    pub token: Address,
+    /// Paid to a keeper per `interval` ledgers of lifetime added to an
+    /// attestation through `extend_sponsored_ttls`.
+    pub reward: i128,
+    /// Ledgers of added lifetime one reward pays for; extensions adding
+    /// fewer earn nothing.
+    pub interval: u32,
+}
+
+/// Fee escrow mode; see `set_fee_escrow`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeEscrowConfig {
+    /// Seconds after issuance during which the fee stays escrowed.
+    pub window: u64,
+    /// Receives the escrowed fee of an attestation a challenge invalidates.
+    pub challenger_pool: Address,
+}
+
+/// Deposit a relier locks per pin; see `set_pin_deposit`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PinDeposit {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Issuance fee held by the contract until `release_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowedFee {
+    pub token: Address,
+    pub amount: i128,
+    /// Fee recipient once the fee is released.
+    pub recipient: Address,
+    /// Escrow target if a challenge invalidates the attestation.
+    pub challenger_pool: Address,
+    pub release_at: u64,
+}
+
+/// Token holding required of attestation subjects; see `set_subject_gate`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubjectGate {
+    pub token: Address,
+    pub min_balance: i128,
+}
+
+/// Admin-tunable size limits on caller-supplied attestation fields.
+///
+/// Enforced by `validation::check_submission` on every issuance path. A
+/// value of `0` disables the corresponding limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FieldBounds {
+    /// Maximum `payload_hash` length in bytes.
+    pub max_payload_hash_len: u32,
+    /// Maximum `signature` length in bytes.
+    pub max_signature_len: u32,
+    /// Maximum number of claims in a single bundle.
+    pub max_bundle_claims: u32,
+}
+
+/// Issuance and revocation counts for one attestor on one UTC day.
+///
+/// `day` is the ledger timestamp divided by 86 400.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestorActivity {
+    pub day: u64,
+    pub issued: u32,
+    pub revoked: u32,
+}
+
+/// Signature scheme of an attestor's registered signing key.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KeyType {
+    /// 32-byte ed25519 public key; signatures are 64 bytes over the payload hash.
+    Ed25519,
+    /// 65-byte uncompressed SEC-1 secp256k1 public key; signatures are
+    /// 65 bytes `r || s || v` over `keccak256(payload_hash)`, as produced by
+    /// EVM wallets. `v` may be `0`/`1` or `27`/`28`.
+    Secp256k1,
+}
+
+/// Self-published profile of an attestor, for discovery.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestorInfo {
+    pub name: Bytes,
+    /// ed25519 signing key; all zeroes when none is registered or the
+    /// attestor signs with `secp256k1_key`.
+    pub public_key: BytesN<32>,
+    /// 65-byte uncompressed secp256k1 signing key; empty when the attestor
+    /// does not sign with one.
+    pub secp256k1_key: Bytes,
+    pub registered_at: u64,
+    pub uri: Bytes,
+}
+
+/// Aggregate issuance counters for an organization.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrgStats {
+    pub total_attestations: u64,
+    pub window_count: u32,
+    pub window_start_ledger: u32,
 }
 
 #[contracttype]
@@ -0,0 +1,60 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::errors::ErrorCodeExt;
+
+fn setup(env: &Env) -> AnchorKitContractClient<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    client
+}
+
+#[test]
+fn test_fresh_deployment_is_current() {
+    let env = Env::default();
+    let client = setup(&env);
+    assert_eq!(client.version(), 1);
+    assert_eq!(client.storage_version(), client.version());
+    assert_eq!(client.migrate(), client.version());
+    assert_eq!(client.storage_version(), client.version());
+}
+
+#[test]
+fn test_upgrade_requires_uploaded_wasm() {
+    let env = Env::default();
+    let client = setup(&env);
+    assert!(client.try_upgrade(&BytesN::from_array(&env, &[7u8; 32])).is_err());
+}
+
+#[test]
+fn test_unversioned_storage_blocks_writes() {
+    let env = Env::default();
+    let client = setup(&env);
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&crate::storage::key_storage_version(&env));
+    });
+    assert_eq!(client.storage_version(), 0);
+    let pending = Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::MigrationPending as u32)));
+    assert_eq!(client.try_set_max_page_size(&10), pending);
+    assert_eq!(
+        client.try_migrate(),
+        Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::NoMigrationPath as u32)))
+    );
+}
+
+#[test]
+fn test_migrate_rejects_newer_storage() {
+    let env = Env::default();
+    let client = setup(&env);
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&crate::storage::key_storage_version(&env), &2u32);
+    });
+    assert_eq!(
+        client.try_migrate(),
+        Err(Ok(soroban_sdk::Error::from_contract_error(ErrorCodeExt::NoMigrationPath as u32)))
+    );
+}
@@ -0,0 +1,78 @@
+//! Central size checks on caller-supplied attestation fields.
+//!
+//! Every issuance path calls [`check_submission`] before touching storage so
+//! that the admin-configured [`FieldBounds`] cap what a single call can write.
+
+use soroban_sdk::{symbol_short, Bytes, Env, Symbol, Vec};
+
+use crate::deterministic_hash::SIGNING_DOMAIN_PREFIX;
+use crate::errors::ErrorCode;
+use crate::events::FieldRejected;
+use crate::storage::key_field_bounds;
+use crate::types::{FieldBounds, MAX_INLINE_DATA_LEN, MAX_URI_LEN};
+
+/// Bounds in effect until the admin configures their own.
+pub const DEFAULT_FIELD_BOUNDS: FieldBounds = FieldBounds {
+    max_payload_hash_len: 128,
+    max_signature_len: 128,
+    max_bundle_claims: 64,
+};
+
+/// Currently configured bounds, falling back to [`DEFAULT_FIELD_BOUNDS`].
+pub fn field_bounds(env: &Env) -> FieldBounds {
+    env.storage()
+        .instance()
+        .get(&key_field_bounds(env))
+        .unwrap_or(DEFAULT_FIELD_BOUNDS)
+}
+
+/// Check the payload hash and signature of a submission against the bounds,
+/// and that the payload hash does not claim a reserved signing domain.
+pub fn check_submission(env: &Env, payload_hash: &Bytes, signature: &Bytes) -> Result<(), ErrorCode> {
+    let bounds = field_bounds(env);
+    check_len(env, symbol_short!("payload"), payload_hash.len(), bounds.max_payload_hash_len)?;
+    check_len(env, symbol_short!("signature"), signature.len(), bounds.max_signature_len)?;
+    let prefix_len = SIGNING_DOMAIN_PREFIX.len() as u32;
+    if payload_hash.len() >= prefix_len
+        && payload_hash.slice(0..prefix_len) == Bytes::from_slice(env, SIGNING_DOMAIN_PREFIX)
+    {
+        return Err(ErrorCode::ValidationError);
+    }
+    Ok(())
+}
+
+/// Check inline claim content: at most [`MAX_INLINE_DATA_LEN`] bytes and
+/// hashing (SHA-256) to the signed `payload_hash`.
+pub fn check_inline_data(env: &Env, payload_hash: &Bytes, data: &Bytes) -> Result<(), ErrorCode> {
+    check_len(env, symbol_short!("inline"), data.len(), MAX_INLINE_DATA_LEN)?;
+    let digest: Bytes = env.crypto().sha256(data).into();
+    if digest != *payload_hash {
+        return Err(ErrorCode::ValidationError);
+    }
+    Ok(())
+}
+
+/// Check a metadata URI is at most [`MAX_URI_LEN`] bytes.
+pub fn check_uri(env: &Env, uri: &Bytes) -> Result<(), ErrorCode> {
+    check_len(env, symbol_short!("uri"), uri.len(), MAX_URI_LEN)
+}
+
+/// Check a bundle's claim count, each claim hash and the signature against the bounds.
+pub fn check_bundle(env: &Env, claims: &Vec<(u64, Bytes)>, signature: &Bytes) -> Result<(), ErrorCode> {
+    let bounds = field_bounds(env);
+    check_len(env, symbol_short!("claims"), claims.len(), bounds.max_bundle_claims)?;
+    for (_, claim_hash) in claims.iter() {
+        check_len(env, symbol_short!("claim"), claim_hash.len(), bounds.max_payload_hash_len)?;
+    }
+    check_len(env, symbol_short!("signature"), signature.len(), bounds.max_signature_len)
+}
+
+/// Publishes a `reject/field` event naming `field` when it is over `max`.
+fn check_len(env: &Env, field: Symbol, len: u32, max: u32) -> Result<(), ErrorCode> {
+    if max > 0 && len > max {
+        env.events()
+            .publish((symbol_short!("reject"), symbol_short!("field")), FieldRejected { field, len, max });
+        return Err(ErrorCode::FieldTooLarge);
+    }
+    Ok(())
+}
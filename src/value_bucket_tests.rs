@@ -0,0 +1,119 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env, String,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, SigningKey, u64) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    let schema_id = client.register_schema(
+        &Address::generate(env),
+        &Bytes::from_array(env, &[1u8; 32]),
+        &String::from_str(env, "ipfs://audit-score"),
+    );
+    client.set_schema_value_buckets(&schema_id, &10);
+    (client, attestor, sk, schema_id)
+}
+
+fn scored(env: &Env, client: &AnchorKitContractClient, attestor: &Address, sk: &SigningKey, subject: &Address, (seed, score): (u8, u32), schema_id: u64) -> u64 {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    let input = AttestationInput::new(subject.clone(), NOW, payload, sig)
+        .with_schema(schema_id)
+        .with_value(score);
+    client.record_attestation(attestor, &input).id
+}
+
+#[test]
+fn test_count_subjects_above_threshold() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    for entry in [(1u8, 95u32), (2, 80), (3, 79), (4, 40)] {
+        scored(&env, &client, &attestor, &sk, &Address::generate(&env), entry, schema_id);
+    }
+    assert_eq!(client.count_subjects_above(&schema_id, &80), 2);
+    assert_eq!(client.count_subjects_above(&schema_id, &0), 4);
+    assert!(client.try_count_subjects_above(&schema_id, &160).is_err());
+    let buckets = client.get_value_buckets(&schema_id);
+    assert_eq!((buckets.get(7).unwrap(), buckets.get(8).unwrap()), (1, 1));
+    assert!(client.try_count_subjects_above(&schema_id, &85).is_err());
+}
+
+#[test]
+fn test_overflow_bucket_counts_at_top_threshold() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    scored(&env, &client, &attestor, &sk, &Address::generate(&env), (1, 500), schema_id);
+    scored(&env, &client, &attestor, &sk, &Address::generate(&env), (2, 150), schema_id);
+    assert_eq!(client.get_value_buckets(&schema_id).get(15).unwrap(), 2);
+    assert_eq!(client.count_subjects_above(&schema_id, &150), 2);
+}
+
+#[test]
+fn test_subject_counts_once_with_latest_value() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    let subject = Address::generate(&env);
+    scored(&env, &client, &attestor, &sk, &subject, (1, 60), schema_id);
+    let latest = scored(&env, &client, &attestor, &sk, &subject, (2, 90), schema_id);
+    assert_eq!(client.count_subjects_above(&schema_id, &0), 1);
+    assert_eq!(client.count_subjects_above(&schema_id, &90), 1);
+
+    client.revoke_attestation(&attestor, &latest);
+    assert_eq!(client.count_subjects_above(&schema_id, &0), 0);
+}
+
+#[test]
+fn test_bucket_width_locked_after_issuance() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    scored(&env, &client, &attestor, &sk, &Address::generate(&env), (1, 50), schema_id);
+    assert!(client.try_set_schema_value_buckets(&schema_id, &5).is_err());
+}
+
+#[test]
+fn test_lazily_invalidated_values_can_be_released() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    let subject = Address::generate(&env);
+    scored(&env, &client, &attestor, &sk, &subject, (1, 70), schema_id);
+    client.revoke_all_by_schema(&attestor, &schema_id);
+    assert_eq!(client.count_subjects_above(&schema_id, &0), 1);
+
+    client.release_subject_value(&subject, &schema_id);
+    assert_eq!(client.count_subjects_above(&schema_id, &0), 0);
+}
+
+#[test]
+fn test_refreshed_expiry_releases_value() {
+    let env = Env::default();
+    let (client, attestor, sk, schema_id) = setup(&env);
+    let subject = Address::generate(&env);
+    let payload = Bytes::from_array(&env, &[1u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    let input = AttestationInput::new(subject, NOW, payload, sig)
+        .with_schema(schema_id)
+        .with_value(50)
+        .with_expiry(NOW + 60);
+    let id = client.record_attestation(&attestor, &input).id;
+
+    env.ledger().with_mut(|li| li.timestamp = NOW + 60);
+    client.refresh_status(&id);
+    assert_eq!(client.count_subjects_above(&schema_id, &0), 0);
+}
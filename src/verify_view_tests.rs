@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+use anchorkit_interface::AnchorKitClient;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, BytesN, Env, String,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+
+struct Setup<'a> {
+    client: AnchorKitContractClient<'a>,
+    consumer: AnchorKitClient<'a>,
+    attestor: Address,
+    sk: SigningKey,
+    kyc: u64,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    let kyc = client.register_schema(&Address::generate(env), &Bytes::from_array(env, &[1u8; 32]), &String::from_str(env, "kyc"));
+    Setup { client, consumer: AnchorKitClient::new(env, &contract_id), attestor, sk, kyc }
+}
+
+fn input(env: &Env, sk: &SigningKey, subject: &Address, seed: u8) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(subject.clone(), NOW, payload, sig)
+}
+
+#[test]
+fn test_verify_matches_hash_of_valid_attestation() {
+    let env = Env::default();
+    let s = setup(&env);
+    let id = s.client.record_attestation(&s.attestor, &input(&env, &s.sk, &Address::generate(&env), 7)).id;
+    assert!(s.consumer.verify(&id, &BytesN::from_array(&env, &[7u8; 32])));
+    assert!(!s.consumer.verify(&id, &BytesN::from_array(&env, &[8u8; 32])));
+    assert!(!s.consumer.verify(&(id + 1), &BytesN::from_array(&env, &[7u8; 32])));
+
+    s.client.revoke_attestation(&s.attestor, &id);
+    assert!(!s.consumer.verify(&id, &BytesN::from_array(&env, &[7u8; 32])));
+}
+
+#[test]
+fn test_verify_for_subject_by_schema() {
+    let env = Env::default();
+    let s = setup(&env);
+    let subject = Address::generate(&env);
+    s.client.record_attestation(&s.attestor, &input(&env, &s.sk, &subject, 1));
+    assert!(!s.consumer.verify_for_subject(&subject, &s.kyc));
+
+    let id = s.client.record_attestation(&s.attestor, &input(&env, &s.sk, &subject, 2).with_schema(s.kyc)).id;
+    assert!(s.consumer.verify_for_subject(&subject, &s.kyc));
+    assert!(!s.consumer.verify_for_subject(&Address::generate(&env), &s.kyc));
+
+    s.client.revoke_attestation(&s.attestor, &id);
+    assert!(!s.consumer.verify_for_subject(&subject, &s.kyc));
+}
@@ -0,0 +1,122 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, Env, Symbol,
+};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::contract::{AnchorKitContract, AnchorKitContractClient};
+use crate::errors::ErrorCode;
+use crate::sep10_test_util::{register_attestor_with_sep10, sign_payload};
+use crate::types::{AttestationDisclosure, AttestationInput, RedactedAttestation, Status};
+
+const NOW: u64 = 1_700_000_000;
+
+fn setup(env: &Env) -> (AnchorKitContractClient<'_>, Address, Address, u64) {
+    let (client, attestor, sk) = setup_attestor(env);
+    let subject = Address::generate(env);
+    let payload = Bytes::from_array(env, &[1u8; 32]);
+    let sig = sign_payload(env, &sk, &payload);
+    let id = client.submit_attestation(&attestor, &subject, &NOW, &payload, &sig);
+    (client, attestor, subject, id)
+}
+
+fn setup_attestor(env: &Env) -> (AnchorKitContractClient<'_>, Address, SigningKey) {
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = NOW);
+    let contract_id = env.register_contract(None, AnchorKitContract);
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &100_u64, &None);
+    let attestor = Address::generate(env);
+    let sk = SigningKey::generate(&mut OsRng);
+    register_attestor_with_sep10(env, &client, &attestor, &attestor, &sk);
+    (client, attestor, sk)
+}
+
+#[test]
+fn test_hidden_record_redacted_for_outsiders() {
+    let env = Env::default();
+    let (client, attestor, subject, id) = setup(&env);
+    client.set_visibility(&subject, &id, &true);
+    assert!(client.is_hidden(&id));
+    let withheld = client.get_attestation(&id).unwrap();
+    assert_eq!(withheld.issuer, attestor);
+    assert_ne!(withheld.subject, subject);
+    assert!(withheld.payload_hash.is_empty() && withheld.signature.is_empty());
+    assert_eq!(client.list_attestations(&subject, &0, &10).len(), 0);
+
+    let outsider = Address::generate(&env);
+    let Some(AttestationDisclosure::Redacted(redacted)) = client.get_attestation_as(&outsider, &id) else {
+        panic!("expected a redacted record");
+    };
+    assert_eq!(redacted, RedactedAttestation { id, issuer: attestor.clone(), status: Status::Active });
+    for viewer in [&subject, &attestor] {
+        assert!(matches!(client.get_attestation_as(viewer, &id), Some(AttestationDisclosure::Full(_))));
+    }
+    // Hiding withholds content; the credential itself stays valid.
+    assert!(client.verify_for_holder(&id, &subject));
+    assert!(client.is_valid(&id));
+    assert!(client.get_proof_bundle(&id).unwrap().validity.is_valid);
+}
+
+#[test]
+fn test_redacted_status_is_current() {
+    let env = Env::default();
+    let (client, _, subject, id) = setup(&env);
+    client.set_visibility(&subject, &id, &true);
+    client.set_attestation_max_age(&100);
+    env.ledger().with_mut(|li| li.timestamp = NOW + 101);
+    let Some(AttestationDisclosure::Redacted(redacted)) = client.get_attestation_as(&Address::generate(&env), &id) else {
+        panic!("expected a redacted record");
+    };
+    assert_eq!(redacted.status, Status::Expired);
+}
+
+#[test]
+fn test_reveal_restores_public_reads() {
+    let env = Env::default();
+    let (client, _, subject, id) = setup(&env);
+    client.set_visibility(&subject, &id, &true);
+    client.set_visibility(&subject, &id, &false);
+    assert!(!client.is_hidden(&id));
+    assert_eq!(client.get_attestation(&id).unwrap().subject, subject);
+}
+
+#[test]
+fn test_only_subject_sets_visibility() {
+    let env = Env::default();
+    let (client, attestor, _, id) = setup(&env);
+    let err = client.try_set_visibility(&attestor, &id, &true);
+    assert_eq!(err.err(), Some(Ok(soroban_sdk::Error::from_contract_error(ErrorCode::NotAttestationSubject as u32))));
+}
+
+#[test]
+fn test_hidden_record_not_exported() {
+    let env = Env::default();
+    let (client, _, subject, id) = setup(&env);
+    client.set_visibility(&subject, &id, &true);
+    let err = client.try_export_attestation(&id);
+    assert_eq!(err.err(), Some(Ok(soroban_sdk::Error::from_contract_error(ErrorCode::AttestationNotFound as u32))));
+}
+
+#[test]
+fn test_hidden_record_skipped_by_asset_and_jurisdiction_listings() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env);
+    let asset = Address::generate(&env);
+    let jurisdiction = Symbol::new(&env, "EU");
+    let payload = Bytes::from_array(&env, &[2u8; 32]);
+    let sig = sign_payload(&env, &sk, &payload);
+    let input = AttestationInput::new(asset.clone(), NOW, payload, sig)
+        .with_asset_subject()
+        .with_jurisdiction(jurisdiction.clone());
+    let id = client.record_attestation(&attestor, &input).id;
+    assert_eq!(client.list_asset_attestations(&asset, &0, &10).len(), 1);
+    assert_eq!(client.list_by_jurisdiction(&jurisdiction, &0, &10).len(), 1);
+
+    client.set_visibility(&asset, &id, &true);
+    assert_eq!(client.list_asset_attestations(&asset, &0, &10).len(), 0);
+    assert_eq!(client.list_by_jurisdiction(&jurisdiction, &0, &10).len(), 0);
+}
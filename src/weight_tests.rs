@@ -0,0 +1,48 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Bytes, Env};
+use ed25519_dalek::SigningKey;
+
+use crate::sep10_test_util::{setup_attestor, sign_payload};
+use anchorkit_interface::MAX_WEIGHT_BPS;
+
+use crate::types::AttestationInput;
+
+const NOW: u64 = 1_700_000_000;
+
+fn input(env: &Env, sk: &SigningKey, seed: u8) -> AttestationInput {
+    let payload = Bytes::from_array(env, &[seed; 32]);
+    let sig = sign_payload(env, sk, &payload);
+    AttestationInput::new(Address::generate(env), NOW, payload, sig)
+}
+
+#[test]
+fn test_weight_defaults_to_full_confidence() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let id = client.record_attestation(&attestor, &input(&env, &sk, 1)).id;
+
+    let view = client.get_attestation_v2(&id).unwrap();
+    assert_eq!(view.attestation.weight_bps, MAX_WEIGHT_BPS);
+    assert_eq!(view.validity.weight_bps, MAX_WEIGHT_BPS);
+}
+
+#[test]
+fn test_weight_surfaced_in_validity() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    let id = client.record_attestation(&attestor, &input(&env, &sk, 1).with_weight(7_250)).id;
+
+    let view = client.get_attestation_v2(&id).unwrap();
+    assert_eq!(view.attestation.weight_bps, 7_250);
+    assert_eq!(view.validity.weight_bps, 7_250);
+    assert!(view.validity.is_valid);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #71)")]
+fn test_weight_above_max_rejected() {
+    let env = Env::default();
+    let (client, attestor, sk) = setup_attestor(&env, NOW);
+    client.record_attestation(&attestor, &input(&env, &sk, 1).with_weight(MAX_WEIGHT_BPS + 1));
+}